@@ -33,6 +33,7 @@ use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -147,6 +148,23 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.postgres_handler_tcp_keepalive_timeout_secs;
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -U root -h {} -p {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -292,6 +310,15 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -uroot -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U root -h {} -p {}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
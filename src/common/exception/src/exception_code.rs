@@ -144,6 +144,14 @@ build_exceptions! {
     UnmatchColumnDataType(1114),
     VirtualColumnNotFound(1115),
     VirtualColumnAlreadyExists(1116),
+    RecursiveCteDepthExceeded(1117),
+    InvalidTransactionState(1118),
+    CurrentTransactionIsAborted(1119),
+    IllegalStream(1120),
+    StreamVersionMismatched(1121),
+    GeometryError(1122),
+    UnknownRowAccessPolicy(1123),
+    IllegalRowAccessPolicy(1124),
 
     // Data Related Errors
 
@@ -232,6 +240,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
 
 
     // Cluster error codes.
@@ -293,6 +303,13 @@ build_exceptions! {
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
+    UserQuotaExceeded(2904),
+
+    // Workload group error codes.
+    IllegalWorkloadGroupFormat(2951),
+    UnknownWorkloadGroup(2952),
+    WorkloadGroupAlreadyExists(2953),
+    WorkloadGroupQueueTimeout(2954),
 
 }
 
@@ -0,0 +1,779 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between the text and binary representations of geometries.
+//!
+//! Geometries are stored as WKB (well-known binary) in little endian, and can be
+//! read from or written to WKT (well-known text), WKB and GeoJSON.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use geo_types::Coord;
+use geo_types::Geometry;
+use geo_types::GeometryCollection;
+use geo_types::LineString;
+use geo_types::MultiLineString;
+use geo_types::MultiPoint;
+use geo_types::MultiPolygon;
+use geo_types::Point;
+use geo_types::Polygon;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+/// Parse a geometry from WKT, GeoJSON or hex encoded WKB, and return it as WKB.
+pub fn parse_to_wkb(buf: &[u8]) -> Result<Vec<u8>> {
+    let text = std::str::from_utf8(buf)
+        .map_err(|e| ErrorCode::GeometryError(format!("invalid utf8 sequence: {e}")))?
+        .trim();
+    let geometry = if text.starts_with('{') {
+        geometry_from_geojson(text)?
+    } else if !text.is_empty() && text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let wkb = hex::decode(text)
+            .map_err(|e| ErrorCode::GeometryError(format!("invalid hex wkb: {e}")))?;
+        geometry_from_wkb(&wkb)?
+    } else {
+        geometry_from_wkt(text)?
+    };
+    Ok(geometry_to_wkb(&geometry))
+}
+
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String> {
+    Ok(geometry_to_wkt(&geometry_from_wkb(wkb)?))
+}
+
+pub fn wkb_to_geojson(wkb: &[u8]) -> Result<String> {
+    Ok(geometry_to_geojson(&geometry_from_wkb(wkb)?))
+}
+
+pub fn point_to_wkb(x: f64, y: f64) -> Vec<u8> {
+    geometry_to_wkb(&Geometry::Point(Point::new(x, y)))
+}
+
+pub fn geometry_to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_wkb(geometry, &mut buf);
+    buf
+}
+
+fn write_wkb(geometry: &Geometry<f64>, buf: &mut Vec<u8>) {
+    fn write_header(buf: &mut Vec<u8>, ty: u32) {
+        buf.push(1);
+        buf.extend_from_slice(&ty.to_le_bytes());
+    }
+    fn write_coord(buf: &mut Vec<u8>, coord: &Coord<f64>) {
+        buf.extend_from_slice(&coord.x.to_le_bytes());
+        buf.extend_from_slice(&coord.y.to_le_bytes());
+    }
+    fn write_coords(buf: &mut Vec<u8>, line: &LineString<f64>) {
+        buf.extend_from_slice(&(line.0.len() as u32).to_le_bytes());
+        for coord in line.0.iter() {
+            write_coord(buf, coord);
+        }
+    }
+    fn write_rings(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+        if polygon.exterior().0.is_empty() {
+            buf.extend_from_slice(&0u32.to_le_bytes());
+            return;
+        }
+        buf.extend_from_slice(&(polygon.interiors().len() as u32 + 1).to_le_bytes());
+        write_coords(buf, polygon.exterior());
+        for interior in polygon.interiors() {
+            write_coords(buf, interior);
+        }
+    }
+
+    match geometry {
+        Geometry::Point(point) => {
+            write_header(buf, WKB_POINT);
+            write_coord(buf, &point.0);
+        }
+        Geometry::LineString(line) => {
+            write_header(buf, WKB_LINESTRING);
+            write_coords(buf, line);
+        }
+        Geometry::Polygon(polygon) => {
+            write_header(buf, WKB_POLYGON);
+            write_rings(buf, polygon);
+        }
+        Geometry::MultiPoint(points) => {
+            write_header(buf, WKB_MULTIPOINT);
+            buf.extend_from_slice(&(points.0.len() as u32).to_le_bytes());
+            for point in points.0.iter() {
+                write_header(buf, WKB_POINT);
+                write_coord(buf, &point.0);
+            }
+        }
+        Geometry::MultiLineString(lines) => {
+            write_header(buf, WKB_MULTILINESTRING);
+            buf.extend_from_slice(&(lines.0.len() as u32).to_le_bytes());
+            for line in lines.0.iter() {
+                write_header(buf, WKB_LINESTRING);
+                write_coords(buf, line);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            write_header(buf, WKB_MULTIPOLYGON);
+            buf.extend_from_slice(&(polygons.0.len() as u32).to_le_bytes());
+            for polygon in polygons.0.iter() {
+                write_header(buf, WKB_POLYGON);
+                write_rings(buf, polygon);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            write_header(buf, WKB_GEOMETRYCOLLECTION);
+            buf.extend_from_slice(&(collection.0.len() as u32).to_le_bytes());
+            for geometry in collection.0.iter() {
+                write_wkb(geometry, buf);
+            }
+        }
+        Geometry::Line(line) => write_wkb(&Geometry::LineString(LineString::from(*line)), buf),
+        Geometry::Rect(rect) => write_wkb(&Geometry::Polygon(rect.to_polygon()), buf),
+        Geometry::Triangle(triangle) => write_wkb(&Geometry::Polygon(triangle.to_polygon()), buf),
+    }
+}
+
+pub fn geometry_from_wkb(wkb: &[u8]) -> Result<Geometry<f64>> {
+    let mut reader = WkbReader { buf: wkb, pos: 0 };
+    let geometry = reader.read_geometry()?;
+    if reader.pos != wkb.len() {
+        return Err(ErrorCode::GeometryError(
+            "invalid wkb: unexpected trailing bytes",
+        ));
+    }
+    Ok(geometry)
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        if self.pos + N > self.buf.len() {
+            return Err(ErrorCode::GeometryError(
+                "invalid wkb: unexpected end of data",
+            ));
+        }
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        self.pos += N;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_coord(&mut self, little_endian: bool) -> Result<Coord<f64>> {
+        let read_f64 = |reader: &mut Self| -> Result<f64> {
+            let bytes = reader.read_bytes::<8>()?;
+            Ok(if little_endian {
+                f64::from_le_bytes(bytes)
+            } else {
+                f64::from_be_bytes(bytes)
+            })
+        };
+        let x = read_f64(self)?;
+        let y = read_f64(self)?;
+        Ok(Coord { x, y })
+    }
+
+    fn read_line_string(&mut self, little_endian: bool) -> Result<LineString<f64>> {
+        let len = self.read_u32(little_endian)? as usize;
+        // Every coordinate takes 16 bytes, check the length before allocating.
+        if len > (self.buf.len() - self.pos) / 16 {
+            return Err(ErrorCode::GeometryError(
+                "invalid wkb: unexpected end of data",
+            ));
+        }
+        let coords = (0..len)
+            .map(|_| self.read_coord(little_endian))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LineString::new(coords))
+    }
+
+    fn read_polygon(&mut self, little_endian: bool) -> Result<Polygon<f64>> {
+        let num_rings = self.read_u32(little_endian)? as usize;
+        if num_rings == 0 {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = self.read_line_string(little_endian)?;
+        let interiors = (1..num_rings)
+            .map(|_| self.read_line_string(little_endian))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    fn read_geometries(&mut self, little_endian: bool) -> Result<Vec<Geometry<f64>>> {
+        let len = self.read_u32(little_endian)? as usize;
+        // Every geometry takes at least 5 bytes, check the length before allocating.
+        if len > (self.buf.len() - self.pos) / 5 {
+            return Err(ErrorCode::GeometryError(
+                "invalid wkb: unexpected end of data",
+            ));
+        }
+        (0..len).map(|_| self.read_geometry()).collect()
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>> {
+        let little_endian = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => {
+                return Err(ErrorCode::GeometryError(format!(
+                    "invalid wkb: unknown byte order {b}"
+                )));
+            }
+        };
+        let geometry = match self.read_u32(little_endian)? {
+            WKB_POINT => Geometry::Point(Point(self.read_coord(little_endian)?)),
+            WKB_LINESTRING => Geometry::LineString(self.read_line_string(little_endian)?),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon(little_endian)?),
+            WKB_MULTIPOINT => {
+                let points = self
+                    .read_geometries(little_endian)?
+                    .into_iter()
+                    .map(|geometry| match geometry {
+                        Geometry::Point(point) => Ok(point),
+                        _ => Err(ErrorCode::GeometryError(
+                            "invalid wkb: multipoint can only contain points",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTILINESTRING => {
+                let lines = self
+                    .read_geometries(little_endian)?
+                    .into_iter()
+                    .map(|geometry| match geometry {
+                        Geometry::LineString(line) => Ok(line),
+                        _ => Err(ErrorCode::GeometryError(
+                            "invalid wkb: multilinestring can only contain linestrings",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTIPOLYGON => {
+                let polygons = self
+                    .read_geometries(little_endian)?
+                    .into_iter()
+                    .map(|geometry| match geometry {
+                        Geometry::Polygon(polygon) => Ok(polygon),
+                        _ => Err(ErrorCode::GeometryError(
+                            "invalid wkb: multipolygon can only contain polygons",
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => Geometry::GeometryCollection(GeometryCollection(
+                self.read_geometries(little_endian)?,
+            )),
+            ty => {
+                return Err(ErrorCode::GeometryError(format!(
+                    "invalid wkb: unsupported geometry type {ty}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+}
+
+pub fn geometry_to_wkt(geometry: &Geometry<f64>) -> String {
+    let mut buf = String::new();
+    write_wkt(geometry, &mut buf);
+    buf
+}
+
+fn write_wkt(geometry: &Geometry<f64>, buf: &mut String) {
+    fn write_coord(buf: &mut String, coord: &Coord<f64>) {
+        buf.push_str(&format!("{} {}", coord.x, coord.y));
+    }
+    fn write_coords(buf: &mut String, line: &LineString<f64>) {
+        buf.push('(');
+        for (i, coord) in line.0.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            write_coord(buf, coord);
+        }
+        buf.push(')');
+    }
+    fn write_rings(buf: &mut String, polygon: &Polygon<f64>) {
+        buf.push('(');
+        write_coords(buf, polygon.exterior());
+        for interior in polygon.interiors() {
+            buf.push(',');
+            write_coords(buf, interior);
+        }
+        buf.push(')');
+    }
+    fn write_list<T>(
+        buf: &mut String,
+        name: &str,
+        items: &[T],
+        write_item: impl Fn(&mut String, &T),
+    ) {
+        buf.push_str(name);
+        if items.is_empty() {
+            buf.push_str(" EMPTY");
+            return;
+        }
+        buf.push('(');
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            write_item(buf, item);
+        }
+        buf.push(')');
+    }
+
+    match geometry {
+        Geometry::Point(point) => {
+            buf.push_str("POINT(");
+            write_coord(buf, &point.0);
+            buf.push(')');
+        }
+        Geometry::LineString(line) => {
+            if line.0.is_empty() {
+                buf.push_str("LINESTRING EMPTY");
+            } else {
+                buf.push_str("LINESTRING");
+                write_coords(buf, line);
+            }
+        }
+        Geometry::Polygon(polygon) => {
+            if polygon.exterior().0.is_empty() {
+                buf.push_str("POLYGON EMPTY");
+            } else {
+                buf.push_str("POLYGON");
+                write_rings(buf, polygon);
+            }
+        }
+        Geometry::MultiPoint(points) => write_list(buf, "MULTIPOINT", &points.0, |buf, point| {
+            buf.push('(');
+            write_coord(buf, &point.0);
+            buf.push(')');
+        }),
+        Geometry::MultiLineString(lines) => {
+            write_list(buf, "MULTILINESTRING", &lines.0, write_coords)
+        }
+        Geometry::MultiPolygon(polygons) => {
+            write_list(buf, "MULTIPOLYGON", &polygons.0, write_rings)
+        }
+        Geometry::GeometryCollection(collection) => {
+            write_list(buf, "GEOMETRYCOLLECTION", &collection.0, |buf, geometry| {
+                write_wkt(geometry, buf)
+            })
+        }
+        Geometry::Line(line) => write_wkt(&Geometry::LineString(LineString::from(*line)), buf),
+        Geometry::Rect(rect) => write_wkt(&Geometry::Polygon(rect.to_polygon()), buf),
+        Geometry::Triangle(triangle) => write_wkt(&Geometry::Polygon(triangle.to_polygon()), buf),
+    }
+}
+
+pub fn geometry_from_wkt(wkt: &str) -> Result<Geometry<f64>> {
+    let mut parser = WktParser {
+        tokens: tokenize_wkt(wkt)?,
+        pos: 0,
+    };
+    let geometry = parser.parse_geometry()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(ErrorCode::GeometryError(format!(
+            "invalid wkt {wkt:?}: unexpected trailing characters"
+        )));
+    }
+    Ok(geometry)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum WktToken {
+    Word(String),
+    Number(f64),
+    LParen,
+    RParen,
+    Comma,
+}
+
+fn tokenize_wkt(wkt: &str) -> Result<Vec<WktToken>> {
+    let mut tokens = vec![];
+    let mut chars = wkt.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '(' => tokens.push(WktToken::LParen),
+            ')' => tokens.push(WktToken::RParen),
+            ',' => tokens.push(WktToken::Comma),
+            c if c.is_whitespace() => {}
+            c if c.is_ascii_alphabetic() => {
+                let mut end = start + 1;
+                while let Some((i, c)) = chars.peek() {
+                    if !c.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = i + 1;
+                    chars.next();
+                }
+                tokens.push(WktToken::Word(wkt[start..end].to_ascii_uppercase()));
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let mut end = start + 1;
+                while let Some((i, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || *c == '.' || *c == '-' || *c == '+') {
+                        break;
+                    }
+                    end = i + 1;
+                    chars.next();
+                }
+                let number = wkt[start..end].parse::<f64>().map_err(|_| {
+                    ErrorCode::GeometryError(format!(
+                        "invalid wkt {wkt:?}: invalid number {}",
+                        &wkt[start..end]
+                    ))
+                })?;
+                tokens.push(WktToken::Number(number));
+            }
+            c => {
+                return Err(ErrorCode::GeometryError(format!(
+                    "invalid wkt {wkt:?}: unexpected character {c:?}"
+                )));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct WktParser {
+    tokens: Vec<WktToken>,
+    pos: usize,
+}
+
+impl WktParser {
+    fn error(&self, expected: &str) -> ErrorCode {
+        match self.tokens.get(self.pos) {
+            Some(token) => ErrorCode::GeometryError(format!(
+                "invalid wkt: expected {expected}, but got {token:?}"
+            )),
+            None => ErrorCode::GeometryError(format!(
+                "invalid wkt: expected {expected}, but got end of input"
+            )),
+        }
+    }
+
+    fn next_is(&self, token: &WktToken) -> bool {
+        self.tokens.get(self.pos) == Some(token)
+    }
+
+    fn expect(&mut self, token: WktToken, expected: &str) -> Result<()> {
+        if self.next_is(&token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    /// Consume the `EMPTY` keyword, or the opening parenthesis of a non-empty geometry.
+    fn parse_empty(&mut self) -> Result<bool> {
+        if self.next_is(&WktToken::Word("EMPTY".to_string())) {
+            self.pos += 1;
+            return Ok(true);
+        }
+        self.expect(WktToken::LParen, "'(' or EMPTY")?;
+        Ok(false)
+    }
+
+    /// Parse a comma separated list terminated by a closing parenthesis.
+    fn parse_list<T>(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![parse_item(self)?];
+        while self.next_is(&WktToken::Comma) {
+            self.pos += 1;
+            items.push(parse_item(self)?);
+        }
+        self.expect(WktToken::RParen, "',' or ')'")?;
+        Ok(items)
+    }
+
+    fn parse_number(&mut self) -> Result<f64> {
+        match self.tokens.get(self.pos) {
+            Some(WktToken::Number(n)) => {
+                self.pos += 1;
+                Ok(*n)
+            }
+            _ => Err(self.error("number")),
+        }
+    }
+
+    fn parse_coord(&mut self) -> Result<Coord<f64>> {
+        let x = self.parse_number()?;
+        let y = self.parse_number()?;
+        if matches!(self.tokens.get(self.pos), Some(WktToken::Number(_))) {
+            return Err(ErrorCode::GeometryError(
+                "invalid wkt: only 2D coordinates are supported",
+            ));
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn parse_line_string(&mut self) -> Result<LineString<f64>> {
+        if self.parse_empty()? {
+            return Ok(LineString::new(vec![]));
+        }
+        Ok(LineString::new(self.parse_list(Self::parse_coord)?))
+    }
+
+    fn parse_polygon(&mut self) -> Result<Polygon<f64>> {
+        if self.parse_empty()? {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let mut rings = self.parse_list(Self::parse_line_string)?;
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    fn parse_geometry(&mut self) -> Result<Geometry<f64>> {
+        let name = match self.tokens.get(self.pos) {
+            Some(WktToken::Word(name)) => name.clone(),
+            _ => return Err(self.error("geometry type")),
+        };
+        self.pos += 1;
+        if let Some(WktToken::Word(dim)) = self.tokens.get(self.pos) {
+            if dim == "Z" || dim == "M" || dim == "ZM" {
+                return Err(ErrorCode::GeometryError(
+                    "invalid wkt: only 2D coordinates are supported",
+                ));
+            }
+        }
+        let geometry = match name.as_str() {
+            "POINT" => {
+                if self.parse_empty()? {
+                    return Err(ErrorCode::GeometryError(
+                        "invalid wkt: empty point is not supported",
+                    ));
+                }
+                let coord = self.parse_coord()?;
+                self.expect(WktToken::RParen, "')'")?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" => Geometry::LineString(self.parse_line_string()?),
+            "POLYGON" => Geometry::Polygon(self.parse_polygon()?),
+            "MULTIPOINT" => {
+                let points = if self.parse_empty()? {
+                    vec![]
+                } else {
+                    // Both `MULTIPOINT((1 2),(3 4))` and `MULTIPOINT(1 2,3 4)` are accepted.
+                    self.parse_list(|parser| {
+                        if parser.next_is(&WktToken::LParen) {
+                            parser.pos += 1;
+                            let coord = parser.parse_coord()?;
+                            parser.expect(WktToken::RParen, "')'")?;
+                            Ok(Point(coord))
+                        } else {
+                            Ok(Point(parser.parse_coord()?))
+                        }
+                    })?
+                };
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            "MULTILINESTRING" => {
+                let lines = if self.parse_empty()? {
+                    vec![]
+                } else {
+                    self.parse_list(Self::parse_line_string)?
+                };
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            "MULTIPOLYGON" => {
+                let polygons = if self.parse_empty()? {
+                    vec![]
+                } else {
+                    self.parse_list(Self::parse_polygon)?
+                };
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            "GEOMETRYCOLLECTION" => {
+                let geometries = if self.parse_empty()? {
+                    vec![]
+                } else {
+                    self.parse_list(Self::parse_geometry)?
+                };
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            _ => {
+                return Err(ErrorCode::GeometryError(format!(
+                    "invalid wkt: unknown geometry type {name}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+}
+
+pub fn geometry_to_geojson(geometry: &Geometry<f64>) -> String {
+    geometry_to_json_value(geometry).to_string()
+}
+
+fn geometry_to_json_value(geometry: &Geometry<f64>) -> JsonValue {
+    fn coord(coord: &Coord<f64>) -> JsonValue {
+        json!([coord.x, coord.y])
+    }
+    fn coords(line: &LineString<f64>) -> JsonValue {
+        JsonValue::Array(line.0.iter().map(coord).collect())
+    }
+    fn rings(polygon: &Polygon<f64>) -> JsonValue {
+        if polygon.exterior().0.is_empty() {
+            return JsonValue::Array(vec![]);
+        }
+        let mut rings = vec![coords(polygon.exterior())];
+        rings.extend(polygon.interiors().iter().map(coords));
+        JsonValue::Array(rings)
+    }
+
+    match geometry {
+        Geometry::Point(point) => json!({"type": "Point", "coordinates": coord(&point.0)}),
+        Geometry::LineString(line) => json!({"type": "LineString", "coordinates": coords(line)}),
+        Geometry::Polygon(polygon) => json!({"type": "Polygon", "coordinates": rings(polygon)}),
+        Geometry::MultiPoint(points) => json!({
+            "type": "MultiPoint",
+            "coordinates": points.0.iter().map(|point| coord(&point.0)).collect::<Vec<_>>(),
+        }),
+        Geometry::MultiLineString(lines) => json!({
+            "type": "MultiLineString",
+            "coordinates": lines.0.iter().map(coords).collect::<Vec<_>>(),
+        }),
+        Geometry::MultiPolygon(polygons) => json!({
+            "type": "MultiPolygon",
+            "coordinates": polygons.0.iter().map(rings).collect::<Vec<_>>(),
+        }),
+        Geometry::GeometryCollection(collection) => json!({
+            "type": "GeometryCollection",
+            "geometries": collection.0.iter().map(geometry_to_json_value).collect::<Vec<_>>(),
+        }),
+        Geometry::Line(line) => {
+            geometry_to_json_value(&Geometry::LineString(LineString::from(*line)))
+        }
+        Geometry::Rect(rect) => geometry_to_json_value(&Geometry::Polygon(rect.to_polygon())),
+        Geometry::Triangle(triangle) => {
+            geometry_to_json_value(&Geometry::Polygon(triangle.to_polygon()))
+        }
+    }
+}
+
+pub fn geometry_from_geojson(geojson: &str) -> Result<Geometry<f64>> {
+    let value: JsonValue = serde_json::from_str(geojson)
+        .map_err(|e| ErrorCode::GeometryError(format!("invalid geojson: {e}")))?;
+    geometry_from_json_value(&value)
+}
+
+fn geometry_from_json_value(value: &JsonValue) -> Result<Geometry<f64>> {
+    fn invalid(msg: &str) -> ErrorCode {
+        ErrorCode::GeometryError(format!("invalid geojson: {msg}"))
+    }
+    fn array(value: &JsonValue) -> Result<&Vec<JsonValue>> {
+        value
+            .as_array()
+            .ok_or_else(|| invalid("coordinates must be an array"))
+    }
+    fn coord(value: &JsonValue) -> Result<Coord<f64>> {
+        match array(value)?.as_slice() {
+            [x, y] => match (x.as_f64(), y.as_f64()) {
+                (Some(x), Some(y)) => Ok(Coord { x, y }),
+                _ => Err(invalid("coordinate must be numbers")),
+            },
+            _ => Err(invalid("only 2D coordinates are supported")),
+        }
+    }
+    fn coords(value: &JsonValue) -> Result<LineString<f64>> {
+        Ok(LineString::new(
+            array(value)?.iter().map(coord).collect::<Result<_>>()?,
+        ))
+    }
+    fn polygon(value: &JsonValue) -> Result<Polygon<f64>> {
+        let mut rings = array(value)?
+            .iter()
+            .map(coords)
+            .collect::<Result<Vec<_>>>()?;
+        if rings.is_empty() {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    let object = value
+        .as_object()
+        .ok_or_else(|| invalid("geometry must be an object"))?;
+    let ty = object
+        .get("type")
+        .and_then(|ty| ty.as_str())
+        .ok_or_else(|| invalid("missing geometry type"))?;
+    if ty == "GeometryCollection" {
+        let geometries = object
+            .get("geometries")
+            .and_then(|geometries| geometries.as_array())
+            .ok_or_else(|| invalid("missing geometries"))?
+            .iter()
+            .map(geometry_from_json_value)
+            .collect::<Result<Vec<_>>>()?;
+        return Ok(Geometry::GeometryCollection(GeometryCollection(geometries)));
+    }
+
+    let coordinates = object
+        .get("coordinates")
+        .ok_or_else(|| invalid("missing coordinates"))?;
+    let geometry = match ty {
+        "Point" => Geometry::Point(Point(coord(coordinates)?)),
+        "LineString" => Geometry::LineString(coords(coordinates)?),
+        "Polygon" => Geometry::Polygon(polygon(coordinates)?),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint(
+            array(coordinates)?
+                .iter()
+                .map(|value| Ok(Point(coord(value)?)))
+                .collect::<Result<_>>()?,
+        )),
+        "MultiLineString" => Geometry::MultiLineString(MultiLineString(
+            array(coordinates)?
+                .iter()
+                .map(coords)
+                .collect::<Result<_>>()?,
+        )),
+        "MultiPolygon" => Geometry::MultiPolygon(MultiPolygon(
+            array(coordinates)?
+                .iter()
+                .map(polygon)
+                .collect::<Result<_>>()?,
+        )),
+        _ => return Err(invalid(&format!("unknown geometry type {ty}"))),
+    };
+    Ok(geometry)
+}
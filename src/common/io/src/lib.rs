@@ -34,6 +34,7 @@ pub mod cursor_ext;
 mod decimal;
 mod escape;
 mod format_settings;
+pub mod geometry;
 mod position;
 mod serialization;
 mod stat_buffer;
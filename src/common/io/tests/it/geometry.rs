@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_io::geometry::parse_to_wkb;
+use common_io::geometry::point_to_wkb;
+use common_io::geometry::wkb_to_geojson;
+use common_io::geometry::wkb_to_wkt;
+
+#[test]
+fn test_wkt_round_trip() -> Result<()> {
+    let cases = [
+        "POINT(1 2)",
+        "POINT(-1.5 0.25)",
+        "LINESTRING(1 2,3 4)",
+        "LINESTRING EMPTY",
+        "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 1,2 2,1 1))",
+        "MULTIPOINT((1 2),(3 4))",
+        "MULTILINESTRING((1 2,3 4),(5 6,7 8))",
+        "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))",
+        "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(1 2,3 4))",
+        "GEOMETRYCOLLECTION EMPTY",
+    ];
+    for wkt in cases {
+        let wkb = parse_to_wkb(wkt.as_bytes())?;
+        assert_eq!(wkb_to_wkt(&wkb)?, wkt);
+    }
+
+    let cases = [
+        ("point ( 1.5   -2 )", "POINT(1.5 -2)"),
+        ("MultiPoint(1 2, 3 4)", "MULTIPOINT((1 2),(3 4))"),
+        ("  LINESTRING (1e2 2, 3 4)  ", "LINESTRING(100 2,3 4)"),
+    ];
+    for (input, expected) in cases {
+        let wkb = parse_to_wkb(input.as_bytes())?;
+        assert_eq!(wkb_to_wkt(&wkb)?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_wkb() -> Result<()> {
+    let little_endian = "0101000000000000000000F03F0000000000000040";
+    let big_endian = "00000000013FF00000000000004000000000000000";
+
+    let wkb = point_to_wkb(1.0, 2.0);
+    assert_eq!(hex::encode_upper(&wkb), little_endian);
+    assert_eq!(parse_to_wkb(little_endian.as_bytes())?, wkb);
+    // Big endian input is normalized to little endian.
+    assert_eq!(parse_to_wkb(big_endian.as_bytes())?, wkb);
+    assert_eq!(wkb_to_wkt(&wkb)?, "POINT(1 2)");
+    Ok(())
+}
+
+#[test]
+fn test_geojson() -> Result<()> {
+    let cases = [
+        (r#"{"type":"Point","coordinates":[1.0,2.0]}"#, "POINT(1 2)"),
+        (
+            r#"{"type":"Polygon","coordinates":[[[0.0,0.0],[1.0,0.0],[1.0,1.0],[0.0,0.0]]]}"#,
+            "POLYGON((0 0,1 0,1 1,0 0))",
+        ),
+        (
+            r#"{"type":"GeometryCollection","geometries":[{"type":"MultiPoint","coordinates":[[1.0,2.0],[3.0,4.0]]}]}"#,
+            "GEOMETRYCOLLECTION(MULTIPOINT((1 2),(3 4)))",
+        ),
+    ];
+    for (geojson, wkt) in cases {
+        let wkb = parse_to_wkb(geojson.as_bytes())?;
+        assert_eq!(wkb_to_wkt(&wkb)?, wkt);
+        assert_eq!(wkb_to_geojson(&wkb)?, geojson);
+    }
+
+    // Integer coordinates and extra whitespace are accepted.
+    let wkb = parse_to_wkb(br#" { "type": "LineString", "coordinates": [[1, 2], [3, 4]] } "#)?;
+    assert_eq!(wkb_to_wkt(&wkb)?, "LINESTRING(1 2,3 4)");
+    Ok(())
+}
+
+#[test]
+fn test_invalid_geometry() {
+    let cases = [
+        "",
+        "POINT(1)",
+        "POINT(1 2 3)",
+        "POINT Z(1 2 3)",
+        "POINT EMPTY",
+        "POINT(1 2",
+        "POINT(1 2) POINT(3 4)",
+        "CIRCLE(1 2)",
+        "LINESTRING(1 2,)",
+        "0101000000000000000000F03F",
+        "01FF000000000000000000F03F0000000000000040",
+        r#"{"type": "Point", "coordinates": [1, 2, 3]}"#,
+        r#"{"type": "Point"}"#,
+        r#"{"type": "Circle", "coordinates": [1, 2]}"#,
+    ];
+    for input in cases {
+        let result = parse_to_wkb(input.as_bytes());
+        assert!(result.is_err(), "{input:?} should be rejected");
+        assert_eq!(result.unwrap_err().code(), 1122);
+    }
+}
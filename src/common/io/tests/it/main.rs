@@ -18,3 +18,4 @@ mod binary_read;
 mod binary_write;
 mod cursor_ext;
 mod escape;
+mod geometry;
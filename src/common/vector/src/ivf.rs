@@ -0,0 +1,257 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::VectorMetric;
+
+/// Maximum number of lists of an index.
+const MAX_LISTS: usize = 32;
+/// Maximum number of vectors used to train the centroids.
+const MAX_TRAINING_VECTORS: usize = 2048;
+/// Number of k-means iterations used to train the centroids.
+const TRAINING_ITERATIONS: usize = 5;
+
+/// An inverted file (IVF) index over a set of vectors.
+///
+/// The vectors are clustered by k-means, and each cluster (list) keeps the
+/// offsets of its vectors. A search only probes the lists whose centroids
+/// are nearest to the query, so the result is approximate.
+///
+/// The centroids are trained with euclidean distance, so the same index
+/// serves all the [`VectorMetric`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IvfIndex {
+    dimension: usize,
+    centroids: Vec<Vec<f32>>,
+    lists: Vec<Vec<u32>>,
+}
+
+impl IvfIndex {
+    pub const VERSION: u8 = 1;
+
+    /// Builds an index over `(offset, vector)` pairs.
+    ///
+    /// The dimension of the index is the one of the first vector, vectors with
+    /// another dimension are not indexed. Returns `None` if there is no vector.
+    pub fn build(vectors: &[(u32, &[f32])]) -> Option<Self> {
+        let dimension = vectors.first()?.1.len();
+        let vectors = vectors
+            .iter()
+            .filter(|(_, v)| v.len() == dimension)
+            .collect::<Vec<_>>();
+
+        let num_lists = ((vectors.len() as f64).sqrt() as usize).clamp(1, MAX_LISTS);
+        let step = std::cmp::max(vectors.len() / MAX_TRAINING_VECTORS, 1);
+        let training = vectors
+            .iter()
+            .step_by(step)
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+
+        // Initialize the centroids by farthest point sampling, which spreads them
+        // like k-means++ but always builds the same index from the same data.
+        let mut centroids = vec![training[0].to_vec()];
+        let mut min_distances = training
+            .iter()
+            .map(|v| squared_l2(&centroids[0], v))
+            .collect::<Vec<_>>();
+        while centroids.len() < num_lists {
+            let (farthest, distance) = min_distances
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap();
+            if *distance <= 0.0 {
+                // All the remaining vectors duplicate a centroid.
+                break;
+            }
+            let centroid = training[farthest].to_vec();
+            for (d, v) in min_distances.iter_mut().zip(training.iter()) {
+                *d = d.min(squared_l2(&centroid, v));
+            }
+            centroids.push(centroid);
+        }
+        let num_lists = centroids.len();
+        for _ in 0..TRAINING_ITERATIONS {
+            let mut sums = vec![vec![0f64; dimension]; num_lists];
+            let mut counts = vec![0usize; num_lists];
+            for v in &training {
+                let i = nearest_centroid(&centroids, v);
+                for (s, x) in sums[i].iter_mut().zip(v.iter()) {
+                    *s += *x as f64;
+                }
+                counts[i] += 1;
+            }
+            for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+                // Keep the previous centroid of an empty cluster.
+                if count > 0 {
+                    for (c, s) in centroid.iter_mut().zip(sum) {
+                        *c = (s / count as f64) as f32;
+                    }
+                }
+            }
+        }
+
+        let mut lists = vec![vec![]; num_lists];
+        for (offset, v) in &vectors {
+            lists[nearest_centroid(&centroids, v)].push(*offset);
+        }
+        let (centroids, lists) = centroids
+            .into_iter()
+            .zip(lists)
+            .filter(|(_, list)| !list.is_empty())
+            .unzip();
+
+        Some(Self {
+            dimension,
+            centroids,
+            lists,
+        })
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns the distance from `query` to the centroid of each list, along with the list size.
+    pub fn probe(&self, metric: VectorMetric, query: &[f32]) -> Result<Vec<(f32, usize)>> {
+        self.centroids
+            .iter()
+            .zip(self.lists.iter())
+            .map(|(centroid, list)| Ok((metric.distance(query, centroid)?, list.len())))
+            .collect()
+    }
+
+    /// Returns the offsets of the vectors in the `nprobe` lists nearest to `query`.
+    pub fn search(&self, metric: VectorMetric, query: &[f32], nprobe: usize) -> Result<Vec<u32>> {
+        let mut lists = self
+            .probe(metric, query)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, (distance, _))| (distance, i))
+            .collect::<Vec<_>>();
+        lists.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut offsets = lists
+            .iter()
+            .take(nprobe)
+            .flat_map(|(_, i)| self.lists[*i].iter().copied())
+            .collect::<Vec<_>>();
+        offsets.sort_unstable();
+        Ok(offsets)
+    }
+
+    /// Serializes the index in little endian:
+    ///
+    /// `version: u8, dimension: u32, num_lists: u32, (centroid: [f32; dimension], len: u32, offsets: [u32; len])*`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let offsets = self.lists.iter().map(|l| l.len()).sum::<usize>();
+        let mut buf =
+            Vec::with_capacity(9 + self.lists.len() * (4 + self.dimension * 4) + offsets * 4);
+        buf.push(Self::VERSION);
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.lists.len() as u32).to_le_bytes());
+        for (centroid, list) in self.centroids.iter().zip(self.lists.iter()) {
+            for x in centroid {
+                buf.extend_from_slice(&x.to_le_bytes());
+            }
+            buf.extend_from_slice(&(list.len() as u32).to_le_bytes());
+            for offset in list {
+                buf.extend_from_slice(&offset.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = BytesReader { bytes };
+        let version = reader.read_u8()?;
+        if version != Self::VERSION {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported vector index version: {version}"
+            )));
+        }
+        let dimension = reader.read_u32()? as usize;
+        let num_lists = reader.read_u32()? as usize;
+        let mut centroids = Vec::with_capacity(num_lists);
+        let mut lists = Vec::with_capacity(num_lists);
+        for _ in 0..num_lists {
+            let centroid = (0..dimension)
+                .map(|_| reader.read_u32().map(f32::from_bits))
+                .collect::<Result<Vec<_>>>()?;
+            let len = reader.read_u32()? as usize;
+            let list = (0..len)
+                .map(|_| reader.read_u32())
+                .collect::<Result<Vec<_>>>()?;
+            centroids.push(centroid);
+            lists.push(list);
+        }
+        if !reader.bytes.is_empty() {
+            return Err(ErrorCode::BadBytes("trailing bytes in vector index"));
+        }
+
+        Ok(Self {
+            dimension,
+            centroids,
+            lists,
+        })
+    }
+}
+
+fn nearest_centroid(centroids: &[Vec<f32>], v: &[f32]) -> usize {
+    let mut nearest = 0;
+    let mut min_distance = f32::MAX;
+    for (i, centroid) in centroids.iter().enumerate() {
+        let distance = squared_l2(centroid, v);
+        if distance < min_distance {
+            nearest = i;
+            min_distance = distance;
+        }
+    }
+    nearest
+}
+
+fn squared_l2(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+struct BytesReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl BytesReader<'_> {
+    fn read_u8(&mut self) -> Result<u8> {
+        let (v, rest) = self
+            .bytes
+            .split_first()
+            .ok_or_else(|| ErrorCode::BadBytes("unexpected end of vector index"))?;
+        self.bytes = rest;
+        Ok(*v)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        if self.bytes.len() < 4 {
+            return Err(ErrorCode::BadBytes("unexpected end of vector index"));
+        }
+        let (v, rest) = self.bytes.split_at(4);
+        self.bytes = rest;
+        Ok(u32::from_le_bytes(v.try_into().unwrap()))
+    }
+}
@@ -13,5 +13,11 @@
 // limitations under the License.
 
 mod distance;
+mod ivf;
+mod metric;
 
 pub use distance::cosine_distance;
+pub use distance::inner_product;
+pub use distance::l2_distance;
+pub use ivf::IvfIndex;
+pub use metric::VectorMetric;
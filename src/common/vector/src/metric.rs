@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::cosine_distance;
+use crate::inner_product;
+use crate::l2_distance;
+
+/// The metric used to compare two vectors.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorMetric {
+    Cosine,
+    L2,
+    InnerProduct,
+}
+
+impl VectorMetric {
+    /// Returns the metric computed by the SQL function `name`, if any.
+    pub fn from_function_name(name: &str) -> Option<Self> {
+        match name {
+            "cosine_distance" => Some(VectorMetric::Cosine),
+            "l2_distance" => Some(VectorMetric::L2),
+            "inner_product" => Some(VectorMetric::InnerProduct),
+            _ => None,
+        }
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            VectorMetric::Cosine => "cosine_distance",
+            VectorMetric::L2 => "l2_distance",
+            VectorMetric::InnerProduct => "inner_product",
+        }
+    }
+
+    /// Whether the nearest vectors come first when sorting the SQL function result in ascending order.
+    ///
+    /// The inner product grows as vectors get closer, so its nearest neighbors are the largest values.
+    pub fn nearest_first_asc(&self) -> bool {
+        !matches!(self, VectorMetric::InnerProduct)
+    }
+
+    /// Computes a distance where smaller means nearer, for all the metrics.
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> Result<f32> {
+        match self {
+            VectorMetric::Cosine => cosine_distance(from, to),
+            VectorMetric::L2 => l2_distance(from, to),
+            VectorMetric::InnerProduct => inner_product(from, to).map(|v| -v),
+        }
+    }
+}
+
+impl Display for VectorMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.function_name())
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_vector::cosine_distance;
+use common_vector::inner_product;
+use common_vector::l2_distance;
 
 #[test]
 fn test_cosine() {
@@ -39,3 +41,20 @@ fn test_cosine() {
         assert!(d.is_err());
     }
 }
+
+#[test]
+fn test_l2() {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![4.0, 6.0, 3.0];
+    approx::assert_relative_eq!(l2_distance(&x, &y).unwrap(), 5.0);
+    approx::assert_relative_eq!(l2_distance(&x, &x).unwrap(), 0.0);
+    assert!(l2_distance(&x, &[1.0]).is_err());
+}
+
+#[test]
+fn test_inner_product() {
+    let x = vec![1.0, 2.0, 3.0];
+    let y = vec![4.0, -5.0, 6.0];
+    approx::assert_relative_eq!(inner_product(&x, &y).unwrap(), 12.0);
+    assert!(inner_product(&x, &[1.0]).is_err());
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_vector::IvfIndex;
+use common_vector::VectorMetric;
+
+// Vectors on a 10 x 10 grid, where the x axis is much more spread than the y axis.
+fn grid() -> Vec<Vec<f32>> {
+    (0..100)
+        .map(|i| vec![(i % 10) as f32 * 10.0, (i / 10) as f32])
+        .collect()
+}
+
+#[test]
+fn test_ivf_build() {
+    let vectors = grid();
+    let pairs = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i as u32, v.as_slice()))
+        .collect::<Vec<_>>();
+    let index = IvfIndex::build(&pairs).unwrap();
+    assert_eq!(index.dimension(), 2);
+    assert_eq!(index.num_lists(), 10);
+
+    // Every vector lands in exactly one list.
+    let probes = index.probe(VectorMetric::L2, &[0.0, 0.0]).unwrap();
+    assert_eq!(probes.iter().map(|(_, len)| len).sum::<usize>(), 100);
+
+    // Vectors of another dimension are not indexed.
+    let short = [1.0];
+    let mut pairs = pairs;
+    pairs.push((100, &short));
+    let index = IvfIndex::build(&pairs).unwrap();
+    let probes = index.probe(VectorMetric::L2, &[0.0, 0.0]).unwrap();
+    assert_eq!(probes.iter().map(|(_, len)| len).sum::<usize>(), 100);
+
+    assert!(IvfIndex::build(&[]).is_none());
+}
+
+#[test]
+fn test_ivf_search() {
+    let vectors = grid();
+    let pairs = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i as u32, v.as_slice()))
+        .collect::<Vec<_>>();
+    let index = IvfIndex::build(&pairs).unwrap();
+
+    let offsets = index.search(VectorMetric::L2, &[90.0, 5.0], 1).unwrap();
+    assert_eq!(offsets, vec![9, 19, 29, 39, 49, 59, 69, 79, 89, 99]);
+
+    let offsets = index.search(VectorMetric::L2, &[0.0, 5.0], 2).unwrap();
+    assert_eq!(offsets.len(), 20);
+    assert!(offsets.iter().all(|i| i % 10 < 2));
+
+    let offsets = index
+        .search(VectorMetric::InnerProduct, &[1.0, 0.0], 1)
+        .unwrap();
+    assert!(offsets.iter().all(|i| i % 10 == 9));
+
+    let offsets = index.search(VectorMetric::L2, &[0.0, 0.0], 100).unwrap();
+    assert_eq!(offsets, (0..100).collect::<Vec<_>>());
+
+    assert!(index.search(VectorMetric::Cosine, &[1.0], 1).is_err());
+}
+
+#[test]
+fn test_ivf_serialize() {
+    let vectors = grid();
+    let pairs = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i as u32, v.as_slice()))
+        .collect::<Vec<_>>();
+    let index = IvfIndex::build(&pairs).unwrap();
+
+    let bytes = index.to_bytes();
+    assert_eq!(IvfIndex::from_bytes(&bytes).unwrap(), index);
+
+    assert!(IvfIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(IvfIndex::from_bytes(&[0]).is_err());
+}
+
+#[test]
+fn test_vector_metric() {
+    for name in ["cosine_distance", "l2_distance", "inner_product"] {
+        let metric = VectorMetric::from_function_name(name).unwrap();
+        assert_eq!(metric.function_name(), name);
+    }
+    assert!(VectorMetric::from_function_name("plus").is_none());
+
+    assert!(VectorMetric::L2.nearest_first_asc());
+    assert!(!VectorMetric::InnerProduct.nearest_first_asc());
+    assert_eq!(
+        VectorMetric::InnerProduct
+            .distance(&[1.0, 2.0], &[3.0, 4.0])
+            .unwrap(),
+        -11.0
+    );
+}
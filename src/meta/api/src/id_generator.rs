@@ -15,6 +15,7 @@
 use common_meta_kvapi::kvapi;
 
 use crate::data_mask_api_keys::ID_GEN_DATA_MASK;
+use crate::row_access_policy_api_keys::ID_GEN_ROW_ACCESS_POLICY;
 use crate::schema_api_keys::ID_GEN_DATABASE;
 use crate::schema_api_keys::ID_GEN_INDEX;
 use crate::schema_api_keys::ID_GEN_TABLE;
@@ -74,6 +75,12 @@ impl IdGenerator {
         }
     }
 
+    pub fn row_access_policy_id() -> Self {
+        Self {
+            resource: ID_GEN_ROW_ACCESS_POLICY.to_string(),
+        }
+    }
+
     pub fn table_lock_id() -> Self {
         Self {
             resource: ID_GEN_TABLE_LOCK.to_string(),
@@ -170,6 +177,16 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        // Row access policy id generator
+        {
+            let g1 = IdGenerator::row_access_policy_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/row_access_policy_id", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         {
             let g1 = IdGenerator::table_lock_id();
             let k = g1.to_string_key();
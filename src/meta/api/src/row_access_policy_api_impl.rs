@@ -0,0 +1,249 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use common_meta_app::app_error::AppError;
+use common_meta_app::app_error::RowAccessPolicyAlreadyExists;
+use common_meta_app::app_error::UnknownRowAccessPolicy;
+use common_meta_app::row_access_policy::CreateRowAccessPolicyReply;
+use common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use common_meta_app::row_access_policy::DropRowAccessPolicyReply;
+use common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReply;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyId;
+use common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_kvapi::kvapi;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::MetaError;
+use common_meta_types::TxnRequest;
+use common_tracing::func_name;
+use tracing::debug;
+
+use crate::fetch_id;
+use crate::get_pb_value;
+use crate::get_u64_value;
+use crate::id_generator::IdGenerator;
+use crate::kv_app_error::KVAppError;
+use crate::row_access_policy_api::RowAccessPolicyApi;
+use crate::send_txn;
+use crate::serialize_struct;
+use crate::serialize_u64;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+use crate::util::txn_trials;
+
+/// RowAccessPolicyApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls RowAccessPolicyApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> RowAccessPolicyApi for KV {
+    async fn create_row_access_policy(
+        &self,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<CreateRowAccessPolicyReply, KVAppError> {
+        debug!(req = debug(&req), "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+        let id = loop {
+            trials.next().unwrap()?;
+            // Get row access policy by name to ensure absence
+            let (seq, id) = get_u64_value(self, name_key).await?;
+            debug!(seq, id, ?name_key, "create_row_access_policy");
+
+            if seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreateRowAccessPolicyReply { id })
+                } else {
+                    Err(KVAppError::AppError(
+                        AppError::RowAccessPolicyAlreadyExists(RowAccessPolicyAlreadyExists::new(
+                            &name_key.name,
+                            format!("create row access policy: {}", req.name),
+                        )),
+                    ))
+                };
+            }
+
+            // Create row access policy by inserting these record:
+            // name -> id
+            // id -> policy
+
+            let id = fetch_id(self, IdGenerator::row_access_policy_id()).await?;
+            let id_key = RowAccessPolicyId { id };
+
+            debug!(
+                id = debug(&id_key),
+                name_key = debug(&name_key),
+                "new row access policy id"
+            );
+
+            {
+                let meta: RowAccessPolicyMeta = req.clone().into();
+                let condition = vec![txn_cond_seq(name_key, Eq, 0)];
+                let if_then = vec![
+                    txn_op_put(name_key, serialize_u64(id)?), // name -> policy_id
+                    txn_op_put(&id_key, serialize_struct(&meta)?), // id -> meta
+                ];
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name = debug(&name_key),
+                    id = debug(&id_key),
+                    succ = display(succ),
+                    "create_row_access_policy"
+                );
+
+                if succ {
+                    break id;
+                }
+            }
+        };
+
+        Ok(CreateRowAccessPolicyReply { id })
+    }
+
+    async fn drop_row_access_policy(
+        &self,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<DropRowAccessPolicyReply, KVAppError> {
+        debug!(req = debug(&req), "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let result = get_row_access_policy_or_err(
+                self,
+                name_key,
+                format!("drop_row_access_policy: {}", name_key),
+            )
+            .await;
+
+            let (id_seq, id, policy_seq, _) = match result {
+                Ok((id_seq, id, policy_seq, meta)) => (id_seq, id, policy_seq, meta),
+                Err(err) => {
+                    if let KVAppError::AppError(AppError::UnknownRowAccessPolicy(_)) = err {
+                        if req.if_exists {
+                            return Ok(DropRowAccessPolicyReply {});
+                        }
+                    }
+
+                    return Err(err);
+                }
+            };
+            let id_key = RowAccessPolicyId { id };
+            let condition = vec![
+                txn_cond_seq(name_key, Eq, id_seq),
+                txn_cond_seq(&id_key, Eq, policy_seq),
+            ];
+            let if_then = vec![txn_op_del(name_key), txn_op_del(&id_key)];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(name_key),
+                id = debug(&RowAccessPolicyId { id }),
+                succ = display(succ),
+                "drop_row_access_policy"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropRowAccessPolicyReply {})
+    }
+
+    async fn get_row_access_policy(
+        &self,
+        req: GetRowAccessPolicyReq,
+    ) -> Result<GetRowAccessPolicyReply, KVAppError> {
+        debug!(req = debug(&req), "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let (_id_seq, _id, _policy_seq, policy) = get_row_access_policy_or_err(
+            self,
+            name_key,
+            format!("get_row_access_policy: {}", name_key),
+        )
+        .await?;
+
+        Ok(GetRowAccessPolicyReply { policy })
+    }
+}
+
+/// Returns (id_seq, id, policy_seq, policy)
+async fn get_row_access_policy_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(u64, u64, u64, RowAccessPolicyMeta), KVAppError> {
+    let (id_seq, id) = get_u64_value(kv_api, name_key).await?;
+    row_access_policy_has_to_exist(id_seq, name_key, &msg)?;
+
+    let id_key = RowAccessPolicyId { id };
+
+    let (policy_seq, policy) = get_pb_value(kv_api, &id_key).await?;
+    row_access_policy_has_to_exist(policy_seq, name_key, msg)?;
+
+    Ok((
+        id_seq,
+        id,
+        policy_seq,
+        // Safe unwrap(): policy_seq > 0 implies policy is not None.
+        policy.unwrap(),
+    ))
+}
+
+/// Return OK if a policy id or policy meta exists by checking the seq.
+///
+/// Otherwise returns UnknownRowAccessPolicy error
+pub fn row_access_policy_has_to_exist(
+    seq: u64,
+    name_ident: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(), KVAppError> {
+    if seq == 0 {
+        debug!(seq, ?name_ident, "row access policy does not exist");
+
+        Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+            UnknownRowAccessPolicy::new(&name_ident.name, format!("{}: {}", msg, name_ident)),
+        )))
+    } else {
+        Ok(())
+    }
+}
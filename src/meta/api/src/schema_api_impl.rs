@@ -112,6 +112,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2648,6 +2650,138 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for update_req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update_req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                debug!(ident = display(&tbid), "update_multi_table_meta");
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update_req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update_req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update_req.table_id,
+                            update_req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req.if_then.push(txn_op_put(
+                    &tbid,
+                    serialize_struct(&update_req.new_table_meta)?,
+                )); // tb_id -> tb_meta
+                txn_req.else_then.push(TxnOp {
+                    request: Some(Request::Get(TxnGetRequest {
+                        key: tbid.to_string_key(),
+                    })),
+                });
+
+                if let Some(copied_files) = &update_req.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                if let Some(deduplicated_label) = update_req.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta.unwrap());
+            }
+
+            let (succ, responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info = vec![];
+                for table_meta in &table_metas {
+                    if let Some(info) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.extend(info);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply {
+                    share_table_info: if share_table_info.is_empty() {
+                        None
+                    } else {
+                        Some(share_table_info)
+                    },
+                });
+            }
+
+            // the txn failed, find out whether it is caused by a concurrent
+            // commit to one of the tables or by the duplicated copied files.
+            for (update_req, resp) in req.update_table_metas.iter().zip(responses.iter()) {
+                if let Some(Response::Get(get_resp)) = &resp.response {
+                    let tb_meta_seq = get_resp.value.as_ref().map(|v| v.seq).unwrap_or(0);
+                    if update_req.seq.match_seq(tb_meta_seq).is_err() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            TableVersionMismatched::new(
+                                update_req.table_id,
+                                update_req.seq,
+                                tb_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+                } else {
+                    unreachable!(
+                        "internal error: expect some TxnGetResponseGet, but got {:?}",
+                        resp.response
+                    );
+                }
+            }
+
+            // table versions do match, but tx failed
+            if let Some(update_req) = req.update_table_metas.iter().find(|r| {
+                r.copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false)
+            }) {
+                // report file duplication error
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(update_req.table_id, "update_multi_table_meta"),
+                )));
+            }
+            // continue and try update the "table copied files"
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
@@ -58,6 +58,22 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("CreateDatabaseWithDropTime: `{db_name}` with drop_on")]
 pub struct CreateDatabaseWithDropTime {
@@ -268,6 +284,22 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownDatabaseId: `{db_id}` while `{context}`")]
 pub struct UnknownDatabaseId {
@@ -802,6 +834,12 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
     #[error(transparent)]
     UnmatchColumnDataType(#[from] UnmatchColumnDataType),
 
@@ -1048,6 +1086,18 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exists", self.name)
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1141,6 +1191,12 @@ impl From<AppError> for ErrorCode {
             AppError::DropIndexWithDropTime(err) => ErrorCode::DropIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
             AppError::UnmatchColumnDataType(err) => ErrorCode::UnmatchColumnDataType(err.message()),
             AppError::VirtualColumnNotFound(err) => ErrorCode::VirtualColumnNotFound(err.message()),
             AppError::VirtualColumnAlreadyExists(err) => {
@@ -26,26 +26,49 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    Column(String, String, String, String),
+    Stage(String),
+    UDF(String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, and Table object contains all its Column objects.
+    /// Stage and UDF objects are only contained by the Global object and themselves.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
-            (GrantObject::Database(_, _), GrantObject::Global) => false,
             (GrantObject::Database(lcat, ldb), GrantObject::Database(rcat, rdb)) => {
                 lcat == rcat && ldb == rdb
             }
-            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
+            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _))
+            | (GrantObject::Database(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::Database(_, _), _) => false,
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
+            )
+            | (
+                GrantObject::Table(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
+            (GrantObject::Column(_, _, _, _), _) => false,
+            (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
+            (GrantObject::Stage(_), _) => false,
+            (GrantObject::UDF(ludf), GrantObject::UDF(rudf)) => ludf == rudf,
+            (GrantObject::UDF(_), _) => false,
         }
     }
 
@@ -55,6 +78,9 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
+            GrantObject::Stage(_) => UserPrivilegeSet::available_privileges_on_stage(),
+            GrantObject::UDF(_) => UserPrivilegeSet::available_privileges_on_udf(),
         }
     }
 }
@@ -67,6 +93,11 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'.'{}'", cat, db, table, column)
+            }
+            GrantObject::Stage(ref stage) => write!(f, "STAGE {}", stage),
+            GrantObject::UDF(ref udf) => write!(f, "UDF {}", udf),
         }
     }
 }
@@ -121,11 +152,21 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
-        let privileges_str = if self.has_all_available_privileges() {
-            "ALL".to_string()
-        } else {
-            privileges.to_string()
-        };
+        if let GrantObject::Column(cat, db, table, column) = &self.object {
+            return write!(
+                f,
+                "GRANT {}({}) ON '{}'.'{}'.'{}'",
+                privileges, column, cat, db, table
+            );
+        }
+
+        // USAGE is the only privilege of a UDF, so it is never shown as ALL.
+        let privileges_str =
+            if self.has_all_available_privileges() && !matches!(self.object, GrantObject::UDF(_)) {
+                "ALL".to_string()
+            } else {
+                privileges.to_string()
+            };
         write!(f, "GRANT {} ON {}", &privileges_str, self.object)
     }
 }
@@ -195,6 +236,11 @@ impl UserGrantSet {
         self.entries = new_entries;
     }
 
+    /// Revoke all the privileges on the object.
+    pub fn revoke_object(&mut self, object: &GrantObject) {
+        self.entries.retain(|e| !e.matches_entry(object));
+    }
+
     pub fn revoke_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
         let privileges: BitFlags<UserPrivilegeType> = privileges.into();
         let new_entries = self
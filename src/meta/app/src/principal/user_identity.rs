@@ -14,7 +14,7 @@
 
 use std::fmt;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash, Default)]
 pub struct UserIdentity {
     pub username: String,
     pub hostname: String,
@@ -64,6 +64,10 @@ pub enum UserPrivilegeType {
     DropUser = 1 << 15,
     // Privilege to Create/Drop DataMask.
     CreateDataMask = 1 << 16,
+    // Privilege to read files from a stage.
+    Read = 1 << 17,
+    // Privilege to write files into a stage.
+    Write = 1 << 18,
     // TODO: remove this later
     Set = 1 << 4,
 }
@@ -86,6 +90,8 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | CreateStage
         | Set
         | CreateDataMask
+        | Read
+        | Write
     }
 );
 
@@ -109,6 +115,8 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Grant => "GRANT",
             UserPrivilegeType::Set => "SET",
             UserPrivilegeType::CreateDataMask => "CREATE DATAMASK",
+            UserPrivilegeType::Read => "READ",
+            UserPrivilegeType::Write => "WRITE",
         })
     }
 }
@@ -133,7 +141,7 @@ impl UserPrivilegeSet {
     /// on databases and tables, and has some Global only privileges.
     pub fn available_privileges_on_global() -> Self {
         let database_privs = Self::available_privileges_on_database();
-        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant | CreateDataMask });
+        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | Grant | CreateDataMask | Read | Write });
         (database_privs.privileges | privs).into()
     }
 
@@ -149,6 +157,21 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// Only SELECT can be granted on a column.
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select }).into()
+    }
+
+    /// READ and WRITE can be granted on a stage.
+    pub fn available_privileges_on_stage() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Read | Write }).into()
+    }
+
+    /// Only USAGE can be granted on a UDF.
+    pub fn available_privileges_on_udf() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -0,0 +1,86 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::UserIdentity;
+
+/// A named group of users and roles sharing the admission limits of their queries.
+///
+/// A limit of 0 means no limit.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct WorkloadGroup {
+    pub name: String,
+
+    /// The max number of queries of the group running at the same time on a node.
+    pub max_concurrency: u64,
+    /// The percentage of `max_server_memory_usage` the running queries of the group can use.
+    pub memory_share: u64,
+    /// Queued queries of groups with higher priority are admitted first.
+    pub priority: u64,
+    /// The max seconds a query can wait in the queue before it fails.
+    pub queue_timeout_secs: u64,
+
+    /// The users mapped to the group.
+    pub users: Vec<UserIdentity>,
+    /// The roles mapped to the group.
+    pub roles: Vec<String>,
+}
+
+impl WorkloadGroup {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn queue_timeout(&self) -> Option<Duration> {
+        match self.queue_timeout_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+
+    /// The memory limit in bytes of a single query of the group, if any.
+    pub fn query_memory_limit(&self, max_server_memory_usage: u64) -> Option<u64> {
+        if self.memory_share == 0 || max_server_memory_usage == 0 {
+            return None;
+        }
+
+        let group_memory = max_server_memory_usage / 100 * self.memory_share.min(100);
+        Some(group_memory / self.max_concurrency.max(1))
+    }
+}
+
+impl TryFrom<Vec<u8>> for WorkloadGroup {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(group) => Ok(group),
+            Err(serialize_error) => Err(ErrorCode::IllegalWorkloadGroupFormat(format!(
+                "Cannot deserialize workload group from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
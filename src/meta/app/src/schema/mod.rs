@@ -79,11 +79,14 @@ pub use table::TableInfo;
 pub use table::TableLockKey;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
@@ -231,6 +231,16 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+}
+
+/// A row access policy attached to a table.
+///
+/// The policy arguments are bound to `columns` by position.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
 }
 
 impl TableMeta {
@@ -328,6 +338,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
         }
     }
 }
@@ -607,6 +618,17 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+/// Update the metas of several tables atomically, all of them are updated or none of them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -77,6 +77,71 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "t".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "t".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t2".into(), "c".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "d".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "t".into(), "c".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "t".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Global,
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Stage("s1".into()),
+            rhs: GrantObject::Stage("s2".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Stage("s1".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Global,
+            rhs: GrantObject::UDF("f1".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::UDF("f1".into()),
+            rhs: GrantObject::UDF("f2".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::UDF("f1".into()),
+            rhs: GrantObject::Stage("f1".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -200,3 +265,77 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_on_columns() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+
+    let column_a = GrantObject::Column("default".into(), "db1".into(), "t".into(), "a".into());
+    let column_b = GrantObject::Column("default".into(), "db1".into(), "t".into(), "b".into());
+    grants.grant_privileges(
+        &column_a,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(1, grants.entries().len());
+    assert_eq!(
+        "GRANT SELECT(a) ON 'default'.'db1'.'t'",
+        grants.entries()[0].to_string()
+    );
+
+    assert!(grants.verify_privilege(&column_a, vec![UserPrivilegeType::Select]));
+    assert!(!grants.verify_privilege(&column_b, vec![UserPrivilegeType::Select]));
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "t".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+
+    grants.revoke_privileges(
+        &column_a,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(0, grants.entries().len());
+
+    Ok(())
+}
+
+#[test]
+fn test_user_grant_set_on_stages_and_udfs() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+
+    let stage = GrantObject::Stage("s1".into());
+    let udf = GrantObject::UDF("f1".into());
+    grants.grant_privileges(&stage, make_bitflags!(UserPrivilegeType::{Read}).into());
+    grants.grant_privileges(&udf, make_bitflags!(UserPrivilegeType::{Usage}).into());
+    assert_eq!(2, grants.entries().len());
+    assert_eq!("GRANT READ ON STAGE s1", grants.entries()[0].to_string());
+    assert_eq!("GRANT USAGE ON UDF f1", grants.entries()[1].to_string());
+
+    assert!(grants.verify_privilege(&stage, vec![UserPrivilegeType::Read]));
+    assert!(!grants.verify_privilege(&stage, vec![UserPrivilegeType::Write]));
+    assert!(
+        !grants.verify_privilege(&GrantObject::Stage("s2".into()), vec![
+            UserPrivilegeType::Read
+        ])
+    );
+    assert!(grants.verify_privilege(&udf, vec![UserPrivilegeType::Usage]));
+    assert!(
+        !grants.verify_privilege(&GrantObject::UDF("f2".into()), vec![
+            UserPrivilegeType::Usage
+        ])
+    );
+
+    grants.revoke_privileges(&stage, make_bitflags!(UserPrivilegeType::{Read}).into());
+    assert_eq!(1, grants.entries().len());
+
+    grants.grant_privileges(
+        &stage,
+        make_bitflags!(UserPrivilegeType::{Read | Write}).into(),
+    );
+    grants.revoke_object(&stage);
+    assert_eq!(1, grants.entries().len());
+    assert!(!grants.verify_privilege(&stage, vec![UserPrivilegeType::Read]));
+    grants.revoke_object(&udf);
+    assert_eq!(0, grants.entries().len());
+
+    Ok(())
+}
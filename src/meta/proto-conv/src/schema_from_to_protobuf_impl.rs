@@ -192,6 +192,9 @@ impl FromToProto for ex::TableDataType {
                         Box::into_inner(x),
                     )?)),
                     Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::TupleT(t) => {
                         reader_check_msg(t.ver, t.min_reader_ver)?;
 
@@ -238,6 +241,9 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
@@ -227,6 +227,10 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            row_access_policy: p.row_access_policy.map(|binding| mt::TableRowAccessPolicy {
+                policy: binding.policy,
+                columns: binding.columns,
+            }),
         };
         Ok(v)
     }
@@ -266,6 +270,13 @@ impl FromToProto for mt::TableMeta {
                 Some(column_mask_policy) => column_mask_policy.clone(),
                 None => BTreeMap::new(),
             },
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|p| pb::TableRowAccessPolicy {
+                    policy: p.policy.clone(),
+                    columns: p.columns.clone(),
+                }),
         };
         Ok(p)
     }
@@ -158,6 +158,20 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
+            Some(pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage })) => {
+                Ok(mt::principal::GrantObject::Stage(stage))
+            }
+            Some(pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf })) => {
+                Ok(mt::principal::GrantObject::UDF(udf))
+            }
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -182,6 +196,22 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    column: column.clone(),
+                }),
+            ),
+            mt::principal::GrantObject::Stage(stage) => Some(pb::grant_object::Object::Stage(
+                pb::grant_object::GrantStageObject {
+                    stage: stage.clone(),
+                },
+            )),
+            mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
+                pb::grant_object::GrantUdfObject { udf: udf.clone() },
+            )),
         };
         Ok(pb::GrantObject {
             ver: VER,
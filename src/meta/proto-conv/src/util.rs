@@ -71,6 +71,13 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (39, "2023-05-22: Add: data_mask.proto", ),
     (40, "2023-05-26: Add: TableMeta add column_mask_policy field", ),
     (41, "2023-05-29: Add: virtual_column.proto", ),
+    (42, "2023-06-07: Add: file_format.proto/AvroFileFormatParams and OrcFileFormatParams", ),
+    (43, "2023-06-12: Add: metadata.proto/DataType Interval type", ),
+    (44, "2023-06-14: Add: metadata.proto/DataType Binary type", ),
+    (45, "2023-06-15: Add: metadata.proto/DataType Geometry type", ),
+    (46, "2023-06-19: Add: user.proto/GrantObject::GrantColumnObject", ),
+    (47, "2023-06-21: Add: row_access_policy.proto; TableMeta add row_access_policy field", ),
+    (48, "2023-06-23: Add: user.proto/GrantObject::GrantStageObject and GrantUdfObject", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -46,3 +46,10 @@ mod v038_empty_proto;
 mod v039_data_mask;
 mod v040_table_meta;
 mod v041_virtual_column;
+mod v042_file_format_params;
+mod v043_schema;
+mod v044_schema;
+mod v045_schema;
+mod v046_grant_object;
+mod v047_row_access_policy;
+mod v048_grant_object;
@@ -200,6 +200,7 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        row_access_policy: None,
     }
 }
 
@@ -242,6 +243,9 @@ pub(crate) fn new_latest_schema() -> TableSchema {
         ),
         TableField::new("empty_map", TableDataType::EmptyMap),
         TableField::new("bitmap", TableDataType::Bitmap),
+        TableField::new("interval", TableDataType::Interval),
+        TableField::new("binary", TableDataType::Binary),
+        TableField::new("geometry", TableDataType::Geometry),
     ];
     TableSchema::new(fields)
 }
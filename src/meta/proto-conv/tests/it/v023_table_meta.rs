@@ -146,6 +146,7 @@ fn test_decode_v23_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
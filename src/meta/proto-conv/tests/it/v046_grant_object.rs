@@ -0,0 +1,53 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::GrantEntry;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v46_grant_object() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 30, 34, 22, 10, 7, 100, 101, 102, 97, 117, 108, 116, 18, 3, 100, 98, 49, 26, 2, 116,
+        49, 34, 2, 99, 49, 160, 6, 46, 168, 6, 24, 16, 4, 160, 6, 46, 168, 6, 24,
+    ];
+
+    let want = || {
+        GrantEntry::new(
+            GrantObject::Column(
+                "default".to_string(),
+                "db1".to_string(),
+                "t1".to_string(),
+                "c1".to_string(),
+            ),
+            make_bitflags!(UserPrivilegeType::{ Select }),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 46, want())
+}
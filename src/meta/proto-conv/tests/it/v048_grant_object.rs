@@ -0,0 +1,65 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::GrantEntry;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v48_grant_object_stage() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 12, 42, 4, 10, 2, 115, 49, 160, 6, 48, 168, 6, 24, 16, 128, 128, 24, 160, 6, 48, 168,
+        6, 24,
+    ];
+
+    let want = || {
+        GrantEntry::new(
+            GrantObject::Stage("s1".to_string()),
+            make_bitflags!(UserPrivilegeType::{ Read | Write }),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 48, want())
+}
+
+#[test]
+fn test_decode_v48_grant_object_udf() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 12, 50, 4, 10, 2, 102, 49, 160, 6, 48, 168, 6, 24, 16, 1, 160, 6, 48, 168, 6, 24,
+    ];
+
+    let want = || {
+        GrantEntry::new(
+            GrantObject::UDF("f1".to_string()),
+            make_bitflags!(UserPrivilegeType::{ Usage }),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 48, want())
+}
@@ -158,6 +158,7 @@ pub enum Expr {
         args: Vec<Expr>,
         params: Vec<Literal>,
         window: Option<Window>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -423,7 +424,10 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     String,
+    Binary,
+    Geometry,
     Array(Box<TypeName>),
     Map {
         key_type: Box<TypeName>,
@@ -455,6 +459,13 @@ pub enum TrimWhere {
     Trailing,
 }
 
+/// The lambda of higher-order functions, like `x -> x + 1` or `(acc, x) -> acc + x`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<Identifier>,
+    pub expr: Box<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq, EnumAsInner)]
 pub enum Window {
     WindowReference(WindowRef),
@@ -839,9 +850,18 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Array(ty) => {
                 write!(f, "ARRAY({})", ty)?;
             }
@@ -944,6 +964,19 @@ impl Display for WindowDefinition {
     }
 }
 
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
 impl Display for Window {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let window_fmt = match *self {
@@ -1181,6 +1214,7 @@ impl Display for Expr {
                 args,
                 params,
                 window,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -1194,6 +1228,9 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if let Some(lambda) = lambda {
+                    write!(f, ", {lambda}")?;
+                }
                 write!(f, ")")?;
 
                 if let Some(window) = window {
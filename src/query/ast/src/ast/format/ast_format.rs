@@ -431,6 +431,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         _over: &'ast Option<Window>,
+        _lambda: &'ast Option<Lambda>,
     ) {
         let mut children = Vec::with_capacity(args.len());
         for arg in args.iter() {
@@ -846,6 +847,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_begin_transaction(&mut self) {
+        let name = "Begin".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_commit_transaction(&mut self) {
+        let name = "Commit".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
+    fn visit_abort_transaction(&mut self) {
+        let name = "Abort".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_show_process_list(&mut self) {
         let name = "ShowProcessList".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1388,6 +1410,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::AddRowAccessPolicy { policy, .. } => {
+                let action_name = format!("Action Add row access policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop row access policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1501,6 +1533,50 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1732,6 +1808,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1786,6 +1883,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -2194,6 +2312,73 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_workload_groups(&mut self) {
+        let name = "ShowWorkloadGroups".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2384,6 +2569,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(window_list_node);
         }
 
+        if let Some(qualify) = &stmt.qualify {
+            self.visit_expr(qualify);
+            let qualify_child = self.children.pop().unwrap();
+            let qualify_name = "Qualify".to_string();
+            let qualify_format_ctx = AstFormatContext::with_children(qualify_name, 1);
+            let qualify_node =
+                FormatTreeNode::with_children(qualify_format_ctx, vec![qualify_child]);
+            children.push(qualify_node);
+        }
+
         let name = "SelectQuery".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
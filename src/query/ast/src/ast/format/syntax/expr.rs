@@ -238,6 +238,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             args,
             params,
             window,
+            lambda,
             ..
         } => RcDoc::text(name.to_string())
             .append(if !params.is_empty() {
@@ -258,6 +259,13 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(inline_comma(args.into_iter().map(pretty_expr)))
+            .append(if let Some(lambda) = lambda {
+                RcDoc::text(",")
+                    .append(RcDoc::space())
+                    .append(RcDoc::text(lambda.to_string()))
+            } else {
+                RcDoc::nil()
+            })
             .append(RcDoc::text(")"))
             .append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
@@ -90,6 +90,8 @@ pub struct SelectStmt {
     pub having: Option<Expr>,
     // `WINDOW` clause
     pub window_list: Option<Vec<WindowDefinition>>,
+    // `QUALIFY` clause
+    pub qualify: Option<Expr>,
 }
 
 /// Group by Clause.
@@ -619,6 +621,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // QUALIFY clause
+        if let Some(qualify) = &self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+
         Ok(())
     }
 }
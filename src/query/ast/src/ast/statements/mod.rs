@@ -23,18 +23,22 @@ mod hint;
 mod index;
 mod insert;
 mod kill;
+mod merge_into;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod unset;
 mod update;
 mod user;
 mod view;
 mod virtual_columns;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -47,15 +51,19 @@ pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_columns::*;
+pub use workload_group::*;
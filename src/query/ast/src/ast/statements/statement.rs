@@ -42,6 +42,11 @@ pub enum Statement {
     Copy(CopyStmt),
     Call(CallStmt),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     ShowSettings {
         like: Option<String>,
     },
@@ -85,6 +90,8 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    MergeInto(MergeIntoStmt),
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -124,6 +131,12 @@ pub enum Statement {
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
 
     // Indexes
     CreateIndex(CreateIndexStmt),
@@ -227,6 +240,17 @@ pub enum Statement {
     CreateDatamaskPolicy(CreateDatamaskPolicyStmt),
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
+
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+    ShowWorkloadGroups,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -301,7 +325,11 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
                 if like.is_some() {
@@ -387,6 +415,10 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -536,6 +568,13 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWorkloadGroups => write!(f, "SHOW WORKLOAD GROUPS")?,
         }
         Ok(())
     }
@@ -303,6 +303,13 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -344,6 +351,14 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
         }
     }
 }
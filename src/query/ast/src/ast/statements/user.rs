@@ -21,8 +21,10 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::principal::UserQuota;
 
 use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateUserStmt {
@@ -142,6 +144,12 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    /// Privileges on some columns of a table, e.g. `SELECT(a, b) ON db.t`.
+    ColumnPrivs {
+        privileges: Vec<UserPrivilegeType>,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -152,12 +160,15 @@ pub enum AccountMgrLevel {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    Stage(String),
+    UDF(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    Quota(Vec<UserQuotaItem>),
 }
 
 impl UserOptionItem {
@@ -167,10 +178,30 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::Quota(_) => {}
+        }
+    }
+
+    pub fn apply_quota(&self, quota: &mut UserQuota) {
+        if let Self::Quota(items) = self {
+            for item in items {
+                match item {
+                    UserQuotaItem::MaxCpu(v) => quota.max_cpu = *v,
+                    UserQuotaItem::MaxMemory(v) => quota.max_memory_in_bytes = *v,
+                    UserQuotaItem::MaxStorage(v) => quota.max_storage_in_bytes = *v,
+                }
+            }
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserQuotaItem {
+    MaxCpu(u64),
+    MaxMemory(u64),
+    MaxStorage(u64),
+}
+
 impl Display for AccountMgrSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -178,57 +209,78 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
+                write!(f, " ON {level}")?;
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                write!(f, " ")?;
+                for (i, privilege) in privileges.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
                     }
+                    write!(f, "{privilege}(")?;
+                    write_comma_separated_list(f, columns)?;
+                    write!(f, ")")?;
                 }
+                write!(f, " ON {level}")?;
             }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
             }
         }
         Ok(())
     }
 }
 
+impl Display for AccountMgrLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountMgrLevel::Global => write!(f, "*.*"),
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.*")
+                } else {
+                    write!(f, "*")
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.{table_name}")
+                } else {
+                    write!(f, "{table_name}")
+                }
+            }
+            AccountMgrLevel::Stage(stage_name) => write!(f, "STAGE {stage_name}"),
+            AccountMgrLevel::UDF(udf_name) => write!(f, "UDF {udf_name}"),
+        }
+    }
+}
+
 impl Display for UserOptionItem {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::Quota(items) => {
+                write!(f, "QUOTA (")?;
+                write_comma_separated_list(f, items)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl Display for UserQuotaItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UserQuotaItem::MaxCpu(v) => write!(f, "MAX_CPU = {v}"),
+            UserQuotaItem::MaxMemory(v) => write!(f, "MAX_MEMORY = {v}"),
+            UserQuotaItem::MaxStorage(v) => write!(f, "MAX_STORAGE = {v}"),
         }
     }
 }
@@ -288,6 +288,7 @@ pub enum ExprElement {
         args: Vec<Expr>,
         window: Option<Window>,
         params: Vec<Literal>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -487,6 +488,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
@@ -494,6 +496,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             },
             ExprElement::Case {
                 operand,
@@ -827,6 +830,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: None,
+            lambda: None,
+        },
+    );
+
+    let function_call_with_lambda = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
 
@@ -842,6 +864,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: Some(window.1),
+            lambda: None,
         },
     );
 
@@ -857,6 +880,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: params.map(|x| x.1).unwrap_or_default(),
             window: None,
+            lambda: None,
         },
     );
 
@@ -991,6 +1015,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1015,6 +1052,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
         ),
@@ -1025,6 +1063,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all_with_window : "`COUNT(*) OVER ...`"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_window : "<function>"
             | #function_call_with_params : "<function>"
             | #function_call : "<function>"
@@ -1336,10 +1375,16 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { (DATETIME | TIMESTAMP) ~ ( "(" ~ #literal_u64 ~ ")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     let ty_string = value(
         TypeName::String,
-        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     map(
         alt((
@@ -1366,7 +1411,10 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_interval
             | #ty_string
+            | #ty_binary
+            | #ty_geometry
             | #ty_variant
             | #ty_nullable
             ) ~ NULL? : "type name" },
@@ -1431,6 +1479,21 @@ pub fn interval_kind(i: Input) -> IResult<IntervalKind> {
     ))(i)
 }
 
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(rule! { #ident }, |param| vec![param]);
+    let multi_params = map(
+        rule! {
+            "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, params, _)| params,
+    );
+
+    rule!(
+        #single_param
+        | #multi_params
+    )(i)
+}
+
 pub fn map_access(i: Input) -> IResult<MapAccessor> {
     let bracket = map(
         rule! {
@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::combinator::map;
+
+use crate::ast::RowAccessPolicy;
+use crate::ast::RowAccessPolicyArg;
+use crate::input::Input;
+use crate::parser::expr::*;
+use crate::parser::stage::ident_to_string;
+use crate::parser::token::*;
+use crate::rule;
+use crate::util::*;
+
+fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(
+        rule! { #ident_to_string ~ #type_name },
+        |(arg_name, arg_type)| RowAccessPolicyArg { arg_name, arg_type },
+    )(i)
+}
+
+pub fn row_access_policy(i: Input) -> IResult<RowAccessPolicy> {
+    map(
+        rule! {
+            AS ~ "(" ~ #comma_separated_list1(row_access_policy_arg) ~ ")"
+            ~ RETURNS ~ BOOLEAN ~ "->" ~ #expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, args, _, _, _, _, body, opt_comment)| RowAccessPolicy {
+            args,
+            body,
+            comment: opt_comment.map(|(_, _, comment)| comment),
+        },
+    )(i)
+}
@@ -36,6 +36,7 @@ use crate::parser::data_mask::data_mask_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::query::*;
+use crate::parser::row_access_policy::row_access_policy;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
 use crate::parser::token::*;
@@ -176,6 +177,36 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge = map(
+        rule! {
+            MERGE ~ #hint? ~ INTO ~ #period_separated_idents_1_to_3 ~ #alias_name?
+            ~ USING ~ #table_reference ~ ON ~ ^#expr ~ #merge_option+
+        },
+        |(
+            _,
+            opt_hints,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            Statement::MergeInto(MergeIntoStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                merge_options,
+            })
+        },
+    );
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -659,6 +690,34 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
     let drop_view = map(
         rule! {
             DROP ~ VIEW ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
@@ -672,6 +731,47 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #period_separated_idents_1_to_2
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -1063,6 +1163,10 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ WORK? });
+    let abort = value(Statement::Abort, rule! { ( ABORT | ROLLBACK ) ~ WORK? });
+
     let presign = map(
         rule! {
             PRESIGN ~ ( #presign_action )?
@@ -1267,6 +1371,81 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, _, _, _, opt_if_not_exists, name, policy)| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                policy,
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
+    // workload group
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ GROUP ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ ( WITH ~ ^#comma_separated_list1(workload_group_option) )?
+        },
+        |(_, _, _, opt_if_not_exists, name, opt_options)| {
+            Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options: opt_options.map(|(_, options)| options).unwrap_or_default(),
+            })
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ GROUP ~ #ident ~ SET ~ ^#comma_separated_list1(workload_group_option)
+        },
+        |(_, _, _, name, _, options)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ GROUPS },
+    );
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1294,6 +1473,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge : "`MERGE INTO <target_table> [AS <alias>] USING <source> ON <join_expr> { WHEN [NOT] MATCHED [AND <condition>] THEN <action> }...`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1322,6 +1502,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+        ),
+        rule!(
+            #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [COMMENT = '<comment>']`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
@@ -1373,6 +1559,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
         ),
+        rule!(
+            #begin: "`BEGIN [TRANSACTION]`"
+            | #commit: "`COMMIT [WORK]`"
+            | #abort: "`{ ROLLBACK | ABORT } [WORK]`"
+        ),
         rule!(
             #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
             | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON {DATABASE <db_name> | TABLE <db_name>.<table_name>} }`"
@@ -1387,6 +1578,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
         ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type> [, ...]) RETURNS BOOLEAN -> <expr>`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <name>`"
+        ),
+        // workload group
+        rule!(
+            #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] <name> [WITH <option> [, <option>, ...]]`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP <name> SET <option> [, <option>, ...]`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] <name>`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
+        ),
         // share
         rule!(
             #create_share_endpoint: "`CREATE SHARE ENDPOINT [IF NOT EXISTS] <endpoint_name> URL=endpoint_location tenant=tenant_name ARGS=(arg=..) [ COMMENT = '<string_literal>' ]`"
@@ -1594,6 +1798,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            SELECT ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(_, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privileges: vec![UserPrivilegeType::Select],
+            columns,
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -1601,6 +1815,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
 
     rule!(
         #role : "ROLE <role_name>"
+        | #column_privs : "SELECT(<column>, ...) ON <table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
@@ -1622,6 +1837,8 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::Grant, rule! { GRANT }),
         value(UserPrivilegeType::CreateStage, rule! { CREATE ~ STAGE }),
         value(UserPrivilegeType::Set, rule! { SET }),
+        value(UserPrivilegeType::Read, rule! { READ }),
+        value(UserPrivilegeType::Write, rule! { WRITE }),
         value(UserPrivilegeType::Drop, rule! { DROP }),
         value(UserPrivilegeType::Create, rule! { CREATE }),
     ))(i)
@@ -1688,8 +1905,20 @@ pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
         },
     );
 
+    // STAGE s1
+    let stage = map(rule! { STAGE ~ #ident }, |(_, stage)| {
+        AccountMgrLevel::Stage(stage.name)
+    });
+
+    // UDF f1
+    let udf = map(rule! { UDF ~ #ident }, |(_, udf)| {
+        AccountMgrLevel::UDF(udf.name)
+    });
+
     rule!(
         #global : "*.*"
+        | #stage : "STAGE <stage>"
+        | #udf : "UDF <udf>"
         | #db : "<database>.*"
         | #table : "<database>.<table>"
     )(i)
@@ -1841,6 +2070,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
         | #add_column
@@ -1850,6 +2096,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #add_row_access_policy
+        | #drop_row_access_policy
     )(i)
 }
 
@@ -2069,6 +2317,52 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        map(
+            rule! {
+                QUOTA ~ "(" ~ ^#comma_separated_list1(user_quota_item) ~ ^")"
+            },
+            |(_, _, items, _)| UserOptionItem::Quota(items),
+        ),
+    ))(i)
+}
+
+pub fn user_quota_item(i: Input) -> IResult<UserQuotaItem> {
+    alt((
+        map(rule! { MAX_CPU ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            UserQuotaItem::MaxCpu(v)
+        }),
+        map(rule! { MAX_MEMORY ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            UserQuotaItem::MaxMemory(v)
+        }),
+        map(rule! { MAX_STORAGE ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            UserQuotaItem::MaxStorage(v)
+        }),
+    ))(i)
+}
+
+pub fn workload_group_option(i: Input) -> IResult<WorkloadGroupOption> {
+    alt((
+        map(
+            rule! { MAX_CONCURRENCY ~ "=" ~ #literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::MaxConcurrency(v),
+        ),
+        map(rule! { MEMORY_SHARE ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::MemoryShare(v)
+        }),
+        map(rule! { PRIORITY ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::Priority(v)
+        }),
+        map(rule! { QUEUE_TIMEOUT ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::QueueTimeout(v)
+        }),
+        map(
+            rule! { USERS ~ "=" ~ "(" ~ #comma_separated_list0(user_identity) ~ ")" },
+            |(_, _, _, users, _)| WorkloadGroupOption::Users(users),
+        ),
+        map(
+            rule! { ROLES ~ "=" ~ "(" ~ #comma_separated_list0(literal_string) ~ ")" },
+            |(_, _, _, roles, _)| WorkloadGroupOption::Roles(roles),
+        ),
     ))(i)
 }
 
@@ -2193,6 +2487,46 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
     )(i)
 }
 
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let match_operation = alt((
+        map(
+            rule! { UPDATE ~ SET ~ ^#comma_separated_list1(update_expr) },
+            |(_, _, update_list)| MatchOperation::Update { update_list },
+        ),
+        value(MatchOperation::Delete, rule! { DELETE }),
+    ));
+    let matched_clause = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ ^THEN ~ ^#match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched_clause = map(
+        rule! {
+            WHEN ~ NOT ~ ^MATCHED ~ ( AND ~ ^#expr )? ~ ^THEN ~ ^INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ ^VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns.map(|(_, columns, _)| columns),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched_clause
+        | #unmatched_clause
+    )(i)
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
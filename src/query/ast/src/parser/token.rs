@@ -284,6 +284,10 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ADD", ignore(ascii_case))]
@@ -318,6 +322,8 @@ pub enum TokenKind {
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -356,6 +362,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -524,12 +532,16 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATE", ignore(ascii_case))]
     GENERATE,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -612,14 +624,30 @@ pub enum TokenKind {
     MASKING,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MAX_CPU", ignore(ascii_case))]
+    MAX_CPU,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_MEMORY", ignore(ascii_case))]
+    MAX_MEMORY,
+    #[token("MAX_STORAGE", ignore(ascii_case))]
+    MAX_STORAGE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MEMORY_SHARE", ignore(ascii_case))]
+    MEMORY_SHARE,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -692,28 +720,40 @@ pub enum TokenKind {
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
+    #[token("QUALIFY", ignore(ascii_case))]
+    QUALIFY,
     #[token("QUARTER", ignore(ascii_case))]
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
+    #[token("QUOTA", ignore(ascii_case))]
+    QUOTA,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
     RAWDEFLATE,
+    #[token("READ", ignore(ascii_case))]
+    READ,
     #[token("RECLUSTER", ignore(ascii_case))]
     RECLUSTER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFRESH", ignore(ascii_case))]
+    REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
@@ -730,6 +770,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIORITY", ignore(ascii_case))]
+    PRIORITY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
@@ -742,6 +784,8 @@ pub enum TokenKind {
     RECURSIVE,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("RUN", ignore(ascii_case))]
     RUN,
     #[token("GRANTS", ignore(ascii_case))]
@@ -800,6 +844,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -856,6 +902,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -872,6 +920,8 @@ pub enum TokenKind {
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
     TYPE,
+    #[token("UDF", ignore(ascii_case))]
+    UDF,
     #[token("UNBOUNDED", ignore(ascii_case))]
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
@@ -924,6 +974,12 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
+    #[token("WRITE", ignore(ascii_case))]
+    WRITE,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1290,6 +1346,7 @@ impl TokenKind {
             | TokenKind::IGNORE_RESULT
             | TokenKind::MASKING
             | TokenKind::POLICY
+            | TokenKind::QUALIFY
             if !after_as => true,
             _ => false
         }
@@ -217,6 +217,7 @@ pub trait Visitor<'ast>: Sized {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
             walk_expr(self, arg);
@@ -225,6 +226,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(over) = over {
             self.visit_window(over);
         }
+
+        if let Some(lambda) = lambda {
+            walk_expr(self, &lambda.expr)
+        }
     }
 
     fn visit_window(&mut self, window: &'ast Window) {
@@ -358,6 +363,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_unset_variable(&mut self, _stmt: &'ast UnSetStmt) {}
 
+    fn visit_begin_transaction(&mut self) {}
+
+    fn visit_commit_transaction(&mut self) {}
+
+    fn visit_abort_transaction(&mut self) {}
+
     fn visit_show_process_list(&mut self) {}
 
     fn visit_show_metrics(&mut self) {}
@@ -398,6 +409,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_update(&mut self, _update: &'ast UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &'ast ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &'ast ShowCreateCatalogStmt) {}
@@ -460,6 +473,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
@@ -569,6 +590,20 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
+    fn visit_create_workload_group(&mut self, _stmt: &'ast CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &'ast AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &'ast DropWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
@@ -599,6 +634,7 @@ pub trait Visitor<'ast>: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -633,6 +669,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(having) = having {
             walk_expr(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            walk_expr(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &'ast SelectTarget) {
@@ -214,6 +214,10 @@ pub trait VisitorMut: Sized {
                 }
             }
         }
+
+        if let Some(lambda) = lambda {
+            walk_expr_mut(self, &mut lambda.expr)
+        }
     }
 
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
@@ -230,6 +234,7 @@ pub trait VisitorMut: Sized {
         args: &mut [Expr],
         _params: &mut [Literal],
         over: &mut Option<Window>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
             walk_expr_mut(self, arg);
@@ -370,6 +375,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_settings(&mut self, _like: &mut Option<String>) {}
 
+    fn visit_begin_transaction(&mut self) {}
+
+    fn visit_commit_transaction(&mut self) {}
+
+    fn visit_abort_transaction(&mut self) {}
+
     fn visit_show_process_list(&mut self) {}
 
     fn visit_show_metrics(&mut self) {}
@@ -412,6 +423,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -474,6 +487,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
@@ -583,6 +604,20 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &mut AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt) {}
+
+    fn visit_show_workload_groups(&mut self) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
@@ -613,6 +648,7 @@ pub trait VisitorMut: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -647,6 +683,10 @@ pub trait VisitorMut: Sized {
         if let Some(having) = having {
             walk_expr_mut(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            walk_expr_mut(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &mut SelectTarget) {
@@ -94,7 +94,8 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             args,
             params,
             window,
-        } => visitor.visit_function_call(*span, *distinct, name, args, params, window),
+            lambda,
+        } => visitor.visit_function_call(*span, *distinct, name, args, params, window, lambda),
         Expr::Case {
             span,
             operand,
@@ -334,7 +335,11 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             ..
         } => visitor.visit_delete(table_reference, selection),
         Statement::Update(update) => visitor.visit_update(update),
+        Statement::MergeInto(merge_into) => visitor.visit_merge_into(merge_into),
         Statement::Copy(stmt) => visitor.visit_copy(stmt),
+        Statement::Begin => visitor.visit_begin_transaction(),
+        Statement::Commit => visitor.visit_commit_transaction(),
+        Statement::Abort => visitor.visit_abort_transaction(),
         Statement::ShowSettings { like } => visitor.visit_show_settings(like),
         Statement::ShowProcessList => visitor.visit_show_process_list(),
         Statement::ShowMetrics => visitor.visit_show_metrics(),
@@ -385,6 +390,10 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
+        Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
+        Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::CreateVirtualColumns(stmt) => visitor.visit_create_virtual_columns(stmt),
@@ -469,5 +478,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
+        Statement::CreateWorkloadGroup(stmt) => visitor.visit_create_workload_group(stmt),
+        Statement::AlterWorkloadGroup(stmt) => visitor.visit_alter_workload_group(stmt),
+        Statement::DropWorkloadGroup(stmt) => visitor.visit_drop_workload_group(stmt),
+        Statement::ShowWorkloadGroups => visitor.visit_show_workload_groups(),
     }
 }
@@ -78,6 +78,11 @@ fn test_statement() {
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit work;"#,
+        r#"rollback;"#,
+        r#"abort;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -110,6 +115,10 @@ fn test_statement() {
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
+        r#"create stream if not exists db.s on table t1 comment = 'changes of t1';"#,
+        r#"drop stream s;"#,
+        r#"create materialized view mv as select * from t1;"#,
+        r#"refresh materialized view db.mv;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
         r#"rename table d.t to e.s;"#,
@@ -125,6 +134,7 @@ fn test_statement() {
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
+        r#"ALTER USER u1 WITH QUOTA (MAX_CPU = 4, MAX_MEMORY = 1073741824, MAX_STORAGE = 10737418240);"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
@@ -205,6 +215,9 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT(a, b) ON db01.tb1 TO ROLE 'role1';"#,
+        r#"GRANT READ, WRITE ON STAGE s1 TO ROLE 'role1';"#,
+        r#"GRANT USAGE ON UDF f1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
@@ -212,6 +225,8 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT(a) ON tb1 FROM ROLE 'role1';"#,
+        r#"REVOKE WRITE ON STAGE s1 FROM ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -400,6 +415,15 @@ fn test_statement() {
         r#"SELECT * FROM t GROUP BY CUBE (a, b, c)"#,
         r#"SELECT * FROM t GROUP BY ROLLUP (a, b, c)"#,
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURN STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl WITH MAX_CONCURRENCY = 4, MEMORY_SHARE = 50, PRIORITY = 1, QUEUE_TIMEOUT = 60, USERS = ('u1', 'u2'@'10.0.0.1')"#,
+        r#"ALTER WORKLOAD GROUP etl SET ROLES = ('analyst'), MAX_CONCURRENCY = 2"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl"#,
+        r#"SHOW WORKLOAD GROUPS"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS rap_region AS (region STRING) RETURNS BOOLEAN -> region = 'EU' OR current_role() = 'admin' COMMENT = 'eu rows only'"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap_region"#,
+        r#"DESC ROW ACCESS POLICY rap_region"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap_region ON (region)"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY rap_region"#,
         r#"CREATE VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMNS FOR t"#,
@@ -629,6 +653,7 @@ fn test_expr() {
         r#"a is distinct from b"#,
         r#"1 is not distinct from null"#,
         r#"{'k1':1,'k2':2}"#,
+        r#"array_transform(arr, x -> x + 1)"#,
         // window expr
         r#"ROW_NUMBER() OVER (ORDER BY salary DESC)"#,
         r#"SUM(salary) OVER ()"#,
@@ -54,6 +54,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -191,6 +193,16 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply>;
 
+    /// Update the metas of several tables of this catalog atomically.
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "'update_multi_table_meta' not implemented",
+        ))
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
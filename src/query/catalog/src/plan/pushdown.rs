@@ -15,11 +15,13 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
+use common_vector::VectorMetric;
 
 use crate::plan::Projection;
 
@@ -74,6 +76,16 @@ pub struct AggIndexInfo {
     pub filter: Option<RemoteExpr>,
 }
 
+/// Approximate nearest neighbor search, pushed down from
+/// `ORDER BY <distance function>(<column>, <constant vector>) LIMIT <n>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorSearchInfo {
+    pub column_name: String,
+    pub metric: VectorMetric,
+    pub query: Vec<F32>,
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -101,6 +113,8 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Optional vector search, which may be answered by vector indexes.
+    pub vector_search: Option<VectorSearchInfo>,
 }
 
 /// TopK is a wrapper for topk push down items.
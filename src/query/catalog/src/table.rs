@@ -217,6 +217,24 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// The input blocks of MERGE INTO consist of the table columns, the `on_conflict_fields`
+    /// of the matched row (NULL if nothing is matched) and whether the row should be appended.
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        on_conflict_fields: Vec<TableField>,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, pipeline, on_conflict_fields);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "merge_into operation for table {} is not implemented. table engine : {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn commit_insertion(
         &self,
@@ -17,6 +17,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
@@ -27,9 +28,11 @@ use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -44,6 +47,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -62,6 +66,10 @@ pub struct ProcessInfo {
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    /// The workload group the query is admitted by, if any.
+    pub workload_group: Option<String>,
+    /// How long the query waited in the workload group queue.
+    pub queued_time: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
@@ -141,8 +149,21 @@ pub trait TableContext: Send + Sync {
     fn push_precommit_block(&self, block: DataBlock);
     fn consume_precommit_blocks(&self) -> Vec<DataBlock>;
 
+    fn txn_mgr(&self) -> TxnManagerRef;
+
+    /// The streams read by the current query, whose offsets are advanced
+    /// when the query commits its changes.
+    fn get_consumed_streams(&self) -> Vec<Arc<dyn Table>>;
+
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
+    /// Check that the current user or role is granted the privileges on the object.
+    async fn validate_privilege(
+        &self,
+        object: &GrantObject,
+        privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()>;
+
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
@@ -0,0 +1,172 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_meta_types::MatchSeq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement commits its changes by itself.
+    AutoCommit,
+    /// In an explicit transaction started by `BEGIN`, the changes are buffered
+    /// until `COMMIT`.
+    Active,
+    /// A statement of the transaction failed, the transaction can only be ended
+    /// by `COMMIT` or `ROLLBACK`, both of them discard the buffered changes.
+    Fail,
+}
+
+/// The latest version of a table mutated in the transaction.
+#[derive(Debug, Clone)]
+struct TxnTableUpdate {
+    /// The seq of the table meta when the transaction mutates it first time,
+    /// the commit fails if the table is changed by others since then.
+    base_seq: u64,
+    table_info: TableInfo,
+    copied_files: Option<UpsertTableCopiedFileReq>,
+    deduplicated_label: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TxnBuffer {
+    /// table id -> the buffered update of the table.
+    mutated_tables: BTreeMap<u64, TxnTableUpdate>,
+}
+
+impl TxnBuffer {
+    fn clear(&mut self) {
+        self.mutated_tables.clear();
+    }
+
+    fn update_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        let update = self
+            .mutated_tables
+            .entry(table_info.ident.table_id)
+            .or_insert_with(|| TxnTableUpdate {
+                base_seq: table_info.ident.seq,
+                table_info: table_info.clone(),
+                copied_files: None,
+                deduplicated_label: None,
+            });
+        update.table_info.meta = req.new_table_meta;
+
+        if let Some(copied_files) = req.copied_files {
+            match &mut update.copied_files {
+                Some(buffered) => {
+                    buffered.file_info.extend(copied_files.file_info);
+                    buffered.expire_at = buffered.expire_at.max(copied_files.expire_at);
+                    buffered.fail_if_duplicated |= copied_files.fail_if_duplicated;
+                }
+                None => update.copied_files = Some(copied_files),
+            }
+        }
+        if req.deduplicated_label.is_some() {
+            update.deduplicated_label = req.deduplicated_label;
+        }
+    }
+}
+
+/// The transaction state of a session.
+///
+/// While a transaction is active, the new table metas produced by the DML
+/// statements are kept here instead of being committed to the meta service,
+/// and they are committed together by `COMMIT`.
+#[derive(Debug, Clone)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if self.state == TxnState::Active {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// End the transaction and drop the buffered changes.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer.clear();
+    }
+
+    /// Keep the new meta of the table in the transaction instead of committing it.
+    pub fn update_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        self.txn_buffer.update_table_meta(table_info, req);
+    }
+
+    /// Get the version of the table mutated in the transaction, which is
+    /// only visible to the session of the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|update| update.table_info.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txn_buffer.mutated_tables.is_empty()
+    }
+
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .mutated_tables
+                .iter()
+                .map(|(table_id, update)| UpdateTableMetaReq {
+                    table_id: *table_id,
+                    seq: MatchSeq::Exact(update.base_seq),
+                    new_table_meta: update.table_info.meta.clone(),
+                    copied_files: update.copied_files.clone(),
+                    deduplicated_label: update.deduplicated_label.clone(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -1216,6 +1216,10 @@ pub struct QueryConfig {
     #[clap(long, parse(try_from_str), default_value = "false")]
     pub max_memory_limit_enabled: bool,
 
+    /// The max number of queries of workload groups running at the same time, 0 means no limit.
+    #[clap(long, default_value = "0")]
+    pub max_running_queries: u64,
+
     #[deprecated(note = "clickhouse tcp support is deprecated")]
     #[clap(long, default_value = "127.0.0.1")]
     pub clickhouse_handler_host: String,
@@ -1245,6 +1249,23 @@ pub struct QueryConfig {
     #[clap(long, default_value = "8900")]
     pub flight_sql_handler_port: u16,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "5433")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, default_value = "120")]
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+
+    /// Certificate of the PostgreSQL handler, clients are allowed to connect with TLS if it's set,
+    /// and passwords are only accepted on TLS connections.
+    #[clap(long, default_value_t)]
+    pub postgres_handler_tls_server_cert: String,
+
+    #[clap(long, default_value_t)]
+    pub postgres_handler_tls_server_key: String,
+
     #[clap(long, default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
@@ -1453,6 +1474,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
+            max_running_queries: self.max_running_queries,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
             clickhouse_http_handler_port: self.clickhouse_http_handler_port,
             http_handler_host: self.http_handler_host,
@@ -1461,6 +1483,12 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: self
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_tls_server_cert: self.postgres_handler_tls_server_cert,
+            postgres_handler_tls_server_key: self.postgres_handler_tls_server_key,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
@@ -1519,6 +1547,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
+            max_running_queries: inner.max_running_queries,
 
             // clickhouse tcp is deprecated
             clickhouse_handler_host: "127.0.0.1".to_string(),
@@ -1532,6 +1561,12 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: inner
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_tls_server_cert: inner.postgres_handler_tls_server_cert,
+            postgres_handler_tls_server_key: inner.postgres_handler_tls_server_key,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
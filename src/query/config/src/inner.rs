@@ -100,6 +100,11 @@ impl InnerConfig {
             && !self.query.flight_sql_tls_server_cert.is_empty()
     }
 
+    pub fn postgres_tls_server_enabled(&self) -> bool {
+        !self.query.postgres_handler_tls_server_key.is_empty()
+            && !self.query.postgres_handler_tls_server_cert.is_empty()
+    }
+
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
@@ -131,6 +136,8 @@ pub struct QueryConfig {
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
+    /// The max number of queries of workload groups running at the same time, 0 means no limit.
+    pub max_running_queries: u64,
     pub clickhouse_http_handler_host: String,
     pub clickhouse_http_handler_port: u16,
     pub http_handler_host: String,
@@ -139,6 +146,11 @@ pub struct QueryConfig {
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+    pub postgres_handler_tls_server_cert: String,
+    pub postgres_handler_tls_server_key: String,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
@@ -201,6 +213,7 @@ impl Default for QueryConfig {
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
+            max_running_queries: 0,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
             clickhouse_http_handler_port: 8124,
             http_handler_host: "127.0.0.1".to_string(),
@@ -209,6 +222,11 @@ impl Default for QueryConfig {
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
+            postgres_handler_tcp_keepalive_timeout_secs: 120,
+            postgres_handler_tls_server_cert: "".to_string(),
+            postgres_handler_tls_server_key: "".to_string(),
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -29,9 +29,12 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,7 +43,9 @@ impl From<&DataType> for ArrowDataType {
         match ty {
             DataType::Null => ArrowDataType::Null,
             DataType::Boolean => ArrowDataType::Boolean,
-            DataType::String | DataType::Bitmap => ArrowDataType::LargeBinary,
+            DataType::String | DataType::Binary | DataType::Geometry | DataType::Bitmap => {
+                ArrowDataType::LargeBinary
+            }
             DataType::Number(ty) => with_number_type!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
             }),
@@ -52,6 +57,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Decimal128(38, 0),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -144,6 +150,21 @@ impl From<&DataField> for ArrowField {
             DataType::Bitmap => {
                 metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
             }
+            DataType::Interval => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_INTERVAL.to_string(),
+                );
+            }
+            DataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
+            DataType::Geometry => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                );
+            }
             _ => Default::default(),
         };
         match ty {
@@ -201,6 +222,9 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(DataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(DataType::Bitmap),
+            Some(ARROW_EXT_TYPE_INTERVAL) => Some(DataType::Interval),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(DataType::Binary),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(DataType::Geometry),
             _ => None,
         };
 
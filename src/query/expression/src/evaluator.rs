@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::ops::Not;
+use std::ops::Range;
 
 use common_arrow::arrow::bitmap;
 use common_arrow::arrow::bitmap::Bitmap;
@@ -37,6 +38,7 @@ use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableDomain;
 use crate::types::BooleanType;
 use crate::types::DataType;
+use crate::types::Int64Type;
 use crate::types::NullableType;
 use crate::types::ValueType;
 use crate::utils::arrow::constant_bitmap;
@@ -161,6 +163,28 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                ..
+            } => {
+                let data_types = args.iter().map(|arg| arg.data_type().clone()).collect();
+                let args = args
+                    .iter()
+                    .map(|expr| self.partial_run(expr, validity.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                assert!(
+                    args.iter()
+                        .filter_map(|val| match val {
+                            Value::Column(col) => Some(col.len()),
+                            Value::Scalar(_) => None,
+                        })
+                        .all_equal()
+                );
+                let lambda_expr = lambda_expr.as_expr(self.fn_registry);
+                self.run_lambda(name, args, data_types, &lambda_expr)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -848,6 +872,318 @@ impl<'a> Evaluator<'a> {
 
     /// Evaluate a set-returning-function. Return multiple sets of results
     /// for each input row, along with the number of rows in each set.
+    /// Evaluate a higher-order function. The lambda is evaluated on the flattened elements
+    /// of the array argument in a single batch, instead of row by row.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        args: Vec<Value<AnyType>>,
+        data_types: Vec<DataType>,
+        lambda_expr: &Expr,
+    ) -> Result<Value<AnyType>> {
+        // Higher-order functions only take one array argument for now.
+        debug_assert_eq!(args.len(), 1);
+        match &args[0] {
+            Value::Scalar(scalar) => {
+                let column = ColumnBuilder::repeat(&scalar.as_ref(), 1, &data_types[0]).build();
+                let result =
+                    self.run_lambda_column(func_name, &column, &data_types[0], lambda_expr)?;
+                Ok(Value::Scalar(result.index(0).unwrap().to_owned()))
+            }
+            Value::Column(column) => {
+                let result =
+                    self.run_lambda_column(func_name, column, &data_types[0], lambda_expr)?;
+                Ok(Value::Column(result))
+            }
+        }
+    }
+
+    fn run_lambda_column(
+        &self,
+        func_name: &str,
+        column: &Column,
+        data_type: &DataType,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        match (column, data_type) {
+            (Column::Nullable(col), DataType::Nullable(inner_ty)) => {
+                let result =
+                    self.run_lambda_column(func_name, &col.column, inner_ty, lambda_expr)?;
+                let (column, validity) = match result {
+                    Column::Nullable(inner) => (inner.column, (&inner.validity) & (&col.validity)),
+                    column => (column, col.validity.clone()),
+                };
+                Ok(Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity,
+                })))
+            }
+            (Column::Array(col), DataType::Array(inner_ty)) => {
+                let (values, offsets) = Self::rebase_array_column(col);
+                match func_name {
+                    "array_transform" => {
+                        self.eval_array_transform(values, offsets, inner_ty, lambda_expr)
+                    }
+                    "array_filter" => {
+                        let params = vec![(values.clone(), (**inner_ty).clone())];
+                        let col = self.eval_filter(values, offsets, params, lambda_expr)?;
+                        Ok(Column::Array(Box::new(col)))
+                    }
+                    "array_reduce" => {
+                        self.eval_array_reduce(values, offsets, inner_ty, lambda_expr)
+                    }
+                    "array_sort" => self.eval_array_sort_by(values, offsets, inner_ty, lambda_expr),
+                    _ => unreachable!("unknown lambda function: {func_name}"),
+                }
+            }
+            (Column::Map(col), DataType::Map(box DataType::Tuple(kv_tys))) => {
+                let (values, offsets) = Self::rebase_array_column(col);
+                match func_name {
+                    "map_filter" => {
+                        // The lambda takes the key and the value of each entry as its parameters.
+                        let params = match &values {
+                            Column::Tuple(fields) => {
+                                fields.iter().cloned().zip(kv_tys.iter().cloned()).collect()
+                            }
+                            _ => unreachable!("map values should be a tuple column"),
+                        };
+                        let col = self.eval_filter(values, offsets, params, lambda_expr)?;
+                        Ok(Column::Map(Box::new(col)))
+                    }
+                    _ => unreachable!("unknown lambda function: {func_name}"),
+                }
+            }
+            _ => unreachable!("lambda function {func_name} with invalid argument: {column:?}"),
+        }
+    }
+
+    /// Rebase the offsets, so that the values column only contains the elements
+    /// of the rows, even if the array column has been sliced.
+    fn rebase_array_column(col: &ArrayColumn<AnyType>) -> (Column, Vec<u64>) {
+        let start = *col.offsets.first().unwrap();
+        let end = *col.offsets.last().unwrap();
+        let values = col.values.slice(start as usize..end as usize);
+        let offsets = col
+            .offsets
+            .iter()
+            .map(|offset| offset - start)
+            .collect::<Vec<_>>();
+        (values, offsets)
+    }
+
+    /// Evaluate the lambda with the columns as its parameters.
+    fn eval_lambda(&self, params: Vec<(Column, DataType)>, lambda_expr: &Expr) -> Result<Column> {
+        let num_rows = params[0].0.len();
+        let entries = params
+            .into_iter()
+            .map(|(column, data_type)| BlockEntry {
+                data_type,
+                value: Value::Column(column),
+            })
+            .collect();
+        let block = DataBlock::new(entries, num_rows);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(lambda_expr)?;
+        Ok(result.convert_to_full_column(lambda_expr.data_type(), num_rows))
+    }
+
+    fn eval_array_transform(
+        &self,
+        values: Column,
+        offsets: Vec<u64>,
+        inner_ty: &DataType,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        let values = self.eval_lambda(vec![(values, inner_ty.clone())], lambda_expr)?;
+        Ok(Column::Array(Box::new(ArrayColumn {
+            values,
+            offsets: offsets.into(),
+        })))
+    }
+
+    /// Keep the elements for which the lambda returns true.
+    fn eval_filter(
+        &self,
+        values: Column,
+        offsets: Vec<u64>,
+        params: Vec<(Column, DataType)>,
+        lambda_expr: &Expr,
+    ) -> Result<ArrayColumn<AnyType>> {
+        let result = self.eval_lambda(params, lambda_expr)?;
+        let bitmap = match result {
+            Column::Boolean(bitmap) => bitmap,
+            Column::Nullable(box NullableColumn {
+                column: Column::Boolean(bitmap),
+                validity,
+            }) => (&bitmap) & (&validity),
+            _ => unreachable!("the lambda of filter functions should return boolean"),
+        };
+
+        let mut new_offsets = Vec::with_capacity(offsets.len());
+        new_offsets.push(0);
+        let mut len = 0;
+        for window in offsets.windows(2) {
+            len += (window[0] as usize..window[1] as usize)
+                .filter(|i| bitmap.get_bit(*i))
+                .count() as u64;
+            new_offsets.push(len);
+        }
+
+        Ok(ArrayColumn {
+            values: values.filter(&bitmap),
+            offsets: new_offsets.into(),
+        })
+    }
+
+    /// Fold the elements of each row from left to right. In step `k`, the lambda is evaluated
+    /// once for all the rows which have more than `k` elements.
+    fn eval_array_reduce(
+        &self,
+        values: Column,
+        offsets: Vec<u64>,
+        inner_ty: &DataType,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        let num_rows = offsets.len() - 1;
+        let mut results = vec![None; num_rows];
+
+        let mut active_rows = (0..num_rows)
+            .filter(|row| offsets[row + 1] > offsets[*row])
+            .collect::<Vec<_>>();
+        let indices = active_rows
+            .iter()
+            .map(|row| offsets[*row] as u32)
+            .collect::<Vec<_>>();
+        let mut acc = values.take(&indices);
+
+        let mut step = 1;
+        loop {
+            let mut next_rows = Vec::with_capacity(active_rows.len());
+            let mut acc_indices = Vec::with_capacity(active_rows.len());
+            let mut elem_indices = Vec::with_capacity(active_rows.len());
+            for (i, row) in active_rows.iter().enumerate() {
+                if offsets[row + 1] - offsets[*row] > step {
+                    next_rows.push(*row);
+                    acc_indices.push(i as u32);
+                    elem_indices.push((offsets[*row] + step) as u32);
+                } else {
+                    results[*row] = Some(acc.index(i).unwrap().to_owned());
+                }
+            }
+            if next_rows.is_empty() {
+                break;
+            }
+
+            acc = self.eval_lambda(
+                vec![
+                    (acc.take(&acc_indices), inner_ty.clone()),
+                    (values.take(&elem_indices), inner_ty.clone()),
+                ],
+                lambda_expr,
+            )?;
+            active_rows = next_rows;
+            step += 1;
+        }
+
+        let mut builder = ColumnBuilder::with_capacity(&inner_ty.wrap_nullable(), num_rows);
+        for result in results {
+            match result {
+                Some(scalar) => builder.push(scalar.as_ref()),
+                None => builder.push_default(),
+            }
+        }
+        Ok(builder.build())
+    }
+
+    /// Sort the elements of each row with the comparator. The comparator is evaluated on
+    /// all the pairs of elements within the same row at once.
+    fn eval_array_sort_by(
+        &self,
+        values: Column,
+        offsets: Vec<u64>,
+        inner_ty: &DataType,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        struct Merge {
+            left: Range<usize>,
+            right: Range<usize>,
+            out: usize,
+        }
+
+        let max_len = offsets
+            .windows(2)
+            .map(|window| (window[1] - window[0]) as usize)
+            .max()
+            .unwrap_or(0);
+
+        // Bottom-up merge sort of all the arrays at once, each round of a pass advances
+        // every unfinished merge by one element, and the comparisons of the round are
+        // evaluated in one batch. Merge sort is stable and never panics even if the
+        // comparator is not a total order.
+        let mut indices = (0..values.len() as u32).collect::<Vec<_>>();
+        let mut width = 1;
+        while width < max_len {
+            let mut sorted_indices = indices.clone();
+            let mut merges = Vec::new();
+            for window in offsets.windows(2) {
+                let (start, end) = (window[0] as usize, window[1] as usize);
+                for left in (start..end).step_by(2 * width) {
+                    let mid = (left + width).min(end);
+                    if mid < end {
+                        merges.push(Merge {
+                            left: left..mid,
+                            right: mid..(mid + width).min(end),
+                            out: left,
+                        });
+                    }
+                }
+            }
+
+            while !merges.is_empty() {
+                let lhs_indices = merges
+                    .iter()
+                    .map(|merge| indices[merge.left.start])
+                    .collect::<Vec<_>>();
+                let rhs_indices = merges
+                    .iter()
+                    .map(|merge| indices[merge.right.start])
+                    .collect::<Vec<_>>();
+                let result = self.eval_lambda(
+                    vec![
+                        (values.take(&lhs_indices), inner_ty.clone()),
+                        (values.take(&rhs_indices), inner_ty.clone()),
+                    ],
+                    lambda_expr,
+                )?;
+                let cmp = Int64Type::try_downcast_column(&result).unwrap();
+
+                for (merge, cmp) in merges.iter_mut().zip(cmp.iter()) {
+                    let next = match *cmp > 0 {
+                        true => merge.right.next(),
+                        false => merge.left.next(),
+                    };
+                    sorted_indices[merge.out] = indices[next.unwrap()];
+                    merge.out += 1;
+                    if merge.left.is_empty() || merge.right.is_empty() {
+                        for i in merge.left.by_ref().chain(merge.right.by_ref()) {
+                            sorted_indices[merge.out] = indices[i];
+                            merge.out += 1;
+                        }
+                    }
+                }
+                merges.retain(|merge| !merge.left.is_empty() && !merge.right.is_empty());
+            }
+
+            indices = sorted_indices;
+            width *= 2;
+        }
+
+        Ok(Column::Array(Box::new(ArrayColumn {
+            values: values.take(&indices),
+            offsets: offsets.into(),
+        })))
+    }
+
     pub fn run_srf(&self, expr: &Expr) -> Result<Vec<(Value<AnyType>, usize)>> {
         if let Expr::FunctionCall {
             span,
@@ -1220,6 +1556,49 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                // The lambda may throw errors, so the domain of the result is unknown.
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
@@ -25,11 +25,14 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::GeometryType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
@@ -131,6 +134,16 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<StringType>(builder, columns)
             }
+            Column::Binary(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BinaryType>(builder, columns)
+            }
+            Column::Geometry(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<GeometryType>(builder, columns)
+            }
             Column::Timestamp(_) => {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<TimestampType>(builder, columns)
@@ -139,6 +152,10 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
             }
+            Column::Interval(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<IntervalType>(builder, columns)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -126,6 +126,14 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::String(column)
             }
+            Column::Binary(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Binary(column)
+            }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
             Column::Timestamp(column) => {
                 let ts = Self::filter_primitive_types(column, filter);
                 Column::Timestamp(ts)
@@ -134,6 +142,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let d = Self::filter_primitive_types(column, filter);
+                Column::Interval(d)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
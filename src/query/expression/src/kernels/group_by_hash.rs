@@ -630,7 +630,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
             NumberColumn::NUM_TYPE(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         }),
         Column::Boolean(v) => vec.push(v.get_bit(row) as u8),
-        Column::String(v) => {
+        Column::String(v) | Column::Binary(v) | Column::Geometry(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
         Column::Decimal(_) => {
@@ -642,6 +642,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
         }
         Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Interval(v) => vec.extend_from_slice(v[row].0.to_le_bytes().as_ref()),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             BinaryWrite::write_uvarint(vec, data.len() as u64).unwrap();
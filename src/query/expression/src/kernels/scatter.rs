@@ -18,8 +18,10 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -29,6 +31,7 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -181,6 +184,18 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Binary(column) => Self::scatter_scalars::<BinaryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
+            Column::Geometry(column) => Self::scatter_scalars::<GeometryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
             Column::Timestamp(column) => Self::scatter_scalars::<TimestampType, _>(
                 column,
                 Vec::with_capacity(length),
@@ -193,6 +208,12 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Interval(column) => Self::scatter_scalars::<IntervalType, _>(
+                column,
+                Vec::with_capacity(length),
+                indices,
+                scatter_size,
+            ),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
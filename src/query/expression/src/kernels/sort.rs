@@ -32,6 +32,8 @@ use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 
 pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 
@@ -201,6 +203,38 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_binary(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Binary)
+        .as_binary()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Binary)
+        .as_binary()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
+fn compare_geometry(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Geometry)
+        .as_geometry()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Geometry)
+        .as_geometry()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
 fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = left
         .as_any()
@@ -221,6 +255,10 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
         ArrowType::Extension(name, _, _) => {
             if name == "Variant" {
                 compare_variant(left, right)
+            } else if name == ARROW_EXT_TYPE_BINARY {
+                compare_binary(left, right)
+            } else if name == ARROW_EXT_TYPE_GEOMETRY {
+                compare_geometry(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
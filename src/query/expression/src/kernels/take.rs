@@ -17,8 +17,10 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -26,6 +28,7 @@ use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -88,6 +91,8 @@ impl Column {
             }),
             Column::Boolean(bm) => Self::take_arg_types::<BooleanType, _>(bm, indices),
             Column::String(column) => Self::take_arg_types::<StringType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
+            Column::Geometry(column) => Self::take_arg_types::<GeometryType, _>(column, indices),
             Column::Timestamp(column) => {
                 let ts = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
                     .into_number()
@@ -104,6 +109,7 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => Self::take_arg_types::<IntervalType, _>(column, indices),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -16,8 +16,10 @@ use common_arrow::arrow::compute::merge_sort::MergeSlice;
 use itertools::Itertools;
 
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -27,6 +29,7 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -238,6 +241,14 @@ impl Column {
                 let builder = StringType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<StringType>(columns, builder, indices)
             }
+            Column::Binary(_) => {
+                let builder = BinaryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BinaryType>(columns, builder, indices)
+            }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
             Column::Timestamp(_) => {
                 let builder = TimestampType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<TimestampType>(columns, builder, indices)
@@ -246,6 +257,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
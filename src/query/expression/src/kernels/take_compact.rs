@@ -19,8 +19,10 @@ use common_exception::Result;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -99,6 +101,12 @@ impl Column {
             Column::String(column) => {
                 Self::take_compact_arg_types::<StringType>(column, indices, row_num)
             }
+            Column::Binary(column) => {
+                Self::take_compact_arg_types::<BinaryType>(column, indices, row_num)
+            }
+            Column::Geometry(column) => {
+                Self::take_compact_arg_types::<GeometryType>(column, indices, row_num)
+            }
             Column::Timestamp(column) => {
                 let builder = Self::take_primitive_types(column, indices, row_num);
                 let ts = <NumberType<i64>>::upcast_column(<NumberType<i64>>::column_from_vec(
@@ -123,6 +131,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices, row_num);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
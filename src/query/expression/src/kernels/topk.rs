@@ -48,8 +48,11 @@ impl TopKSorter {
             DataType::Number(NumberDataType::NUM_TYPE) =>
                 self.push_column_internal::<NumberType::<NUM_TYPE>>(col, bitmap),
             DataType::String => self.push_column_internal::<StringType>(col, bitmap),
+            DataType::Binary => self.push_column_internal::<BinaryType>(col, bitmap),
+            DataType::Geometry => self.push_column_internal::<GeometryType>(col, bitmap),
             DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
             DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+            DataType::Interval => self.push_column_internal::<IntervalType>(col, bitmap),
             _ => {}
         });
     }
@@ -120,8 +123,11 @@ impl TopKSorter {
             DataType::Number(NumberDataType::NUM_TYPE) =>
                 self.never_match_any_internal::<NumberType::<NUM_TYPE>>(col),
             DataType::String => self.never_match_any_internal::<StringType>(col),
+            DataType::Binary => self.never_match_any_internal::<BinaryType>(col),
+            DataType::Geometry => self.never_match_any_internal::<GeometryType>(col),
             DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
             DataType::Date => self.never_match_any_internal::<DateType>(col),
+            DataType::Interval => self.never_match_any_internal::<IntervalType>(col),
             _ => false,
         })
     }
@@ -18,6 +18,7 @@ use crate::types::boolean::BooleanDomain;
 use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -30,6 +31,7 @@ use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -97,6 +99,7 @@ pub enum Domain {
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
+    Interval(SimpleDomain<months_days_micros>),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -176,6 +179,7 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Interval => Domain::Interval(IntervalType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -201,7 +205,9 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Bitmap | DataType::Variant => Domain::Undefined,
+            DataType::Binary | DataType::Geometry | DataType::Bitmap | DataType::Variant => {
+                Domain::Undefined
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -253,6 +259,10 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Interval(this), Domain::Interval(other)) => Domain::Interval(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -384,6 +394,9 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Interval(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::Interval(*min))
+            }
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -34,9 +34,12 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
+use crate::ARROW_EXT_TYPE_INTERVAL;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -116,6 +119,9 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Interval,
+    Binary,
+    Geometry,
 }
 
 impl DataSchema {
@@ -974,10 +980,13 @@ impl From<&TableDataType> for DataType {
             TableDataType::EmptyMap => DataType::EmptyMap,
             TableDataType::Boolean => DataType::Boolean,
             TableDataType::String => DataType::String,
+            TableDataType::Binary => DataType::Binary,
+            TableDataType::Geometry => DataType::Geometry,
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -1215,10 +1224,12 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            // `LargeBinary` is how strings are written by databend, while byte arrays of
+            // other writers without the UTF8 annotation are read as `Binary`.
+            ArrowDataType::Binary => TableDataType::Binary,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+                TableDataType::String
+            }
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
@@ -1239,6 +1250,9 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1314,6 +1328,16 @@ impl From<&DataType> for ArrowDataType {
             ),
             DataType::Boolean => ArrowDataType::Boolean,
             DataType::String => ArrowDataType::LargeBinary,
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             DataType::Number(ty) => with_number_type!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
             }),
@@ -1325,6 +1349,11 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1394,6 +1423,16 @@ impl From<&TableDataType> for ArrowDataType {
             ),
             TableDataType::Boolean => ArrowDataType::Boolean,
             TableDataType::String => ArrowDataType::LargeBinary,
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             TableDataType::Number(ty) => with_number_type!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
             }),
@@ -1405,6 +1444,11 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Decimal(38, 0)),
+                None,
+            ),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1474,10 +1518,13 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::EmptyArray => Ok(TableDataType::EmptyArray),
         DataType::EmptyMap => Ok(TableDataType::EmptyMap),
         DataType::String => Ok(TableDataType::String),
+        DataType::Binary => Ok(TableDataType::Binary),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Number(number_type) => Ok(TableDataType::Number(*number_type)),
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -126,6 +126,28 @@ pub fn check<Index: ColumnIndex>(
 
             check_function(*span, name, params, &args_expr, fn_registry)
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => {
+            let args: Vec<_> = args
+                .iter()
+                .map(|arg| check(arg, fn_registry))
+                .try_collect()?;
+
+            Ok(Expr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            })
+        }
     }
 }
 
@@ -676,6 +698,9 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_interval",
+    "to_binary",
+    "to_geometry",
     "to_variant",
     "to_boolean",
     "to_decimal",
@@ -14,6 +14,7 @@
 
 pub mod any;
 pub mod array;
+pub mod binary;
 pub mod bitmap;
 pub mod boolean;
 pub mod date;
@@ -21,6 +22,8 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -40,6 +43,7 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::binary::BinaryType;
 pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
@@ -48,6 +52,8 @@ use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -71,16 +77,19 @@ pub enum DataType {
     EmptyMap,
     Boolean,
     String,
+    Binary,
     Number(NumberDataType),
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
     Generic(usize),
 }
 
@@ -0,0 +1,466 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// The number of days in a month when intervals are compared or divided.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// An interval of months, days and microseconds.
+///
+/// The three parts are kept separately because the number of days in a month
+/// and the number of hours in a day (with daylight saving time) vary, they are
+/// packed into an `i128` as `months << 96 | days << 64 | micros`.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub const MIN: months_days_micros = months_days_micros::new(i32::MIN, i32::MIN, i64::MIN);
+    pub const MAX: months_days_micros = months_days_micros::new(i32::MAX, i32::MAX, i64::MAX);
+
+    pub const fn new(months: i32, days: i32, micros: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = ((days as u32) as i128) << 64;
+        let micros = (micros as u64) as i128;
+        months_days_micros(months | days | micros)
+    }
+
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The length of the interval with 30-day months and 24-hour days.
+    pub fn total_micros(&self) -> i128 {
+        (self.months() as i128 * DAYS_IN_A_MONTH as i128 + self.days() as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.microseconds() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_sub(other.months())?,
+            self.days().checked_sub(other.days())?,
+            self.microseconds().checked_sub(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Intervals are ordered by their lengths, intervals of the same length
+/// (like `1 month` and `30 days`) are ordered by their parts to be consistent with `Eq`.
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then(self.0.cmp(&other.0))
+    }
+}
+
+/// Display the interval like postgres, e.g. `1 year 2 mons 3 days 04:05:06.5`.
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn plural(n: i32) -> &'static str {
+            if n == 1 { "" } else { "s" }
+        }
+
+        let mut parts = vec![];
+        let (years, months) = (self.months() / 12, self.months() % 12);
+        if years != 0 {
+            parts.push(format!("{years} year{}", plural(years)));
+        }
+        if months != 0 {
+            parts.push(format!("{months} mon{}", plural(months)));
+        }
+        if self.days() != 0 {
+            parts.push(format!("{} day{}", self.days(), plural(self.days())));
+        }
+
+        let micros = self.microseconds();
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let hours = micros / MICROS_IN_AN_HOUR as u64;
+            let minutes = micros / MICROS_IN_A_MINUTE as u64 % 60;
+            let seconds = micros / MICROS_IN_A_SEC as u64 % 60;
+            let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+            let fraction = micros % MICROS_IN_A_SEC as u64;
+            if fraction != 0 {
+                let fraction = format!("{fraction:06}");
+                time.push('.');
+                time.push_str(fraction.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = SimpleDomain<months_days_micros>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<months_days_micros>> {
+        domain.as_interval().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<months_days_micros>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: months_days_micros::MIN,
+            max: months_days_micros::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Accumulates the parts of an interval being parsed.
+#[derive(Default)]
+struct IntervalParts {
+    months: i64,
+    days: i64,
+    micros: i64,
+}
+
+impl IntervalParts {
+    /// Add `value` of the unit, the fraction of months and days is carried down to the smaller parts.
+    fn add(&mut self, value: f64, months: i64, days: i64, micros: i64) -> Option<()> {
+        let mut carry = 0.0;
+        if months != 0 {
+            let value = value * months as f64;
+            self.months = self
+                .months
+                .checked_add(checked_f64_to_i64(value.trunc())?)?;
+            carry = value.fract() * DAYS_IN_A_MONTH as f64;
+        }
+        if days != 0 || carry != 0.0 {
+            let value = value * days as f64 + carry;
+            self.days = self.days.checked_add(checked_f64_to_i64(value.trunc())?)?;
+            carry = value.fract() * MICROS_IN_A_DAY as f64;
+        }
+        let value = value * micros as f64 + carry;
+        self.micros = self
+            .micros
+            .checked_add(checked_f64_to_i64(value.round())?)?;
+        Some(())
+    }
+
+    fn build(self, negative: bool) -> Option<months_days_micros> {
+        let (months, days, micros) = if negative {
+            (
+                self.months.checked_neg()?,
+                self.days.checked_neg()?,
+                self.micros.checked_neg()?,
+            )
+        } else {
+            (self.months, self.days, self.micros)
+        };
+        Some(months_days_micros::new(
+            i32::try_from(months).ok()?,
+            i32::try_from(days).ok()?,
+            micros,
+        ))
+    }
+}
+
+fn checked_f64_to_i64(value: f64) -> Option<i64> {
+    if value.is_finite() && value >= i64::MIN as f64 && value <= i64::MAX as f64 {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+/// The `(months, days, micros)` of a unit of intervals.
+fn interval_unit(unit: &str) -> Option<(i64, i64, i64)> {
+    let unit = match unit {
+        "us" | "usec" | "usecs" | "microsecond" | "microseconds" => (0, 0, 1),
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => (0, 0, 1_000),
+        "s" | "sec" | "secs" | "second" | "seconds" => (0, 0, MICROS_IN_A_SEC),
+        "m" | "min" | "mins" | "minute" | "minutes" => (0, 0, MICROS_IN_A_MINUTE),
+        "h" | "hr" | "hrs" | "hour" | "hours" => (0, 0, MICROS_IN_AN_HOUR),
+        "d" | "day" | "days" => (0, 1, 0),
+        "w" | "week" | "weeks" => (0, 7, 0),
+        "mon" | "mons" | "month" | "months" => (1, 0, 0),
+        "q" | "quarter" | "quarters" => (3, 0, 0),
+        "y" | "year" | "years" => (12, 0, 0),
+        "decade" | "decades" => (120, 0, 0),
+        "century" | "centuries" => (1200, 0, 0),
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// Parse the time part of an interval like `[-]HH:MM[:SS[.ffffff]]` into microseconds.
+fn parse_interval_time(time: &str) -> Option<i64> {
+    let (negative, time) = match time.strip_prefix('-') {
+        Some(time) => (true, time),
+        None => (false, time.strip_prefix('+').unwrap_or(time)),
+    };
+    let mut parts = time.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours
+        .checked_mul(MICROS_IN_AN_HOUR)?
+        .checked_add(minutes * MICROS_IN_A_MINUTE)?
+        .checked_add((seconds * MICROS_IN_A_SEC as f64).round() as i64)?;
+    Some(if negative { -micros } else { micros })
+}
+
+/// Parse an interval in the format of postgres, e.g. `1 year 2 months 3 days 04:05:06`,
+/// `-1.5 hours`, `3 days ago`.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<months_days_micros, String> {
+    let interval_str = std::str::from_utf8(interval_str.as_ref()).map_err(|e| e.to_string())?;
+    let invalid = || format!("invalid interval '{interval_str}'");
+
+    let lower = interval_str.trim().to_ascii_lowercase();
+    let mut tokens = lower.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(invalid());
+    }
+
+    let mut parts = IntervalParts::default();
+    let mut negative = false;
+    while let Some(token) = tokens.next() {
+        if token == "ago" && tokens.peek().is_none() {
+            negative = true;
+            continue;
+        }
+        if token.contains(':') {
+            let micros = parse_interval_time(token).ok_or_else(invalid)?;
+            parts.micros = parts.micros.checked_add(micros).ok_or_else(invalid)?;
+            continue;
+        }
+
+        // the unit may follow the number without spaces, like `10d`
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(split);
+        let value = number.parse::<f64>().map_err(|_| invalid())?;
+        let unit = match unit {
+            "" => match tokens.peek() {
+                Some(&unit) if unit != "ago" && !unit.contains(':') => {
+                    tokens.next();
+                    unit
+                }
+                // a number without unit is seconds
+                _ => "second",
+            },
+            unit => unit,
+        };
+        let (months, days, micros) = interval_unit(unit).ok_or_else(invalid)?;
+        parts
+            .add(value, months, days, micros)
+            .ok_or_else(|| "interval is out of range".to_string())?;
+    }
+    parts
+        .build(negative)
+        .ok_or_else(|| "interval is out of range".to_string())
+}
+
+#[inline]
+pub fn interval_to_string(interval: months_days_micros) -> impl Display {
+    interval
+}
@@ -14,7 +14,10 @@
 
 use std::ops::Range;
 
+use common_io::geometry::wkb_to_geojson;
+
 use super::date::date_to_string;
+use super::interval::interval_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -192,8 +195,10 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::Decimal(x) => x.to_float64().into(),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
+        ScalarRef::Binary(s) => jsonb::Value::String(hex::encode_upper(s).into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => interval_to_string(i).to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -226,6 +231,13 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(b);
             return;
         }
+        ScalarRef::Geometry(g) => {
+            let geojson = wkb_to_geojson(g).expect("invalid geometry wkb");
+            jsonb::parse_value(geojson.as_bytes())
+                .expect("failed to parse geojson")
+                .write_to_vec(buf);
+            return;
+        }
         ScalarRef::Tuple(fields) => {
             let values = cast_scalars_to_variants(fields, tz);
             jsonb::build_object(
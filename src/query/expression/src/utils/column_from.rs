@@ -54,8 +54,11 @@ macro_rules! for_common_scalar_values {
             { Float64Type },
             { BooleanType },
             { StringType },
+            { BinaryType },
+            { GeometryType },
             { DateType },
             { TimestampType },
+            { IntervalType },
             { VariantType },
             { BitmapType }
         }
@@ -115,6 +118,24 @@ impl<'a, D: AsRef<[&'a str]>> FromData<D, [Vec<u8>; 2]> for StringType {
     }
 }
 
+impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BinaryType {
+    fn from_data(d: D) -> Column {
+        BinaryType::upcast_column(BinaryType::column_from_ref_iter(
+            d.as_ref().iter().copied(),
+            &[],
+        ))
+    }
+}
+
+impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for GeometryType {
+    fn from_data(d: D) -> Column {
+        GeometryType::upcast_column(GeometryType::column_from_ref_iter(
+            d.as_ref().iter().copied(),
+            &[],
+        ))
+    }
+}
+
 impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BitmapType {
     fn from_data(d: D) -> Column {
         BitmapType::upcast_column(BitmapType::column_from_ref_iter(
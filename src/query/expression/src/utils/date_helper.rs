@@ -30,6 +30,8 @@ use num_traits::AsPrimitive;
 use once_cell::sync::Lazy;
 
 use crate::types::date::check_date;
+use crate::types::interval::months_days_micros;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::MICROS_IN_A_SEC;
 
@@ -380,6 +382,30 @@ impl AddTimesImpl {
     }
 }
 
+/// Add an interval to a timestamp, the months are added first, then the days and microseconds.
+pub struct AddIntervalImpl;
+
+impl AddIntervalImpl {
+    pub fn eval_date(date: i32, tz: TzLUT, interval: months_days_micros) -> Result<i64, String> {
+        Self::eval_timestamp(date as i64 * MICROS_IN_A_DAY, tz, interval)
+    }
+
+    pub fn eval_timestamp(us: i64, tz: TzLUT, interval: months_days_micros) -> Result<i64, String> {
+        let mut us = us;
+        if interval.months() != 0 {
+            us = AddMonthsImpl::eval_timestamp(us, tz, interval.months())?;
+        }
+        let days = (interval.days() as i64)
+            .checked_mul(MICROS_IN_A_DAY)
+            .ok_or_else(|| "Overflow on adding interval".to_string())?;
+        let us = us
+            .checked_add(days)
+            .and_then(|us| us.checked_add(interval.microseconds()))
+            .ok_or_else(|| "Overflow on adding interval".to_string())?;
+        check_timestamp(us)
+    }
+}
+
 #[inline]
 pub fn today_date() -> i32 {
     let now = Utc::now();
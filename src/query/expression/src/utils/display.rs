@@ -21,6 +21,7 @@ use comfy_table::Cell;
 use comfy_table::Table;
 use common_io::display_decimal_128;
 use common_io::display_decimal_256;
+use common_io::geometry::wkb_to_wkt;
 use itertools::Itertools;
 use num_traits::FromPrimitive;
 use roaring::RoaringTreemap;
@@ -40,6 +41,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::interval::interval_to_string;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -125,6 +127,7 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -156,7 +159,9 @@ impl<'a> Debug for ScalarRef<'a> {
                 }
                 write!(f, ")")
             }
-            ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Binary(s) | ScalarRef::Geometry(s) | ScalarRef::Variant(s) => {
+                write!(f, "0x{}", &hex::encode(s))
+            }
         }
     }
 }
@@ -171,8 +176,11 @@ impl Debug for Column {
             Column::Decimal(col) => write!(f, "{col:?}"),
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::String(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
@@ -202,8 +210,19 @@ impl<'a> Display for ScalarRef<'a> {
                     Ok(())
                 }
             },
+            ScalarRef::Binary(s) => {
+                for c in *s {
+                    write!(f, "{c:02X}")?;
+                }
+                Ok(())
+            }
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "'{wkt}'"),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{}'", interval_to_string(*i)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -430,6 +449,23 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}")?;
+                write!(f, "(")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{arg}")?;
+                }
+                write!(f, ", {lambda_display}")?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -439,10 +475,13 @@ impl Display for DataType {
         match &self {
             DataType::Boolean => write!(f, "Boolean"),
             DataType::String => write!(f, "String"),
+            DataType::Binary => write!(f, "Binary"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Number(num) => write!(f, "{num}"),
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -479,10 +518,13 @@ impl Display for TableDataType {
         match &self {
             TableDataType::Boolean => write!(f, "Boolean"),
             TableDataType::String => write!(f, "String"),
+            TableDataType::Binary => write!(f, "Binary"),
+            TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Number(num) => write!(f, "{num}"),
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -614,6 +656,23 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}")?;
+                write!(f, "(")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{arg}")?;
+                }
+                write!(f, ", {lambda_display}")?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -746,6 +805,26 @@ impl<Index: ColumnIndex> Expr<Index> {
                         s
                     }
                 },
+                Expr::LambdaFunctionCall {
+                    name,
+                    args,
+                    lambda_display,
+                    ..
+                } => {
+                    let mut s = String::new();
+                    s += name;
+                    s += "(";
+                    for (i, arg) in args.iter().enumerate() {
+                        if i > 0 {
+                            s += ", ";
+                        }
+                        s += &arg.sql_display();
+                    }
+                    s += ", ";
+                    s += lambda_display;
+                    s += ")";
+                    s
+                }
             }
         }
 
@@ -902,6 +981,7 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Interval(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
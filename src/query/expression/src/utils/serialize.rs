@@ -16,8 +16,17 @@ use std::cmp::Ordering;
 
 use chrono::Datelike;
 use chrono::NaiveDate;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::error::Result as ArrowResult;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
@@ -105,6 +114,114 @@ pub fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
     Encoding::Plain
 }
 
+/// Annotate the strings of the columns with UTF8, for other readers to tell them from binary columns.
+///
+/// Strings are not guaranteed to be valid UTF-8, a column holding an invalid one keeps
+/// its plain byte arrays.
+pub fn utf8_string_columns(
+    schema: &ArrowSchema,
+    chunks: Vec<ArrowChunk<Box<dyn Array>>>,
+) -> (ArrowSchema, Vec<ArrowChunk<Box<dyn Array>>>) {
+    let mut columns = chunks
+        .into_iter()
+        .map(|chunk| chunk.into_arrays())
+        .collect::<Vec<_>>();
+    let mut fields = Vec::with_capacity(schema.fields.len());
+    for (i, field) in schema.fields.iter().enumerate() {
+        let arrays = columns
+            .iter()
+            .map(|arrays| utf8_string_array(arrays[i].clone()))
+            .collect::<ArrowResult<Vec<_>>>();
+        match arrays {
+            Ok(arrays) => {
+                for (column, array) in columns.iter_mut().zip(arrays) {
+                    column[i] = array;
+                }
+                fields.push(utf8_string_field(field));
+            }
+            Err(_) => fields.push(field.clone()),
+        }
+    }
+    let schema = ArrowSchema::from(fields).with_metadata(schema.metadata.clone());
+    (schema, columns.into_iter().map(ArrowChunk::new).collect())
+}
+
+fn utf8_string_field(field: &ArrowField) -> ArrowField {
+    let data_type = utf8_string_type(&field.data_type);
+    ArrowField::new(&field.name, data_type, field.is_nullable).with_metadata(field.metadata.clone())
+}
+
+fn utf8_string_type(data_type: &ArrowDataType) -> ArrowDataType {
+    match data_type {
+        ArrowDataType::LargeBinary => ArrowDataType::LargeUtf8,
+        ArrowDataType::LargeList(field) => {
+            ArrowDataType::LargeList(Box::new(utf8_string_field(field)))
+        }
+        ArrowDataType::Map(field, sorted) => {
+            ArrowDataType::Map(Box::new(utf8_string_field(field)), *sorted)
+        }
+        ArrowDataType::Struct(fields) => {
+            ArrowDataType::Struct(fields.iter().map(utf8_string_field).collect())
+        }
+        _ => data_type.clone(),
+    }
+}
+
+fn utf8_string_array(array: Box<dyn Array>) -> ArrowResult<Box<dyn Array>> {
+    let data_type = utf8_string_type(array.data_type());
+    Ok(match array.data_type() {
+        ArrowDataType::LargeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .expect("array should be `BinaryArray<i64>`");
+            Box::new(Utf8Array::<i64>::try_new(
+                data_type,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )?)
+        }
+        ArrowDataType::LargeList(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<ListArray<i64>>()
+                .expect("array should be `ListArray<i64>`");
+            Box::new(ListArray::<i64>::new(
+                data_type,
+                array.offsets().clone(),
+                utf8_string_array(array.values().clone())?,
+                array.validity().cloned(),
+            ))
+        }
+        ArrowDataType::Map(_, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<MapArray>()
+                .expect("array should be `MapArray`");
+            Box::new(MapArray::new(
+                data_type,
+                array.offsets().clone(),
+                utf8_string_array(array.field().clone())?,
+                array.validity().cloned(),
+            ))
+        }
+        ArrowDataType::Struct(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .expect("array should be `StructArray`");
+            let values = array.values().iter().cloned().map(utf8_string_array);
+            Box::new(StructArray::new(
+                data_type,
+                values.collect::<ArrowResult<_>>()?,
+                array.validity().cloned(),
+            ))
+        }
+        _ => array,
+    })
+}
+
 pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
 #[inline]
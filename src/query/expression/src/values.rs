@@ -45,6 +45,7 @@ use serde::Serializer;
 use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::DATE_MAX;
@@ -55,6 +56,9 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::GeometryType;
+use crate::types::interval::months_days_micros;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -112,6 +116,9 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Interval(months_days_micros),
+    Binary(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -124,8 +131,11 @@ pub enum ScalarRef<'a> {
     Decimal(DecimalScalar),
     Boolean(bool),
     String(&'a [u8]),
+    Binary(&'a [u8]),
+    Geometry(&'a [u8]),
     Timestamp(i64),
     Date(i32),
+    Interval(months_days_micros),
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
@@ -142,8 +152,11 @@ pub enum Column {
     Decimal(DecimalColumn),
     Boolean(Bitmap),
     String(StringColumn),
+    Binary(StringColumn),
+    Geometry(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Interval(Buffer<months_days_micros>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Bitmap(StringColumn),
@@ -161,8 +174,11 @@ pub enum ColumnBuilder {
     Decimal(DecimalColumnBuilder),
     Boolean(MutableBitmap),
     String(StringColumnBuilder),
+    Binary(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Interval(Vec<months_days_micros>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Bitmap(StringColumnBuilder),
@@ -306,8 +322,11 @@ impl Scalar {
             Scalar::Decimal(d) => ScalarRef::Decimal(*d),
             Scalar::Boolean(b) => ScalarRef::Boolean(*b),
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
+            Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
+            Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
@@ -323,6 +342,8 @@ impl Scalar {
             DataType::EmptyMap => Scalar::EmptyMap,
             DataType::Boolean => Scalar::Boolean(false),
             DataType::String => Scalar::String(vec![]),
+            DataType::Binary => Scalar::Binary(vec![]),
+            DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Number(num_ty) => Scalar::Number(match num_ty {
                 NumberDataType::UInt8 => NumberScalar::UInt8(0),
                 NumberDataType::UInt16 => NumberScalar::UInt16(0),
@@ -338,6 +359,7 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(months_days_micros::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -378,8 +400,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Decimal(d) => Scalar::Decimal(*d),
             ScalarRef::Boolean(b) => Scalar::Boolean(*b),
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
+            ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
+            ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
@@ -424,6 +449,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Interval(i) => Domain::Interval(SimpleDomain { min: *i, max: *i }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -455,7 +481,10 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Binary(_)
+            | ScalarRef::Geometry(_)
+            | ScalarRef::Bitmap(_)
+            | ScalarRef::Variant(_) => Domain::Undefined,
         }
     }
 
@@ -476,8 +505,11 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Decimal(DecimalScalar::Decimal256(_, _)) => 32,
             ScalarRef::Boolean(_) => 1,
             ScalarRef::String(s) => s.len(),
+            ScalarRef::Binary(s) => s.len(),
+            ScalarRef::Geometry(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
@@ -503,8 +535,11 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Boolean(_) => DataType::Boolean,
             ScalarRef::String(_) => DataType::String,
+            ScalarRef::Binary(_) => DataType::Binary,
+            ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
@@ -530,8 +565,11 @@ impl PartialOrd for Scalar {
             (Scalar::Decimal(d1), Scalar::Decimal(d2)) => d1.partial_cmp(d2),
             (Scalar::Boolean(b1), Scalar::Boolean(b2)) => b1.partial_cmp(b2),
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
+            (Scalar::Binary(b1), Scalar::Binary(b2)) => b1.partial_cmp(b2),
+            (Scalar::Geometry(b1), Scalar::Geometry(b2)) => b1.partial_cmp(b2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => {
@@ -570,8 +608,11 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Decimal(d1), ScalarRef::Decimal(d2)) => d1.partial_cmp(d2),
             (ScalarRef::Boolean(b1), ScalarRef::Boolean(b2)) => b1.partial_cmp(b2),
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
+            (ScalarRef::Binary(b1), ScalarRef::Binary(b2)) => b1.partial_cmp(b2),
+            (ScalarRef::Geometry(b1), ScalarRef::Geometry(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => {
@@ -614,8 +655,11 @@ impl Hash for ScalarRef<'_> {
             }),
             ScalarRef::Boolean(v) => v.hash(state),
             ScalarRef::String(v) => v.hash(state),
+            ScalarRef::Binary(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -653,10 +697,17 @@ impl PartialOrd for Column {
             (Column::Decimal(col1), Column::Decimal(col2)) => col1.partial_cmp(col2),
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1
@@ -689,6 +740,9 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -700,8 +754,11 @@ impl Column {
             Column::Decimal(col) => col.len(),
             Column::Boolean(col) => col.len(),
             Column::String(col) => col.len(),
+            Column::Binary(col) => col.len(),
+            Column::Geometry(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
@@ -720,8 +777,11 @@ impl Column {
             Column::Decimal(col) => Some(ScalarRef::Decimal(col.index(index)?)),
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
@@ -748,8 +808,11 @@ impl Column {
             Column::Decimal(col) => ScalarRef::Decimal(col.index_unchecked(index)),
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
@@ -793,12 +856,17 @@ impl Column {
                 Column::Boolean(col.clone().sliced(range.start, range.end - range.start))
             }
             Column::String(col) => Column::String(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Timestamp(col) => {
                 Column::Timestamp(col.clone().sliced(range.start, range.end - range.start))
             }
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
@@ -857,6 +925,13 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Interval(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Interval(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Array(col) => {
                 if col.len() == 0 {
                     Domain::Array(None)
@@ -890,7 +965,9 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Binary(_) | Column::Geometry(_) | Column::Bitmap(_) | Column::Variant(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -908,8 +985,11 @@ impl Column {
             }),
             Column::Boolean(_) => DataType::Boolean,
             Column::String(_) => DataType::String,
+            Column::Binary(_) => DataType::Binary,
+            Column::Geometry(_) => DataType::Geometry,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -1059,7 +1139,7 @@ impl Column {
                 common_arrow::arrow::array::BooleanArray::try_new(arrow_type, col.clone(), None)
                     .unwrap(),
             ),
-            Column::String(col) => {
+            Column::String(col) | Column::Binary(col) | Column::Geometry(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1088,6 +1168,17 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col.clone())
+                };
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1556,9 +1647,53 @@ impl Column {
                     offsets,
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BINARY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Geometry(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_INTERVAL => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::PrimitiveArray<i128>>()
+                    .expect("fail to read from arrow: array should be `PrimitiveArray<i128>`");
+                let values = unsafe {
+                    std::mem::transmute::<Buffer<i128>, Buffer<months_days_micros>>(
+                        arrow_col.values().clone(),
+                    )
+                };
+                Column::Interval(values)
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
+        // byte arrays of other writers are read into binary columns
+        let column = match (column, &data_type) {
+            (Column::String(col), DataType::Binary) => Column::Binary(col),
+            (column, _) => column,
+        };
+
         if is_nullable {
             let validity = arrow_col.validity().cloned().unwrap_or_else(|| {
                 let mut validity = MutableBitmap::with_capacity(arrow_col.len());
@@ -1572,6 +1707,7 @@ impl Column {
     }
 
     pub fn random(ty: &DataType, len: usize) -> Self {
+        use common_io::geometry::point_to_wkb;
         use jsonb::Number as JsonbNumber;
         use jsonb::Object as JsonbObject;
         use jsonb::Value as JsonbValue;
@@ -1597,6 +1733,17 @@ impl Column {
                     .map(u8::from)
                     .collect::<Vec<_>>()
             })),
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let rng = SmallRng::from_entropy();
+                rng.sample_iter(rand::distributions::Standard)
+                    // randomly generate 5 bytes.
+                    .take(5)
+                    .collect::<Vec<u8>>()
+            })),
+            DataType::Geometry => GeometryType::from_data((0..len).map(|_| {
+                let mut rng = SmallRng::from_entropy();
+                point_to_wkb(rng.gen_range(-180.0..180.0), rng.gen_range(-90.0..90.0))
+            })),
             DataType::Number(num_ty) => {
                 with_number_mapped_type!(|NUM_TYPE| match num_ty {
                     NumberDataType::NUM_TYPE => {
@@ -1624,6 +1771,18 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        months_days_micros::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-100..=100),
+                            rng.gen_range(-MICROS_IN_A_DAY..=MICROS_IN_A_DAY),
+                        )
+                    })
+                    .collect::<Vec<months_days_micros>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1771,9 +1930,12 @@ impl Column {
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.as_slice().0.len(),
-            Column::String(col) => col.data.len() + col.offsets.len() * 8,
+            Column::String(col) | Column::Binary(col) | Column::Geometry(col) => {
+                col.data.len() + col.offsets.len() * 8
+            }
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
@@ -1847,8 +2009,11 @@ impl ColumnBuilder {
             Column::Decimal(col) => ColumnBuilder::Decimal(DecimalColumnBuilder::from_column(col)),
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1906,8 +2071,11 @@ impl ColumnBuilder {
             }
             ScalarRef::Boolean(b) => ColumnBuilder::Boolean(constant_bitmap(*b, n)),
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(s) => ColumnBuilder::Binary(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Geometry(s) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1946,8 +2114,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(col) => col.len(),
             ColumnBuilder::Boolean(builder) => builder.len(),
             ColumnBuilder::String(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
@@ -1979,9 +2150,12 @@ impl ColumnBuilder {
                 builder.len() * 32
             }
             ColumnBuilder::Boolean(c) => c.as_slice().len(),
-            ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::String(col)
+            | ColumnBuilder::Binary(col)
+            | ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
@@ -2005,8 +2179,11 @@ impl ColumnBuilder {
             }),
             ColumnBuilder::Boolean(_) => DataType::Boolean,
             ColumnBuilder::String(_) => DataType::String,
+            ColumnBuilder::Binary(_) => DataType::Binary,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -2050,8 +2227,17 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::String(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -2105,7 +2291,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Number(builder), ScalarRef::Number(value)) => builder.push(value),
             (ColumnBuilder::Decimal(builder), ScalarRef::Decimal(value)) => builder.push(value),
             (ColumnBuilder::Boolean(builder), ScalarRef::Boolean(value)) => builder.push(value),
-            (ColumnBuilder::String(builder), ScalarRef::String(value)) => {
+            (ColumnBuilder::String(builder), ScalarRef::String(value))
+            | (ColumnBuilder::Binary(builder), ScalarRef::Binary(value))
+            | (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
                 builder.put_slice(value);
                 builder.commit_row();
             }
@@ -2113,6 +2301,7 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2151,9 +2340,12 @@ impl ColumnBuilder {
             ColumnBuilder::Number(builder) => builder.push_default(),
             ColumnBuilder::Decimal(builder) => builder.push_default(),
             ColumnBuilder::Boolean(builder) => builder.push(false),
-            ColumnBuilder::String(builder) => builder.commit_row(),
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(months_days_micros::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
@@ -2192,6 +2384,8 @@ impl ColumnBuilder {
                 builder.push(v);
             }
             ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Geometry(builder)
             | ColumnBuilder::Variant(builder)
             | ColumnBuilder::Bitmap(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
@@ -2209,6 +2403,9 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                builder.push(months_days_micros(i128::de_binary(reader)));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_uvarint()?;
                 for _ in 0..len {
@@ -2278,6 +2475,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::String(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Geometry(builder)
             | ColumnBuilder::Variant(builder)
             | ColumnBuilder::Bitmap(builder) => {
                 for row in 0..rows {
@@ -2301,6 +2500,12 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(months_days_micros(i128::de_binary(&mut reader)));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2371,8 +2576,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => builder.pop().map(Scalar::Decimal),
             ColumnBuilder::Boolean(builder) => builder.pop().map(Scalar::Boolean),
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
@@ -2413,7 +2621,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Boolean(builder), Column::Boolean(other)) => {
                 append_bitmap(builder, other);
             }
-            (ColumnBuilder::String(builder), Column::String(other)) => {
+            (ColumnBuilder::String(builder), Column::String(other))
+            | (ColumnBuilder::Binary(builder), Column::Binary(other))
+            | (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
                 builder.append_column(other);
             }
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
@@ -2425,6 +2635,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2460,8 +2673,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => Column::Decimal(builder.build()),
             ColumnBuilder::Boolean(builder) => Column::Boolean(builder.into()),
             ColumnBuilder::String(builder) => Column::String(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
@@ -2484,8 +2700,11 @@ impl ColumnBuilder {
             ColumnBuilder::Decimal(builder) => Scalar::Decimal(builder.build_scalar()),
             ColumnBuilder::Boolean(builder) => Scalar::Boolean(builder.get(0)),
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
@@ -0,0 +1,93 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_interval_from_string() {
+    let cases = vec![
+        (
+            "1 year 2 months 3 days 04:05:06.5",
+            months_days_micros::new(14, 3, 14_706_500_000),
+            "1 year 2 mons 3 days 04:05:06.5",
+        ),
+        (
+            "-1.5 hours",
+            months_days_micros::new(0, 0, -5_400_000_000),
+            "-01:30:00",
+        ),
+        ("3 days ago", months_days_micros::new(0, -3, 0), "-3 days"),
+        (
+            "1.5 months",
+            months_days_micros::new(1, 15, 0),
+            "1 mon 15 days",
+        ),
+        ("2 weeks", months_days_micros::new(0, 14, 0), "14 days"),
+        (
+            "10d 1h",
+            months_days_micros::new(0, 10, 3_600_000_000),
+            "10 days 01:00:00",
+        ),
+        ("90", months_days_micros::new(0, 0, 90_000_000), "00:01:30"),
+        ("1 QUARTER", months_days_micros::new(3, 0, 0), "3 mons"),
+        ("0 seconds", months_days_micros::new(0, 0, 0), "00:00:00"),
+    ];
+
+    for (s, expected, display) in cases {
+        let interval = string_to_interval(s).unwrap();
+        assert_eq!(interval, expected, "{s}");
+        assert_eq!(interval_to_string(interval).to_string(), display, "{s}");
+        assert_eq!(string_to_interval(display).unwrap(), interval, "{display}");
+    }
+}
+
+#[test]
+fn test_interval_from_invalid_string() {
+    let cases = vec!["", "day", "1 fortnight", "10:61:00", "1 day ago 2 hours"];
+    for s in cases {
+        let r = string_to_interval(s);
+        assert_eq!(r, Err(format!("invalid interval '{s}'")), "{s}");
+    }
+
+    let r = string_to_interval("3000000000 months");
+    assert_eq!(r, Err("interval is out of range".to_string()));
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let a = months_days_micros::new(1, 2, 3);
+    let b = months_days_micros::new(-1, 5, 7);
+    assert_eq!(a.checked_add(&b), Some(months_days_micros::new(0, 7, 10)));
+    assert_eq!(a.checked_sub(&b), Some(months_days_micros::new(2, -3, -4)));
+    assert_eq!(a.checked_neg(), Some(months_days_micros::new(-1, -2, -3)));
+
+    let max = months_days_micros::new(i32::MAX, 0, 0);
+    assert_eq!(max.checked_add(&a), None);
+    let min = months_days_micros::new(0, 0, i64::MIN);
+    assert_eq!(min.checked_neg(), None);
+}
+
+#[test]
+fn test_interval_ordering() {
+    let month = months_days_micros::new(1, 0, 0);
+    let days = months_days_micros::new(0, 29, 0);
+    let hours = months_days_micros::new(0, 0, 25 * 3_600_000_000);
+    let day = months_days_micros::new(0, 1, 0);
+    assert!(month > days);
+    assert!(hours > day);
+    assert!(months_days_micros::new(0, -1, 0) < months_days_micros::new(0, 0, -1));
+}
@@ -12,15 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Cursor;
 use std::vec;
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_arrow::arrow::io::parquet::write::transverse;
+use common_arrow::arrow::io::parquet::write::CompressionOptions;
+use common_arrow::arrow::io::parquet::write::Encoding;
+use common_arrow::arrow::io::parquet::write::FileWriter;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::Version;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_exception::Result;
+use common_expression::serialize::serialize_to_parquet;
+use common_expression::serialize::utf8_string_columns;
+use common_expression::types::BinaryType;
 use common_expression::types::DataType;
 use common_expression::types::StringType;
 use common_expression::Column;
+use common_expression::DataBlock;
 use common_expression::FromData;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_io::prelude::deserialize_from_slice;
 use common_io::prelude::serialize_into_buf;
 
@@ -65,3 +88,132 @@ fn test_serde_expr() -> Result<()> {
     assert!(expr == new_expr);
     Ok(())
 }
+
+#[test]
+fn test_parquet_string_and_binary() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("s", TableDataType::String),
+        TableField::new("b", TableDataType::Binary),
+    ]);
+    let types = vec![TableDataType::String, TableDataType::Binary];
+    let columns = vec![
+        StringType::from_data(vec!["a", "bc"]),
+        BinaryType::from_data(vec![b"\x00\xff".as_ref(), b"x"]),
+    ];
+
+    let mut buf = vec![];
+    let block = DataBlock::new_from_columns(columns.clone());
+    serialize_to_parquet(vec![block], &schema, &mut buf)?;
+    assert_eq!(read_parquet(buf)?, (types.clone(), columns.clone()));
+
+    // files written by others have no arrow extension types,
+    // only byte arrays annotated with UTF8 are strings.
+    let arrow_schema = ArrowSchema::from(vec![
+        ArrowField::new("s", ArrowDataType::Utf8, false),
+        ArrowField::new("b", ArrowDataType::Binary, false),
+    ]);
+    let chunk = Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["a", "bc"]).boxed(),
+        BinaryArray::<i32>::from_slice([b"\x00\xff".as_ref(), b"x"]).boxed(),
+    ]);
+    let buf = write_parquet(arrow_schema, chunk)?;
+    assert_eq!(read_parquet(buf)?, (types, columns));
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_utf8_string_columns() -> Result<()> {
+    let schema = TableSchema::new(vec![
+        TableField::new("s", TableDataType::String),
+        TableField::new("invalid", TableDataType::String),
+        TableField::new("b", TableDataType::Binary),
+    ]);
+    let types = vec![
+        TableDataType::String,
+        TableDataType::String,
+        TableDataType::Binary,
+    ];
+    let invalid = match BinaryType::from_data(vec![b"\xff".as_ref(), b"x"]) {
+        Column::Binary(col) => Column::String(col),
+        _ => unreachable!(),
+    };
+    let columns = vec![
+        StringType::from_data(vec!["a", "bc"]),
+        invalid,
+        BinaryType::from_data(vec![b"\x00\xff".as_ref(), b"x"]),
+    ];
+
+    // only the column of valid strings is annotated
+    let block = DataBlock::new_from_columns(columns.clone());
+    let (arrow_schema, chunks) =
+        utf8_string_columns(&schema.to_arrow(), vec![Chunk::try_from(block)?]);
+    let arrow_types = arrow_schema
+        .fields
+        .iter()
+        .map(|f| f.data_type.to_logical_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(arrow_types, vec![
+        ArrowDataType::LargeUtf8,
+        ArrowDataType::LargeBinary,
+        ArrowDataType::LargeBinary,
+    ]);
+
+    let chunk = chunks.into_iter().next().unwrap();
+    let buf = write_parquet(arrow_schema, chunk)?;
+    assert_eq!(read_parquet(buf)?, (types, columns));
+
+    Ok(())
+}
+
+fn write_parquet(arrow_schema: ArrowSchema, chunk: Chunk<Box<dyn Array>>) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = arrow_schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+        .collect();
+    let row_groups = RowGroupIterator::try_new(
+        std::iter::once(Ok(chunk)),
+        &arrow_schema,
+        options,
+        encodings,
+    )?;
+
+    let mut buf = vec![];
+    let mut writer = FileWriter::try_new(&mut buf, arrow_schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(buf)
+}
+
+/// Read a parquet file with the schema inferred from it.
+fn read_parquet(buf: Vec<u8>) -> Result<(Vec<TableDataType>, Vec<Column>)> {
+    let mut reader = Cursor::new(buf);
+    let metadata = pread::read_metadata(&mut reader)?;
+    let arrow_schema = pread::infer_schema(&metadata)?;
+    let schema = TableSchema::from(&arrow_schema);
+
+    let mut chunks =
+        pread::FileReader::new(reader, metadata.row_groups, arrow_schema, None, None, None);
+    let chunk = chunks.next().unwrap()?;
+    let columns = chunk
+        .arrays()
+        .iter()
+        .zip(schema.fields())
+        .map(|(array, field)| Column::from_arrow(array.as_ref(), &field.data_type().into()))
+        .collect();
+    let types = schema
+        .fields()
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect();
+    Ok((types, columns))
+}
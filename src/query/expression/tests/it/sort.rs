@@ -18,6 +18,7 @@ use std::vec;
 use common_exception::Result;
 use common_expression::types::decimal::*;
 use common_expression::types::number::*;
+use common_expression::types::BinaryType;
 use common_expression::types::StringType;
 use common_expression::Column;
 use common_expression::DataBlock;
@@ -316,3 +317,28 @@ fn test_blocks_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_binary_block_sort() -> Result<()> {
+    let block = new_block(&[
+        Int64Type::from_data(vec![1i64, 2, 3, 4]),
+        BinaryType::from_data(vec![&[0x10u8, 0xff][..], &[], &[0x10, 0x00], &[0xff]]),
+    ]);
+
+    let sort_descs = vec![SortColumnDescription {
+        offset: 1,
+        asc: true,
+        nulls_first: false,
+        is_nullable: false,
+    }];
+    let res = DataBlock::sort(&block, &sort_descs, None)?;
+    let expected = vec![
+        Int64Type::from_data(vec![2i64, 3, 1, 4]),
+        BinaryType::from_data(vec![&[][..], &[0x10, 0x00], &[0x10, 0xff], &[0xff]]),
+    ];
+    for (entry, expect) in res.columns().iter().zip(expected.iter()) {
+        assert_eq!(entry.value.as_column().unwrap(), expect);
+    }
+
+    Ok(())
+}
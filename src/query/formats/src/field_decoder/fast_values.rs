@@ -29,6 +29,8 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -48,6 +50,7 @@ use common_io::cursor_ext::BufferReadStringExt;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use common_io::prelude::FormatSettings;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
@@ -131,7 +134,11 @@ impl FastFieldDecoderValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
-            ColumnBuilder::String(c) => self.read_string(c, reader, positions),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, positions),
+            ColumnBuilder::String(c) | ColumnBuilder::Binary(c) => {
+                self.read_string(c, reader, positions)
+            }
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
             ColumnBuilder::Bitmap(_) => Err(ErrorCode::Unimplemented("not implement")),
@@ -240,6 +247,20 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let geometry = parse_to_wkb(&buf)?;
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_date<R: AsRef<[u8]>>(
         &self,
         column: &mut Vec<i32>,
@@ -281,6 +302,24 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let interval = string_to_interval(&buf).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to deserialize interval {}: {e}",
+                buf.to_str().unwrap_or("not utf8")
+            ))
+        })?;
+        column.push(interval);
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
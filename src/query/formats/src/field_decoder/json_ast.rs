@@ -26,6 +26,8 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -37,6 +39,7 @@ use common_expression::with_number_mapped_type;
 use common_expression::ColumnBuilder;
 use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use serde_json::Value;
@@ -82,7 +85,10 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
@@ -186,6 +192,34 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let binary = hex::decode(s.as_bytes())
+                    .map_err(|e| ErrorCode::BadBytes(format!("Incorrect binary value {s}: {e}")))?;
+                column.put_slice(&binary);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be string")),
+        }
+    }
+
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        let geometry = match value {
+            Value::String(s) => parse_to_wkb(s.as_bytes())?,
+            Value::Object(_) => parse_to_wkb(value.to_string().as_bytes())?,
+            _ => {
+                return Err(ErrorCode::BadBytes(
+                    "Incorrect json value, must be string or GeoJSON object",
+                ));
+            }
+        };
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -234,6 +268,19 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let interval = string_to_interval(v).map_err(|e| {
+                    ErrorCode::BadBytes(format!("Incorrect interval value {v}: {e}"))
+                })?;
+                column.push(interval);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         let v = jsonb::Value::from(value);
         v.write_to_vec(&mut column.data);
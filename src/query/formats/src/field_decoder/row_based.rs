@@ -28,6 +28,8 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -41,6 +43,7 @@ use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
 
@@ -87,7 +90,10 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
             ColumnBuilder::Bitmap(c) => self.read_string(c, reader, raw),
@@ -258,6 +264,57 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<months_days_micros>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let interval = string_to_interval(&buf).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to deserialize interval {}: {e}",
+                buf.to_str().unwrap_or("not utf8")
+            ))
+        })?;
+        column.push(interval);
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let binary = hex::decode(&buf).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to deserialize binary {}: {e}",
+                buf.to_str().unwrap_or("not utf8")
+            ))
+        })?;
+        column.put_slice(&binary);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let geometry = parse_to_wkb(&buf)?;
+        column.put_slice(&geometry);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -16,12 +16,14 @@ use chrono_tz::Tz;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::ValueType;
 use common_expression::Column;
+use common_io::constants::FALSE_BYTES_CHAR;
 use common_io::constants::FALSE_BYTES_NUM;
 use common_io::constants::INF_BYTES_LONG;
 use common_io::constants::INF_BYTES_LOWER;
 use common_io::constants::NAN_BYTES_LOWER;
 use common_io::constants::NAN_BYTES_SNAKE;
 use common_io::constants::NULL_BYTES_UPPER;
+use common_io::constants::TRUE_BYTES_CHAR;
 use common_io::constants::TRUE_BYTES_NUM;
 
 use super::helpers::write_escaped_string;
@@ -83,6 +85,22 @@ impl FieldEncoderValues {
             quote_char: b'\'',
         }
     }
+
+    // The text format of postgres, booleans are 't' and 'f'.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: CommonSettings {
+                true_bytes: TRUE_BYTES_CHAR.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_CHAR.as_bytes().to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+                disable_variant_check: false,
+            },
+            quote_char: b'\'',
+        }
+    }
 }
 
 impl FieldEncoderRowBased for FieldEncoderValues {
@@ -20,11 +20,13 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_settings::Settings;
 
+use crate::output_format::AvroOutputFormat;
 use crate::output_format::CSVOutputFormat;
 use crate::output_format::CSVWithNamesAndTypesOutputFormat;
 use crate::output_format::CSVWithNamesOutputFormat;
 use crate::output_format::JSONOutputFormat;
 use crate::output_format::NDJSONOutputFormatBase;
+use crate::output_format::OrcOutputFormat;
 use crate::output_format::OutputFormat;
 use crate::output_format::ParquetOutputFormat;
 use crate::output_format::TSVOutputFormat;
@@ -151,6 +153,8 @@ impl FileFormatOptionsExt {
             }
             FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
+            FileFormatParams::Avro(_) => Box::new(AvroOutputFormat::create(schema, self)),
+            FileFormatParams::Orc(_) => Box::new(OrcOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
                     "Unsupported output file format:{:?}",
@@ -170,6 +174,8 @@ impl FileFormatTypeExt for StageFileFormatType {
             StageFileFormatType::Parquet => "application/octet-stream",
             StageFileFormatType::NdJson => "application/x-ndjson; charset=UTF-8",
             StageFileFormatType::Json => "application/json; charset=UTF-8",
+            StageFileFormatType::Avro => "application/avro",
+            StageFileFormatType::Orc => "application/octet-stream",
             _ => "text/plain; charset=UTF-8",
         }
         .to_string()
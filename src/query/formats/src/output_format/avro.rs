@@ -0,0 +1,291 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::avro::avro_schema::error::Error as AvroError;
+use common_arrow::arrow::io::avro::avro_schema::file::CompressedBlock;
+use common_arrow::arrow::io::avro::avro_schema::schema::BytesLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::Field as AvroField;
+use common_arrow::arrow::io::avro::avro_schema::schema::IntLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::LongLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::Record;
+use common_arrow::arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use common_arrow::arrow::io::avro::avro_schema::write::write_block;
+use common_arrow::arrow::io::avro::avro_schema::write::write_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Avro object container file, the schema is embedded in the file header.
+///
+/// Like parquet, the blocks are buffered and the whole file is written in `finalize`,
+/// with one avro data block for each buffered block.
+#[derive(Default)]
+pub struct AvroOutputFormat {
+    schema: TableSchemaRef,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl AvroOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        Self {
+            schema,
+            data_blocks: vec![],
+        }
+    }
+
+    fn serialize_rows(&self, block: &DataBlock, buf: &mut Vec<u8>) -> Result<()> {
+        let columns: Vec<Column> = block
+            .convert_to_full()
+            .columns()
+            .iter()
+            .map(|column| column.value.clone().into_column().unwrap())
+            .collect();
+        let fields = self.schema.fields();
+        for row in 0..block.num_rows() {
+            for (field, column) in fields.iter().zip(columns.iter()) {
+                write_value(buf, field.data_type(), column.index(row).unwrap())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl OutputFormat for AvroOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut record_index = 0;
+        let fields = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let schema = to_avro_schema(f.data_type(), &mut record_index)?;
+                Ok(AvroField::new(f.name(), schema))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        write_metadata(&mut buf, Record::new("record", fields), None).map_err(avro_error)?;
+        for block in blocks {
+            if block.num_rows() == 0 {
+                continue;
+            }
+            let mut data = Vec::with_capacity(block.memory_size());
+            self.serialize_rows(&block, &mut data)?;
+            write_block(&mut buf, &CompressedBlock::new(block.num_rows(), data))
+                .map_err(avro_error)?;
+        }
+        Ok(buf)
+    }
+}
+
+fn avro_error(e: AvroError) -> ErrorCode {
+    ErrorCode::Internal(format!("fail to write avro file: {e:?}"))
+}
+
+fn to_avro_schema(data_type: &TableDataType, record_index: &mut usize) -> Result<AvroSchema> {
+    let schema = match data_type {
+        TableDataType::Null => AvroSchema::Null,
+        TableDataType::Nullable(inner) => {
+            AvroSchema::Union(vec![AvroSchema::Null, to_avro_schema(inner, record_index)?])
+        }
+        TableDataType::Boolean => AvroSchema::Boolean,
+        TableDataType::Number(ty) => match ty {
+            NumberDataType::UInt8
+            | NumberDataType::UInt16
+            | NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32 => AvroSchema::Int(None),
+            NumberDataType::UInt32 | NumberDataType::UInt64 | NumberDataType::Int64 => {
+                AvroSchema::Long(None)
+            }
+            NumberDataType::Float32 => AvroSchema::Float,
+            NumberDataType::Float64 => AvroSchema::Double,
+        },
+        TableDataType::Decimal(ty) => AvroSchema::Bytes(Some(BytesLogical::Decimal(
+            ty.precision() as usize,
+            ty.scale() as usize,
+        ))),
+        TableDataType::String | TableDataType::Variant | TableDataType::Interval => {
+            AvroSchema::String(None)
+        }
+        TableDataType::Bitmap | TableDataType::Binary | TableDataType::Geometry => {
+            AvroSchema::Bytes(None)
+        }
+        TableDataType::Date => AvroSchema::Int(Some(IntLogical::Date)),
+        TableDataType::Timestamp => AvroSchema::Long(Some(LongLogical::TimestampMicros)),
+        TableDataType::EmptyArray => AvroSchema::Array(Box::new(AvroSchema::Null)),
+        TableDataType::Array(inner) => {
+            AvroSchema::Array(Box::new(to_avro_schema(inner, record_index)?))
+        }
+        TableDataType::EmptyMap => AvroSchema::Map(Box::new(AvroSchema::Null)),
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                AvroSchema::Map(Box::new(to_avro_schema(&fields_type[1], record_index)?))
+            }
+            _ => unreachable!(),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            // names of avro records must be unique in a schema
+            *record_index += 1;
+            let name = format!("r{record_index}");
+            let fields = fields_name
+                .iter()
+                .zip(fields_type.iter())
+                .map(|(name, ty)| Ok(AvroField::new(name, to_avro_schema(ty, record_index)?)))
+                .collect::<Result<Vec<_>>>()?;
+            AvroSchema::Record(Record::new(name, fields))
+        }
+        ty => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Avro output format does not support type {ty}"
+            )));
+        }
+    };
+    Ok(schema)
+}
+
+fn write_long(buf: &mut Vec<u8>, v: i64) {
+    let mut z = ((v << 1) ^ (v >> 63)) as u64;
+    while z >= 0x80 {
+        buf.push((z as u8) | 0x80);
+        z >>= 7;
+    }
+    buf.push(z as u8);
+}
+
+fn write_bytes(buf: &mut Vec<u8>, v: &[u8]) {
+    write_long(buf, v.len() as i64);
+    buf.extend_from_slice(v);
+}
+
+fn write_value(buf: &mut Vec<u8>, data_type: &TableDataType, value: ScalarRef) -> Result<()> {
+    match (data_type, value) {
+        // the index of the branch in union [null, T]
+        (TableDataType::Nullable(_), ScalarRef::Null) => write_long(buf, 0),
+        (TableDataType::Nullable(inner), value) => {
+            write_long(buf, 1);
+            write_value(buf, inner, value)?;
+        }
+        (_, ScalarRef::Null) => {}
+        (_, ScalarRef::Boolean(v)) => buf.push(v as u8),
+        (_, ScalarRef::Number(v)) => match v {
+            NumberScalar::UInt8(v) => write_long(buf, v as i64),
+            NumberScalar::UInt16(v) => write_long(buf, v as i64),
+            NumberScalar::UInt32(v) => write_long(buf, v as i64),
+            NumberScalar::UInt64(v) => {
+                let v = i64::try_from(v).map_err(|_| {
+                    ErrorCode::BadArguments(format!("UInt64 value {v} out of range of avro long"))
+                })?;
+                write_long(buf, v)
+            }
+            NumberScalar::Int8(v) => write_long(buf, v as i64),
+            NumberScalar::Int16(v) => write_long(buf, v as i64),
+            NumberScalar::Int32(v) => write_long(buf, v as i64),
+            NumberScalar::Int64(v) => write_long(buf, v),
+            NumberScalar::Float32(v) => buf.extend_from_slice(&v.0.to_le_bytes()),
+            NumberScalar::Float64(v) => buf.extend_from_slice(&v.0.to_le_bytes()),
+        },
+        // big-endian two's complement of the unscaled value
+        (_, ScalarRef::Decimal(DecimalScalar::Decimal128(v, _))) => {
+            write_bytes(buf, &v.to_be_bytes())
+        }
+        (_, ScalarRef::Decimal(DecimalScalar::Decimal256(v, _))) => {
+            write_bytes(buf, &v.to_be_bytes())
+        }
+        (_, ScalarRef::String(v))
+        | (_, ScalarRef::Bitmap(v))
+        | (_, ScalarRef::Binary(v))
+        | (_, ScalarRef::Geometry(v)) => write_bytes(buf, v),
+        (_, ScalarRef::Variant(v)) => write_bytes(buf, jsonb::to_string(v).as_bytes()),
+        (_, ScalarRef::Interval(v)) => {
+            write_bytes(buf, interval_to_string(v).to_string().as_bytes())
+        }
+        (_, ScalarRef::Timestamp(v)) => write_long(buf, v),
+        (_, ScalarRef::Date(v)) => write_long(buf, v as i64),
+        (_, ScalarRef::EmptyArray) | (_, ScalarRef::EmptyMap) => write_long(buf, 0),
+        (TableDataType::Array(inner), ScalarRef::Array(column)) => {
+            if column.len() > 0 {
+                write_long(buf, column.len() as i64);
+                for item in column.iter() {
+                    write_value(buf, inner, item)?;
+                }
+            }
+            write_long(buf, 0);
+        }
+        (TableDataType::Map(inner), ScalarRef::Map(column)) => {
+            let value_type = match inner.as_ref() {
+                TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                    &fields_type[1]
+                }
+                _ => unreachable!(),
+            };
+            if column.len() > 0 {
+                write_long(buf, column.len() as i64);
+                for item in column.iter() {
+                    let mut kv = match item {
+                        ScalarRef::Tuple(kv) if kv.len() == 2 => kv,
+                        _ => unreachable!(),
+                    };
+                    let value = kv.pop().unwrap();
+                    // keys of avro maps are always strings
+                    match kv.pop().unwrap() {
+                        ScalarRef::String(k) => write_bytes(buf, k),
+                        k => write_bytes(buf, k.to_string().as_bytes()),
+                    }
+                    write_value(buf, value_type, value)?;
+                }
+            }
+            write_long(buf, 0);
+        }
+        (TableDataType::Tuple { fields_type, .. }, ScalarRef::Tuple(values)) => {
+            for (ty, value) in fields_type.iter().zip(values.into_iter()) {
+                write_value(buf, ty, value)?;
+            }
+        }
+        (ty, value) => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Avro output format does not support value {value} of type {ty}"
+            )));
+        }
+    }
+    Ok(())
+}
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
+use common_io::geometry::wkb_to_geojson;
 use common_io::prelude::FormatSettings;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
@@ -96,9 +98,15 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(interval_to_string(v).to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
+        ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
+        ScalarRef::Geometry(x) => match wkb_to_geojson(x) {
+            Ok(geojson) => serde_json::from_str(&geojson).unwrap(),
+            Err(_) => JsonValue::Null,
+        },
         ScalarRef::Array(x) => {
             let vals = x
                 .iter()
@@ -14,18 +14,22 @@
 
 use common_exception::Result;
 use common_expression::DataBlock;
+pub mod avro;
 pub mod csv;
 pub mod json;
 pub mod ndjson;
+pub mod orc;
 pub mod parquet;
 pub mod tsv;
 pub mod values;
 
+pub use avro::AvroOutputFormat;
 pub use csv::CSVOutputFormat;
 pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
 pub use json::JSONOutputFormat;
 pub use ndjson::NDJSONOutputFormatBase;
+pub use orc::OrcOutputFormat;
 pub use parquet::ParquetOutputFormat;
 pub use tsv::TSVOutputFormat;
 pub use tsv::TSVWithNamesAndTypesOutputFormat;
@@ -0,0 +1,696 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_arrow::arrow::io::orc::format::proto::column_encoding::Kind as EncodingKind;
+use common_arrow::arrow::io::orc::format::proto::stream::Kind as StreamKind;
+use common_arrow::arrow::io::orc::format::proto::r#type::Kind as TypeKind;
+use common_arrow::arrow::io::orc::format::proto::ColumnEncoding;
+use common_arrow::arrow::io::orc::format::proto::CompressionKind;
+use common_arrow::arrow::io::orc::format::proto::Footer;
+use common_arrow::arrow::io::orc::format::proto::PostScript;
+use common_arrow::arrow::io::orc::format::proto::Stream;
+use common_arrow::arrow::io::orc::format::proto::StripeFooter;
+use common_arrow::arrow::io::orc::format::proto::StripeInformation;
+use common_arrow::arrow::io::orc::format::proto::Type;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use prost::Message;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+const ORC_MAGIC: &str = "ORC";
+/// Seconds between 1970-01-01 and 2015-01-01, the epoch of ORC timestamps.
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+/// The defaults of Apache ORC, the stripe size is measured on the buffered blocks here.
+const ORC_STRIPE_SIZE: usize = 64 * 1024 * 1024;
+const ORC_COMPRESSION_BLOCK_SIZE: usize = 256 * 1024;
+
+/// ZLIB compressed ORC file without indexes and statistics.
+///
+/// The buffered blocks are written as a stripe each time they reach the stripe size,
+/// the footer is written in `finalize`.
+#[derive(Default)]
+pub struct OrcOutputFormat {
+    schema: TableSchemaRef,
+    stripe_size: usize,
+    data_blocks: Vec<DataBlock>,
+    data_blocks_size: usize,
+    /// The header and the stripes written so far.
+    buf: Vec<u8>,
+    stripes: Vec<StripeInformation>,
+    num_rows: u64,
+}
+
+impl OrcOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        Self {
+            schema,
+            stripe_size: ORC_STRIPE_SIZE,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_stripe_size(mut self, stripe_size: usize) -> Self {
+        self.stripe_size = stripe_size;
+        self
+    }
+
+    fn types(&self) -> Result<Vec<Type>> {
+        // the root column is a struct of all the fields
+        let root_type = TableDataType::Tuple {
+            fields_name: self
+                .schema
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect(),
+            fields_type: self
+                .schema
+                .fields()
+                .iter()
+                .map(|f| f.data_type().clone())
+                .collect(),
+        };
+        let mut types = vec![];
+        build_types(&root_type, &mut types)?;
+        Ok(types)
+    }
+
+    /// Write the buffered blocks as a stripe.
+    fn write_stripe(&mut self) -> Result<()> {
+        if self.data_blocks.is_empty() {
+            return Ok(());
+        }
+        let blocks = std::mem::take(&mut self.data_blocks);
+        self.data_blocks_size = 0;
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows() as u64;
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        let types = self.types()?;
+        let mut writer = StripeWriter {
+            data: vec![],
+            streams: vec![],
+            encodings: vec![ColumnEncoding::default(); types.len()],
+            next_column: 1,
+        };
+        writer.set_encoding(0, EncodingKind::Direct);
+        let columns: Vec<Column> = block
+            .convert_to_full()
+            .columns()
+            .iter()
+            .map(|column| column.value.clone().into_column().unwrap())
+            .collect();
+        for (field, column) in self.schema.fields().iter().zip(columns.iter()) {
+            let values = column.iter().collect::<Vec<_>>();
+            writer.write_column(field.data_type(), &values)?;
+        }
+
+        let stripe_footer = compress(
+            &StripeFooter {
+                streams: writer.streams,
+                columns: writer.encodings,
+                writer_timezone: Some("UTC".to_string()),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        )?;
+        self.stripes.push(StripeInformation {
+            offset: Some(self.buf.len() as u64),
+            index_length: Some(0),
+            data_length: Some(writer.data.len() as u64),
+            footer_length: Some(stripe_footer.len() as u64),
+            number_of_rows: Some(num_rows),
+            ..Default::default()
+        });
+        self.buf.extend_from_slice(&writer.data);
+        self.buf.extend_from_slice(&stripe_footer);
+        self.num_rows += num_rows;
+        Ok(())
+    }
+}
+
+impl OutputFormat for OrcOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        if self.buf.is_empty() {
+            self.buf.extend_from_slice(ORC_MAGIC.as_bytes());
+        }
+        self.data_blocks_size += block.memory_size();
+        self.data_blocks.push(block.clone());
+        if self.data_blocks_size >= self.stripe_size {
+            self.write_stripe()?;
+        }
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.buf.len() + self.data_blocks_size
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        if self.buf.is_empty() {
+            return Ok(vec![]);
+        }
+        self.write_stripe()?;
+
+        let footer = compress(
+            &Footer {
+                header_length: Some(ORC_MAGIC.len() as u64),
+                content_length: Some(self.buf.len() as u64),
+                stripes: std::mem::take(&mut self.stripes),
+                types: self.types()?,
+                number_of_rows: Some(std::mem::take(&mut self.num_rows)),
+                row_index_stride: Some(0),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        )?;
+        let postscript = PostScript {
+            footer_length: Some(footer.len() as u64),
+            compression: Some(CompressionKind::Zlib as i32),
+            compression_block_size: Some(ORC_COMPRESSION_BLOCK_SIZE as u64),
+            version: vec![0, 12],
+            metadata_length: Some(0),
+            magic: Some(ORC_MAGIC.to_string()),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        let mut buf = std::mem::take(&mut self.buf);
+        buf.extend_from_slice(&footer);
+        buf.extend_from_slice(&postscript);
+        buf.push(postscript.len() as u8);
+        Ok(buf)
+    }
+}
+
+/// Append the types of `data_type` and its children in pre-order, returns the column id.
+fn build_types(data_type: &TableDataType, types: &mut Vec<Type>) -> Result<u32> {
+    let column_id = types.len() as u32;
+    types.push(Type::default());
+
+    let mut ty = Type::default();
+    let kind = match data_type.remove_nullable() {
+        TableDataType::Boolean => TypeKind::Boolean,
+        TableDataType::Number(number) => match number {
+            NumberDataType::Int8 => TypeKind::Byte,
+            NumberDataType::UInt8 | NumberDataType::Int16 => TypeKind::Short,
+            NumberDataType::UInt16 | NumberDataType::Int32 => TypeKind::Int,
+            NumberDataType::UInt32 | NumberDataType::UInt64 | NumberDataType::Int64 => {
+                TypeKind::Long
+            }
+            NumberDataType::Float32 => TypeKind::Float,
+            NumberDataType::Float64 => TypeKind::Double,
+        },
+        TableDataType::Decimal(decimal) => {
+            ty.precision = Some(decimal.precision() as u32);
+            ty.scale = Some(decimal.scale() as u32);
+            TypeKind::Decimal
+        }
+        TableDataType::String | TableDataType::Variant | TableDataType::Interval => {
+            TypeKind::String
+        }
+        TableDataType::Bitmap | TableDataType::Binary | TableDataType::Geometry => TypeKind::Binary,
+        TableDataType::Date => TypeKind::Date,
+        TableDataType::Timestamp => TypeKind::Timestamp,
+        TableDataType::Array(inner) => {
+            ty.subtypes = vec![build_types(&inner, types)?];
+            TypeKind::List
+        }
+        TableDataType::Map(inner) => {
+            let fields_type = map_fields_type(&inner);
+            ty.subtypes = vec![
+                build_types(&fields_type[0], types)?,
+                build_types(&fields_type[1], types)?,
+            ];
+            TypeKind::Map
+        }
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            ty.field_names = fields_name;
+            ty.subtypes = fields_type
+                .iter()
+                .map(|f| build_types(f, types))
+                .collect::<Result<Vec<_>>>()?;
+            TypeKind::Struct
+        }
+        data_type => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "ORC output format does not support type {data_type}"
+            )));
+        }
+    };
+    ty.kind = Some(kind as i32);
+    types[column_id as usize] = ty;
+    Ok(column_id)
+}
+
+fn map_fields_type(data_type: &TableDataType) -> &[TableDataType] {
+    match data_type {
+        TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => fields_type,
+        _ => unreachable!(),
+    }
+}
+
+struct StripeWriter {
+    data: Vec<u8>,
+    streams: Vec<Stream>,
+    encodings: Vec<ColumnEncoding>,
+    /// Column ids are assigned in the same pre-order as `build_types`.
+    next_column: u32,
+}
+
+impl StripeWriter {
+    fn add_stream(&mut self, column: u32, kind: StreamKind, data: Vec<u8>) -> Result<()> {
+        let data = compress(&data)?;
+        self.streams.push(Stream {
+            kind: Some(kind as i32),
+            column: Some(column),
+            length: Some(data.len() as u64),
+        });
+        self.data.extend_from_slice(&data);
+        Ok(())
+    }
+
+    fn set_encoding(&mut self, column: u32, kind: EncodingKind) {
+        self.encodings[column as usize] = ColumnEncoding {
+            kind: Some(kind as i32),
+            ..Default::default()
+        };
+    }
+
+    fn write_column(&mut self, data_type: &TableDataType, values: &[ScalarRef]) -> Result<()> {
+        let column = self.next_column;
+        self.next_column += 1;
+
+        // values of nulls are not stored
+        if values.iter().any(|v| matches!(v, ScalarRef::Null)) {
+            let present = values
+                .iter()
+                .map(|v| !matches!(v, ScalarRef::Null))
+                .collect::<Vec<_>>();
+            self.add_stream(column, StreamKind::Present, encode_booleans(&present))?;
+        }
+        let values = values
+            .iter()
+            .filter(|v| !matches!(v, ScalarRef::Null))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let encoding = match data_type.remove_nullable() {
+            TableDataType::Boolean => {
+                let values = values
+                    .iter()
+                    .map(|v| matches!(v, ScalarRef::Boolean(true)))
+                    .collect::<Vec<_>>();
+                self.add_stream(column, StreamKind::Data, encode_booleans(&values))?;
+                EncodingKind::Direct
+            }
+            TableDataType::Number(NumberDataType::Int8) => {
+                let values = values
+                    .iter()
+                    .map(|v| match v {
+                        ScalarRef::Number(NumberScalar::Int8(v)) => *v as u8,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                self.add_stream(column, StreamKind::Data, encode_byte_rle(&values))?;
+                EncodingKind::Direct
+            }
+            TableDataType::Number(NumberDataType::Float32) => {
+                let mut data = Vec::with_capacity(values.len() * 4);
+                for v in values.iter() {
+                    if let ScalarRef::Number(NumberScalar::Float32(v)) = v {
+                        data.extend_from_slice(&v.0.to_le_bytes());
+                    }
+                }
+                self.add_stream(column, StreamKind::Data, data)?;
+                EncodingKind::Direct
+            }
+            TableDataType::Number(NumberDataType::Float64) => {
+                let mut data = Vec::with_capacity(values.len() * 8);
+                for v in values.iter() {
+                    if let ScalarRef::Number(NumberScalar::Float64(v)) = v {
+                        data.extend_from_slice(&v.0.to_le_bytes());
+                    }
+                }
+                self.add_stream(column, StreamKind::Data, data)?;
+                EncodingKind::Direct
+            }
+            TableDataType::Number(_) => {
+                let values = values
+                    .iter()
+                    .map(|v| match v {
+                        ScalarRef::Number(v) => number_to_i64(*v),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                self.add_stream(column, StreamKind::Data, encode_signed_rle(&values))?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Decimal(decimal) => {
+                let mut data = Vec::with_capacity(values.len() * 8);
+                for v in values.iter() {
+                    let unscaled = match v {
+                        ScalarRef::Decimal(DecimalScalar::Decimal128(v, _)) => *v,
+                        ScalarRef::Decimal(DecimalScalar::Decimal256(v, _)) => i128::try_from(*v)
+                            .map_err(|_| {
+                            ErrorCode::BadArguments(format!(
+                                "Decimal value {v} out of range of ORC decimal"
+                            ))
+                        })?,
+                        _ => unreachable!(),
+                    };
+                    write_varint_i128(&mut data, unscaled);
+                }
+                let scales = vec![decimal.scale() as i64; values.len()];
+                self.add_stream(column, StreamKind::Data, data)?;
+                self.add_stream(column, StreamKind::Secondary, encode_signed_rle(&scales))?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::String
+            | TableDataType::Variant
+            | TableDataType::Bitmap
+            | TableDataType::Binary
+            | TableDataType::Geometry
+            | TableDataType::Interval => {
+                let mut data = vec![];
+                let mut lengths = Vec::with_capacity(values.len());
+                for v in values.iter() {
+                    let len = data.len();
+                    match v {
+                        ScalarRef::String(v)
+                        | ScalarRef::Bitmap(v)
+                        | ScalarRef::Binary(v)
+                        | ScalarRef::Geometry(v) => data.extend_from_slice(v),
+                        ScalarRef::Variant(v) => {
+                            data.extend_from_slice(jsonb::to_string(v).as_bytes())
+                        }
+                        ScalarRef::Interval(v) => {
+                            data.extend_from_slice(interval_to_string(*v).to_string().as_bytes())
+                        }
+                        _ => unreachable!(),
+                    }
+                    lengths.push((data.len() - len) as u64);
+                }
+                self.add_stream(column, StreamKind::Data, data)?;
+                self.add_stream(column, StreamKind::Length, encode_unsigned_rle(&lengths))?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Date => {
+                let values = values
+                    .iter()
+                    .map(|v| match v {
+                        ScalarRef::Date(v) => *v as i64,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                self.add_stream(column, StreamKind::Data, encode_signed_rle(&values))?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Timestamp => {
+                let mut seconds = Vec::with_capacity(values.len());
+                let mut nanos = Vec::with_capacity(values.len());
+                for v in values.iter() {
+                    let micros = match v {
+                        ScalarRef::Timestamp(v) => *v,
+                        _ => unreachable!(),
+                    };
+                    seconds.push(micros.div_euclid(1_000_000) - ORC_TIMESTAMP_BASE_SECONDS);
+                    // the low 3 bits are the count of trailing zeros removed, none here
+                    nanos.push((micros.rem_euclid(1_000_000) as u64 * 1000) << 3);
+                }
+                self.add_stream(column, StreamKind::Data, encode_signed_rle(&seconds))?;
+                self.add_stream(column, StreamKind::Secondary, encode_unsigned_rle(&nanos))?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Array(inner) => {
+                let mut lengths = Vec::with_capacity(values.len());
+                let mut items = vec![];
+                for v in values.iter() {
+                    match v {
+                        ScalarRef::Array(c) => {
+                            lengths.push(c.len() as u64);
+                            items.extend(c.iter());
+                        }
+                        ScalarRef::EmptyArray => lengths.push(0),
+                        _ => unreachable!(),
+                    }
+                }
+                self.add_stream(column, StreamKind::Length, encode_unsigned_rle(&lengths))?;
+                self.write_column(&inner, &items)?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Map(inner) => {
+                let fields_type = map_fields_type(&inner);
+                let mut lengths = Vec::with_capacity(values.len());
+                let mut keys = vec![];
+                let mut items = vec![];
+                for v in values.iter() {
+                    match v {
+                        ScalarRef::Map(c) => {
+                            lengths.push(c.len() as u64);
+                            for kv in c.iter() {
+                                match kv {
+                                    ScalarRef::Tuple(mut kv) if kv.len() == 2 => {
+                                        items.push(kv.pop().unwrap());
+                                        keys.push(kv.pop().unwrap());
+                                    }
+                                    _ => unreachable!(),
+                                }
+                            }
+                        }
+                        ScalarRef::EmptyMap => lengths.push(0),
+                        _ => unreachable!(),
+                    }
+                }
+                self.add_stream(column, StreamKind::Length, encode_unsigned_rle(&lengths))?;
+                self.write_column(&fields_type[0], &keys)?;
+                self.write_column(&fields_type[1], &items)?;
+                EncodingKind::DirectV2
+            }
+            TableDataType::Tuple { fields_type, .. } => {
+                for (i, ty) in fields_type.iter().enumerate() {
+                    let children = values
+                        .iter()
+                        .map(|v| match v {
+                            ScalarRef::Tuple(t) => t[i].clone(),
+                            _ => unreachable!(),
+                        })
+                        .collect::<Vec<_>>();
+                    self.write_column(ty, &children)?;
+                }
+                EncodingKind::Direct
+            }
+            ty => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "ORC output format does not support type {ty}"
+                )));
+            }
+        };
+        self.set_encoding(column, encoding);
+        Ok(())
+    }
+}
+
+fn number_to_i64(v: NumberScalar) -> Result<i64> {
+    Ok(match v {
+        NumberScalar::UInt8(v) => v as i64,
+        NumberScalar::UInt16(v) => v as i64,
+        NumberScalar::UInt32(v) => v as i64,
+        NumberScalar::UInt64(v) => i64::try_from(v).map_err(|_| {
+            ErrorCode::BadArguments(format!("UInt64 value {v} out of range of ORC long"))
+        })?,
+        NumberScalar::Int8(v) => v as i64,
+        NumberScalar::Int16(v) => v as i64,
+        NumberScalar::Int32(v) => v as i64,
+        NumberScalar::Int64(v) => v,
+        NumberScalar::Float32(_) | NumberScalar::Float64(_) => unreachable!(),
+    })
+}
+
+/// Split `data` into compression blocks, each one is deflated and prefixed by a 3 bytes
+/// header `len << 1 | is_original`, the block is kept as it is if it doesn't get smaller.
+fn compress(data: &[u8]) -> Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(data.len() / 2 + 3);
+    for chunk in data.chunks(ORC_COMPRESSION_BLOCK_SIZE) {
+        let mut encoder =
+            DeflateEncoder::new(Vec::with_capacity(chunk.len()), Compression::default());
+        encoder.write_all(chunk)?;
+        let compressed = encoder.finish()?;
+        let (header, block) = if compressed.len() < chunk.len() {
+            (compressed.len() << 1, compressed.as_slice())
+        } else {
+            (chunk.len() << 1 | 1, chunk)
+        };
+        buf.extend_from_slice(&(header as u32).to_le_bytes()[..3]);
+        buf.extend_from_slice(block);
+    }
+    Ok(buf)
+}
+
+/// The number of values equal to `values[start]` from `start`, at most `max`.
+fn repeat_count<T: PartialEq>(values: &[T], start: usize, max: usize) -> usize {
+    values[start..]
+        .iter()
+        .take(max)
+        .take_while(|v| **v == values[start])
+        .count()
+}
+
+/// Byte run length encoding: 3 to 130 repeated bytes have a header `len - 3` followed by
+/// the byte, at most 128 literal bytes have a header `-len` followed by the bytes.
+fn encode_byte_rle(values: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(values.len() + values.len() / 128 + 1);
+    let mut i = 0;
+    while i < values.len() {
+        let repeat = repeat_count(values, i, 130);
+        if repeat >= 3 {
+            buf.push((repeat - 3) as u8);
+            buf.push(values[i]);
+            i += repeat;
+            continue;
+        }
+        let start = i;
+        while i < values.len() && i - start < 128 && repeat_count(values, i, 3) < 3 {
+            i += 1;
+        }
+        buf.push((-((i - start) as i16)) as u8);
+        buf.extend_from_slice(&values[start..i]);
+    }
+    buf
+}
+
+/// Booleans are packed into bytes from the most significant bit, then byte run length encoded.
+fn encode_booleans(values: &[bool]) -> Vec<u8> {
+    let bytes = values
+        .chunks(8)
+        .map(|bits| {
+            bits.iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, bit)| byte | ((*bit as u8) << (7 - i)))
+        })
+        .collect::<Vec<_>>();
+    encode_byte_rle(&bytes)
+}
+
+/// Integer run length encoding v2, 3 to 10 repeated values are written with the SHORT_REPEAT
+/// sub-encoding and the others with the DIRECT sub-encoding.
+fn encode_unsigned_rle(values: &[u64]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(values.len() + 2);
+    let mut literals_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let repeat = repeat_count(values, i, 10);
+        if repeat < 3 {
+            i += 1;
+            continue;
+        }
+        encode_direct_runs(&mut buf, &values[literals_start..i]);
+        // a 1 byte header (encoding, value width in bytes and run length) followed by the
+        // big-endian value
+        let width = ((64 - values[i].leading_zeros() as usize + 7) / 8).max(1);
+        buf.push((((width - 1) << 3) | (repeat - 3)) as u8);
+        buf.extend_from_slice(&values[i].to_be_bytes()[8 - width..]);
+        i += repeat;
+        literals_start = i;
+    }
+    encode_direct_runs(&mut buf, &values[literals_start..]);
+    buf
+}
+
+/// The bit widths of DIRECT runs and their codes in the header. Only the widths aligned
+/// like the writer of Apache ORC are used, other readers may not support the others.
+const RLE_V2_DIRECT_WIDTHS: [(u8, u32); 11] = [
+    (0, 1),
+    (1, 2),
+    (3, 4),
+    (7, 8),
+    (15, 16),
+    (23, 24),
+    (27, 32),
+    (28, 40),
+    (29, 48),
+    (30, 56),
+    (31, 64),
+];
+
+/// DIRECT runs of at most 512 values, each one is a 2 bytes header (encoding, bit width
+/// and run length) followed by the values bit packed big-endian with the narrowest width.
+fn encode_direct_runs(buf: &mut Vec<u8>, values: &[u64]) {
+    for chunk in values.chunks(512) {
+        let bits = chunk
+            .iter()
+            .map(|v| 64 - v.leading_zeros())
+            .max()
+            .unwrap_or_default();
+        let &(code, width) = RLE_V2_DIRECT_WIDTHS
+            .iter()
+            .find(|(_, width)| *width >= bits)
+            .unwrap();
+        let len = chunk.len() - 1;
+        buf.push(0x40 | (code << 1) | (len >> 8) as u8);
+        buf.push((len & 0xff) as u8);
+
+        let mut acc = 0u128;
+        let mut acc_bits = 0u32;
+        for v in chunk {
+            acc = (acc << width) | *v as u128;
+            acc_bits += width;
+            while acc_bits >= 8 {
+                acc_bits -= 8;
+                buf.push((acc >> acc_bits) as u8);
+            }
+            acc &= (1 << acc_bits) - 1;
+        }
+        if acc_bits > 0 {
+            buf.push((acc << (8 - acc_bits)) as u8);
+        }
+    }
+}
+
+fn encode_signed_rle(values: &[i64]) -> Vec<u8> {
+    let values = values
+        .iter()
+        .map(|v| ((v << 1) ^ (v >> 63)) as u64)
+        .collect::<Vec<_>>();
+    encode_unsigned_rle(&values)
+}
+
+/// Zigzag encoded unbounded base 128 varint.
+fn write_varint_i128(buf: &mut Vec<u8>, v: i128) {
+    let mut z = ((v << 1) ^ (v >> 127)) as u128;
+    while z >= 0x80 {
+        buf.push((z as u8) | 0x80);
+        z >>= 7;
+    }
+    buf.push(z as u8);
+}
@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::chunk::Chunk;
 use common_exception::Result;
+use common_expression::serialize::utf8_string_columns;
 use common_expression::DataBlock;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
-use storages_common_blocks::blocks_to_parquet;
+use storages_common_blocks::chunks_to_parquet;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::output_format::OutputFormat;
@@ -52,8 +54,13 @@ impl OutputFormat for ParquetOutputFormat {
         if blocks.is_empty() {
             return Ok(vec![]);
         }
+        let batches = blocks
+            .into_iter()
+            .map(Chunk::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let (arrow_schema, batches) = utf8_string_columns(&self.schema.to_arrow(), batches);
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::LZ4)?;
+        let _ = chunks_to_parquet(arrow_schema, batches, &mut buf, TableCompression::LZ4)?;
         Ok(buf)
     }
 }
@@ -0,0 +1,180 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::io::Read;
+
+use common_arrow::arrow::io::avro::avro_schema::read::block_iterator;
+use common_arrow::arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use common_arrow::arrow::io::avro::avro_schema::read::read_metadata;
+use common_arrow::arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use common_arrow::arrow::io::orc::format;
+use common_arrow::arrow::io::orc::format::proto::stream::Kind;
+use common_arrow::arrow::io::orc::format::proto::CompressionKind;
+use common_exception::Result;
+use common_expression::types::number::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_formats::output_format::OrcOutputFormat;
+use common_formats::output_format::OutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_settings::Settings;
+use pretty_assertions::assert_eq;
+
+use crate::get_output_format_clickhouse;
+use crate::output_format_utils::get_simple_block;
+
+#[test]
+fn test_avro_output() -> Result<()> {
+    let (schema, block) = get_simple_block(true);
+    let mut formatter = get_output_format_clickhouse("avro", schema)?;
+    assert!(formatter.serialize_block(&block)?.is_empty());
+    let buffer = formatter.finalize()?;
+
+    let mut reader = Cursor::new(&buffer);
+    let metadata = read_metadata(&mut reader).unwrap();
+    let names = metadata
+        .record
+        .fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["c1", "c2", "c3", "c4", "c5"]);
+    assert_eq!(
+        metadata.record.fields[0].schema,
+        AvroSchema::Union(vec![AvroSchema::Null, AvroSchema::Int(None)])
+    );
+
+    let mut blocks = block_iterator(reader, metadata.compression, metadata.marker);
+    let block = blocks.next().unwrap().unwrap();
+    assert_eq!(block.number_of_rows, 3);
+    // each value is prefixed by the union index 1 (zigzag 2): 1, "a", true
+    assert_eq!(&block.data[..7], &[2, 2, 2, 2, b'a', 2, 1]);
+    assert!(blocks.next().unwrap().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_orc_output() -> Result<()> {
+    let (schema, block) = get_simple_block(false);
+    let mut formatter = get_output_format_clickhouse("orc", schema)?;
+    assert!(formatter.serialize_block(&block)?.is_empty());
+    let buffer = formatter.finalize()?;
+
+    let mut reader = Cursor::new(&buffer);
+    let metadata = format::read::read_metadata(&mut reader).unwrap();
+    assert_eq!(metadata.footer.number_of_rows(), 3);
+    assert_eq!(metadata.footer.stripes.len(), 1);
+    assert_eq!(metadata.footer.types[0].field_names, vec![
+        "c1", "c2", "c3", "c4", "c5"
+    ]);
+
+    let footer = format::read::read_stripe_footer(&mut reader, &metadata, 0, &mut vec![]).unwrap();
+    let column =
+        format::read::read_stripe_column(&mut reader, &metadata, 0, footer.clone(), 1, vec![])
+            .unwrap();
+    let stream = column.get_stream(Kind::Data, vec![]).unwrap();
+    let values = format::read::decode::SignedRleV2Iter::new(stream, 3, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let column =
+        format::read::read_stripe_column(&mut reader, &metadata, 0, footer, 2, vec![]).unwrap();
+    let stream = column.get_stream(Kind::Length, vec![]).unwrap();
+    let lengths = format::read::decode::UnsignedRleV2Iter::new(stream, 3, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(lengths, vec![1, 2, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_orc_output_stripes() -> Result<()> {
+    let schema = TableSchemaRefExt::create(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new("name", TableDataType::String),
+    ]);
+    let settings = Settings::create("default".to_string());
+    let options = FileFormatOptionsExt::create_from_settings(&settings)?;
+    // each block reaches the stripe size
+    let mut formatter = OrcOutputFormat::create(schema, &options).with_stripe_size(1);
+    for i in 0..3 {
+        let block = DataBlock::new_from_columns(vec![
+            Int64Type::from_data((i * 1000..(i + 1) * 1000).collect::<Vec<i64>>()),
+            StringType::from_data(
+                (0..1000)
+                    .map(|j| if j < 500 { "a" } else { "bc" })
+                    .collect::<Vec<_>>(),
+            ),
+        ]);
+        assert!(formatter.serialize_block(&block)?.is_empty());
+    }
+    let buffer = formatter.finalize()?;
+
+    let mut reader = Cursor::new(&buffer);
+    let metadata = format::read::read_metadata(&mut reader).unwrap();
+    assert_eq!(metadata.postscript.compression(), CompressionKind::Zlib);
+    assert_eq!(metadata.footer.number_of_rows(), 3000);
+    assert_eq!(metadata.footer.stripes.len(), 3);
+
+    let mut ids = vec![];
+    for stripe in 0..3 {
+        let footer =
+            format::read::read_stripe_footer(&mut reader, &metadata, stripe, &mut vec![]).unwrap();
+        let column = format::read::read_stripe_column(
+            &mut reader,
+            &metadata,
+            stripe,
+            footer.clone(),
+            1,
+            vec![],
+        )
+        .unwrap();
+        // the zigzag encoded ids are less than 2^13, packed in 2 DIRECT runs of 16 bits values
+        let mut data = vec![];
+        column
+            .get_stream(Kind::Data, vec![])
+            .unwrap()
+            .read_to_end(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), 2 + 512 * 2 + 2 + 488 * 2);
+        let stream = column.get_stream(Kind::Data, vec![]).unwrap();
+        ids.extend(
+            format::read::decode::SignedRleV2Iter::new(stream, 1000, vec![])
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .unwrap(),
+        );
+
+        // the lengths are written as SHORT_REPEAT runs
+        let column =
+            format::read::read_stripe_column(&mut reader, &metadata, stripe, footer, 2, vec![])
+                .unwrap();
+        let stream = column.get_stream(Kind::Length, vec![]).unwrap();
+        let lengths = format::read::decode::UnsignedRleV2Iter::new(stream, 1000, vec![])
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .unwrap();
+        let expected = (0..1000)
+            .map(|j| if j < 500 { 1 } else { 2 })
+            .collect::<Vec<u64>>();
+        assert_eq!(lengths, expected);
+    }
+    assert_eq!(ids, (0..3000).collect::<Vec<i64>>());
+    Ok(())
+}
@@ -33,6 +33,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::aggregate_sum::DecimalSumState;
+use super::aggregate_sum::IntervalSumState;
 use super::aggregate_sum::NumberSumState;
 use super::aggregate_sum::SumState;
 use super::StateAddr;
@@ -220,6 +221,12 @@ pub fn try_create_aggregate_avg_function(
                 decimal_size.scale - s.scale,
             )
         }
+        DataType::Interval => AggregateAvgFunction::<IntervalSumState>::try_create(
+            display_name,
+            arguments,
+            DataType::Interval,
+            0,
+        ),
         _ => Err(ErrorCode::BadDataValueType(format!(
             "AggregateAvgFunction does not support type '{:?}'",
             arguments[0]
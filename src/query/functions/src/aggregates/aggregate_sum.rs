@@ -21,11 +21,15 @@ use common_arrow::arrow::bitmap::Bitmap;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::decimal::*;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::DAYS_IN_A_MONTH;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::number::Int8Type;
 use common_expression::types::number::Number;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::DecimalDataType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
@@ -254,6 +258,102 @@ where T: Decimal
     }
 }
 
+#[derive(Default)]
+pub struct IntervalSumState {
+    pub value: months_days_micros,
+}
+
+impl IntervalSumState {
+    #[inline]
+    fn add(&mut self, other: months_days_micros) -> Result<()> {
+        self.value = self.value.checked_add(&other).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, other))
+        })?;
+        Ok(())
+    }
+}
+
+impl SumState for IntervalSumState {
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_into_buf(writer, &self.value)
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        self.value = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn accumulate_row(&mut self, column: &Column, row: usize) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(column).unwrap();
+        self.add(buffer[row])
+    }
+
+    fn accumulate(&mut self, column: &Column, validity: Option<&Bitmap>) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(column).unwrap();
+        match validity {
+            Some(validity) => {
+                for (v, valid) in buffer.iter().zip(validity.iter()) {
+                    if valid {
+                        self.add(*v)?;
+                    }
+                }
+            }
+            None => {
+                for v in buffer.iter() {
+                    self.add(*v)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(places: &[StateAddr], offset: usize, columns: &Column) -> Result<()> {
+        let buffer = IntervalType::try_downcast_column(columns).unwrap();
+        for (v, place) in buffer.iter().zip(places.iter()) {
+            let state = place.next(offset).get::<IntervalSumState>();
+            state.add(*v)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.add(other.value)
+    }
+
+    fn merge_result(&mut self, builder: &mut ColumnBuilder) -> Result<()> {
+        let builder = IntervalType::try_downcast_builder(builder).unwrap();
+        builder.push(self.value);
+        Ok(())
+    }
+
+    /// The remainder of the months is carried into days and the remainder of the days
+    /// into microseconds, like PostgreSQL does.
+    fn merge_avg_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        count: u64,
+        _scale_add: u8,
+    ) -> Result<()> {
+        let builder = IntervalType::try_downcast_builder(builder).unwrap();
+        if count == 0 {
+            builder.push(months_days_micros::default());
+            return Ok(());
+        }
+
+        let count = count as i128;
+        let months = self.value.months() as i128;
+        let days = self.value.days() as i128 + (months % count) * DAYS_IN_A_MONTH as i128;
+        let micros = self.value.microseconds() as i128 + (days % count) * MICROS_IN_A_DAY as i128;
+        builder.push(months_days_micros::new(
+            (months / count) as i32,
+            (days / count) as i32,
+            (micros / count) as i64,
+        ));
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct AggregateSumFunction<State> {
     display_name: String,
@@ -403,6 +503,11 @@ pub fn try_create_aggregate_sum_function(
                 DataType::Decimal(DecimalDataType::from_size(decimal_size)?),
             )
         }
+        DataType::Interval => AggregateSumFunction::<IntervalSumState>::try_create(
+            display_name,
+            arguments,
+            DataType::Interval,
+        ),
         _ => Err(ErrorCode::BadDataValueType(format!(
             "AggregateSumFunction does not support type '{:?}'",
             arguments[0]
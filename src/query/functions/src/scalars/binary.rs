@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::BinaryType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_| FunctionDomain::Full,
+        |val, _| val.len() as u64,
+    );
+
+    register_string_to_binary(registry);
+    register_binary_to_string(registry);
+    register_hex(registry);
+    register_base64(registry);
+}
+
+fn register_string_to_binary(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+}
+
+/// Converting to `String` requires the bytes to be valid UTF-8.
+fn register_binary_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    fn eval_binary_to_string(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+            match std::str::from_utf8(val) {
+                Ok(s) => output.put_str(s),
+                Err(e) => ctx.set_error(output.len(), format!("invalid utf8 sequence: {e}")),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_hex(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            output.put_str(&hex::encode_upper(val));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_| FunctionDomain::MayThrow,
+        eval_from_hex,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_from_hex",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_from_hex),
+    );
+
+    fn eval_from_hex(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            match hex::decode(val) {
+                Ok(bytes) => output.put_slice(&bytes),
+                Err(e) => ctx.set_error(output.len(), format!("invalid hex string: {e}")),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_base64(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    // `from_base64` of strings returns a string, the bytes are decoded as binary by these functions.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_base64_binary",
+        |_| FunctionDomain::MayThrow,
+        eval_from_base64,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_from_base64_binary",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_from_base64),
+    );
+
+    fn eval_from_base64(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+            if let Err(e) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
+                ctx.set_error(output.len(), format!("invalid base64 string: {e}"));
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
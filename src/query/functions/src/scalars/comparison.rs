@@ -22,11 +22,14 @@ use common_expression::types::string::StringDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::GeometryType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -55,8 +58,11 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
+    register_geometry_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -160,6 +166,52 @@ fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
+fn register_geometry_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "eq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "noteq",
+        |_, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+}
+
 fn register_date_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, DateType);
 }
@@ -168,6 +220,10 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, IntervalType);
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
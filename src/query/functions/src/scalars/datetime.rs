@@ -24,6 +24,8 @@ use common_expression::types::date::date_to_string;
 use common_expression::types::date::string_to_date;
 use common_expression::types::date::DATE_MAX;
 use common_expression::types::date::DATE_MIN;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::MICROS_IN_A_DAY;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::Int64Type;
@@ -41,6 +43,7 @@ use common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use common_expression::types::timestamp::MICROS_IN_A_SEC;
 use common_expression::types::DateType;
 use common_expression::types::Int32Type;
+use common_expression::types::IntervalType;
 use common_expression::types::NullableType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -1120,24 +1123,25 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
-        "minus",
-        |lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
-        },
-        |a, b, _| a - b,
-    );
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, TimestampType, IntervalType>(
+                |a, b, output, ctx| match a.checked_sub(b) {
+                    // The difference is split into days and the time of the day like PostgreSQL.
+                    Some(diff) => output.push(months_days_micros::new(
+                        0,
+                        (diff / MICROS_IN_A_DAY) as i32,
+                        diff % MICROS_IN_A_DAY,
+                    )),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(months_days_micros::default());
+                    }
+                },
+            ),
+        );
 }
 
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
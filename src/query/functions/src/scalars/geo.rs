@@ -101,8 +101,13 @@ pub fn register(registry: &mut FunctionRegistry) {
             |lon, lat, r, builder, ctx| {
                 match LatLng::new(lat.into(), lon.into()) {
                     Ok(coord) => {
-                        let h3_cell =  coord.to_cell(Resolution::try_from(r).unwrap());
-                        builder.push(h3_cell.into())
+                        match Resolution::try_from(r) {
+                            Ok(resolution) => builder.push(coord.to_cell(resolution).into()),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e.to_string());
+                                builder.push(0);
+                            }
+                        }
                     },
                     Err(e) => {
                         ctx.set_error(builder.len(), e.to_string());
@@ -0,0 +1,240 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::map::KvPair;
+use common_expression::types::number::F64;
+use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
+use common_expression::types::Float64Type;
+use common_expression::types::Int32Type;
+use common_expression::types::UInt32Type;
+use common_expression::types::UInt64Type;
+use common_expression::types::UInt8Type;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use h3o::CellIndex;
+use h3o::LatLng;
+use h3o::Resolution;
+
+/// Upper bound of the cells returned by `h3_k_ring` and `h3_to_children`,
+/// to avoid building huge arrays from a single row.
+const MAX_H3_CELLS: u64 = 1 << 20;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("h3_edge_length_m", &["h3_edge_length"]);
+
+    registry
+        .register_passthrough_nullable_1_arg::<UInt64Type, KvPair<Float64Type, Float64Type>, _, _>(
+            "h3_to_geo",
+            |_| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<UInt64Type, KvPair<Float64Type, Float64Type>>(
+                |h3, builder, ctx| match CellIndex::try_from(h3) {
+                    Ok(index) => {
+                        let coord: LatLng = index.into();
+                        builder.push((coord.lng().into(), coord.lat().into()));
+                    }
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push((F64::from(0.0), F64::from(0.0)));
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, ArrayType<KvPair<Float64Type, Float64Type>>, _, _>(
+        "h3_to_geo_boundary",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<UInt64Type, ArrayType<KvPair<Float64Type, Float64Type>>>(|h3, builder, ctx| {
+            match CellIndex::try_from(h3) {
+                Ok(index) => {
+                    for coord in index.boundary().iter() {
+                        builder.put_item((coord.lng().into(), coord.lat().into()));
+                    }
+                    builder.commit_row();
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push_default();
+                }
+            }
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<UInt64Type, UInt32Type, ArrayType<UInt64Type>, _, _>(
+            "h3_k_ring",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<UInt64Type, UInt32Type, ArrayType<UInt64Type>>(
+                |h3, k, builder, ctx| {
+                    let index = match CellIndex::try_from(h3) {
+                        Ok(index) => index,
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e.to_string());
+                            builder.push_default();
+                            return;
+                        }
+                    };
+                    // a disk of radius k has 3k(k+1)+1 cells, which overflows u64 for large k
+                    let k64 = k as u64;
+                    let cells = k64
+                        .checked_add(1)
+                        .and_then(|n| n.checked_mul(k64))
+                        .and_then(|n| n.checked_mul(3))
+                        .and_then(|n| n.checked_add(1));
+                    if !matches!(cells, Some(cells) if cells <= MAX_H3_CELLS) {
+                        ctx.set_error(builder.len(), format!("k {k} is too large for h3_k_ring"));
+                        builder.push_default();
+                        return;
+                    }
+                    for cell in index.grid_disk::<Vec<_>>(k) {
+                        builder.put_item(cell.into());
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, UInt8Type, _, _>(
+        "h3_get_resolution",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<UInt64Type, UInt8Type>(|h3, builder, ctx| {
+            match CellIndex::try_from(h3) {
+                Ok(index) => builder.push(index.resolution().into()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<UInt64Type, UInt8Type, UInt64Type, _, _>(
+        "h3_to_parent",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<UInt64Type, UInt8Type, UInt64Type>(
+            |h3, res, builder, ctx| {
+                let parent = CellIndex::try_from(h3)
+                    .map_err(|e| e.to_string())
+                    .and_then(|index| {
+                        let resolution = Resolution::try_from(res).map_err(|e| e.to_string())?;
+                        index.parent(resolution).ok_or_else(|| {
+                            format!(
+                                "resolution {res} is finer than the resolution {} of cell {h3}",
+                                u8::from(index.resolution())
+                            )
+                        })
+                    });
+                match parent {
+                    Ok(parent) => builder.push(parent.into()),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e);
+                        builder.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<UInt64Type, UInt8Type, ArrayType<UInt64Type>, _, _>(
+        "h3_to_children",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<UInt64Type, UInt8Type, ArrayType<UInt64Type>>(|h3, res, builder, ctx| {
+            let checked = CellIndex::try_from(h3)
+                .map_err(|e| e.to_string())
+                .and_then(|index| {
+                    let resolution = Resolution::try_from(res).map_err(|e| e.to_string())?;
+                    let cell_res = u8::from(index.resolution());
+                    if res < cell_res {
+                        return Err(format!(
+                            "resolution {res} is coarser than the resolution {cell_res} of cell {h3}"
+                        ));
+                    }
+                    if index.children_count(resolution) > MAX_H3_CELLS {
+                        return Err(format!(
+                            "cell {h3} has too many children at resolution {res}"
+                        ));
+                    }
+                    Ok((index, resolution))
+                });
+            match checked {
+                Ok((index, resolution)) => {
+                    for child in index.children(resolution) {
+                        builder.put_item(child.into());
+                    }
+                    builder.commit_row();
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push_default();
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<UInt64Type, UInt64Type, Int32Type, _, _>(
+        "h3_distance",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<UInt64Type, UInt64Type, Int32Type>(|a, b, builder, ctx| {
+            let distance = CellIndex::try_from(a)
+                .and_then(|a| CellIndex::try_from(b).map(|b| (a, b)))
+                .map_err(|e| e.to_string())
+                .and_then(|(a, b)| a.grid_distance(b).map_err(|e| e.to_string()));
+            match distance {
+                Ok(distance) => builder.push(distance),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e);
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, BooleanType, _, _>(
+        "h3_is_valid",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UInt64Type, BooleanType>(|h3, builder, _| {
+            builder.push(CellIndex::try_from(h3).is_ok());
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UInt8Type, Float64Type, _, _>(
+        "h3_edge_length_m",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<UInt8Type, Float64Type>(|res, builder, ctx| {
+            match Resolution::try_from(res) {
+                Ok(resolution) => builder.push(resolution.edge_length_m().into()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UInt8Type, Float64Type, _, _>(
+        "h3_edge_length_km",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<UInt8Type, Float64Type>(|res, builder, ctx| {
+            match Resolution::try_from(res) {
+                Ok(resolution) => builder.push(resolution.edge_length_km().into()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+}
@@ -0,0 +1,301 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::number::F64;
+use common_expression::types::BinaryType;
+use common_expression::types::BooleanType;
+use common_expression::types::GeometryType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use common_io::geometry::geometry_from_geojson;
+use common_io::geometry::geometry_from_wkb;
+use common_io::geometry::geometry_from_wkt;
+use common_io::geometry::geometry_to_wkb;
+use common_io::geometry::parse_to_wkb;
+use common_io::geometry::point_to_wkb;
+use common_io::geometry::wkb_to_geojson;
+use common_io::geometry::wkb_to_wkt;
+use geo::Area;
+use geo::CoordsIter;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Intersects;
+use geo::Line;
+use geo::Point;
+use geo::Relate;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("st_geomfromwkt", &["st_geomfromtext", "st_geometryfromwkt"]);
+    registry.register_aliases("st_astext", &["st_aswkt"]);
+
+    register_constructors(registry);
+    register_outputs(registry);
+    register_relationships(registry);
+    register_measurements(registry);
+}
+
+fn register_constructors(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
+        "st_makepoint",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType>(
+            |x, y, output, _| {
+                output.put_slice(&point_to_wkb(x.0, y.0));
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkt",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            match std::str::from_utf8(val)
+                .map_err(|e| e.to_string())
+                .and_then(|wkt| geometry_from_wkt(wkt).map_err(|e| e.message()))
+            {
+                Ok(geometry) => output.put_slice(&geometry_to_wkb(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|val, output, ctx| {
+            // Re-encode the geometry to normalize the byte order.
+            match geometry_from_wkb(val) {
+                Ok(geometry) => output.put_slice(&geometry_to_wkb(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e.message()),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            match std::str::from_utf8(val)
+                .map_err(|e| e.to_string())
+                .and_then(|geojson| geometry_from_geojson(geojson).map_err(|e| e.message()))
+            {
+                Ok(geometry) => output.put_slice(&geometry_to_wkb(&geometry)),
+                Err(e) => ctx.set_error(output.len(), e),
+            }
+            output.commit_row();
+        }),
+    );
+
+    // `to_geometry` accepts WKT, GeoJSON and hex encoded WKB.
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_geometry,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_geometry),
+    );
+
+    fn eval_string_to_geometry(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<GeometryType> {
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|val, output, ctx| {
+            match parse_to_wkb(val) {
+                Ok(wkb) => output.put_slice(&wkb),
+                Err(e) => ctx.set_error(output.len(), e.message()),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_outputs(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_astext",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_geometry_to_wkt,
+    );
+
+    fn eval_geometry_to_wkt(
+        val: ValueRef<GeometryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_wkt(val) {
+                Ok(wkt) => output.put_str(&wkt),
+                Err(e) => ctx.set_error(output.len(), e.message()),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|val, output, _| {
+            output.put_slice(val);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asgeojson",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|val, output, ctx| {
+            match wkb_to_geojson(val) {
+                Ok(geojson) => output.put_str(&geojson),
+                Err(e) => ctx.set_error(output.len(), e.message()),
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_relationships(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, output, ctx| match decode_pair(l, r) {
+                Ok((l, r)) => output.push(l.relate(&r).is_contains()),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, output, ctx| match decode_pair(l, r) {
+                Ok((l, r)) => output.push(l.intersects(&r)),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+}
+
+fn register_measurements(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, NumberType<F64>>(
+                |l, r, output, ctx| match decode_pair(l, r) {
+                    Ok((l, r)) => output.push(euclidean_distance(&l, &r).into()),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(F64::default());
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_area",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|val, output, ctx| {
+            match geometry_from_wkb(val) {
+                Ok(geometry) => output.push(geometry.unsigned_area().into()),
+                Err(e) => {
+                    ctx.set_error(output.len(), e.message());
+                    output.push(F64::default());
+                }
+            }
+        }),
+    );
+}
+
+fn decode_pair(l: &[u8], r: &[u8]) -> Result<(Geometry<f64>, Geometry<f64>), String> {
+    let l = geometry_from_wkb(l).map_err(|e| e.message())?;
+    let r = geometry_from_wkb(r).map_err(|e| e.message())?;
+    Ok((l, r))
+}
+
+/// The minimum euclidean distance between two geometries, zero if they intersect.
+///
+/// When two geometries are disjoint, the closest pair of points always has at least one
+/// vertex, so it's enough to measure every vertex against the vertices and segments of
+/// the other geometry.
+fn euclidean_distance(l: &Geometry<f64>, r: &Geometry<f64>) -> f64 {
+    if l.intersects(r) {
+        return 0.0;
+    }
+    let l_segments = segments(l);
+    let r_segments = segments(r);
+    let vertex_to_geometry = |vertex: Point<f64>, other: &Geometry<f64>, segments: &[Line<f64>]| {
+        let to_vertices = other
+            .coords_iter()
+            .map(|coord| vertex.euclidean_distance(&Point::from(coord)));
+        let to_segments = segments
+            .iter()
+            .map(|segment| vertex.euclidean_distance(segment));
+        to_vertices.chain(to_segments).fold(f64::INFINITY, f64::min)
+    };
+    let l_to_r = l
+        .coords_iter()
+        .map(|coord| vertex_to_geometry(Point::from(coord), r, &r_segments));
+    let r_to_l = r
+        .coords_iter()
+        .map(|coord| vertex_to_geometry(Point::from(coord), l, &l_segments));
+    l_to_r.chain(r_to_l).fold(f64::INFINITY, f64::min)
+}
+
+fn segments(geometry: &Geometry<f64>) -> Vec<Line<f64>> {
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => vec![],
+        Geometry::Line(line) => vec![*line],
+        Geometry::LineString(line) => line.lines().collect(),
+        Geometry::MultiLineString(lines) => lines.0.iter().flat_map(|line| line.lines()).collect(),
+        Geometry::Polygon(polygon) => std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| ring.lines())
+            .collect(),
+        Geometry::MultiPolygon(polygons) => polygons
+            .0
+            .iter()
+            .flat_map(|polygon| segments(&Geometry::Polygon(polygon.clone())))
+            .collect(),
+        Geometry::GeometryCollection(collection) => {
+            collection.0.iter().flat_map(segments).collect()
+        }
+        Geometry::Rect(rect) => segments(&Geometry::Polygon(rect.to_polygon())),
+        Geometry::Triangle(triangle) => segments(&Geometry::Polygon(triangle.to_polygon())),
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Int64Type;
+use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::AddIntervalImpl;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_string_to_interval(registry);
+    register_interval_to_string(registry);
+    register_number_to_interval(registry);
+    register_interval_arith(registry);
+    register_timestamp_interval_arith(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`: {e}"),
+                    );
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+/// Functions like `to_days(n)` build the interval of `INTERVAL n DAY`.
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    fn register_unit(
+        registry: &mut FunctionRegistry,
+        name: &str,
+        to_interval: fn(i64) -> Option<months_days_micros>,
+    ) {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            |_| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                match to_interval(val) {
+                    Some(interval) => output.push(interval),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(months_days_micros::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    fn months(n: i64, factor: i64) -> Option<months_days_micros> {
+        let months = i32::try_from(n.checked_mul(factor)?).ok()?;
+        Some(months_days_micros::new(months, 0, 0))
+    }
+
+    fn micros(n: i64, factor: i64) -> Option<months_days_micros> {
+        Some(months_days_micros::new(0, 0, n.checked_mul(factor)?))
+    }
+
+    register_unit(registry, "to_years", |n| months(n, 12));
+    register_unit(registry, "to_quarters", |n| months(n, 3));
+    register_unit(registry, "to_months", |n| months(n, 1));
+    register_unit(registry, "to_weeks", |n| {
+        let days = i32::try_from(n.checked_mul(7)?).ok()?;
+        Some(months_days_micros::new(0, days, 0))
+    });
+    register_unit(registry, "to_days", |n| {
+        let days = i32::try_from(n).ok()?;
+        Some(months_days_micros::new(0, days, 0))
+    });
+    register_unit(registry, "to_hours", |n| micros(n, MICROS_IN_AN_HOUR));
+    register_unit(registry, "to_minutes", |n| micros(n, MICROS_IN_A_MINUTE));
+    register_unit(registry, "to_seconds", |n| micros(n, MICROS_IN_A_SEC));
+}
+
+fn register_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            match val.checked_neg() {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match lhs.checked_add(&rhs) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| match lhs.checked_sub(&rhs) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+}
+
+fn register_timestamp_interval_arith(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts,
+                    ctx.func_ctx.tz,
+                    interval,
+                ) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts,
+                    ctx.func_ctx.tz,
+                    interval,
+                ) {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let result = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|interval| {
+                            AddIntervalImpl::eval_timestamp(ts, ctx.func_ctx.tz, interval)
+                        });
+                    match result {
+                        Ok(ts) => output.push(ts),
+                        Err(e) => {
+                            ctx.set_error(output.len(), e);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| match AddIntervalImpl::eval_date(
+                date,
+                ctx.func_ctx.tz,
+                interval,
+            ) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| match AddIntervalImpl::eval_date(
+                date,
+                ctx.func_ctx.tz,
+                interval,
+            ) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let result = interval
+                    .checked_neg()
+                    .ok_or_else(|| "interval out of range".to_string())
+                    .and_then(|interval| {
+                        AddIntervalImpl::eval_date(date, ctx.func_ctx.tz, interval)
+                    });
+                match result {
+                    Ok(ts) => output.push(ts),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+}
@@ -14,14 +14,20 @@
 
 use std::hash::Hash;
 
+use common_expression::types::boolean::BooleanDomain;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::EmptyMapType;
 use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::types::NumberType;
+use common_expression::types::SimpleDomain;
+use common_expression::vectorize_1_arg;
+use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
@@ -31,6 +37,8 @@ use siphasher::sip128::Hasher128;
 use siphasher::sip128::SipHasher24;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("map", &["map_from_arrays"]);
+
     registry
         .register_passthrough_nullable_2_arg::<EmptyArrayType, EmptyArrayType, EmptyMapType, _, _>(
             "map",
@@ -108,4 +116,120 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyMapType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+        "map_keys",
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(key_domain, _)| key_domain.clone())),
+        vectorize_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>>(
+            |map, _| map.keys
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyMapType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+        "map_values",
+        |domain| FunctionDomain::Domain(domain.as_ref().map(|(_, val_domain)| val_domain.clone())),
+        vectorize_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>>(
+            |map, _| map.values
+        ),
+    );
+
+    registry.register_1_arg::<EmptyMapType, NumberType<u64>, _, _>(
+        "map_size",
+        |_| FunctionDomain::Domain(SimpleDomain { min: 0, max: 0 }),
+        |_, _| 0u64,
+    );
+
+    registry.register_1_arg::<MapType<GenericType<0>, GenericType<1>>, NumberType<u64>, _, _>(
+        "map_size",
+        |_| FunctionDomain::Full,
+        |map, _| map.len() as u64,
+    );
+
+    registry.register_2_arg::<EmptyMapType, GenericType<0>, BooleanType, _, _>(
+        "map_contains_key",
+        |_, _| {
+            FunctionDomain::Domain(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            })
+        },
+        |_, _, _| false,
+    );
+
+    registry.register_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, BooleanType, _, _>(
+        "map_contains_key",
+        |_, _| FunctionDomain::Full,
+        |map, key, _| map.iter().any(|(k, _)| k == key),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<EmptyMapType, EmptyMapType, EmptyMapType, _, _>(
+        "map_cat",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<EmptyMapType, EmptyMapType, EmptyMapType>(
+            |_, _, output, _| {
+                *output += 1;
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_cat",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>, MapType<GenericType<0>, GenericType<1>>>(
+            |lhs, rhs, output, _| {
+                // The entries of the right map take precedence over the left map for duplicate keys.
+                for (key, val) in lhs.iter() {
+                    if !rhs.iter().any(|(k, _)| k == key) {
+                        output.put_item((key, val));
+                    }
+                }
+                for (key, val) in rhs.iter() {
+                    output.put_item((key, val));
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyMapType, GenericType<0>, EmptyMapType, _, _>(
+            "map_delete",
+            |_, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<EmptyMapType, GenericType<0>, EmptyMapType>(
+                |_, _, output, _| {
+                    *output += 1;
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "map_delete",
+        |domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_2_arg::<MapType<GenericType<0>, GenericType<1>>, GenericType<0>, MapType<GenericType<0>, GenericType<1>>>(
+            |map, key, output, _| {
+                for (k, v) in map.iter() {
+                    if k != key {
+                        output.put_item((k, v));
+                    }
+                }
+                output.commit_row();
+            }
+        ),
+    );
 }
@@ -17,6 +17,7 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
@@ -24,7 +25,10 @@ mod control;
 mod datetime;
 mod decimal;
 mod geo;
+mod geo_h3;
+mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
@@ -47,12 +51,16 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    interval::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
+    binary::register(registry);
     string_multi_args::register(registry);
     tuple::register(registry);
     geo::register(registry);
+    geo_h3::register(registry);
+    geometry::register(registry);
     hash::register(registry);
     other::register(registry);
     decimal::register(registry);
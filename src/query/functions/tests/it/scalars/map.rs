@@ -27,6 +27,7 @@ fn test_map() {
 
     test_create(file);
     test_get(file);
+    test_map_functions(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -81,3 +82,17 @@ fn test_get(file: &mut impl Write) {
         ("v2", StringType::from_data(vec!["v3", "v4"])),
     ]);
 }
+
+fn test_map_functions(file: &mut impl Write) {
+    run_ast(file, "map_keys({})", &[]);
+    run_ast(file, "map_keys(map([1,2], ['a','b']))", &[]);
+    run_ast(file, "map_values(map([1,2], ['a','b']))", &[]);
+    run_ast(file, "map_size(map([1,2], ['a','b']))", &[]);
+    run_ast(file, "map_contains_key(map([1,2], ['a','b']), 2)", &[]);
+    run_ast(
+        file,
+        "map_cat(map([1,2], ['a','b']), map([2,3], ['c','d']))",
+        &[],
+    );
+    run_ast(file, "map_delete(map([1,2], ['a','b']), 1)", &[]);
+}
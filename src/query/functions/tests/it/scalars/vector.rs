@@ -26,6 +26,8 @@ fn test_vector() {
     let file = &mut mint.new_goldenfile("vector.txt").unwrap();
 
     test_vector_cosine_distance(file);
+    test_vector_l2_distance(file);
+    test_vector_inner_product(file);
 }
 
 fn test_vector_cosine_distance(file: &mut impl Write) {
@@ -34,3 +36,17 @@ fn test_vector_cosine_distance(file: &mut impl Write) {
         ("b", Float32Type::from_data(vec![3f32, 4.0, 5.0])),
     ]);
 }
+
+fn test_vector_l2_distance(file: &mut impl Write) {
+    run_ast(file, "l2_distance([a], [b])", &[
+        ("a", Float32Type::from_data(vec![0f32, 1.0, 2.0])),
+        ("b", Float32Type::from_data(vec![3f32, 4.0, 5.0])),
+    ]);
+}
+
+fn test_vector_inner_product(file: &mut impl Write) {
+    run_ast(file, "inner_product([a], [b])", &[
+        ("a", Float32Type::from_data(vec![0f32, 1.0, 2.0])),
+        ("b", Float32Type::from_data(vec![3f32, 4.0, 5.0])),
+    ]);
+}
@@ -23,6 +23,7 @@ mod setting;
 mod stage;
 mod udf;
 mod user;
+mod workload_group;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
@@ -42,3 +43,5 @@ pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupApi;
+pub use workload_group::WorkloadGroupMgr;
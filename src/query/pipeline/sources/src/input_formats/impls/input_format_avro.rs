@@ -0,0 +1,475 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::avro::avro_schema::read::block_iterator;
+use common_arrow::arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use common_arrow::arrow::io::avro::avro_schema::read::read_metadata;
+use common_arrow::arrow::io::avro::avro_schema::schema::BytesLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::Field as AvroField;
+use common_arrow::arrow::io::avro::avro_schema::schema::Fixed;
+use common_arrow::arrow::io::avro::avro_schema::schema::FixedLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::IntLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::LongLogical;
+use common_arrow::arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Avro object container files.
+///
+/// Each record is decoded into a json value and then written into the columns by
+/// `FieldJsonAstDecoder`, so the conversion rules are the same as NDJSON.
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_file(
+        builder: &mut BlockBuilder<Self>,
+        batch: &RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let schema = &builder.ctx.schema;
+
+        let mut reader = Cursor::new(&batch.data);
+        let metadata = read_metadata(&mut reader)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro file header: {e}")))?;
+        let avro_fields = metadata.record.fields;
+        let names = avro_fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>();
+        let positions = field_positions(schema, &names, field_decoder.ident_case_sensitive);
+
+        let mut blocks = block_iterator(reader, metadata.compression, metadata.marker);
+        let mut row_index = 0usize;
+        let mut num_rows = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        while let Some(block) = blocks
+            .next()
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro data block: {e}")))?
+        {
+            let mut buf = block.data.as_slice();
+            for _ in 0..block.number_of_rows {
+                let i = row_index;
+                row_index += 1;
+                // A value that can not be decoded leaves the rest of the block unreadable,
+                // so only the errors when writing the values into columns respect ON_ERROR.
+                let row = avro_fields
+                    .iter()
+                    .map(|f| read_value(&mut buf, &f.schema))
+                    .collect::<Result<Vec<_>>>()
+                    .map_err(|e| batch.error(&e.message(), &builder.ctx, 0, i))?;
+                if let Err(e) = read_row(field_decoder, &row, &positions, columns, schema) {
+                    match builder.ctx.on_error_mode {
+                        OnErrorMode::Continue => {
+                            Self::on_error_continue(columns, num_rows, e, &mut error_map);
+                            continue;
+                        }
+                        OnErrorMode::AbortNum(n) => {
+                            Self::on_error_abort(
+                                columns,
+                                num_rows,
+                                n,
+                                &builder.ctx.on_error_count,
+                                e,
+                            )
+                            .map_err(|e| batch.error(&e.message(), &builder.ctx, 0, i))?;
+                            continue;
+                        }
+                        _ => return Err(batch.error(&e.message(), &builder.ctx, 0, i)),
+                    }
+                }
+                num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn read_inner_schema(data: &[u8]) -> Result<TableSchemaRef> {
+        let mut reader = Cursor::new(data);
+        let metadata = read_metadata(&mut reader)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro file header: {e}")))?;
+        let fields = metadata
+            .record
+            .fields
+            .iter()
+            .map(|f| Ok(TableField::new(&f.name, infer_avro_type(&f.schema)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(TableSchema::new(fields)))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        Self::read_file(builder, &batch)
+    }
+}
+
+/// Position of each column of `schema` among the fields of the file, `None` if missing.
+pub(crate) fn field_positions(
+    schema: &TableSchemaRef,
+    names: &[&str],
+    case_sensitive: bool,
+) -> Vec<Option<usize>> {
+    schema
+        .fields()
+        .iter()
+        .map(|f| {
+            names.iter().position(|name| {
+                if case_sensitive {
+                    *name == f.name()
+                } else {
+                    name.eq_ignore_ascii_case(f.name())
+                }
+            })
+        })
+        .collect()
+}
+
+/// Write one row of decoded values into the columns, missing fields get the default value.
+pub(crate) fn read_row(
+    field_decoder: &FieldJsonAstDecoder,
+    row: &[Value],
+    positions: &[Option<usize>],
+    columns: &mut [ColumnBuilder],
+    schema: &TableSchemaRef,
+) -> Result<()> {
+    for ((f, column), pos) in schema
+        .fields()
+        .iter()
+        .zip(columns.iter_mut())
+        .zip(positions.iter())
+    {
+        match pos {
+            Some(pos) => {
+                field_decoder.read_field(column, &row[*pos]).map_err(|e| {
+                    ErrorCode::BadBytes(format!("{}. column={} value={}", e, f.name(), row[*pos]))
+                })?;
+            }
+            None => column.push_default(),
+        }
+    }
+    Ok(())
+}
+
+/// Format an unscaled decimal integer, e.g. (12345, 2) => "123.45".
+pub(crate) fn decimal_to_string(unscaled: i128, scale: usize) -> String {
+    let sign = if unscaled < 0 { "-" } else { "" };
+    let digits = unscaled.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale);
+    format!("{sign}{int}.{frac}")
+}
+
+pub(crate) fn infer_decimal_type(precision: usize, scale: usize) -> Result<TableDataType> {
+    let size = DecimalSize {
+        precision: precision as u8,
+        scale: scale as u8,
+    };
+    Ok(TableDataType::Decimal(DecimalDataType::from_size(size)?))
+}
+
+fn infer_avro_type(schema: &AvroSchema) -> Result<TableDataType> {
+    let ty = match schema {
+        AvroSchema::Null => TableDataType::Null,
+        AvroSchema::Boolean => TableDataType::Boolean,
+        AvroSchema::Int(Some(IntLogical::Date)) => TableDataType::Date,
+        AvroSchema::Int(_) => TableDataType::Number(NumberDataType::Int32),
+        AvroSchema::Long(Some(LongLogical::Time)) | AvroSchema::Long(None) => {
+            TableDataType::Number(NumberDataType::Int64)
+        }
+        AvroSchema::Long(Some(_)) => TableDataType::Timestamp,
+        AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes(Some(BytesLogical::Decimal(precision, scale)))
+        | AvroSchema::Fixed(Fixed {
+            logical: Some(FixedLogical::Decimal(precision, scale)),
+            ..
+        }) => infer_decimal_type(*precision, *scale)?,
+        AvroSchema::Bytes(None) | AvroSchema::Fixed(_) => TableDataType::Binary,
+        AvroSchema::String(_) | AvroSchema::Enum(_) => TableDataType::String,
+        AvroSchema::Record(record) => {
+            let (fields_name, fields_type) = record
+                .fields
+                .iter()
+                .map(|f: &AvroField| Ok((f.name.clone(), infer_avro_type(&f.schema)?)))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .unzip();
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        AvroSchema::Array(item) => TableDataType::Array(Box::new(infer_avro_type(item)?)),
+        AvroSchema::Map(value) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, infer_avro_type(value)?],
+        })),
+        AvroSchema::Union(variants) => {
+            let nullable = variants.iter().any(|v| matches!(v, AvroSchema::Null));
+            let non_null = variants
+                .iter()
+                .filter(|v| !matches!(v, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            let ty = match non_null.as_slice() {
+                [] => return Ok(TableDataType::Null),
+                [single] => infer_avro_type(single)?,
+                // values of a real union may have different types in each row
+                _ => TableDataType::Variant,
+            };
+            if nullable { ty.wrap_nullable() } else { ty }
+        }
+    };
+    Ok(ty)
+}
+
+fn eof_error() -> ErrorCode {
+    ErrorCode::BadBytes("unexpected end of avro data")
+}
+
+fn read_long(buf: &mut &[u8]) -> Result<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let (byte, rest) = buf.split_first().ok_or_else(eof_error)?;
+        *buf = rest;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(ErrorCode::BadBytes("invalid avro varint"));
+        }
+    }
+    // zigzag
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+fn read_len(buf: &mut &[u8]) -> Result<usize> {
+    let len = read_long(buf)?;
+    if len < 0 {
+        return Err(ErrorCode::BadBytes(format!("invalid avro length {len}")));
+    }
+    Ok(len as usize)
+}
+
+fn read_slice<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(eof_error());
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(data)
+}
+
+fn read_string(buf: &mut &[u8]) -> Result<String> {
+    let len = read_len(buf)?;
+    let data = read_slice(buf, len)?;
+    String::from_utf8(data.to_vec())
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 in avro string: {e}")))
+}
+
+/// Items of arrays and maps are encoded as a series of blocks, each block
+/// starts with its count, a negative count is followed by the block size in bytes.
+fn read_block_count(buf: &mut &[u8]) -> Result<usize> {
+    let count = read_long(buf)?;
+    if count < 0 {
+        read_long(buf)?;
+        Ok(count.unsigned_abs() as usize)
+    } else {
+        Ok(count as usize)
+    }
+}
+
+fn read_decimal(data: &[u8], scale: usize) -> Result<Value> {
+    if data.len() > 16 {
+        return Err(ErrorCode::BadBytes(
+            "avro decimal wider than 16 bytes is not supported",
+        ));
+    }
+    // big-endian two's complement
+    let mut unscaled: i128 = match data.first() {
+        Some(b) if b & 0x80 != 0 => -1,
+        _ => 0,
+    };
+    for b in data {
+        unscaled = (unscaled << 8) | *b as i128;
+    }
+    Ok(Value::String(decimal_to_string(unscaled, scale)))
+}
+
+pub(crate) fn float_value(v: f64) -> Value {
+    // NaN and infinity have no json representation
+    Number::from_f64(v)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn read_value(buf: &mut &[u8], schema: &AvroSchema) -> Result<Value> {
+    let value = match schema {
+        AvroSchema::Null => Value::Null,
+        AvroSchema::Boolean => {
+            let data = read_slice(buf, 1)?;
+            Value::Bool(data[0] != 0)
+        }
+        AvroSchema::Int(_) => Value::from(read_long(buf)?),
+        AvroSchema::Long(logical) => {
+            let v = read_long(buf)?;
+            match logical {
+                Some(LongLogical::TimestampMillis) | Some(LongLogical::LocalTimestampMillis) => {
+                    Value::from(v * 1000)
+                }
+                _ => Value::from(v),
+            }
+        }
+        AvroSchema::Float => {
+            let data = read_slice(buf, 4)?;
+            float_value(f32::from_le_bytes(data.try_into().unwrap()) as f64)
+        }
+        AvroSchema::Double => {
+            let data = read_slice(buf, 8)?;
+            float_value(f64::from_le_bytes(data.try_into().unwrap()))
+        }
+        AvroSchema::Bytes(logical) => {
+            let len = read_len(buf)?;
+            let data = read_slice(buf, len)?;
+            match logical {
+                Some(BytesLogical::Decimal(_, scale)) => read_decimal(data, *scale)?,
+                // binary values are passed to the decoder as hex, to keep the raw bytes
+                None => Value::String(hex::encode(data)),
+            }
+        }
+        AvroSchema::String(_) => Value::String(read_string(buf)?),
+        AvroSchema::Record(record) => {
+            let mut obj = Map::with_capacity(record.fields.len());
+            for f in &record.fields {
+                obj.insert(f.name.clone(), read_value(buf, &f.schema)?);
+            }
+            Value::Object(obj)
+        }
+        AvroSchema::Enum(e) => {
+            let index = read_len(buf)?;
+            let symbol = e.symbols.get(index).ok_or_else(|| {
+                ErrorCode::BadBytes(format!("invalid index {index} of avro enum {}", e.name))
+            })?;
+            Value::String(symbol.clone())
+        }
+        AvroSchema::Array(item) => {
+            let mut items = vec![];
+            loop {
+                let count = read_block_count(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    items.push(read_value(buf, item)?);
+                }
+            }
+            Value::Array(items)
+        }
+        AvroSchema::Map(value) => {
+            let mut obj = Map::new();
+            loop {
+                let count = read_block_count(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    let key = read_string(buf)?;
+                    obj.insert(key, read_value(buf, value)?);
+                }
+            }
+            Value::Object(obj)
+        }
+        AvroSchema::Union(variants) => {
+            let index = read_len(buf)?;
+            let variant = variants
+                .get(index)
+                .ok_or_else(|| ErrorCode::BadBytes(format!("invalid avro union index {index}")))?;
+            read_value(buf, variant)?
+        }
+        AvroSchema::Fixed(fixed) => {
+            let data = read_slice(buf, fixed.size)?;
+            match fixed.logical {
+                Some(FixedLogical::Decimal(_, scale)) => read_decimal(data, scale)?,
+                _ => Value::String(hex::encode(data)),
+            }
+        }
+    };
+    Ok(value)
+}
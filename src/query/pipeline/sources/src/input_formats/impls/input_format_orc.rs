@@ -0,0 +1,654 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::orc::format::error::Error as OrcError;
+use common_arrow::arrow::io::orc::format::proto::stream::Kind;
+use common_arrow::arrow::io::orc::format::proto::r#type::Kind as TypeKind;
+use common_arrow::arrow::io::orc::format::proto::CompressionKind;
+use common_arrow::arrow::io::orc::format::proto::StripeFooter;
+use common_arrow::arrow::io::orc::format::proto::Type;
+use common_arrow::arrow::io::orc::format::read::decode;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::format::read::Column;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::FieldDecoder;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::OnErrorMode;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::InputError;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::input_format_avro::decimal_to_string;
+use super::input_format_avro::field_positions;
+use super::input_format_avro::float_value;
+use super::input_format_avro::infer_decimal_type;
+use super::input_format_avro::read_row;
+use crate::input_formats::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+/// Seconds between 1970-01-01 and 2015-01-01, the epoch of ORC timestamps.
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// ORC files.
+///
+/// Stripes are decoded column by column into json values, which are then written into the
+/// columns by `FieldJsonAstDecoder` like NDJSON and Avro. Union columns are not supported.
+pub struct InputFormatOrc {}
+
+impl InputFormatOrc {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    fn read_file(
+        builder: &mut BlockBuilder<Self>,
+        batch: &RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldJsonAstDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let schema = &builder.ctx.schema;
+
+        check_compression(&batch.data)?;
+        let mut reader = Cursor::new(batch.data.as_slice());
+        let metadata = read_metadata(&mut reader).map_err(orc_error)?;
+        let root = root_type(&metadata.footer.types)?;
+        let names = root
+            .field_names
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let positions = field_positions(schema, &names, field_decoder.ident_case_sensitive);
+
+        let mut row_index = 0usize;
+        let mut num_rows = 0usize;
+        let mut error_map: HashMap<u16, InputError> = HashMap::new();
+        for (stripe, info) in metadata.footer.stripes.iter().enumerate() {
+            let stripe_rows = info.number_of_rows() as usize;
+            let footer = read_stripe_footer(&mut reader, &metadata, stripe, &mut vec![])
+                .map_err(orc_error)?;
+            let mut stripe_reader = StripeReader {
+                reader: &mut reader,
+                metadata: &metadata,
+                footer,
+                stripe,
+            };
+            // columns not in the table schema are skipped
+            let mut file_columns = root
+                .subtypes
+                .iter()
+                .enumerate()
+                .map(|(i, column_id)| {
+                    if positions.contains(&Some(i)) {
+                        stripe_reader.read_values(*column_id, stripe_rows)
+                    } else {
+                        Ok(vec![])
+                    }
+                })
+                .collect::<Result<Vec<_>>>()
+                .map_err(|e| batch.error(&e.message(), &builder.ctx, 0, row_index))?;
+
+            for row in 0..stripe_rows {
+                let i = row_index;
+                row_index += 1;
+                let values = file_columns
+                    .iter_mut()
+                    .map(|c| c.get_mut(row).map(std::mem::take).unwrap_or(Value::Null))
+                    .collect::<Vec<_>>();
+                if let Err(e) = read_row(field_decoder, &values, &positions, columns, schema) {
+                    match builder.ctx.on_error_mode {
+                        OnErrorMode::Continue => {
+                            Self::on_error_continue(columns, num_rows, e, &mut error_map);
+                            continue;
+                        }
+                        OnErrorMode::AbortNum(n) => {
+                            Self::on_error_abort(
+                                columns,
+                                num_rows,
+                                n,
+                                &builder.ctx.on_error_count,
+                                e,
+                            )
+                            .map_err(|e| batch.error(&e.message(), &builder.ctx, 0, i))?;
+                            continue;
+                        }
+                        _ => return Err(batch.error(&e.message(), &builder.ctx, 0, i)),
+                    }
+                }
+                num_rows += 1;
+            }
+        }
+        Ok(error_map)
+    }
+}
+
+impl InputFormatTextBase for InputFormatOrc {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Orc
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldJsonAstDecoder::create(options))
+    }
+
+    fn read_inner_schema(data: &[u8]) -> Result<TableSchemaRef> {
+        check_compression(data)?;
+        let mut reader = Cursor::new(data);
+        let metadata = read_metadata(&mut reader).map_err(orc_error)?;
+        let types = &metadata.footer.types;
+        let root = root_type(types)?;
+        let fields = root
+            .field_names
+            .iter()
+            .zip(root.subtypes.iter())
+            .map(|(name, id)| Ok(TableField::new(name, infer_orc_type(types, *id)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(TableSchema::new(fields)))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(
+        builder: &mut BlockBuilder<Self>,
+        batch: RowBatch,
+    ) -> Result<HashMap<u16, InputError>> {
+        Self::read_file(builder, &batch)
+    }
+}
+
+fn orc_error(e: OrcError) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid orc file: {e:?}"))
+}
+
+/// Only NONE and ZLIB compression are supported by the decoder, which panics on the
+/// others, so the compression is checked in the postscript before decoding anything.
+fn check_compression(data: &[u8]) -> Result<()> {
+    let invalid = || ErrorCode::BadBytes("invalid orc file: invalid postscript");
+    let postscript_len = *data.last().ok_or_else(invalid)? as usize;
+    let start = data
+        .len()
+        .checked_sub(postscript_len + 1)
+        .ok_or_else(invalid)?;
+    let mut buf = &data[start..data.len() - 1];
+
+    // `compression` is the varint field 2 of the PostScript protobuf message
+    while !buf.is_empty() {
+        let key = read_varint_u64(&mut buf).ok_or_else(invalid)?;
+        match key & 0x7 {
+            0 => {
+                let value = read_varint_u64(&mut buf).ok_or_else(invalid)?;
+                if key >> 3 == 2 {
+                    return match CompressionKind::from_i32(value as i32) {
+                        Some(CompressionKind::None) | Some(CompressionKind::Zlib) => Ok(()),
+                        kind => Err(ErrorCode::Unimplemented(format!(
+                            "ORC compression {kind:?} is not supported yet, only NONE and ZLIB are supported"
+                        ))),
+                    };
+                }
+            }
+            2 => {
+                let len = read_varint_u64(&mut buf).ok_or_else(invalid)? as usize;
+                buf = buf.get(len..).ok_or_else(invalid)?;
+            }
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(())
+}
+
+fn read_varint_u64(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let (b, rest) = buf.split_first()?;
+        *buf = rest;
+        if shift >= 64 {
+            return None;
+        }
+        value |= ((b & 0x7f) as u64) << shift;
+        shift += 7;
+        if b & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+}
+
+fn root_type(types: &[Type]) -> Result<&Type> {
+    match types.first() {
+        Some(ty) if ty.kind() == TypeKind::Struct => Ok(ty),
+        _ => Err(ErrorCode::BadBytes(
+            "invalid orc file: the root type must be a struct",
+        )),
+    }
+}
+
+fn get_type(types: &[Type], column_id: u32) -> Result<&Type> {
+    types.get(column_id as usize).ok_or_else(|| {
+        ErrorCode::BadBytes(format!(
+            "invalid orc file: type of column {column_id} not found"
+        ))
+    })
+}
+
+fn infer_orc_type(types: &[Type], column_id: u32) -> Result<TableDataType> {
+    let ty = get_type(types, column_id)?;
+    let data_type = match ty.kind() {
+        TypeKind::Boolean => TableDataType::Boolean,
+        TypeKind::Byte => TableDataType::Number(NumberDataType::Int8),
+        TypeKind::Short => TableDataType::Number(NumberDataType::Int16),
+        TypeKind::Int => TableDataType::Number(NumberDataType::Int32),
+        TypeKind::Long => TableDataType::Number(NumberDataType::Int64),
+        TypeKind::Float => TableDataType::Number(NumberDataType::Float32),
+        TypeKind::Double => TableDataType::Number(NumberDataType::Float64),
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => TableDataType::String,
+        TypeKind::Binary => TableDataType::Binary,
+        TypeKind::Date => TableDataType::Date,
+        TypeKind::Timestamp | TypeKind::TimestampInstant => TableDataType::Timestamp,
+        TypeKind::Decimal => {
+            // files written by old versions of hive have no precision
+            let precision = match ty.precision() {
+                0 => 38,
+                p => p,
+            };
+            infer_decimal_type(precision as usize, ty.scale() as usize)?
+        }
+        TypeKind::Struct => TableDataType::Tuple {
+            fields_name: ty.field_names.clone(),
+            fields_type: ty
+                .subtypes
+                .iter()
+                .map(|id| infer_orc_type(types, *id))
+                .collect::<Result<Vec<_>>>()?,
+        },
+        TypeKind::List => TableDataType::Array(Box::new(infer_orc_type(types, child_id(ty, 0)?)?)),
+        TypeKind::Map => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![
+                TableDataType::String,
+                infer_orc_type(types, child_id(ty, 1)?)?,
+            ],
+        })),
+        kind => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "ORC type {kind:?} is not supported yet"
+            )));
+        }
+    };
+    Ok(data_type.wrap_nullable())
+}
+
+fn child_id(ty: &Type, i: usize) -> Result<u32> {
+    ty.subtypes.get(i).copied().ok_or_else(|| {
+        ErrorCode::BadBytes(format!(
+            "invalid orc file: {:?} type without child type",
+            ty.kind()
+        ))
+    })
+}
+
+struct StripeReader<'a, 'b> {
+    reader: &'a mut Cursor<&'b [u8]>,
+    metadata: &'a FileMetadata,
+    footer: StripeFooter,
+    stripe: usize,
+}
+
+impl<'a, 'b> StripeReader<'a, 'b> {
+    fn column(&mut self, column_id: u32) -> Result<Column> {
+        read_stripe_column(
+            self.reader,
+            self.metadata,
+            self.stripe,
+            self.footer.clone(),
+            column_id,
+            vec![],
+        )
+        .map_err(orc_error)
+    }
+
+    fn has_stream(&self, column_id: u32, kind: Kind) -> bool {
+        self.footer
+            .streams
+            .iter()
+            .any(|s| s.column() == column_id && s.kind() == kind)
+    }
+
+    /// Decode `num_rows` values of a column, nested columns are decoded recursively.
+    fn read_values(&mut self, column_id: u32, num_rows: usize) -> Result<Vec<Value>> {
+        let ty = get_type(&self.metadata.footer.types, column_id)?.clone();
+        let column = self.column(column_id)?;
+        let validity = if self.has_stream(column_id, Kind::Present) {
+            let stream = column
+                .get_stream(Kind::Present, vec![])
+                .map_err(orc_error)?;
+            Some(
+                decode::BooleanIter::new(stream, num_rows)
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(orc_error)?,
+            )
+        } else {
+            None
+        };
+        // children and values of nulls are not stored
+        let num_values = validity
+            .as_ref()
+            .map_or(num_rows, |v| v.iter().filter(|b| **b).count());
+
+        let values = if num_values == 0 {
+            vec![]
+        } else {
+            self.read_non_null_values(column_id, &ty, &column, num_values)?
+        };
+        if values.len() != num_values {
+            return Err(ErrorCode::BadBytes(format!(
+                "invalid orc file: expect {num_values} values in column {column_id}, got {}",
+                values.len()
+            )));
+        }
+        Ok(match validity {
+            None => values,
+            Some(validity) => {
+                let mut values = values.into_iter();
+                validity
+                    .iter()
+                    .map(|valid| match valid {
+                        true => values.next().unwrap_or(Value::Null),
+                        false => Value::Null,
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    fn read_non_null_values(
+        &mut self,
+        column_id: u32,
+        ty: &Type,
+        column: &Column,
+        n: usize,
+    ) -> Result<Vec<Value>> {
+        let values = match ty.kind() {
+            TypeKind::Boolean => {
+                let stream = column.get_stream(Kind::Data, vec![]).map_err(orc_error)?;
+                decode::BooleanIter::new(stream, n)
+                    .map(|v| v.map(Value::Bool))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(orc_error)?
+            }
+            TypeKind::Byte => read_bytes(column, n)?
+                .into_iter()
+                .map(|v| Value::from(v as i8))
+                .collect(),
+            TypeKind::Short | TypeKind::Int | TypeKind::Long | TypeKind::Date => {
+                read_signed(column, Kind::Data, n)?
+                    .into_iter()
+                    .map(Value::from)
+                    .collect()
+            }
+            TypeKind::Float => {
+                let stream = column.get_stream(Kind::Data, vec![]).map_err(orc_error)?;
+                decode::FloatIter::<f32, _>::new(stream, n)
+                    .map(|v| v.map(|v| float_value(v as f64)))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(orc_error)?
+            }
+            TypeKind::Double => {
+                let stream = column.get_stream(Kind::Data, vec![]).map_err(orc_error)?;
+                decode::FloatIter::<f64, _>::new(stream, n)
+                    .map(|v| v.map(float_value))
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(orc_error)?
+            }
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char => {
+                let dictionary = self.has_stream(column_id, Kind::DictionaryData);
+                read_strings(column, dictionary, n)?
+                    .into_iter()
+                    .map(|v| Value::String(String::from_utf8_lossy(&v).into_owned()))
+                    .collect()
+            }
+            // binary values are passed to the decoder as hex, to keep the raw bytes
+            TypeKind::Binary => {
+                let dictionary = self.has_stream(column_id, Kind::DictionaryData);
+                read_strings(column, dictionary, n)?
+                    .into_iter()
+                    .map(|v| Value::String(hex::encode(v)))
+                    .collect()
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = read_signed(column, Kind::Data, n)?;
+                let nanos = read_unsigned(column, Kind::Secondary, n)?;
+                seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        // the low 3 bits are the number of trailing zeros removed minus one
+                        let zeros = nanos & 0x7;
+                        let mut nanos = nanos >> 3;
+                        if zeros != 0 {
+                            nanos *= 10u64.pow(zeros as u32 + 1);
+                        }
+                        let micros = (seconds + ORC_TIMESTAMP_BASE_SECONDS) * 1_000_000
+                            + (nanos / 1000) as i64;
+                        Value::from(micros)
+                    })
+                    .collect()
+            }
+            TypeKind::Decimal => {
+                let mut data = vec![];
+                column
+                    .get_stream(Kind::Data, vec![])
+                    .map_err(orc_error)?
+                    .read_to_end(&mut data)?;
+                let scales = read_signed(column, Kind::Secondary, n)?;
+                let mut buf = data.as_slice();
+                scales
+                    .into_iter()
+                    .map(|scale| {
+                        let unscaled = read_varint_i128(&mut buf)?;
+                        Ok(Value::String(decimal_to_string(unscaled, scale as usize)))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            TypeKind::Struct => {
+                let mut children = ty
+                    .subtypes
+                    .iter()
+                    .map(|id| self.read_values(*id, n))
+                    .collect::<Result<Vec<_>>>()?;
+                (0..n)
+                    .map(|row| {
+                        let mut object = Map::with_capacity(children.len());
+                        for (name, child) in ty.field_names.iter().zip(children.iter_mut()) {
+                            let value = child.get_mut(row).map(std::mem::take);
+                            object.insert(name.clone(), value.unwrap_or(Value::Null));
+                        }
+                        Value::Object(object)
+                    })
+                    .collect()
+            }
+            TypeKind::List => {
+                let lengths = read_unsigned(column, Kind::Length, n)?;
+                let total = lengths.iter().sum::<u64>() as usize;
+                let mut items = self.read_values(child_id(ty, 0)?, total)?.into_iter();
+                lengths
+                    .iter()
+                    .map(|len| Value::Array(items.by_ref().take(*len as usize).collect()))
+                    .collect()
+            }
+            TypeKind::Map => {
+                let lengths = read_unsigned(column, Kind::Length, n)?;
+                let total = lengths.iter().sum::<u64>() as usize;
+                let mut keys = self.read_values(child_id(ty, 0)?, total)?.into_iter();
+                let mut values = self.read_values(child_id(ty, 1)?, total)?.into_iter();
+                lengths
+                    .iter()
+                    .map(|len| {
+                        let object = keys
+                            .by_ref()
+                            .zip(values.by_ref())
+                            .take(*len as usize)
+                            .map(|(k, v)| match k {
+                                Value::String(k) => (k, v),
+                                k => (k.to_string(), v),
+                            })
+                            .collect::<Map<_, _>>();
+                        Value::Object(object)
+                    })
+                    .collect()
+            }
+            kind => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "ORC type {kind:?} is not supported yet"
+                )));
+            }
+        };
+        Ok(values)
+    }
+}
+
+fn read_signed(column: &Column, kind: Kind, n: usize) -> Result<Vec<i64>> {
+    let stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    decode::SignedRleV2Iter::new(stream, n, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(orc_error)
+}
+
+fn read_unsigned(column: &Column, kind: Kind, n: usize) -> Result<Vec<u64>> {
+    let stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    decode::UnsignedRleV2Iter::new(stream, n, vec![])
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(orc_error)
+}
+
+/// Byte run length encoding: a control byte `c < 128` is followed by one byte repeated
+/// `c + 3` times, otherwise by `256 - c` literal bytes.
+fn read_bytes(column: &Column, n: usize) -> Result<Vec<u8>> {
+    let mut data = vec![];
+    column
+        .get_stream(Kind::Data, vec![])
+        .map_err(orc_error)?
+        .read_to_end(&mut data)?;
+    let eof = || ErrorCode::BadBytes("invalid orc file: unexpected end of byte stream");
+
+    let mut values = Vec::with_capacity(n);
+    let mut pos = 0;
+    while values.len() < n {
+        let control = *data.get(pos).ok_or_else(eof)?;
+        pos += 1;
+        if control < 0x80 {
+            let value = *data.get(pos).ok_or_else(eof)?;
+            pos += 1;
+            values.extend(std::iter::repeat(value).take(control as usize + 3));
+        } else {
+            let len = 0x100 - control as usize;
+            values.extend_from_slice(data.get(pos..pos + len).ok_or_else(eof)?);
+            pos += len;
+        }
+    }
+    values.truncate(n);
+    Ok(values)
+}
+
+/// Both direct and dictionary encoding.
+fn read_strings(column: &Column, dictionary: bool, n: usize) -> Result<Vec<Vec<u8>>> {
+    match dictionary {
+        true => {
+            let mut dictionary_data = column
+                .get_stream(Kind::DictionaryData, vec![])
+                .map_err(orc_error)?;
+            let indexes = read_unsigned(column, Kind::Data, n)?;
+            let dictionary_size = indexes.iter().max().map_or(0, |i| *i as usize + 1);
+            let lengths = read_unsigned(column, Kind::Length, dictionary_size)?;
+            let mut dictionary = Vec::with_capacity(dictionary_size);
+            for len in lengths {
+                let mut value = vec![0; len as usize];
+                dictionary_data.read_exact(&mut value)?;
+                dictionary.push(value);
+            }
+            Ok(indexes
+                .into_iter()
+                .map(|i| dictionary[i as usize].clone())
+                .collect())
+        }
+        false => {
+            let lengths = read_unsigned(column, Kind::Length, n)?;
+            let mut data = column.get_stream(Kind::Data, vec![]).map_err(orc_error)?;
+            lengths
+                .into_iter()
+                .map(|len| {
+                    let mut value = vec![0; len as usize];
+                    data.read_exact(&mut value)?;
+                    Ok(value)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Zigzag encoded unbounded base 128 varint.
+fn read_varint_i128(buf: &mut &[u8]) -> Result<i128> {
+    let mut value = 0u128;
+    let mut shift = 0;
+    loop {
+        let (b, rest) = buf.split_first().ok_or_else(|| {
+            ErrorCode::BadBytes("invalid orc file: unexpected end of decimal stream")
+        })?;
+        *buf = rest;
+        if shift >= 128 {
+            return Err(ErrorCode::BadBytes(
+                "invalid orc file: decimal value overflow",
+            ));
+        }
+        value |= ((b & 0x7f) as u128) << shift;
+        shift += 7;
+        if b & 0x80 == 0 {
+            break;
+        }
+    }
+    Ok((value >> 1) as i128 ^ -((value & 1) as i128))
+}
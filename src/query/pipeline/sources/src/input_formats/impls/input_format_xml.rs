@@ -33,7 +33,7 @@ use common_pipeline_core::InputError;
 use xml::reader::XmlEvent;
 use xml::ParserConfig;
 
-use crate::input_formats::AligningStateTextBased;
+use crate::input_formats::AligningStateWholeFile;
 use crate::input_formats::BlockBuilder;
 use crate::input_formats::InputContext;
 use crate::input_formats::InputFormatTextBase;
@@ -94,43 +94,6 @@ impl InputFormatXML {
     }
 }
 
-pub struct AligningStateWholeFile {
-    #[allow(unused)]
-    split_info: Arc<SplitInfo>,
-    bufs: Vec<Vec<u8>>,
-}
-
-impl AligningStateWholeFile {
-    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
-        Ok(Self {
-            split_info: split_info.clone(),
-            bufs: vec![],
-        })
-    }
-}
-
-impl AligningStateTextBased for AligningStateWholeFile {
-    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
-        self.bufs.push(buf.to_vec());
-        Ok(vec![])
-    }
-
-    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
-        let data = self.bufs.concat();
-
-        Ok(vec![RowBatch {
-            data,
-            row_ends: vec![],
-            field_ends: vec![],
-            batch_id: 0,
-            split_info: self.split_info.clone(),
-            start_offset_in_split: 0,
-            start_row_in_split: 0,
-            start_row_of_split: Some(0),
-        }])
-    }
-}
-
 impl InputFormatTextBase for InputFormatXML {
     type AligningState = AligningStateWholeFile;
 
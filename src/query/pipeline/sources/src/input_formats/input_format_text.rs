@@ -216,6 +216,45 @@ impl AligningStateTextBased for AligningStateRowDelimiter {
     }
 }
 
+/// Buffers the whole file into a single batch, for formats that can not be
+/// split at row boundaries (e.g. XML, or binary formats with a file footer).
+pub struct AligningStateWholeFile {
+    #[allow(unused)]
+    split_info: Arc<SplitInfo>,
+    bufs: Vec<Vec<u8>>,
+}
+
+impl AligningStateWholeFile {
+    pub fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(Self {
+            split_info: split_info.clone(),
+            bufs: vec![],
+        })
+    }
+}
+
+impl AligningStateTextBased for AligningStateWholeFile {
+    fn align(&mut self, buf: &[u8]) -> Result<Vec<RowBatch>> {
+        self.bufs.push(buf.to_vec());
+        Ok(vec![])
+    }
+
+    fn align_flush(&mut self) -> Result<Vec<RowBatch>> {
+        let data = self.bufs.concat();
+
+        Ok(vec![RowBatch {
+            data,
+            row_ends: vec![],
+            field_ends: vec![],
+            batch_id: 0,
+            split_info: self.split_info.clone(),
+            start_offset_in_split: 0,
+            start_row_in_split: 0,
+            start_row_of_split: Some(0),
+        }])
+    }
+}
+
 pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
     type AligningState: AligningStateTextBased;
 
@@ -239,6 +278,13 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         options: &FileFormatOptionsExt,
     ) -> Arc<dyn FieldDecoder>;
 
+    /// Read the schema embedded in the file, only for formats with `has_inner_schema()`.
+    fn read_inner_schema(_data: &[u8]) -> Result<TableSchemaRef> {
+        Err(ErrorCode::Unimplemented(
+            "infer_schema is not implemented for this format yet.",
+        ))
+    }
+
     fn deserialize(
         builder: &mut BlockBuilder<Self>,
         batch: RowBatch,
@@ -373,10 +419,14 @@ impl<T: InputFormatTextBase> InputFormat for T {
     }
 
     #[async_backtrace::framed]
-    async fn infer_schema(&self, _path: &str, _op: &Operator) -> Result<TableSchemaRef> {
-        Err(ErrorCode::Unimplemented(
-            "infer_schema is not implemented for this format yet.",
-        ))
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        if !T::format_type().has_inner_schema() {
+            return Err(ErrorCode::Unimplemented(
+                "infer_schema is not implemented for this format yet.",
+            ));
+        }
+        let data = op.read(path).await?;
+        T::read_inner_schema(&data)
     }
 
     fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
@@ -56,6 +56,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -466,6 +468,14 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.mutable_catalog.update_multi_table_meta(req).await
+    }
+
     #[async_backtrace::framed]
     async fn update_table_meta(
         &self,
@@ -67,6 +67,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -367,6 +369,21 @@ impl Catalog for MutableCatalog {
         db.upsert_table_option(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        info!(
+            "updating multiple table metas, tables: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|r| r.table_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(self.ctx.meta.update_multi_table_meta(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn update_table_meta(
         &self,
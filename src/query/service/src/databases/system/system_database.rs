@@ -48,6 +48,7 @@ use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
+use common_storages_system::WorkloadGroupsTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -103,6 +104,7 @@ impl SystemDatabase {
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
             CachesTable::create(sys_db_meta.next_table_id()),
             IndexesTable::create(sys_db_meta.next_table_id()),
+            WorkloadGroupsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
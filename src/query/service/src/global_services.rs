@@ -25,14 +25,17 @@ use common_storage::ShareTableConfig;
 use common_tracing::QueryLogger;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
+use common_users::WorkloadGroupCacheManager;
 use storages_common_cache_manager::CacheManager;
 
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::StorageUsageCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadQueueManager;
 
 pub struct GlobalServices;
 
@@ -70,6 +73,8 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        WorkloadQueueManager::init(&config)?;
+        StorageUsageCache::init()?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -79,6 +84,7 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        WorkloadGroupCacheManager::init()?;
         ShareEndpointManager::init()?;
 
         Ok(())
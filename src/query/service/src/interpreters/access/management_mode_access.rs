@@ -50,7 +50,8 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ShowStages
                             | RewriteKind::DescribeStage
                             | RewriteKind::ListStage
-                            | RewriteKind::ShowRoles),
+                            | RewriteKind::ShowRoles
+                            | RewriteKind::ShowWorkloadGroups),
                         _ => false
                     }
                 },
@@ -88,6 +89,11 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Workload group
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
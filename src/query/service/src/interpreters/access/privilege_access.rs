@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserPrivilegeType;
@@ -63,7 +64,7 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
-                    session
+                    let result = session
                         .validate_privilege(
                             &GrantObject::Table(
                                 table.catalog().to_string(),
@@ -72,7 +73,28 @@ impl AccessChecker for PrivilegeAccess {
                             ),
                             vec![UserPrivilegeType::Select],
                         )
-                        .await?
+                        .await;
+                    if let Err(err) = result {
+                        // Without SELECT on the table, every column referenced by the query
+                        // must be granted SELECT.
+                        let columns = metadata.referenced_column_names(table.index());
+                        if err.code() != ErrorCode::PERMISSION_DENIED || columns.is_empty() {
+                            return Err(err);
+                        }
+                        for column in columns {
+                            session
+                                .validate_privilege(
+                                    &GrantObject::Column(
+                                        table.catalog().to_string(),
+                                        table.database().to_string(),
+                                        table.name().to_string(),
+                                        column,
+                                    ),
+                                    vec![UserPrivilegeType::Select],
+                                )
+                                .await?;
+                        }
+                    }
                 }
             }
             Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => self.check(plan).await?,
@@ -401,6 +423,22 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -425,6 +463,66 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                let create_table_plan = &plan.create_table_plan;
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(
+                            create_table_plan.catalog.clone(),
+                            create_table_plan.database.clone(),
+                        ),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.source_catalog.clone(),
+                            plan.source_database.clone(),
+                            plan.source_table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select, UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
@@ -510,7 +608,14 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::RemoveStage(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
-            | Plan::ShowFileFormats(_) => {
+            | Plan::ShowFileFormats(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -523,12 +628,17 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             // Note: No need to check privileges
+            // The READ or WRITE privilege on the stage is checked when binding.
             Plan::Presign(_) => {}
             Plan::ExplainAst { .. } => {}
             Plan::ExplainSyntax { .. } => {}
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Abort => {}
         }
 
         Ok(())
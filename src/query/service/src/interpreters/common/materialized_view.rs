@@ -0,0 +1,384 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::Expr;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::parser::parse_expr;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_catalog::txn::TxnManagerRef;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::SEGMENT_NAME_COL_NAME;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use tracing::info;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Keeps the materialized views of a table in sync with a statement mutating it.
+///
+/// The views are refreshed after the statement has produced the new version of the
+/// table, and they are committed together with it in one transaction, so a failed
+/// refresh leaves neither the table nor the views changed. If the statement doesn't
+/// run in an explicit transaction, an implicit one is started by `try_create` and
+/// committed once the views are refreshed, otherwise the new versions are left to
+/// the `COMMIT` of the explicit transaction.
+///
+/// If the statement only appends segments to the table, the query of a view is
+/// executed on the new segments and the result is appended to the view, otherwise,
+/// e.g. the table is overwritten, updated or deleted from, the view is rebuilt.
+pub struct MaterializedViewsMaintainer {
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    view_ids: Vec<u64>,
+    base_segments: HashSet<String>,
+    implicit_txn: Option<ImplicitTxn>,
+}
+
+/// The transaction started for a statement, discarded if it is not committed.
+struct ImplicitTxn {
+    txn_mgr: TxnManagerRef,
+    committed: bool,
+}
+
+impl Drop for ImplicitTxn {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.txn_mgr.lock().clear();
+        }
+    }
+}
+
+impl MaterializedViewsMaintainer {
+    /// Must be called before `table` is mutated. Returns None if there is no
+    /// materialized view built on the table.
+    #[async_backtrace::framed]
+    pub async fn try_create(
+        ctx: &Arc<QueryContext>,
+        table: &Arc<dyn Table>,
+    ) -> Result<Option<Self>> {
+        let view_ids = match table.options().get(OPT_KEY_MATERIALIZED_VIEWS) {
+            Some(view_ids) => view_ids
+                .split(',')
+                .map(|view_id| {
+                    view_id.parse::<u64>().map_err(|e| {
+                        ErrorCode::Internal(format!(
+                            "invalid materialized view id {}: {}",
+                            view_id, e
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => return Ok(None),
+        };
+        let base_segments = segments_of(table.as_ref()).await?;
+
+        let txn_mgr = ctx.txn_mgr();
+        let implicit_txn = {
+            let mut guard = txn_mgr.lock();
+            match guard.state() {
+                TxnState::AutoCommit => {
+                    guard.begin();
+                    Some(ImplicitTxn {
+                        txn_mgr: txn_mgr.clone(),
+                        committed: false,
+                    })
+                }
+                _ => None,
+            }
+        };
+
+        Ok(Some(MaterializedViewsMaintainer {
+            ctx: ctx.clone(),
+            table: table.clone(),
+            view_ids,
+            base_segments,
+            implicit_txn,
+        }))
+    }
+
+    /// Refresh the views and commit once `pipeline` finishes successfully, or
+    /// right now if the statement has been done without a pipeline.
+    #[async_backtrace::framed]
+    pub async fn attach(self, pipeline: &mut Pipeline) -> Result<()> {
+        if pipeline.is_empty() {
+            return self.refresh_and_commit().await;
+        }
+
+        pipeline.set_on_finished(move |may_error| match may_error {
+            None => GlobalIORuntime::instance().block_on(self.refresh_and_commit()),
+            // The implicit transaction is discarded when `self` is dropped.
+            Some(error) => Err(error.clone()),
+        });
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_and_commit(mut self) -> Result<()> {
+        let table_info = self.table.get_table_info();
+        let buffered = self
+            .ctx
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(table_info.ident.table_id);
+        let catalog = self.ctx.get_catalog(table_info.catalog())?;
+
+        if let Some(new_table_info) = buffered {
+            let new_table = catalog.get_table_by_info(&new_table_info)?;
+            let new_segments = segments_of(new_table.as_ref()).await?;
+            let new_segments = if new_segments.is_superset(&self.base_segments) {
+                let appended = new_segments
+                    .difference(&self.base_segments)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                Some(appended)
+            } else {
+                None
+            };
+            if !matches!(&new_segments, Some(appended) if appended.is_empty()) {
+                for view_id in self.view_ids.iter() {
+                    self.refresh_view(*view_id, new_segments.as_deref()).await?;
+                }
+            }
+        }
+
+        if let Some(implicit_txn) = self.implicit_txn.as_mut() {
+            let req = {
+                let mut txn_mgr = implicit_txn.txn_mgr.lock();
+                let req = txn_mgr.req();
+                txn_mgr.clear();
+                req
+            };
+            implicit_txn.committed = true;
+            if !req.update_table_metas.is_empty() {
+                // Fails with `TableVersionMismatched` if the table or any of its views
+                // is changed by others since the statement started.
+                catalog.update_multi_table_meta(req).await?;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_view(&self, view_id: u64, new_segments: Option<&[String]>) -> Result<()> {
+        let catalog = self
+            .ctx
+            .get_catalog(self.table.get_table_info().catalog())?;
+        // The view may have been mutated in the transaction already.
+        let buffered = self.ctx.txn_mgr().lock().get_table_from_buffer(view_id);
+        let view_info = match buffered {
+            Some(view_info) => view_info,
+            None => {
+                let (ident, meta) = match catalog.get_table_meta_by_id(view_id).await {
+                    Ok(reply) => reply,
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => return Ok(()),
+                    Err(e) => return Err(e),
+                };
+                if meta.drop_on.is_some() {
+                    return Ok(());
+                }
+                TableInfo {
+                    ident,
+                    desc: "".to_owned(),
+                    name: "".to_owned(),
+                    meta: meta.as_ref().clone(),
+                    tenant: self.ctx.get_tenant(),
+                    db_type: DatabaseType::NormalDB,
+                }
+            }
+        };
+        let view = catalog.get_table_by_info(&view_info)?;
+
+        info!(
+            "refreshing materialized view {} of table {}",
+            view_id,
+            self.table.get_table_info().desc
+        );
+        // Use a new context, the tables cached in the current one are outdated.
+        let refresh_ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut build_res =
+            build_refresh_materialized_view_pipeline(&refresh_ctx, view, new_segments).await?;
+
+        let settings = refresh_ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, refresh_ctx.get_id())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        refresh_ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+}
+
+/// The locations of the segments of the current snapshot of the fuse `table`.
+async fn segments_of(table: &dyn Table) -> Result<HashSet<String>> {
+    let fuse_table = FuseTable::try_from_table(table)?;
+    Ok(match fuse_table.read_table_snapshot().await? {
+        Some(snapshot) => snapshot
+            .segments
+            .iter()
+            .map(|(location, _)| location.clone())
+            .collect(),
+        None => HashSet::new(),
+    })
+}
+
+/// Build the pipeline which writes the result of the query of materialized `view`
+/// into it. The view is rebuilt if `new_segments` is None, otherwise only the rows
+/// in `new_segments` of the source table are queried and appended to the view.
+pub async fn build_refresh_materialized_view_pipeline(
+    ctx: &Arc<QueryContext>,
+    view: Arc<dyn Table>,
+    new_segments: Option<&[String]>,
+) -> Result<PipelineBuildResult> {
+    let query = view
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        .ok_or_else(|| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{} is not a materialized view",
+                view.get_table_info().desc
+            ))
+        })?;
+    let query = match new_segments {
+        None => query.clone(),
+        Some(segments) => incremental_query(query, segments)?,
+    };
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&query).await?;
+    let (mut select_plan, select_column_bindings) = match &plan {
+        Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } => {
+            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone());
+            (builder.build(s_expr).await?, bind_context.columns.clone())
+        }
+        _ => {
+            return Err(ErrorCode::Internal(format!(
+                "invalid query of materialized view: {}",
+                query
+            )));
+        }
+    };
+
+    let select_schema = plan.schema();
+    let insert_schema: DataSchemaRef = Arc::new(DataSchema::from(view.schema().as_ref()));
+    let insert_select = |input: Box<PhysicalPlan>| {
+        PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+            input,
+            catalog: view.get_table_info().catalog().to_string(),
+            table_info: view.get_table_info().clone(),
+            select_schema: select_schema.clone(),
+            select_column_bindings: select_column_bindings.clone(),
+            insert_schema: insert_schema.clone(),
+            cast_needed: select_schema != insert_schema,
+        }))
+    };
+    let insert_select_plan = match select_plan {
+        PhysicalPlan::Exchange(ref mut exchange) => {
+            // insert can be dispatched to different nodes
+            exchange.input = Box::new(insert_select(exchange.input.clone()));
+            select_plan
+        }
+        other_plan => insert_select(Box::new(other_plan)),
+    };
+
+    let mut build_res = build_query_pipeline(ctx, &[], &insert_select_plan, false, false).await?;
+
+    let ctx = ctx.clone();
+    let overwrite = new_segments.is_none();
+    build_res.main_pipeline.set_on_finished(move |may_error| {
+        if may_error.is_none() {
+            let append_entries = ctx.consume_precommit_blocks();
+            return GlobalIORuntime::instance().block_on(async move {
+                view.commit_insertion(ctx, append_entries, None, overwrite)
+                    .await
+            });
+        }
+
+        Err(may_error.as_ref().unwrap().clone())
+    });
+
+    Ok(build_res)
+}
+
+/// Restrict the query of a materialized view to the rows in the given segments.
+fn incremental_query(query: &str, segments: &[String]) -> Result<String> {
+    let tokens = tokenize_sql(query)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    let mut query = match stmt {
+        Statement::Query(query) => query,
+        _ => {
+            return Err(ErrorCode::Internal(format!(
+                "invalid query of materialized view: {}",
+                stmt
+            )));
+        }
+    };
+
+    let segments = segments
+        .iter()
+        .map(|segment| format!("'{}'", segment))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let predicate_sql = format!("{} IN ({})", SEGMENT_NAME_COL_NAME, segments);
+    let predicate_tokens = tokenize_sql(&predicate_sql)?;
+    let predicate = parse_expr(&predicate_tokens, Dialect::PostgreSQL)?;
+    if let SetExpr::Select(select) = &mut query.body {
+        select.selection = Some(match select.selection.take() {
+            Some(selection) => Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::And,
+                left: Box::new(selection),
+                right: Box::new(predicate),
+            },
+            None => predicate,
+        });
+    }
+    Ok(query.to_string())
+}
@@ -13,10 +13,16 @@
 // limitations under the License.
 
 mod grant;
+mod materialized_view;
+mod quota;
 mod stage;
 mod table;
 mod util;
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::build_refresh_materialized_view_pipeline;
+pub use materialized_view::MaterializedViewsMaintainer;
+pub use quota::check_user_storage_quota;
+pub use quota::StorageUsageCache;
 pub use stage::try_purge_files;
 pub use table::append2table;
 pub use util::check_deduplicate_label;
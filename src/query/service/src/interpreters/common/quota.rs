@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use parking_lot::Mutex;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
+
+/// Caches the storage used by the tables of each user, computing it lists all the tables
+/// of the catalog so it's not done on every write.
+///
+/// The writes committed meanwhile are not taken into account until the cached usage
+/// is older than `ttl`.
+pub struct StorageUsageCache {
+    ttl: Duration,
+    usages: Mutex<HashMap<(String, UserIdentity), (Instant, u64)>>,
+}
+
+impl StorageUsageCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Self::create(Duration::from_secs(30)));
+        Ok(())
+    }
+
+    pub fn create(ttl: Duration) -> Arc<StorageUsageCache> {
+        Arc::new(StorageUsageCache {
+            ttl,
+            usages: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn instance() -> Arc<StorageUsageCache> {
+        GlobalInstance::get()
+    }
+
+    pub fn invalidate_cache(&self, tenant: &str, user: &UserIdentity) {
+        self.usages
+            .lock()
+            .remove(&(tenant.to_string(), user.clone()));
+    }
+
+    /// Get the storage used by the tables owned by the user.
+    #[async_backtrace::framed]
+    pub async fn get_storage_usage(
+        &self,
+        ctx: &dyn TableContext,
+        tenant: &str,
+        user: &UserIdentity,
+    ) -> Result<u64> {
+        let key = (tenant.to_string(), user.clone());
+        let cached = self
+            .usages
+            .lock()
+            .get(&key)
+            .filter(|(computed_at, _)| computed_at.elapsed() < self.ttl)
+            .map(|(_, used)| *used);
+        if let Some(used) = cached {
+            return Ok(used);
+        }
+
+        let used = storage_used_by(ctx, tenant, user).await?;
+        self.usages.lock().insert(key, (Instant::now(), used));
+        Ok(used)
+    }
+}
+
+/// Checks that the tables owned by the current user do not exceed the storage quota of the user.
+///
+/// The storage used by a table is the size of its compressed data and indexes,
+/// as recorded in the table statistics by the last commit. The owner of a table is recorded
+/// when it is created, tables created before that have no owner and count against no quota.
+pub async fn check_user_storage_quota(ctx: Arc<dyn TableContext>) -> Result<()> {
+    let user = ctx.get_current_user()?;
+    let max_storage = user.quota.max_storage_in_bytes;
+    if max_storage == 0 {
+        return Ok(());
+    }
+
+    let used = StorageUsageCache::instance()
+        .get_storage_usage(ctx.as_ref(), &ctx.get_tenant(), &user.identity())
+        .await?;
+    if used >= max_storage {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "Storage quota exceeded for user {}: tables owned by the user use {} bytes, the quota is {} bytes",
+            user.identity(),
+            used,
+            max_storage
+        )));
+    }
+    Ok(())
+}
+
+async fn storage_used_by(ctx: &dyn TableContext, tenant: &str, user: &UserIdentity) -> Result<u64> {
+    let owner = user.to_string();
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+    let mut used = 0;
+    for database in catalog.list_databases(tenant).await? {
+        for table in catalog.list_tables(tenant, database.name()).await? {
+            let meta = &table.get_table_info().meta;
+            if meta.options.get(OPT_KEY_OWNER) == Some(&owner) {
+                used += meta.statistics.compressed_data_bytes + meta.statistics.index_data_bytes;
+            }
+        }
+    }
+    Ok(used)
+}
@@ -53,6 +53,11 @@ pub trait Interpreter: Sync + Send {
         InterpreterMetrics::record_query_start(&ctx);
         log_query_start(&ctx);
 
+        if let Err(err) = ctx.acquire_workload_slot().await {
+            log_query_finished(&ctx, Some(err.clone()));
+            return Err(err);
+        }
+
         if let Err(err) = ctx.check_aborting() {
             log_query_finished(&ctx, Some(err.clone()));
             return Err(err);
@@ -140,11 +145,18 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    ctx.release_workload_slot();
+
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    // the failure of a statement aborts the explicit transaction it belongs to
+    if error.is_some() {
+        ctx.txn_mgr().lock().set_fail();
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         tracing::error!("interpreter.finish.error: {:?}", error)
     }
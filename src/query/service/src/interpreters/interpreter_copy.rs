@@ -46,6 +46,8 @@ use tracing::info;
 
 use crate::interpreters::common::append2table;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
@@ -291,6 +293,12 @@ impl CopyInterpreter {
             )?;
         }
 
+        // The materialized views of the table mutated by `REPLACE INTO` are maintained
+        // by the replace interpreter.
+        let mv_maintainer = match plan.write_mode {
+            CopyIntoTableMode::Replace => None,
+            _ => MaterializedViewsMaintainer::try_create(&ctx, &to_table).await?,
+        };
         if matches!(
             plan.write_mode,
             CopyIntoTableMode::Insert { .. } | CopyIntoTableMode::Copy
@@ -320,7 +328,10 @@ impl CopyInterpreter {
         let stage_table_info_clone = plan.stage_table_info.clone();
         let force = plan.force;
         let write_mode = plan.write_mode;
+        let copied_files = files.clone();
+        let commit_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            let ctx = commit_ctx;
             match may_error {
                 None => {
                     match write_mode {
@@ -330,33 +341,16 @@ impl CopyInterpreter {
                             GlobalIORuntime::instance().block_on(async move {
                                 to_table
                                     .commit_insertion(ctx.clone(), operations, None, overwrite)
-                                    .await?;
-                                CopyInterpreter::try_purge_files(
-                                    ctx.clone(),
-                                    &stage_table_info_clone.stage_info,
-                                    &files,
-                                )
-                                .await;
-                                Ok(())
-                            })?;
-                        }
-                        CopyIntoTableMode::Replace => {
-                            GlobalIORuntime::instance().block_on(async move {
-                                CopyInterpreter::try_purge_files(
-                                    ctx.clone(),
-                                    &stage_table_info_clone.stage_info,
-                                    &files,
-                                )
-                                .await;
-                                Ok(())
+                                    .await
                             })?;
                         }
+                        CopyIntoTableMode::Replace => {}
                         CopyIntoTableMode::Copy => {
                             CopyInterpreter::commit_copy_into_table(
                                 ctx.clone(),
                                 to_table,
                                 stage_table_info_clone.stage_info,
-                                files,
+                                copied_files,
                                 write_mode,
                                 force,
                             )?;
@@ -377,6 +371,27 @@ impl CopyInterpreter {
             }
             Ok(())
         });
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
+
+        // Purge the files only after the data is committed, together with the
+        // materialized views of the table.
+        let stage_info = plan.stage_table_info.stage_info.clone();
+        build_res.main_pipeline.set_on_finished(move |may_error| {
+            // If a file is already copied(status with AlreadyCopied) we will try to purge them.
+            let purge = match write_mode {
+                CopyIntoTableMode::Copy => stage_info.copy_options.purge,
+                _ => true,
+            };
+            if may_error.is_none() && purge {
+                GlobalIORuntime::instance().block_on(async move {
+                    CopyInterpreter::try_purge_files(ctx, &stage_info, &files).await;
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        });
 
         Ok(build_res)
     }
@@ -385,7 +400,6 @@ impl CopyInterpreter {
     /// 1. commit the data.
     /// 2. update the NeedCopy file into to meta.
     /// 3. log on_error mode errors.
-    /// 4. purge the copied files.
     #[allow(clippy::too_many_arguments)]
     fn commit_copy_into_table(
         ctx: Arc<QueryContext>,
@@ -464,12 +478,6 @@ impl CopyInterpreter {
                 }
             }
 
-            // 4. Try to purge copied files if purge option is true, if error will skip.
-            // If a file is already copied(status with AlreadyCopied) we will try to purge them.
-            if stage_info.copy_options.purge {
-                CopyInterpreter::try_purge_files(ctx.clone(), &stage_info, &copied_files).await;
-            }
-
             Ok(())
         })
     }
@@ -505,6 +513,7 @@ impl Interpreter for CopyInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(self.ctx.clone()).await?;
 
         match &self.plan {
             CopyPlan::IntoTable(plan) => self.build_copy_into_table_pipeline(plan).await,
@@ -21,6 +21,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::executor::cast_expr_to_non_null_boolean;
 use table_lock::TableLockHandlerWrapper;
 
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -96,6 +97,7 @@ impl Interpreter for DeleteInterpreter {
             (None, vec![])
         };
 
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &tbl).await?;
         let mut build_res = PipelineBuildResult::create();
         tbl.delete(
             self.ctx.clone(),
@@ -117,6 +119,9 @@ impl Interpreter for DeleteInterpreter {
                 }
             });
         }
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
 
         Ok(build_res)
     }
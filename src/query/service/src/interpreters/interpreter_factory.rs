@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use tracing::error;
@@ -43,6 +44,7 @@ use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::plans::Plan;
 
 /// InterpreterFactory is the entry of Interpreter.
@@ -59,9 +61,56 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    /// Only queries and DML statements can run in an explicit transaction,
+    /// and nothing but `COMMIT` and `ROLLBACK` after a statement of the
+    /// transaction fails.
+    fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if matches!(plan, Plan::Begin | Plan::Commit | Plan::Abort) {
+            return Ok(());
+        }
+        if txn_mgr.is_fail() {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+        if txn_mgr.is_active() {
+            let allowed = matches!(
+                plan,
+                Plan::Query { .. }
+                    | Plan::Explain { .. }
+                    | Plan::ExplainAst { .. }
+                    | Plan::ExplainSyntax { .. }
+                    | Plan::Insert(_)
+                    | Plan::Replace(_)
+                    | Plan::Delete(_)
+                    | Plan::Update(_)
+                    | Plan::MergeInto(_)
+                    | Plan::Copy(_)
+                    | Plan::TruncateTable(_)
+                    | Plan::UseDatabase(_)
+                    | Plan::SetVariable(_)
+                    | Plan::UnSetVariable(_)
+                    | Plan::ShowCreateDatabase(_)
+                    | Plan::ShowCreateTable(_)
+                    | Plan::DescribeTable(_)
+                    | Plan::ExistsTable(_)
+            );
+            if !allowed {
+                return Err(ErrorCode::InvalidTransactionState(format!(
+                    "{} is not allowed in a transaction",
+                    plan
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// This is used for handlers to get the schema of the plan.
     /// Some plan may miss the schema and return empty plan such as `CallPlan`
     /// So we need to map the plan into to `Interpreter` and get the right schema.
@@ -115,6 +164,10 @@ impl InterpreterFactory {
 
             Plan::Call(plan) => Ok(Arc::new(CallInterpreter::try_create(ctx, *plan.clone())?)),
 
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             Plan::Copy(copy_plan) => Ok(Arc::new(CopyInterpreter::try_create(
                 ctx,
                 *copy_plan.clone(),
@@ -181,6 +234,12 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -222,6 +281,28 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -278,6 +359,10 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => {
+                MergeIntoInterpreter::try_create(ctx, *merge_into.clone())
+            }
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -354,6 +439,17 @@ impl InterpreterFactory {
                 *drop_udf.clone(),
             )?)),
 
+            // Workload groups
+            Plan::CreateWorkloadGroup(create_group) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *create_group.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(alter_group) => Ok(Arc::new(
+                AlterWorkloadGroupInterpreter::try_create(ctx, *alter_group.clone())?,
+            )),
+            Plan::DropWorkloadGroup(drop_group) => Ok(Arc::new(
+                DropWorkloadGroupInterpreter::try_create(ctx, *drop_group.clone())?,
+            )),
+
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
                 *presign.clone(),
@@ -429,6 +525,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
         }
     }
 }
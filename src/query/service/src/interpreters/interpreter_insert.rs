@@ -51,6 +51,8 @@ use parking_lot::RwLock;
 
 use crate::interpreters::common::append2table;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
@@ -105,11 +107,13 @@ impl Interpreter for InsertInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(self.ctx.clone()).await?;
         let plan = &self.plan;
         let table = self
             .ctx
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &table).await?;
 
         let mut build_res = PipelineBuildResult::create();
 
@@ -255,6 +259,9 @@ impl Interpreter for InsertInterpreter {
 
                     Err(may_error.as_ref().unwrap().clone())
                 });
+                if let Some(mv_maintainer) = mv_maintainer {
+                    mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+                }
 
                 return Ok(build_res);
             }
@@ -275,6 +282,9 @@ impl Interpreter for InsertInterpreter {
             true,
             append_mode,
         )?;
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
 
         Ok(build_res)
     }
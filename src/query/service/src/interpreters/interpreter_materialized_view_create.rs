@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateMaterializedViewPlan;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Record the view in the options of the source table, so that the view
+    /// is refreshed when rows are appended to the source table.
+    #[async_backtrace::framed]
+    async fn add_view_to_source(&self, view_id: u64) -> Result<()> {
+        let plan = &self.plan;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.source_catalog)?;
+        loop {
+            let source = catalog
+                .get_table(&tenant, &plan.source_database, &plan.source_table)
+                .await?;
+            let mut view_ids = source
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEWS)
+                .map(|ids| ids.split(',').map(str::to_string).collect::<Vec<_>>())
+                .unwrap_or_default();
+            view_ids.push(view_id.to_string());
+
+            let req = UpsertTableOptionReq {
+                table_id: source.get_id(),
+                seq: MatchSeq::Exact(source.get_table_info().ident.seq),
+                options: HashMap::from([(
+                    OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+                    Some(view_ids.join(",")),
+                )]),
+            };
+            match catalog
+                .upsert_table_option(&tenant, &plan.source_database, req)
+                .await
+            {
+                Ok(_) => return Ok(()),
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let create_table_plan = &self.plan.create_table_plan;
+        let build_res =
+            CreateTableInterpreter::try_create(self.ctx.clone(), create_table_plan.clone())?
+                .execute2()
+                .await?;
+        if build_res.main_pipeline.is_empty() {
+            // the view already exists
+            return Ok(build_res);
+        }
+
+        let view = self
+            .ctx
+            .get_catalog(&create_table_plan.catalog)?
+            .get_table(
+                &create_table_plan.tenant,
+                &create_table_plan.database,
+                &create_table_plan.table,
+            )
+            .await?;
+        self.add_view_to_source(view.get_id()).await?;
+
+        Ok(build_res)
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::RefreshMaterializedViewPlan;
+
+use crate::interpreters::common::build_refresh_materialized_view_pipeline;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let view = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.view_name)
+            .await?;
+
+        // rebuild the view with the full result of its query
+        build_refresh_materialized_view_pipeline(&self.ctx, view, None).await
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::MergeInto;
+use common_sql::plans::Plan;
+
+use crate::interpreters::common::check_user_storage_quota;
+use crate::interpreters::common::MaterializedViewsMaintainer;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// interprets MergeInto plan
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<InterpreterPtr> {
+        Ok(Arc::new(MergeIntoInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_user_storage_quota(self.ctx.clone()).await?;
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &table).await?;
+
+        let (s_expr, metadata, bind_context, formatted_ast) = match plan.input.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => (s_expr, metadata, bind_context, formatted_ast),
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        };
+
+        // The rows of `source LEFT OUTER JOIN target` with the merge clauses applied.
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            formatted_ast.clone(),
+            false,
+        )?;
+        let mut build_res = select_interpreter.execute2().await?;
+
+        table
+            .merge_into(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                plan.on_conflict_fields.clone(),
+            )
+            .await?;
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
+        Ok(build_res)
+    }
+}
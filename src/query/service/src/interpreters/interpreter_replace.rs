@@ -26,6 +26,8 @@ use common_sql::plans::Replace;
 use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::interpreter_copy::CopyInterpreter;
 use crate::interpreters::interpreter_insert::ValueSource;
 use crate::interpreters::Interpreter;
@@ -59,6 +61,7 @@ impl Interpreter for ReplaceInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(self.ctx.clone()).await?;
 
         self.check_on_conflicts()?;
 
@@ -67,6 +70,7 @@ impl Interpreter for ReplaceInterpreter {
             .ctx
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &table).await?;
 
         let mut pipeline = self
             .connect_input_source(self.ctx.clone(), &self.plan.source, self.plan.schema())
@@ -96,6 +100,13 @@ impl Interpreter for ReplaceInterpreter {
                 on_conflict_fields,
             )
             .await?;
+        if let Some(mv_maintainer) = mv_maintainer {
+            // The files of a stage source are purged by the callback of the copy
+            // interpreter, which must run after the views are committed.
+            let source_on_finished = pipeline.main_pipeline.take_on_finished();
+            mv_maintainer.attach(&mut pipeline.main_pipeline).await?;
+            pipeline.main_pipeline.set_on_finished(source_on_finished);
+        }
         Ok(pipeline)
     }
 }
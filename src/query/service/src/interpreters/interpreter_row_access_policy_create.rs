@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_api::RowAccessPolicyApi;
+use common_sql::plans::CreateRowAccessPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateRowAccessPolicyPlan,
+}
+
+impl CreateRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateRowAccessPolicyPlan) -> Result<Self> {
+        Ok(CreateRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreateRowAccessPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let _ = meta_api
+            .create_row_access_policy(self.plan.clone().into())
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -28,14 +28,17 @@ use common_sql::binder::INTERNAL_COLUMN_FACTORY;
 use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use common_storages_fuse::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
 use common_users::UserApiProvider;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
@@ -43,6 +46,8 @@ use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_EXTERNAL_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
@@ -244,6 +249,23 @@ impl CreateTableInterpreter {
             }
         }
 
+        if let Some(value) = table_meta.options.get(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS) {
+            for column in FuseTable::parse_vector_index_columns(value) {
+                let field = table_meta.schema.field_with_name(&column).map_err(|_| {
+                    ErrorCode::TableOptionInvalid(format!(
+                        "vector index column {column} does not exist",
+                    ))
+                })?;
+                if !VectorIndex::supported_type(field.data_type()) {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "vector index is not supported on column {} of type {}, only ARRAY(FLOAT32) is supported",
+                        column,
+                        field.data_type()
+                    )));
+                }
+            }
+        }
+
         if let Some(cluster_key) = &self.plan.cluster_key {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
@@ -270,12 +292,15 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS);
 
     r.insert(OPT_KEY_SNAPSHOT_LOCATION);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_OWNER);
 
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_EXTERNAL_LOCATION);
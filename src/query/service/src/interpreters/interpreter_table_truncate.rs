@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_sql::plans::TruncateTablePlan;
 
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -46,7 +47,13 @@ impl Interpreter for TruncateTableInterpreter {
         let tbl_name = self.plan.table.as_str();
 
         let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &tbl).await?;
         tbl.truncate(self.ctx.clone(), self.plan.purge).await?;
-        Ok(PipelineBuildResult::create())
+
+        let mut build_res = PipelineBuildResult::create();
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
+        Ok(build_res)
     }
 }
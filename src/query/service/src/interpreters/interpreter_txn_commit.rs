@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tracing::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction ends no matter whether the commit succeeds or not.
+        let (is_fail, req) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            let state = (txn_mgr.is_fail(), txn_mgr.req());
+            txn_mgr.clear();
+            state
+        };
+
+        if is_fail {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "Current transaction is aborted, the changes of the transaction are rolled back",
+            ));
+        }
+
+        if !req.update_table_metas.is_empty() {
+            info!(
+                "committing transaction of query {}, tables: {:?}",
+                self.ctx.get_id(),
+                req.update_table_metas
+                    .iter()
+                    .map(|r| r.table_id)
+                    .collect::<Vec<_>>()
+            );
+            // Fails with `TableVersionMismatched` if any of the tables is changed
+            // by others since the transaction mutated it.
+            let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+            catalog.update_multi_table_meta(req).await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
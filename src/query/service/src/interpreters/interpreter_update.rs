@@ -20,6 +20,8 @@ use common_sql::executor::cast_expr_to_non_null_boolean;
 use table_lock::TableLockHandlerWrapper;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
+use crate::interpreters::common::MaterializedViewsMaintainer;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -52,6 +54,7 @@ impl Interpreter for UpdateInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(self.ctx.clone()).await?;
 
         let catalog_name = self.plan.catalog.as_str();
         let db_name = self.plan.database.as_str();
@@ -90,6 +93,7 @@ impl Interpreter for UpdateInterpreter {
             .plan
             .generate_update_list(tbl.schema().into(), col_indices.clone())?;
 
+        let mv_maintainer = MaterializedViewsMaintainer::try_create(&self.ctx, &tbl).await?;
         let mut build_res = PipelineBuildResult::create();
         tbl.update(
             self.ctx.clone(),
@@ -112,6 +116,9 @@ impl Interpreter for UpdateInterpreter {
                 }
             });
         }
+        if let Some(mv_maintainer) = mv_maintainer {
+            mv_maintainer.attach(&mut build_res.main_pipeline).await?;
+        }
         Ok(build_res)
     }
 }
@@ -48,7 +48,12 @@ impl Interpreter for AlterUserInterpreter {
         let tenant = self.ctx.get_tenant();
         if plan.auth_info.is_some() || plan.user_option.is_some() {
             UserApiProvider::instance()
-                .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
+                .update_user(&tenant, plan.user.clone(), plan.auth_info, plan.user_option)
+                .await?;
+        }
+        if let Some(quota) = plan.quota {
+            UserApiProvider::instance()
+                .update_user_quota(&tenant, plan.user, quota)
                 .await?;
         }
 
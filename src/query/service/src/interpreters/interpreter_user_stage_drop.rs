@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::StageType;
 use common_sql::plans::DropStagePlan;
 use common_storages_stage::StageTable;
@@ -65,6 +66,10 @@ impl Interpreter for DropUserStageInterpreter {
             .await?;
 
         if let Ok(stage) = stage {
+            user_mgr
+                .revoke_object_privileges(&tenant, &GrantObject::Stage(plan.name.clone()))
+                .await?;
+
             if !matches!(&stage.stage_type, StageType::External) {
                 let op = StageTable::get_op(&stage)?;
                 op.remove_all("/").await?;
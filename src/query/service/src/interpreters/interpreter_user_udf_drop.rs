@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_app::principal::GrantObject;
 use common_sql::plans::DropUDFPlan;
 use common_users::UserApiProvider;
 
@@ -46,9 +47,13 @@ impl Interpreter for DropUserUDFInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
-        UserApiProvider::instance()
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
             .drop_udf(&tenant, plan.name.as_str(), plan.if_exists)
             .await?;
+        user_mgr
+            .revoke_object_privileges(&tenant, &GrantObject::UDF(plan.name.clone()))
+            .await?;
 
         Ok(PipelineBuildResult::create())
     }
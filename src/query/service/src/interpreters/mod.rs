@@ -40,6 +40,9 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_insert;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
+mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
@@ -52,6 +55,9 @@ mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -67,12 +73,16 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
@@ -83,6 +93,9 @@ mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -102,9 +115,13 @@ mod interpreter_virtual_columns_alter;
 mod interpreter_virtual_columns_create;
 mod interpreter_virtual_columns_drop;
 mod interpreter_virtual_columns_generate;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 
 pub use access::ManagementModeAccess;
 pub use common::append2table;
+pub use common::StorageUsageCache;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_call::CallInterpreter;
@@ -124,6 +141,9 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
+pub use interpreter_merge_into::MergeIntoInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
@@ -134,6 +154,9 @@ pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -148,12 +171,16 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
@@ -163,6 +190,9 @@ pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -182,3 +212,6 @@ pub use interpreter_virtual_columns_alter::AlterVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_create::CreateVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_drop::DropVirtualColumnsInterpreter;
 pub use interpreter_virtual_columns_generate::GenerateVirtualColumnsInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
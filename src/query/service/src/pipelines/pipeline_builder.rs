@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -41,6 +42,7 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::BlocksSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
 use common_profile::ProfSpanSetRef;
@@ -61,6 +63,8 @@ use common_sql::executor::Limit;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::RowFetch;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
@@ -84,12 +88,15 @@ use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::build_partition_bucket;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
+use crate::pipelines::processors::transforms::GraceHashJoinState;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::IEJoinState;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
+use crate::pipelines::processors::transforms::TransformGraceHashJoinBuild;
+use crate::pipelines::processors::transforms::TransformGraceHashJoinProbe;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformIEJoinLeft;
 use crate::pipelines::processors::transforms::TransformIEJoinRight;
@@ -98,8 +105,10 @@ use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformRecursiveCteSource;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
+use crate::pipelines::processors::transforms::TransformSortSpill;
 use crate::pipelines::processors::transforms::TransformWindow;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
@@ -200,6 +209,8 @@ impl PipelineBuilder {
                 self.build_runtime_filter_source(runtime_filter_source)
             }
             PhysicalPlan::IEJoin(ie_join) => self.build_ie_join(ie_join),
+            PhysicalPlan::RecursiveCte(recursive_cte) => self.build_recursive_cte(recursive_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
         }
     }
 
@@ -262,6 +273,14 @@ impl PipelineBuilder {
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_join_spilling_bytes_threshold()?;
+        if spilling_bytes_threshold != 0 && GraceHashJoinState::support(join) {
+            return self.build_grace_join(join, spilling_bytes_threshold);
+        }
+
         let state = self.build_join_state(join)?;
         self.expand_build_side_pipeline(&join.build, join, state.clone())?;
         self.build_join_probe(join, state)
@@ -322,6 +341,68 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_grace_join(&mut self, join: &HashJoin, spilling_bytes_threshold: usize) -> Result<()> {
+        let state = GraceHashJoinState::try_create(
+            self.ctx.clone(),
+            join,
+            spilling_bytes_threshold,
+            self.ctx.get_settings().get_join_spilling_partition_bits()?,
+            DataOperator::instance().operator(),
+            format!("_join_spill/{}", self.ctx.get_tenant()),
+        )?;
+
+        let build_side_context = QueryContext::create_from(self.ctx.clone());
+        let build_side_builder = PipelineBuilder::create(
+            build_side_context,
+            self.enable_profiling,
+            self.prof_span_set.clone(),
+        );
+        let mut build_res = build_side_builder.finalize(&join.build)?;
+
+        assert!(build_res.main_pipeline.is_pulling_pipeline()?);
+        build_res.main_pipeline.add_sink(|input| {
+            let transform = TransformGraceHashJoinBuild::create(
+                input,
+                GraceHashJoinState::attach_build(state.clone())?,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })?;
+
+        self.pipelines.push(build_res.main_pipeline);
+        self.pipelines
+            .extend(build_res.sources_pipelines.into_iter());
+
+        // Both sides are partitioned by the probe processors, and then the partitions are joined
+        // one by one, including the left/right/semi/anti/mark join compact steps.
+        self.build_pipeline(&join.probe)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformGraceHashJoinProbe::create(
+                input,
+                output,
+                GraceHashJoinState::attach_probe(state.clone())?,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    join.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
     pub fn render_result_set(
         func_ctx: &FunctionContext,
         input_schema: DataSchemaRef,
@@ -1025,16 +1106,39 @@ impl PipelineBuilder {
             }
         })?;
 
+        // Sorts with limit only keep the top rows in memory, so spilling is not needed.
+        let spilling_bytes_threshold = match limit {
+            None => self
+                .ctx
+                .get_settings()
+                .get_spilling_bytes_threshold_per_proc()?,
+            Some(_) => 0,
+        };
+
         // Merge
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!("_sort_spill/{}", self.ctx.get_tenant());
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                limit,
-                sort_desc.clone(),
-            )?;
+            let transform = match spilling_bytes_threshold {
+                0 => try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    limit,
+                    sort_desc.clone(),
+                )?,
+                threshold => TransformSortSpill::create(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    sort_desc.clone(),
+                    threshold,
+                    operator.clone(),
+                    location_prefix.clone(),
+                ),
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -1286,6 +1390,42 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                let source = TransformRecursiveCteSource::try_create(
+                    self.ctx.clone(),
+                    output,
+                    recursive_cte.clone(),
+                    self.enable_profiling,
+                    self.prof_span_set.clone(),
+                )?;
+
+                if self.enable_profiling {
+                    Ok(ProcessorPtr::create(ProfileWrapper::create(
+                        source,
+                        recursive_cte.plan_id,
+                        self.prof_span_set.clone(),
+                    )))
+                } else {
+                    Ok(ProcessorPtr::create(source))
+                }
+            },
+            1,
+        )
+    }
+
+    fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        // The working table is filled by the recursive CTE before the pipeline of
+        // the recursive term is built.
+        let blocks = self.ctx.get_recursive_cte_working_table(scan.cte_id);
+        let blocks = Arc::new(parking_lot::Mutex::new(VecDeque::from(blocks)));
+        self.main_pipeline.add_source(
+            |output| BlocksSource::create(self.ctx.clone(), output, blocks.clone()),
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
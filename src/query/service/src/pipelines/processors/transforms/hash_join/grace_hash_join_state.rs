@@ -0,0 +1,772 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::Notify;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::executor::HashJoin;
+use common_sql::plans::JoinType;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::info;
+
+use crate::api::FlightScatter;
+use crate::api::HashFlightScatter;
+use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::desc::JOIN_MAX_BLOCK_SIZE;
+use crate::pipelines::processors::transforms::hash_join::HashJoinState;
+use crate::pipelines::processors::transforms::hash_join::ProbeState;
+use crate::pipelines::processors::transforms::spill::deserialize_block;
+use crate::pipelines::processors::transforms::spill::serialize_block;
+use crate::pipelines::processors::transforms::spill::SpillFiles;
+use crate::pipelines::processors::transforms::spill::SpilledBlock;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The partitions are split by at most `MAX_PARTITION_BITS` bits of the hash of join keys.
+const MAX_PARTITION_BITS: usize = 16;
+/// The number of bits added when a restored partition is partitioned again.
+const REPARTITION_BITS: usize = 4;
+
+#[derive(Clone, Copy, Debug)]
+enum JoinSide {
+    Build,
+    Probe,
+}
+
+#[derive(Default)]
+pub struct PartitionData {
+    blocks: Vec<DataBlock>,
+    memory_bytes: usize,
+    spilled_blocks: Vec<SpilledBlock>,
+}
+
+impl PartitionData {
+    fn is_empty(&self) -> bool {
+        self.blocks.is_empty() && self.spilled_blocks.is_empty()
+    }
+
+    /// The in-memory bytes and the serialized bytes of the spilled blocks.
+    fn total_bytes(&self) -> usize {
+        self.spilled_blocks
+            .iter()
+            .flat_map(|block| block.columns_layout.iter())
+            .sum::<usize>()
+            + self.memory_bytes
+    }
+
+    fn push(&mut self, block: DataBlock) {
+        self.memory_bytes += block.memory_size();
+        self.blocks.push(block);
+    }
+}
+
+/// The blocks of a partition which are serialized and waiting to be written to storage.
+pub struct SpillingPartition {
+    side: JoinSide,
+    partition: usize,
+    blocks: Vec<(SpilledBlock, Vec<u8>)>,
+}
+
+/// Both sides of a partition, joined with a dedicated hash table after the build side is restored.
+pub struct JoinPartition {
+    index: usize,
+    /// The partition contains the rows whose hash of join keys modulo `2^partition_bits` is `index`.
+    partition_bits: usize,
+    /// False if partitioning it again can't split the build side, e.g. all the rows have the same keys.
+    can_repartition: bool,
+    build: PartitionData,
+    probe: PartitionData,
+}
+
+/// Joins a partition with the hash table built from its build side, the probe blocks are
+/// restored and probed one by one, so only the build side is kept in memory.
+pub struct PartitionJoiner {
+    index: usize,
+    join_type: JoinType,
+    has_non_equi_conditions: bool,
+    hash_table: Arc<JoinHashTable>,
+    probe_state: ProbeState,
+    block_size: usize,
+    probe: PartitionData,
+    /// The probed blocks which are needed by the final compact step, right join with non-equi
+    /// conditions and right semi/anti join need all of them, same as the in-memory hash join.
+    probed_blocks: Vec<DataBlock>,
+    interrupt: Arc<AtomicBool>,
+}
+
+/// Shared state of the grace hash join.
+///
+/// Both sides of the join are scattered into `2^join_spilling_partition_bits` partitions by
+/// the hash of the join keys. The partitions are kept in memory until the memory used by the
+/// join exceeds `join_spilling_bytes_threshold`, then the largest partition is written to
+/// storage. Once both sides are partitioned, each partition is restored and joined by a
+/// dedicated [`JoinHashTable`], so that all the join types can be supported partition by
+/// partition: the rows matched with each other are always in the same partition.
+///
+/// Only the build side of a partition is restored to memory, the probe side is restored and
+/// probed block by block. A partition whose build side is still larger than the threshold is
+/// partitioned again by more bits of the hash, and its sub-partitions are joined instead.
+///
+/// The spilled blocks which are not restored yet are deleted when the state is dropped,
+/// e.g. the query is aborted.
+pub struct GraceHashJoinState {
+    ctx: Arc<QueryContext>,
+    join: HashJoin,
+    build_schema: DataSchemaRef,
+    probe_schema: DataSchemaRef,
+    block_size: usize,
+    spilling_bytes_threshold: usize,
+    spill_files: SpillFiles,
+    location_prefix: String,
+
+    build_scatter: Box<dyn FlightScatter>,
+    probe_scatter: Box<dyn FlightScatter>,
+    build_partitions: Mutex<Vec<PartitionData>>,
+    probe_partitions: Mutex<Vec<PartitionData>>,
+    memory_bytes: AtomicUsize,
+
+    /// The NULL marker of mark join depends on the whole build side (right mark join)
+    /// or the whole probe side (left mark join), so it's collected across partitions.
+    build_has_null: AtomicBool,
+    probe_has_null: AtomicBool,
+
+    /// Number of the attached (build processors, probe processors).
+    workers: Mutex<(usize, usize)>,
+    is_partitioned: Mutex<bool>,
+    partitioned_notify: Notify,
+    pending_partitions: Mutex<VecDeque<usize>>,
+    /// The sub-partitions of the partitions which are partitioned again.
+    repartitioned_partitions: Mutex<Vec<JoinPartition>>,
+    partition_bits: usize,
+    interrupt: Arc<AtomicBool>,
+}
+
+impl GraceHashJoinState {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        join: &HashJoin,
+        spilling_bytes_threshold: usize,
+        partition_bits: usize,
+        operator: Operator,
+        location_prefix: String,
+    ) -> Result<Arc<GraceHashJoinState>> {
+        if partition_bits == 0 || partition_bits > 10 {
+            return Err(ErrorCode::BadArguments(format!(
+                "join_spilling_partition_bits must be between 1 and 10, but got {}",
+                partition_bits
+            )));
+        }
+
+        let partition_num = 1 << partition_bits;
+        let func_ctx = ctx.get_function_context()?;
+        let build_scatter = HashFlightScatter::try_create(
+            func_ctx.clone(),
+            join.build_keys.clone(),
+            partition_num,
+        )?;
+        let probe_scatter =
+            HashFlightScatter::try_create(func_ctx, join.probe_keys.clone(), partition_num)?;
+
+        Ok(Arc::new(GraceHashJoinState {
+            block_size: ctx.get_settings().get_max_block_size()? as usize,
+            ctx,
+            join: join.clone(),
+            build_schema: join.build.output_schema()?,
+            probe_schema: join.probe.output_schema()?,
+            spilling_bytes_threshold,
+            spill_files: SpillFiles::create(operator),
+            location_prefix,
+            build_scatter,
+            probe_scatter,
+            build_partitions: Mutex::new((0..partition_num).map(|_| Default::default()).collect()),
+            probe_partitions: Mutex::new((0..partition_num).map(|_| Default::default()).collect()),
+            memory_bytes: AtomicUsize::new(0),
+            build_has_null: AtomicBool::new(false),
+            probe_has_null: AtomicBool::new(false),
+            workers: Mutex::new((0, 0)),
+            is_partitioned: Mutex::new(false),
+            partitioned_notify: Notify::new(),
+            pending_partitions: Mutex::new((0..partition_num).collect()),
+            repartitioned_partitions: Mutex::new(vec![]),
+            partition_bits,
+            interrupt: Arc::new(AtomicBool::new(false)),
+        }))
+    }
+
+    /// Whether the hash join can be executed as a grace hash join.
+    pub fn support(join: &HashJoin) -> bool {
+        !join.build_keys.is_empty()
+            && !join.contain_runtime_filter
+            && join.join_type != JoinType::Cross
+    }
+
+    pub fn attach_build(state: Arc<GraceHashJoinState>) -> Result<Arc<GraceHashJoinState>> {
+        state.workers.lock().0 += 1;
+        Ok(state)
+    }
+
+    pub fn attach_probe(state: Arc<GraceHashJoinState>) -> Result<Arc<GraceHashJoinState>> {
+        state.workers.lock().1 += 1;
+        Ok(state)
+    }
+
+    pub fn build_end(&self) -> Result<()> {
+        let mut workers = self.workers.lock();
+        workers.0 -= 1;
+        self.try_finish_partitioning(&workers);
+        Ok(())
+    }
+
+    pub fn probe_end(&self) -> Result<()> {
+        let mut workers = self.workers.lock();
+        workers.1 -= 1;
+        self.try_finish_partitioning(&workers);
+        Ok(())
+    }
+
+    fn try_finish_partitioning(&self, workers: &(usize, usize)) {
+        if *workers == (0, 0) {
+            let mut is_partitioned = self.is_partitioned.lock();
+            *is_partitioned = true;
+            self.partitioned_notify.notify_waiters();
+        }
+    }
+
+    /// Wait until both sides of the join are partitioned.
+    #[async_backtrace::framed]
+    pub async fn wait_partitioned(&self) -> Result<()> {
+        let notified = self.partitioned_notify.notified();
+        if !*self.is_partitioned.lock() {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    pub fn interrupt(&self) {
+        self.interrupt.store(true, Ordering::Release);
+    }
+
+    fn check_interrupt(&self) -> Result<()> {
+        if self.interrupt.load(Ordering::Relaxed) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn add_build_block(&self, block: DataBlock) -> Result<Option<SpillingPartition>> {
+        if self.join.join_type == JoinType::RightMark && !block.is_empty() {
+            let func_ctx = self.ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let key = evaluator.run(&self.join.build_keys[0].as_expr(&BUILTIN_FUNCTIONS))?;
+            if has_null(&key) {
+                self.build_has_null.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let blocks = self.build_scatter.execute(block)?;
+        self.add_partitioned_blocks(JoinSide::Build, blocks)
+    }
+
+    pub fn add_probe_block(&self, block: DataBlock) -> Result<Option<SpillingPartition>> {
+        if self.join.join_type == JoinType::LeftMark && block.num_columns() > 0 {
+            if has_null(&block.get_by_offset(0).value) {
+                self.probe_has_null.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let blocks = self.probe_scatter.execute(block)?;
+        self.add_partitioned_blocks(JoinSide::Probe, blocks)
+    }
+
+    fn add_partitioned_blocks(
+        &self,
+        side: JoinSide,
+        blocks: Vec<DataBlock>,
+    ) -> Result<Option<SpillingPartition>> {
+        {
+            let mut partitions = match side {
+                JoinSide::Build => self.build_partitions.lock(),
+                JoinSide::Probe => self.probe_partitions.lock(),
+            };
+
+            for (partition, block) in blocks.into_iter().enumerate() {
+                if block.is_empty() {
+                    continue;
+                }
+
+                let memory_bytes = block.memory_size();
+                partitions[partition].blocks.push(block);
+                partitions[partition].memory_bytes += memory_bytes;
+                self.memory_bytes.fetch_add(memory_bytes, Ordering::Relaxed);
+            }
+        }
+
+        if self.memory_bytes.load(Ordering::Relaxed) <= self.spilling_bytes_threshold {
+            return Ok(None);
+        }
+
+        self.take_spilling_partition()
+    }
+
+    /// Take the largest in-memory partition of both sides to spill.
+    fn take_spilling_partition(&self) -> Result<Option<SpillingPartition>> {
+        let (side, partition, blocks) = {
+            let mut build_partitions = self.build_partitions.lock();
+            let mut probe_partitions = self.probe_partitions.lock();
+
+            let largest = build_partitions
+                .iter()
+                .enumerate()
+                .map(|(idx, p)| (JoinSide::Build, idx, p.memory_bytes))
+                .chain(
+                    probe_partitions
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, p)| (JoinSide::Probe, idx, p.memory_bytes)),
+                )
+                .max_by_key(|(_, _, memory_bytes)| *memory_bytes);
+
+            let Some((side, partition, memory_bytes)) = largest else {
+                return Ok(None);
+            };
+
+            if memory_bytes == 0 {
+                return Ok(None);
+            }
+
+            let data = match side {
+                JoinSide::Build => &mut build_partitions[partition],
+                JoinSide::Probe => &mut probe_partitions[partition],
+            };
+
+            data.memory_bytes = 0;
+            self.memory_bytes.fetch_sub(memory_bytes, Ordering::Relaxed);
+            (side, partition, std::mem::take(&mut data.blocks))
+        };
+
+        let mut spilling_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            spilling_blocks.push(serialize_block(&self.location_prefix, block));
+        }
+
+        Ok(Some(SpillingPartition {
+            side,
+            partition,
+            blocks: spilling_blocks,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn spill(&self, spilling: SpillingPartition) -> Result<()> {
+        let instant = Instant::now();
+        let mut spilled_blocks = Vec::with_capacity(spilling.blocks.len());
+
+        for (spilled_block, data) in spilling.blocks {
+            self.spill_files
+                .write(&spilled_block.location, data)
+                .await?;
+            spilled_blocks.push(spilled_block);
+        }
+
+        info!(
+            "Write {:?} partition {} of grace hash join spill successfully, elapsed: {:?}",
+            spilling.side,
+            spilling.partition,
+            instant.elapsed()
+        );
+
+        let mut partitions = match spilling.side {
+            JoinSide::Build => self.build_partitions.lock(),
+            JoinSide::Probe => self.probe_partitions.lock(),
+        };
+        partitions[spilling.partition]
+            .spilled_blocks
+            .extend(spilled_blocks);
+        Ok(())
+    }
+
+    /// Take the next partition to join, should be called after both sides are partitioned.
+    pub fn next_partition(&self) -> Option<JoinPartition> {
+        if let Some(partition) = self.repartitioned_partitions.lock().pop() {
+            self.memory_bytes.fetch_sub(
+                partition.build.memory_bytes + partition.probe.memory_bytes,
+                Ordering::Relaxed,
+            );
+            return Some(partition);
+        }
+
+        loop {
+            let index = self.pending_partitions.lock().pop_front()?;
+            let build = std::mem::take(&mut self.build_partitions.lock()[index]);
+            let probe = std::mem::take(&mut self.probe_partitions.lock()[index]);
+            self.memory_bytes
+                .fetch_sub(build.memory_bytes + probe.memory_bytes, Ordering::Relaxed);
+
+            if !self.can_skip_partition(build.is_empty(), probe.is_empty()) {
+                return Some(JoinPartition {
+                    index,
+                    partition_bits: self.partition_bits,
+                    can_repartition: true,
+                    build,
+                    probe,
+                });
+            }
+        }
+    }
+
+    fn can_skip_partition(&self, build_empty: bool, probe_empty: bool) -> bool {
+        match self.join.join_type {
+            _ if build_empty && probe_empty => true,
+            JoinType::Inner | JoinType::LeftSemi | JoinType::RightSemi => {
+                build_empty || probe_empty
+            }
+            // Only the rows of build side are returned.
+            JoinType::RightAnti | JoinType::Right | JoinType::LeftMark => build_empty,
+            // Only the rows of probe side are returned.
+            JoinType::LeftAnti | JoinType::Left | JoinType::Single | JoinType::RightMark => {
+                probe_empty
+            }
+            _ => false,
+        }
+    }
+
+    /// Read the spilled blocks of the build side back to memory, returns false if the
+    /// partition is partitioned again instead.
+    ///
+    /// The build side has to be kept in memory by the hash table, so it's partitioned again
+    /// if it's still larger than `join_spilling_bytes_threshold`, e.g. the data is skewed.
+    #[async_backtrace::framed]
+    pub async fn restore_partition(&self, partition: &mut JoinPartition) -> Result<bool> {
+        if partition.can_repartition
+            && partition.partition_bits < MAX_PARTITION_BITS
+            && partition.build.total_bytes() > self.spilling_bytes_threshold
+        {
+            let partition = JoinPartition {
+                index: partition.index,
+                partition_bits: partition.partition_bits,
+                can_repartition: partition.can_repartition,
+                build: std::mem::take(&mut partition.build),
+                probe: std::mem::take(&mut partition.probe),
+            };
+            self.repartition(partition).await?;
+            return Ok(false);
+        }
+
+        for spilled_block in std::mem::take(&mut partition.build.spilled_blocks) {
+            let block = self
+                .read_spilled_block(partition.index, &spilled_block)
+                .await?;
+            partition.build.push(block);
+        }
+
+        Ok(true)
+    }
+
+    /// Read the next spilled probe block of the partition back to memory.
+    #[async_backtrace::framed]
+    pub async fn restore_probe_block(&self, joiner: &mut PartitionJoiner) -> Result<()> {
+        if let Some(spilled_block) = joiner.probe.spilled_blocks.pop() {
+            let block = self
+                .read_spilled_block(joiner.index, &spilled_block)
+                .await?;
+            joiner.probe.push(block);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_spilled_block(
+        &self,
+        partition: usize,
+        spilled_block: &SpilledBlock,
+    ) -> Result<DataBlock> {
+        self.check_interrupt()?;
+
+        let instant = Instant::now();
+        let bytes = self.spill_files.read(&spilled_block.location).await?;
+
+        info!(
+            "Read grace hash join spill {} of partition {} successfully, elapsed: {:?}",
+            &spilled_block.location,
+            partition,
+            instant.elapsed()
+        );
+
+        deserialize_block(&spilled_block.columns_layout, &bytes)
+    }
+
+    /// Scatter both sides of the partition into sub-partitions by `REPARTITION_BITS` more bits
+    /// of the hash of join keys, the rows of partition `index` are scattered into the partitions
+    /// `index + i * 2^partition_bits`. The sub-partitions are spilled in the same way as the
+    /// partitions once their memory exceeds `join_spilling_bytes_threshold`.
+    #[async_backtrace::framed]
+    async fn repartition(&self, partition: JoinPartition) -> Result<()> {
+        let instant = Instant::now();
+        let partition_bits = MAX_PARTITION_BITS.min(partition.partition_bits + REPARTITION_BITS);
+        let partition_num = 1 << partition_bits;
+        let sub_partition_num = 1 << (partition_bits - partition.partition_bits);
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let build_scatter = HashFlightScatter::try_create(
+            func_ctx.clone(),
+            self.join.build_keys.clone(),
+            partition_num,
+        )?;
+        let probe_scatter =
+            HashFlightScatter::try_create(func_ctx, self.join.probe_keys.clone(), partition_num)?;
+
+        let mut build_partitions: Vec<PartitionData> =
+            (0..sub_partition_num).map(|_| Default::default()).collect();
+        let mut probe_partitions: Vec<PartitionData> =
+            (0..sub_partition_num).map(|_| Default::default()).collect();
+
+        for (data, scatter, sub_partitions) in [
+            (partition.build, &build_scatter, &mut build_partitions),
+            (partition.probe, &probe_scatter, &mut probe_partitions),
+        ] {
+            let mut blocks = data.blocks;
+            let mut spilled_blocks = data.spilled_blocks;
+            loop {
+                let block = match blocks.pop() {
+                    Some(block) => block,
+                    None => match spilled_blocks.pop() {
+                        Some(spilled_block) => {
+                            self.read_spilled_block(partition.index, &spilled_block)
+                                .await?
+                        }
+                        None => break,
+                    },
+                };
+
+                for (index, block) in scatter.execute(block)?.into_iter().enumerate() {
+                    if !block.is_empty() {
+                        sub_partitions[index >> partition.partition_bits].push(block);
+                    }
+                }
+
+                // Spill the largest sub-partition to keep the memory under the threshold.
+                while sub_partitions.iter().map(|p| p.memory_bytes).sum::<usize>()
+                    > self.spilling_bytes_threshold
+                {
+                    let largest = sub_partitions.iter_mut().max_by_key(|p| p.memory_bytes);
+                    if let Some(sub_partition) = largest {
+                        sub_partition.memory_bytes = 0;
+                        for block in std::mem::take(&mut sub_partition.blocks) {
+                            let (spilled_block, data) =
+                                serialize_block(&self.location_prefix, block);
+                            self.spill_files
+                                .write(&spilled_block.location, data)
+                                .await?;
+                            sub_partition.spilled_blocks.push(spilled_block);
+                        }
+                    }
+                }
+            }
+        }
+
+        // It can't be split any more if all the build rows have the same hash.
+        let can_repartition = build_partitions.iter().filter(|p| !p.is_empty()).count() > 1;
+
+        info!(
+            "Repartition partition {} of grace hash join by {} bits successfully, elapsed: {:?}",
+            partition.index,
+            partition_bits,
+            instant.elapsed()
+        );
+
+        let mut repartitioned_partitions = self.repartitioned_partitions.lock();
+        for (sub_index, (build, probe)) in build_partitions
+            .into_iter()
+            .zip(probe_partitions)
+            .enumerate()
+        {
+            if self.can_skip_partition(build.is_empty(), probe.is_empty()) {
+                continue;
+            }
+
+            self.memory_bytes
+                .fetch_add(build.memory_bytes + probe.memory_bytes, Ordering::Relaxed);
+            repartitioned_partitions.push(JoinPartition {
+                index: partition.index + (sub_index << partition.partition_bits),
+                partition_bits,
+                can_repartition,
+                build,
+                probe,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Build the hash table of the restored partition.
+    ///
+    /// It follows the same steps as the in-memory hash join pipeline: build, finalize, and
+    /// then the probe and compact steps are done by the returned [`PartitionJoiner`].
+    pub fn build_partition(&self, partition: JoinPartition) -> Result<PartitionJoiner> {
+        self.check_interrupt()?;
+
+        let join_type = self.join.join_type.clone();
+        let hash_table = JoinHashTable::create_join_state(
+            self.ctx.clone(),
+            &self.join.build_keys,
+            self.build_schema.clone(),
+            self.probe_schema.clone(),
+            HashJoinDesc::create(&self.join)?,
+        )?;
+
+        if self.interrupt.load(Ordering::Relaxed) {
+            hash_table.interrupt();
+        }
+
+        // Build
+        hash_table.attach()?;
+        for block in partition.build.blocks {
+            hash_table.build(block)?;
+        }
+        hash_table.build_end()?;
+
+        // Finalize
+        while let Some(task) = hash_table.task() {
+            self.check_interrupt()?;
+            hash_table.finalize(task)?;
+        }
+        hash_table.finalize_end()?;
+
+        let has_null = match join_type {
+            JoinType::RightMark => self.build_has_null.load(Ordering::Relaxed),
+            JoinType::LeftMark => self.probe_has_null.load(Ordering::Relaxed),
+            _ => false,
+        };
+        if has_null {
+            *hash_table.hash_join_desc.marker_join_desc.has_null.write() = true;
+        }
+
+        Ok(PartitionJoiner {
+            index: partition.index,
+            join_type,
+            has_non_equi_conditions: !self.join.non_equi_conditions.is_empty(),
+            hash_table,
+            probe_state: ProbeState::with_capacity(self.block_size),
+            block_size: self.block_size,
+            probe: partition.probe,
+            probed_blocks: vec![],
+            interrupt: self.interrupt.clone(),
+        })
+    }
+}
+
+impl PartitionJoiner {
+    /// Take the next in-memory probe block.
+    pub fn next_probe_block(&mut self) -> Option<DataBlock> {
+        self.probe.blocks.pop()
+    }
+
+    /// Whether there are spilled probe blocks to be restored.
+    pub fn has_spilled_probe_blocks(&self) -> bool {
+        !self.probe.spilled_blocks.is_empty()
+    }
+
+    /// Probe the hash table with the block, returns the joined blocks which are ready to output.
+    pub fn probe(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        let (sub_blocks, remain_block) = block.split_by_rows(self.block_size);
+        for sub_block in sub_blocks.into_iter().chain(remain_block) {
+            self.check_interrupt()?;
+            self.probe_state.clear();
+            let sub_block = sub_block.convert_to_full();
+            blocks.extend(self.hash_table.probe(&sub_block, &mut self.probe_state)?);
+        }
+
+        // Same as the partial compact steps of the in-memory hash join pipeline.
+        let blocks = match self.join_type {
+            JoinType::Left | JoinType::Full | JoinType::Single if !self.has_non_equi_conditions => {
+                self.hash_table.left_join_blocks(&blocks)?
+            }
+            JoinType::Right if !self.has_non_equi_conditions => {
+                let rest_len = self.hash_table.join_state().rest_pairs.read().1.len();
+                if rest_len >= JOIN_MAX_BLOCK_SIZE {
+                    blocks.extend(self.hash_table.left_join_blocks(&[])?);
+                }
+                blocks
+            }
+            // The markers are kept by the hash table.
+            JoinType::LeftMark => vec![],
+            // Only the unmatched build rows are returned, which are kept by the hash table.
+            JoinType::RightAnti if !self.has_non_equi_conditions => vec![],
+            JoinType::Right | JoinType::RightSemi | JoinType::RightAnti => {
+                self.probed_blocks.extend(blocks);
+                vec![]
+            }
+            _ => blocks,
+        };
+
+        Ok(blocks.into_iter().filter(|b| !b.is_empty()).collect())
+    }
+
+    /// The final compact steps of left/right/semi/anti/mark joins, should be called after all
+    /// the probe blocks are probed.
+    pub fn finish(self) -> Result<Vec<DataBlock>> {
+        self.check_interrupt()?;
+
+        let blocks = match self.join_type {
+            JoinType::LeftMark => self.hash_table.mark_join_blocks()?,
+            JoinType::Right | JoinType::Full => {
+                self.hash_table.right_join_blocks(&self.probed_blocks)?
+            }
+            JoinType::RightSemi | JoinType::RightAnti => self
+                .hash_table
+                .right_semi_join_blocks(&self.probed_blocks)?,
+            _ => vec![],
+        };
+
+        Ok(blocks.into_iter().filter(|b| !b.is_empty()).collect())
+    }
+
+    fn check_interrupt(&self) -> Result<()> {
+        if self.interrupt.load(Ordering::Relaxed) {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn has_null(value: &Value<AnyType>) -> bool {
+    match value {
+        Value::Scalar(scalar) => matches!(scalar, Scalar::Null),
+        Value::Column(column) => {
+            let (is_all_null, validity) = column.validity();
+            is_all_null || matches!(validity, Some(x) if x.unset_bits() > 0)
+        }
+    }
+}
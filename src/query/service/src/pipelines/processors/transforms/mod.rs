@@ -17,6 +17,7 @@ pub mod group_by;
 pub(crate) mod hash_join;
 mod transform_cast_schema;
 mod transform_create_sets;
+mod transform_grace_hash_join;
 mod transform_hash_join;
 mod transform_left_join;
 mod transform_limit;
@@ -26,14 +27,17 @@ mod window;
 mod ie_join;
 mod profile_wrapper;
 mod runtime_filter;
+mod spill;
 mod transform_add_const_columns;
 mod transform_ie_join;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -60,6 +64,7 @@ use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_merge;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
 pub use hash_join::FixedKeyHashJoinHashTable;
+pub use hash_join::GraceHashJoinState;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::JoinHashTable;
@@ -74,6 +79,8 @@ pub use transform_compact::Compactor;
 pub use transform_compact::TransformCompact;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_grace_hash_join::TransformGraceHashJoinBuild;
+pub use transform_grace_hash_join::TransformGraceHashJoinProbe;
 pub use transform_hash_join::TransformHashJoinBuild;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_ie_join::TransformIEJoinLeft;
@@ -84,6 +91,7 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::TransformRecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -94,6 +102,7 @@ pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use opendal::Operator;
+use parking_lot::Mutex;
+use tracing::error;
+use tracing::info;
+
+/// A block which has been written to spill storage.
+pub struct SpilledBlock {
+    pub location: String,
+    pub columns_layout: Vec<usize>,
+}
+
+/// Serialize the block column by column, returns the spilled block to be written at
+/// a unique location under `location_prefix` and the serialized data.
+pub fn serialize_block(location_prefix: &str, block: DataBlock) -> (SpilledBlock, Vec<u8>) {
+    let block = block.convert_to_full();
+    let mut data = Vec::with_capacity(block.memory_size());
+    let mut columns_layout = Vec::with_capacity(block.num_columns());
+
+    for entry in block.columns() {
+        let column_data = serialize_column(entry.value.as_column().unwrap());
+        columns_layout.push(column_data.len());
+        data.extend(column_data);
+    }
+
+    let location = format!("{}/{}", location_prefix, GlobalUniqName::unique());
+    (
+        SpilledBlock {
+            location,
+            columns_layout,
+        },
+        data,
+    )
+}
+
+pub fn deserialize_block(columns_layout: &[usize], data: &[u8]) -> Result<DataBlock> {
+    let mut begin = 0;
+    let mut columns = Vec::with_capacity(columns_layout.len());
+
+    for column_layout in columns_layout {
+        let column = deserialize_column(&data[begin..begin + column_layout])
+            .ok_or_else(|| ErrorCode::Internal("Cannot deserialize column from spill file"))?;
+
+        columns.push(column);
+        begin += column_layout;
+    }
+
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+/// The spill files which have been written but not read back yet.
+///
+/// Spill files are deleted as soon as they are read back. The files left when it is
+/// dropped, e.g. the query is aborted or failed, are deleted in the background.
+pub struct SpillFiles {
+    operator: Operator,
+    locations: Mutex<HashSet<String>>,
+}
+
+impl SpillFiles {
+    pub fn create(operator: Operator) -> Self {
+        SpillFiles {
+            operator,
+            locations: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Write the spill file, the location is recorded before writing so that
+    /// a partially written file is also deleted.
+    #[async_backtrace::framed]
+    pub async fn write(&self, location: &str, data: Vec<u8>) -> Result<()> {
+        self.locations.lock().insert(location.to_string());
+        self.operator.write(location, data).await?;
+        Ok(())
+    }
+
+    /// Read the spill file back and delete it.
+    #[async_backtrace::framed]
+    pub async fn read(&self, location: &str) -> Result<Vec<u8>> {
+        let data = self.operator.read(location).await?;
+
+        if let Err(cause) = self.operator.delete(location).await {
+            error!("Cannot delete spill file {}, cause: {:?}", location, cause);
+        }
+
+        self.locations.lock().remove(location);
+        Ok(data)
+    }
+}
+
+impl Drop for SpillFiles {
+    fn drop(&mut self) {
+        let locations = std::mem::take(&mut *self.locations.lock());
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let files = locations.len();
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!("Cannot delete spill file {}, cause: {:?}", location, cause);
+                }
+            }
+
+            info!("Delete {} spill files left by the unfinished query", files);
+        });
+    }
+}
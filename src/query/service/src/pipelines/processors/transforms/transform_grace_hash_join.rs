@@ -0,0 +1,300 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::transforms::hash_join::GraceHashJoinState;
+use crate::pipelines::processors::transforms::hash_join::JoinPartition;
+use crate::pipelines::processors::transforms::hash_join::PartitionJoiner;
+use crate::pipelines::processors::transforms::hash_join::SpillingPartition;
+use crate::pipelines::processors::Processor;
+
+pub struct TransformGraceHashJoinBuild {
+    input_port: Arc<InputPort>,
+    input_data: Option<DataBlock>,
+    spilling_partition: Option<SpillingPartition>,
+    join_state: Arc<GraceHashJoinState>,
+}
+
+impl TransformGraceHashJoinBuild {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        join_state: Arc<GraceHashJoinState>,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformGraceHashJoinBuild {
+            input_port,
+            input_data: None,
+            spilling_partition: None,
+            join_state,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformGraceHashJoinBuild {
+    fn name(&self) -> String {
+        "GraceHashJoinBuild".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.spilling_partition.is_some() {
+            return Ok(Event::Async);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.is_finished() {
+            self.join_state.build_end()?;
+            return Ok(Event::Finished);
+        }
+
+        match self.input_port.has_data() {
+            true => {
+                self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                Ok(Event::Sync)
+            }
+            false => {
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn interrupt(&self) {
+        self.join_state.interrupt()
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            self.spilling_partition = self.join_state.add_build_block(data_block)?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spilling_partition) = self.spilling_partition.take() {
+            self.join_state.spill(spilling_partition).await?;
+        }
+        Ok(())
+    }
+}
+
+enum GraceHashJoinStep {
+    /// Scatter the probe side into partitions.
+    Partition,
+    /// Wait until both sides are partitioned.
+    WaitPartitioned,
+    /// Read the spilled build blocks of the current partition, or partition it again.
+    Restore,
+    /// Build the hash table of the current partition.
+    Build,
+    /// Probe the hash table with the probe blocks of the current partition one by one.
+    Probe,
+    /// Read the next spilled probe block of the current partition.
+    RestoreProbe,
+    Finished,
+}
+
+pub struct TransformGraceHashJoinProbe {
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+
+    step: GraceHashJoinStep,
+    spilling_partition: Option<SpillingPartition>,
+    join_partition: Option<JoinPartition>,
+    partition_joiner: Option<PartitionJoiner>,
+    join_state: Arc<GraceHashJoinState>,
+}
+
+impl TransformGraceHashJoinProbe {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        join_state: Arc<GraceHashJoinState>,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformGraceHashJoinProbe {
+            input_port,
+            output_port,
+            input_data: None,
+            output_data_blocks: VecDeque::new(),
+            step: GraceHashJoinStep::Partition,
+            spilling_partition: None,
+            join_partition: None,
+            partition_joiner: None,
+            join_state,
+        })
+    }
+
+    fn next_partition(&mut self) {
+        self.join_partition = self.join_state.next_partition();
+        self.step = match self.join_partition.is_some() {
+            true => GraceHashJoinStep::Restore,
+            false => GraceHashJoinStep::Finished,
+        };
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformGraceHashJoinProbe {
+    fn name(&self) -> String {
+        "GraceHashJoinProbe".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output_port.is_finished() {
+            self.input_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output_port.can_push() {
+            self.input_port.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data_blocks.pop_front() {
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.step {
+            GraceHashJoinStep::Partition => {
+                if self.spilling_partition.is_some() {
+                    return Ok(Event::Async);
+                }
+
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    self.join_state.probe_end()?;
+                    self.step = GraceHashJoinStep::WaitPartitioned;
+                    return Ok(Event::Async);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            GraceHashJoinStep::WaitPartitioned
+            | GraceHashJoinStep::Restore
+            | GraceHashJoinStep::RestoreProbe => Ok(Event::Async),
+            GraceHashJoinStep::Build | GraceHashJoinStep::Probe => Ok(Event::Sync),
+            GraceHashJoinStep::Finished => {
+                self.output_port.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn interrupt(&self) {
+        self.join_state.interrupt()
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.step {
+            GraceHashJoinStep::Partition => {
+                if let Some(data_block) = self.input_data.take() {
+                    self.spilling_partition = self.join_state.add_probe_block(data_block)?;
+                }
+            }
+            GraceHashJoinStep::Build => {
+                if let Some(join_partition) = self.join_partition.take() {
+                    let joiner = self.join_state.build_partition(join_partition)?;
+                    self.partition_joiner = Some(joiner);
+                }
+                self.step = GraceHashJoinStep::Probe;
+            }
+            GraceHashJoinStep::Probe => {
+                // The joined blocks of each probe block are pushed before the next one is probed.
+                if let Some(mut joiner) = self.partition_joiner.take() {
+                    if let Some(data_block) = joiner.next_probe_block() {
+                        self.output_data_blocks.extend(joiner.probe(data_block)?);
+                        self.partition_joiner = Some(joiner);
+                        return Ok(());
+                    }
+
+                    if joiner.has_spilled_probe_blocks() {
+                        self.partition_joiner = Some(joiner);
+                        self.step = GraceHashJoinStep::RestoreProbe;
+                        return Ok(());
+                    }
+
+                    self.output_data_blocks.extend(joiner.finish()?);
+                }
+                self.next_partition();
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.step {
+            GraceHashJoinStep::Partition => {
+                if let Some(spilling_partition) = self.spilling_partition.take() {
+                    self.join_state.spill(spilling_partition).await?;
+                }
+            }
+            GraceHashJoinStep::WaitPartitioned => {
+                self.join_state.wait_partitioned().await?;
+                self.next_partition();
+            }
+            GraceHashJoinStep::Restore => {
+                if let Some(join_partition) = self.join_partition.as_mut() {
+                    if !self.join_state.restore_partition(join_partition).await? {
+                        // The sub-partitions are taken by the next partitions.
+                        self.next_partition();
+                        return Ok(());
+                    }
+                }
+                self.step = GraceHashJoinStep::Build;
+            }
+            GraceHashJoinStep::RestoreProbe => {
+                if let Some(joiner) = self.partition_joiner.as_mut() {
+                    self.join_state.restore_probe_block(joiner).await?;
+                }
+                self.step = GraceHashJoinStep::Probe;
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
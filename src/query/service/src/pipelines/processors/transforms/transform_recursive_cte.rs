@@ -0,0 +1,218 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::runtime::GlobalIORuntime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize_column_binary;
+use common_expression::DataBlock;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveCte;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Evaluates a recursive CTE: the anchor term is executed once, then the recursive
+/// term is executed repeatedly against the rows produced by the previous iteration
+/// (the working table) until an iteration produces no new rows.
+///
+/// Each iteration is executed by a nested pipeline executor on the blocking threads of the
+/// IO runtime, so that it doesn't occupy a worker thread of the outer pipeline.
+pub struct TransformRecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    output_port: Arc<OutputPort>,
+    output_data_blocks: VecDeque<DataBlock>,
+
+    plan: RecursiveCte,
+    max_depth: u64,
+    /// Number of iterations of the recursive term which produced rows.
+    depth: u64,
+    anchor_executed: bool,
+    finished: bool,
+    /// Serialized rows produced so far, only used by `UNION`.
+    distinct_rows: HashSet<Vec<u8>>,
+
+    enable_profiling: bool,
+    prof_span_set: ProfSpanSetRef,
+}
+
+impl TransformRecursiveCteSource {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        plan: RecursiveCte,
+        enable_profiling: bool,
+        prof_span_set: ProfSpanSetRef,
+    ) -> Result<Box<dyn Processor>> {
+        let max_depth = ctx.get_settings().get_max_recursive_cte_depth()?;
+        Ok(Box::new(TransformRecursiveCteSource {
+            ctx,
+            output_port,
+            output_data_blocks: VecDeque::new(),
+            plan,
+            max_depth,
+            depth: 0,
+            anchor_executed: false,
+            finished: false,
+            distinct_rows: HashSet::new(),
+            enable_profiling,
+            prof_span_set,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let plan = plan.clone();
+        let enable_profiling = self.enable_profiling;
+        let prof_span_set = self.prof_span_set.clone();
+
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let builder = PipelineBuilder::create(ctx.clone(), enable_profiling, prof_span_set);
+                let mut build_res = builder.finalize(&plan)?;
+                build_res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+
+                let settings = ExecutorSettings::try_create(&ctx.get_settings(), ctx.get_id())?;
+                let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+                executor.start();
+
+                let mut blocks = vec![];
+                while let Some(block) = executor.pull_data()? {
+                    if !block.is_empty() {
+                        blocks.push(block);
+                    }
+                }
+                Ok(blocks)
+            })
+            .await
+    }
+
+    /// Remove the rows which have been produced before, including the duplicates in `blocks`.
+    fn remove_duplicate_rows(&mut self, blocks: Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        let mut distinct_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let columns = block
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows)
+                })
+                .collect::<Vec<_>>();
+
+            let mut bitmap = MutableBitmap::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let mut key = vec![];
+                for column in columns.iter() {
+                    serialize_column_binary(column, row, &mut key);
+                }
+                bitmap.push(self.distinct_rows.insert(key));
+            }
+
+            let block = block.filter_with_bitmap(&bitmap.into())?;
+            if !block.is_empty() {
+                distinct_blocks.push(block);
+            }
+        }
+        Ok(distinct_blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRecursiveCteSource {
+    fn name(&self) -> String {
+        "RecursiveCteSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output_port.is_finished() {
+            self.ctx
+                .remove_recursive_cte_working_table(self.plan.cte_id);
+            return Ok(Event::Finished);
+        }
+
+        if !self.output_port.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data_blocks.pop_front() {
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.finished {
+            self.output_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::Async)
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        let (blocks, is_recursive_term) = match self.anchor_executed {
+            true => (self.execute_plan(&self.plan.recursive).await?, true),
+            false => {
+                self.anchor_executed = true;
+                (self.execute_plan(&self.plan.anchor).await?, false)
+            }
+        };
+        let blocks = match self.plan.distinct {
+            true => self.remove_duplicate_rows(blocks)?,
+            false => blocks,
+        };
+
+        if blocks.is_empty() {
+            self.finished = true;
+            self.ctx
+                .remove_recursive_cte_working_table(self.plan.cte_id);
+            return Ok(());
+        }
+
+        if is_recursive_term {
+            self.depth += 1;
+            if self.depth > self.max_depth {
+                return Err(ErrorCode::RecursiveCteDepthExceeded(format!(
+                    "Recursive CTE exceeds the maximum depth {}, please check the termination condition of the recursive term or increase the setting `max_recursive_cte_depth`",
+                    self.max_depth
+                )));
+            }
+        }
+
+        self.ctx
+            .set_recursive_cte_working_table(self.plan.cte_id, blocks.clone());
+        self.output_data_blocks.extend(blocks);
+        Ok(())
+    }
+}
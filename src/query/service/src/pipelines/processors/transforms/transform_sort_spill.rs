@@ -0,0 +1,432 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::sort::Cursor;
+use common_pipeline_transforms::processors::transforms::sort::RowConverter;
+use common_pipeline_transforms::processors::transforms::sort_merge;
+use opendal::Operator;
+use tracing::info;
+
+use crate::pipelines::processors::transforms::spill::deserialize_block;
+use crate::pipelines::processors::transforms::spill::serialize_block;
+use crate::pipelines::processors::transforms::spill::SpillFiles;
+use crate::pipelines::processors::transforms::spill::SpilledBlock;
+
+/// A sorted block of a run which is serialized and waiting to be written to storage.
+struct SpillingBlock {
+    location: String,
+    data: Vec<u8>,
+}
+
+enum State {
+    /// Collect blocks from input until the buffered bytes reach the threshold.
+    Collect,
+    /// Merge the buffered blocks into a sorted run.
+    SortRun,
+    /// Write the serialized sorted run to storage.
+    SpillRun(Vec<SpillingBlock>),
+    /// The input is finished, decide whether we need to merge the spilled runs.
+    Finalize,
+    /// Read the next block of the runs whose current block has been drained.
+    LoadBlocks,
+    /// Merge the current blocks of all runs into an output block.
+    Merge,
+    Finished,
+}
+
+/// External merge sort.
+///
+/// The input blocks should be sorted (by [`TransformSortPartial`]). When the buffered blocks
+/// exceed `spilling_bytes_threshold`, they are merged into a sorted run and the run is written
+/// to storage block by block. After the input is finished, all the spilled runs are merged back
+/// with a k-way merge which only keeps one block of each run in memory.
+///
+/// If nothing is spilled, it behaves the same as the in-memory sort merge. The spilled blocks
+/// which are not merged yet are deleted when the processor is dropped, e.g. the query is aborted.
+///
+/// [`TransformSortPartial`]: crate::pipelines::processors::TransformSortPartial
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    schema: DataSchemaRef,
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    spilling_bytes_threshold: usize,
+
+    spill_files: SpillFiles,
+    location_prefix: String,
+
+    state: State,
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    spilled_runs: Vec<VecDeque<SpilledBlock>>,
+    merger: Option<SpilledRunsMerger>,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        spilling_bytes_threshold: usize,
+        operator: Operator,
+        location_prefix: String,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSortSpill {
+            input,
+            output,
+            schema,
+            block_size,
+            sort_desc,
+            spilling_bytes_threshold,
+            spill_files: SpillFiles::create(operator),
+            location_prefix,
+            state: State::Collect,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            spilled_runs: vec![],
+            merger: None,
+            output_blocks: VecDeque::new(),
+        })
+    }
+
+    fn sort_buffered_blocks(&mut self) -> Result<Vec<DataBlock>> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        self.buffered_bytes = 0;
+
+        sort_merge(
+            self.schema.clone(),
+            self.block_size,
+            self.sort_desc.clone(),
+            &blocks,
+        )
+    }
+
+    fn serialize_run(&self, run: Vec<DataBlock>) -> (Vec<SpillingBlock>, VecDeque<SpilledBlock>) {
+        let mut spilling_blocks = Vec::with_capacity(run.len());
+        let mut spilled_blocks = VecDeque::with_capacity(run.len());
+
+        for block in run {
+            if block.is_empty() {
+                continue;
+            }
+
+            let (spilled_block, data) = serialize_block(&self.location_prefix, block);
+            spilling_blocks.push(SpillingBlock {
+                location: spilled_block.location.clone(),
+                data,
+            });
+            spilled_blocks.push_back(spilled_block);
+        }
+
+        (spilling_blocks, spilled_blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.state {
+            State::Collect => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+
+                    if !block.is_empty() {
+                        self.buffered_bytes += block.memory_size();
+                        self.buffered_blocks.push(block);
+                    }
+
+                    if self.buffered_bytes >= self.spilling_bytes_threshold {
+                        self.input.set_not_need_data();
+                        self.state = State::SortRun;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.state = State::Finalize;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::SortRun | State::Finalize | State::Merge => Ok(Event::Sync),
+            State::SpillRun(_) | State::LoadBlocks => Ok(Event::Async),
+            State::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finished) {
+            State::SortRun => {
+                let run = self.sort_buffered_blocks()?;
+                let (spilling_blocks, spilled_blocks) = self.serialize_run(run);
+
+                self.spilled_runs.push(spilled_blocks);
+                self.state = State::SpillRun(spilling_blocks);
+            }
+            State::Finalize => {
+                if self.spilled_runs.is_empty() {
+                    // Nothing was spilled, all data fits in memory.
+                    self.output_blocks.extend(self.sort_buffered_blocks()?);
+                    self.state = State::Finished;
+                } else if !self.buffered_blocks.is_empty() {
+                    // Spill the remaining blocks as the last run, and then finalize again.
+                    self.state = State::SortRun;
+                } else {
+                    let runs = std::mem::take(&mut self.spilled_runs);
+                    self.merger = Some(SpilledRunsMerger::try_create(
+                        self.schema.clone(),
+                        self.block_size,
+                        self.sort_desc.clone(),
+                        runs,
+                    )?);
+                    self.state = State::LoadBlocks;
+                }
+            }
+            State::Merge => {
+                let merger = self.merger.as_mut().unwrap();
+
+                if let Some(block) = merger.next_block()? {
+                    self.output_blocks.push_back(block);
+                }
+
+                self.state = match merger.is_finished() {
+                    true => State::Finished,
+                    false if merger.need_load() => State::LoadBlocks,
+                    false => State::Merge,
+                };
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finished) {
+            State::SpillRun(spilling_blocks) => {
+                let instant = Instant::now();
+                let blocks = spilling_blocks.len();
+
+                for block in spilling_blocks {
+                    self.spill_files.write(&block.location, block.data).await?;
+                }
+
+                info!(
+                    "Write sort spill run with {} blocks successfully, elapsed: {:?}",
+                    blocks,
+                    instant.elapsed()
+                );
+
+                self.state = State::Collect;
+            }
+            State::LoadBlocks => {
+                let merger = self.merger.as_mut().unwrap();
+
+                for (run_index, spilled_block) in merger.take_pending_loads() {
+                    let instant = Instant::now();
+                    let data = self.spill_files.read(&spilled_block.location).await?;
+
+                    info!(
+                        "Read sort spill {} successfully, elapsed: {:?}",
+                        &spilled_block.location,
+                        instant.elapsed()
+                    );
+
+                    let block = deserialize_block(&spilled_block.columns_layout, &data)?;
+                    merger.add_block(run_index, block)?;
+                }
+
+                self.state = State::Merge;
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+
+        Ok(())
+    }
+}
+
+/// K-way merge of the spilled runs, only the current block of each run is kept in memory.
+struct SpilledRunsMerger {
+    block_size: usize,
+    sort_field_indices: Vec<usize>,
+    row_converter: ArrowRowConverter,
+
+    /// The blocks of each run which are still in storage.
+    runs: Vec<VecDeque<SpilledBlock>>,
+    /// The block being merged of each run.
+    blocks: Vec<DataBlock>,
+    /// Heap that yields [`Cursor`] in increasing order.
+    heap: BinaryHeap<Reverse<Cursor<ArrowRows>>>,
+    /// Runs whose current block has been drained and whose next block need to be loaded.
+    pending_loads: Vec<usize>,
+}
+
+impl SpilledRunsMerger {
+    fn try_create(
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        runs: Vec<VecDeque<SpilledBlock>>,
+    ) -> Result<Self> {
+        let sort_field_indices = sort_desc.iter().map(|d| d.offset).collect::<Vec<_>>();
+        let row_converter = ArrowRowConverter::create(sort_desc, schema.clone())?;
+
+        Ok(SpilledRunsMerger {
+            block_size,
+            sort_field_indices,
+            row_converter,
+            blocks: vec![DataBlock::empty_with_schema(schema); runs.len()],
+            heap: BinaryHeap::with_capacity(runs.len()),
+            pending_loads: (0..runs.len()).collect(),
+            runs,
+        })
+    }
+
+    fn is_finished(&self) -> bool {
+        self.heap.is_empty() && self.pending_loads.is_empty()
+    }
+
+    fn need_load(&self) -> bool {
+        !self.pending_loads.is_empty()
+    }
+
+    fn take_pending_loads(&mut self) -> Vec<(usize, SpilledBlock)> {
+        let mut loads = Vec::with_capacity(self.pending_loads.len());
+
+        for run_index in std::mem::take(&mut self.pending_loads) {
+            if let Some(spilled_block) = self.runs[run_index].pop_front() {
+                loads.push((run_index, spilled_block));
+            }
+        }
+
+        loads
+    }
+
+    fn add_block(&mut self, run_index: usize, block: DataBlock) -> Result<()> {
+        let columns = self
+            .sort_field_indices
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect::<Vec<_>>();
+
+        let rows = self.row_converter.convert(&columns, block.num_rows())?;
+        self.heap.push(Reverse(Cursor::try_create(run_index, rows)));
+        self.blocks[run_index] = block;
+        Ok(())
+    }
+
+    /// Merge rows from the current blocks until the block size is reached or
+    /// the current block of a run is drained and its next block needs to be loaded.
+    fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut indices: Vec<(usize, usize)> = Vec::with_capacity(self.block_size);
+
+        while indices.len() < self.block_size {
+            let Some(Reverse(mut cursor)) = self.heap.pop() else {
+                break;
+            };
+
+            let run_index = cursor.input_index;
+            while !cursor.is_finished() && indices.len() < self.block_size {
+                if let Some(Reverse(next_cursor)) = self.heap.peek() {
+                    if cursor.gt(next_cursor) {
+                        break;
+                    }
+                }
+
+                indices.push((run_index, cursor.advance()));
+            }
+
+            if !cursor.is_finished() {
+                self.heap.push(Reverse(cursor));
+            } else if !self.runs[run_index].is_empty() {
+                // Rows of the next block may be smaller than the rows of other runs.
+                self.pending_loads.push(run_index);
+                break;
+            }
+        }
+
+        if indices.is_empty() {
+            return Ok(None);
+        }
+
+        let mut merge_slices: Vec<(usize, usize, usize)> = Vec::with_capacity(indices.len());
+        for (run_index, row_index) in indices {
+            match merge_slices.last_mut() {
+                Some(slice) if slice.0 == run_index && slice.1 + slice.2 == row_index => {
+                    slice.2 += 1;
+                }
+                _ => merge_slices.push((run_index, row_index, 1)),
+            }
+        }
+
+        Ok(Some(DataBlock::take_by_slices_limit_from_blocks(
+            &self.blocks,
+            &merge_slices,
+            None,
+        )))
+    }
+}
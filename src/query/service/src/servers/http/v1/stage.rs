@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::UserPrivilegeType;
+use common_sql::binder::validate_stage_privilege;
 use common_storages_stage::StageTable;
 use common_users::UserApiProvider;
 use poem::error::InternalServerError;
@@ -69,10 +73,15 @@ pub async fn upload_to_stage(
                 .as_str(),
         )
     } else {
-        UserApiProvider::instance()
+        let stage = UserApiProvider::instance()
             .get_stage(context.get_tenant().as_str(), stage_name)
             .await
-            .map_err(InternalServerError)?
+            .map_err(InternalServerError)?;
+        let table_ctx: Arc<dyn TableContext> = context.clone();
+        validate_stage_privilege(&table_ctx, &stage, UserPrivilegeType::Write)
+            .await
+            .map_err(|e| poem::Error::from_string(e.message(), StatusCode::FORBIDDEN))?;
+        stage
     };
 
     let op = StageTable::get_op(&stage).map_err(InternalServerError)?;
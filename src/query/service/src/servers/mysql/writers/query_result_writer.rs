@@ -171,9 +171,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 },
                 DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
+                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
@@ -275,6 +278,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                         )?;
                                     }
                                 },
+                                ScalarRef::Binary(v) => {
+                                    row_writer.write_col(v)?;
+                                }
                                 ScalarRef::Bitmap(_) => {
                                     let bitmap_result = "<bitmap binary>".as_bytes();
                                     row_writer.write_col(bitmap_result)?;
@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod postgres_codec;
+mod postgres_handler;
+mod postgres_interactive_worker;
+mod postgres_metrics;
+mod postgres_session;
+mod postgres_statement;
+mod postgres_types;
+
+use common_exception::ErrorCode;
+
+pub use self::postgres_handler::PostgresHandler;
+pub use self::postgres_session::PostgresConnection;
+
+const POSTGRES_VERSION: &str = "14.0";
+
+/// The SQLSTATE code of an error, see https://www.postgresql.org/docs/current/errcodes-appendix.html
+fn sqlstate(error: &ErrorCode) -> &'static str {
+    match error.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        _ => "XX000",
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol 3.0,
+//! see https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+
+/// Messages larger than this are rejected, instead of allocating buffers for them.
+const MAX_MESSAGE_LENGTH: usize = 1 << 30;
+
+/// Flush the buffered messages once they are larger than this, while writing rows.
+const WRITE_BUFFER_SIZE: usize = 100 * 1024;
+
+/// The first message of a connection, which has no type byte.
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Startup(HashMap<String, String>),
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// `kind` is `b'S'` for statements and `b'P'` for portals
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    /// `PasswordMessage` is only sent after an authentication request
+    Password(String),
+    Unsupported(u8),
+}
+
+struct BodyReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> BodyReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.buf.len() < len {
+            return Err(ErrorCode::BadBytes("unexpected end of postgres message"));
+        }
+        let (data, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(data)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn read_i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn read_cstr(&mut self) -> Result<String> {
+        let end = self
+            .buf
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| ErrorCode::BadBytes("unterminated string in postgres message"))?;
+        let s = String::from_utf8(self.take(end)?.to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 in postgres message: {e}")))?;
+        self.take(1)?;
+        Ok(s)
+    }
+
+    fn read_i16_array(&mut self) -> Result<Vec<i16>> {
+        let len = self.read_i16()?;
+        (0..len).map(|_| self.read_i16()).collect()
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = reader.read_i32().await?;
+    if len < 4 || len as usize > MAX_MESSAGE_LENGTH {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid postgres message length {len}"
+        )));
+    }
+    let mut body = vec![0; len as usize - 4];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+impl StartupMessage {
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+        let body = read_body(reader).await?;
+        let mut body = BodyReader { buf: &body };
+        match body.read_i32()? {
+            SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+            PROTOCOL_VERSION_3 => {
+                let mut params = HashMap::new();
+                loop {
+                    let key = body.read_cstr()?;
+                    if key.is_empty() {
+                        break;
+                    }
+                    params.insert(key, body.read_cstr()?);
+                }
+                Ok(StartupMessage::Startup(params))
+            }
+            version => Err(ErrorCode::BadBytes(format!(
+                "unsupported postgres protocol version {}.{}",
+                version >> 16,
+                version & 0xffff
+            ))),
+        }
+    }
+}
+
+impl FrontendMessage {
+    /// Read the next message, `None` if the connection is closed by the client.
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+        let tag = match reader.read_u8().await {
+            Ok(tag) => tag,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let body = read_body(reader).await?;
+        let mut body = BodyReader { buf: &body };
+        let message = match tag {
+            b'Q' => FrontendMessage::Query(body.read_cstr()?),
+            b'P' => {
+                let name = body.read_cstr()?;
+                let query = body.read_cstr()?;
+                let len = body.read_i16()?;
+                let param_types = (0..len)
+                    .map(|_| body.read_i32().map(|t| t as u32))
+                    .collect::<Result<_>>()?;
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                }
+            }
+            b'B' => {
+                let portal = body.read_cstr()?;
+                let statement = body.read_cstr()?;
+                let param_formats = body.read_i16_array()?;
+                let len = body.read_i16()?;
+                let mut params = Vec::with_capacity(len.max(0) as usize);
+                for _ in 0..len {
+                    let param_len = body.read_i32()?;
+                    if param_len < 0 {
+                        params.push(None);
+                    } else {
+                        params.push(Some(body.take(param_len as usize)?.to_vec()));
+                    }
+                }
+                let result_formats = body.read_i16_array()?;
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                }
+            }
+            b'D' => FrontendMessage::Describe {
+                kind: body.read_u8()?,
+                name: body.read_cstr()?,
+            },
+            b'E' => FrontendMessage::Execute {
+                portal: body.read_cstr()?,
+                max_rows: body.read_i32()?,
+            },
+            b'C' => FrontendMessage::Close {
+                kind: body.read_u8()?,
+                name: body.read_cstr()?,
+            },
+            b'S' => FrontendMessage::Sync,
+            b'H' => FrontendMessage::Flush,
+            b'X' => FrontendMessage::Terminate,
+            b'p' => FrontendMessage::Password(body.read_cstr()?),
+            other => FrontendMessage::Unsupported(other),
+        };
+        Ok(Some(message))
+    }
+}
+
+/// The description of a column in `RowDescription`.
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_len: i16,
+    pub format: i16,
+}
+
+/// Buffers backend messages, which are sent to the client on `flush`.
+pub struct MessageWriter<W: AsyncWrite + Send + Unpin> {
+    writer: W,
+    buf: Vec<u8>,
+    /// the start of the message being written
+    start: usize,
+}
+
+impl<W: AsyncWrite + Send + Unpin> MessageWriter<W> {
+    pub fn create(writer: W) -> Self {
+        MessageWriter {
+            writer,
+            buf: Vec::with_capacity(WRITE_BUFFER_SIZE),
+            start: 0,
+        }
+    }
+
+    fn begin(&mut self, tag: u8) {
+        self.buf.push(tag);
+        self.start = self.buf.len();
+        self.buf.extend_from_slice(&[0; 4]);
+    }
+
+    fn end(&mut self) {
+        let len = (self.buf.len() - self.start) as i32;
+        self.buf[self.start..self.start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn put_i16(&mut self, v: i16) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn put_i32(&mut self, v: i32) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn put_cstr(&mut self, s: &str) {
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+
+    /// The answer to `SSLRequest` and `GSSENCRequest`, which is a single byte without length.
+    pub fn encryption_refused(&mut self) {
+        self.buf.push(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.begin(b'R');
+        self.put_i32(0);
+        self.end();
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        self.begin(b'R');
+        self.put_i32(3);
+        self.end();
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.begin(b'S');
+        self.put_cstr(name);
+        self.put_cstr(value);
+        self.end();
+    }
+
+    /// `status` is `b'I'` for idle, `b'T'` in a transaction block and `b'E'` in a failed one
+    pub fn ready_for_query(&mut self, status: u8) {
+        self.begin(b'Z');
+        self.buf.push(status);
+        self.end();
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        self.begin(b'T');
+        self.put_i16(fields.len() as i16);
+        for field in fields {
+            self.put_cstr(&field.name);
+            // table oid and column attribute number
+            self.put_i32(0);
+            self.put_i16(0);
+            self.put_i32(field.type_oid as i32);
+            self.put_i16(field.type_len);
+            // type modifier
+            self.put_i32(-1);
+            self.put_i16(field.format);
+        }
+        self.end();
+    }
+
+    pub fn parameter_description(&mut self, types: &[u32]) {
+        self.begin(b't');
+        self.put_i16(types.len() as i16);
+        for t in types {
+            self.put_i32(*t as i32);
+        }
+        self.end();
+    }
+
+    /// Start a `DataRow`, the values are appended by `data_row_value` or `data_row_null`.
+    pub fn begin_data_row(&mut self, num_values: usize) {
+        self.begin(b'D');
+        self.put_i16(num_values as i16);
+    }
+
+    /// Append a value to the `DataRow`, the value is written by `f` into the buffer.
+    pub fn data_row_value(&mut self, f: impl FnOnce(&mut Vec<u8>)) {
+        let len_pos = self.buf.len();
+        self.put_i32(0);
+        f(&mut self.buf);
+        let len = (self.buf.len() - len_pos - 4) as i32;
+        self.buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    pub fn data_row_null(&mut self) {
+        self.put_i32(-1);
+    }
+
+    pub fn end_data_row(&mut self) {
+        self.end();
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.begin(b'C');
+        self.put_cstr(tag);
+        self.end();
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.begin(b'I');
+        self.end();
+    }
+
+    pub fn error_response(&mut self, sqlstate: &str, message: &str) {
+        self.begin(b'E');
+        for (field, value) in [(b'S', "ERROR"), (b'V', "ERROR"), (b'C', sqlstate)] {
+            self.buf.push(field);
+            self.put_cstr(value);
+        }
+        self.buf.push(b'M');
+        // strings are null terminated in the protocol
+        self.put_cstr(&message.replace('\0', ""));
+        self.buf.push(0);
+        self.end();
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.begin(b'1');
+        self.end();
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.begin(b'2');
+        self.end();
+    }
+
+    pub fn close_complete(&mut self) {
+        self.begin(b'3');
+        self.end();
+    }
+
+    pub fn no_data(&mut self) {
+        self.begin(b'n');
+        self.end();
+    }
+
+    pub fn portal_suspended(&mut self) {
+        self.begin(b's');
+        self.end();
+    }
+
+    #[async_backtrace::framed]
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Flush while writing large results, so that the buffer doesn't grow unbounded.
+    #[async_backtrace::framed]
+    pub async fn flush_if_full(&mut self) -> Result<()> {
+        if self.buf.len() >= WRITE_BUFFER_SIZE {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::future::Future;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::runtime::Runtime;
+use common_base::runtime::TrySpawn;
+use common_config::GlobalConfig;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::AbortRegistration;
+use futures::future::Abortable;
+use futures::StreamExt;
+use socket2::SockRef;
+use socket2::TcpKeepalive;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+use tokio_stream::wrappers::TcpListenerStream;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::servers::postgres::postgres_codec::MessageWriter;
+use crate::servers::postgres::postgres_codec::StartupMessage;
+use crate::servers::postgres::postgres_session::PostgresConnection;
+use crate::servers::postgres::sqlstate;
+use crate::servers::server::ListeningStream;
+use crate::servers::server::Server;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub struct PostgresHandler {
+    abort_handle: AbortHandle,
+    abort_registration: Option<AbortRegistration>,
+    join_handle: Option<JoinHandle<()>>,
+    keepalive: TcpKeepalive,
+    tls: Option<TlsAcceptor>,
+}
+
+impl PostgresHandler {
+    pub fn create(tcp_keepalive_timeout_secs: u64) -> Result<Box<dyn Server>> {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let keepalive = TcpKeepalive::new()
+            .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
+        let config = GlobalConfig::instance();
+        let tls = match config.postgres_tls_server_enabled() {
+            true => Some(Self::build_tls(config.as_ref())?),
+            false => None,
+        };
+        Ok(Box::new(PostgresHandler {
+            abort_handle,
+            abort_registration: Some(registration),
+            join_handle: None,
+            keepalive,
+            tls,
+        }))
+    }
+
+    fn build_tls(config: &InnerConfig) -> Result<TlsAcceptor> {
+        let cert_file = File::open(&config.query.postgres_handler_tls_server_cert)?;
+        let certs = rustls_pemfile::certs(&mut BufReader::new(cert_file))?
+            .into_iter()
+            .map(Certificate)
+            .collect::<Vec<_>>();
+
+        let key_file = File::open(&config.query.postgres_handler_tls_server_key)?;
+        let key = rustls_pemfile::read_all(&mut BufReader::new(key_file))?
+            .into_iter()
+            .find_map(|item| match item {
+                rustls_pemfile::Item::RSAKey(key)
+                | rustls_pemfile::Item::PKCS8Key(key)
+                | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ErrorCode::TLSConfigurationFailure(format!(
+                    "no private key found in {}",
+                    config.query.postgres_handler_tls_server_key
+                ))
+            })?;
+
+        let server_config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map_err(|e| ErrorCode::TLSConfigurationFailure(e.to_string()))?;
+        Ok(TlsAcceptor::from(Arc::new(server_config)))
+    }
+
+    #[async_backtrace::framed]
+    async fn listener_tcp(listening: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
+        let listener = tokio::net::TcpListener::bind(listening)
+            .await
+            .map_err(|e| {
+                ErrorCode::TokioError(format!("{{{}:{}}} {}", listening.ip(), listening.port(), e))
+            })?;
+        let listener_addr = listener.local_addr()?;
+        Ok((TcpListenerStream::new(listener), listener_addr))
+    }
+
+    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        let keepalive = self.keepalive.clone();
+        let tls = self.tls.clone();
+        stream.for_each(move |accept_socket| {
+            let keepalive = keepalive.clone();
+            let tls = tls.clone();
+            let executor = rt.clone();
+            let sessions = SessionManager::instance();
+            async move {
+                match accept_socket {
+                    Err(error) => error!("Broken session connection: {}", error),
+                    Ok(socket) => {
+                        PostgresHandler::accept_socket(sessions, executor, socket, keepalive, tls)
+                    }
+                };
+            }
+        })
+    }
+
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        keepalive: TcpKeepalive,
+        tls: Option<TlsAcceptor>,
+    ) {
+        executor.spawn(async move {
+            match sessions.create_session(SessionType::PostgreSQL).await {
+                Err(error) => {
+                    warn!("create session failed, {:?}", error);
+                    Self::reject_session(socket, error).await
+                }
+                Ok(session) => {
+                    info!("PostgreSQL connection coming: {:?}", socket.peer_addr());
+
+                    // TcpStream must implement AsFd for socket2 0.5, wait https://github.com/tokio-rs/tokio/pull/5514
+                    if let Err(e) = SockRef::from(&socket).set_tcp_keepalive(&keepalive) {
+                        warn!("failed to set socket option keepalive {}", e);
+                    }
+
+                    if let Err(error) = PostgresConnection::run_on_stream(session, socket, tls) {
+                        error!("Unexpected error occurred during query: {:?}", error);
+                    };
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn reject_session(stream: TcpStream, error: ErrorCode) {
+        if let Err(error) = Self::reject_postgres_connection(stream, &error).await {
+            error!(
+                "Unexpected error occurred during reject connection: {:?}",
+                error
+            );
+        }
+    }
+
+    /// Answer the startup message with the error, clients don't expect errors before it.
+    #[async_backtrace::framed]
+    async fn reject_postgres_connection(stream: TcpStream, error: &ErrorCode) -> Result<()> {
+        let (mut reader, writer) = stream.into_split();
+        let mut writer = MessageWriter::create(writer);
+        loop {
+            match StartupMessage::read(&mut reader).await? {
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    writer.encryption_refused();
+                    writer.flush().await?;
+                }
+                StartupMessage::CancelRequest => return Ok(()),
+                StartupMessage::Startup(_) => break,
+            }
+        }
+        writer.error_response(sqlstate(error), &error.message());
+        writer.flush().await
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for PostgresHandler {
+    #[async_backtrace::framed]
+    async fn shutdown(&mut self, graceful: bool) {
+        if !graceful {
+            return;
+        }
+
+        self.abort_handle.abort();
+
+        if let Some(join_handle) = self.join_handle.take() {
+            if let Err(error) = join_handle.await {
+                error!(
+                    "Unexpected error during shutdown PostgresHandler. cause {}",
+                    error
+                );
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        match self.abort_registration.take() {
+            None => Err(ErrorCode::Internal("PostgresHandler already running.")),
+            Some(registration) => {
+                let rejected_rt = Arc::new(Runtime::with_worker_threads(
+                    1,
+                    Some("postgres-handler".to_string()),
+                )?);
+                let (stream, listener) = Self::listener_tcp(listening).await?;
+                let stream = Abortable::new(stream, registration);
+                self.join_handle = Some(tokio::spawn(
+                    async_backtrace::location!().frame(self.listen_loop(stream, rejected_rt)),
+                ));
+                Ok(listener)
+            }
+        }
+    }
+}
@@ -0,0 +1,654 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_catalog::txn::TxnState;
+use common_config::DATABEND_COMMIT_VERSION;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_expression::SendableDataBlockStream;
+use common_meta_app::principal::AuthInfo;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use metrics::histogram;
+use tracing::error;
+use tracing::info;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_codec::FieldDescription;
+use crate::servers::postgres::postgres_codec::FrontendMessage;
+use crate::servers::postgres::postgres_codec::MessageWriter;
+use crate::servers::postgres::postgres_statement::bind_parameters;
+use crate::servers::postgres::postgres_statement::command_tag;
+use crate::servers::postgres::postgres_statement::ignored_command;
+use crate::servers::postgres::postgres_statement::parameter_count;
+use crate::servers::postgres::postgres_statement::split_statements;
+use crate::servers::postgres::postgres_types::parameter_literal;
+use crate::servers::postgres::postgres_types::postgres_type;
+use crate::servers::postgres::postgres_types::ValueEncoder;
+use crate::servers::postgres::postgres_types::BINARY_FORMAT;
+use crate::servers::postgres::postgres_types::TEXT_FORMAT;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::sqlstate;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+struct PreparedStatement {
+    query: String,
+    /// the declared types of the parameters, `0` if unspecified
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    /// the query is started by the first `Execute`, and may be suspended by `max_rows`
+    running: Option<RunningQuery>,
+}
+
+/// A started query whose rows are being sent.
+struct RunningQuery {
+    query: String,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    blocks: SendableDataBlockStream,
+    context: Option<Arc<QueryContext>>,
+    encoder: Option<ValueEncoder>,
+    formats: Vec<i16>,
+    columns: Vec<Column>,
+    num_rows: usize,
+    next_row: usize,
+    rows_sent: usize,
+}
+
+impl RunningQuery {
+    /// A command answered without being executed.
+    fn ignored(query: &str) -> Self {
+        RunningQuery {
+            query: query.to_string(),
+            schema: Arc::new(DataSchema::empty()),
+            has_result_set: false,
+            blocks: futures::stream::empty().boxed(),
+            context: None,
+            encoder: None,
+            formats: vec![],
+            columns: vec![],
+            num_rows: 0,
+            next_row: 0,
+            rows_sent: 0,
+        }
+    }
+
+    fn command_tag(&self) -> String {
+        match ignored_command(&self.query) {
+            Some(tag) => tag.to_string(),
+            None => {
+                let affected_rows = self
+                    .context
+                    .as_ref()
+                    .map(|ctx| ctx.get_write_progress_value().rows)
+                    .unwrap_or_default();
+                command_tag(
+                    &self.query,
+                    self.has_result_set,
+                    self.rows_sent,
+                    affected_rows,
+                )
+            }
+        }
+    }
+}
+
+/// The formats of result columns requested by `Bind`, a single format applies to all columns.
+fn column_formats(formats: &[i16], num_columns: usize) -> Result<Vec<i16>> {
+    match formats.len() {
+        0 => Ok(vec![TEXT_FORMAT; num_columns]),
+        1 => Ok(vec![formats[0]; num_columns]),
+        n if n == num_columns => Ok(formats.to_vec()),
+        n => Err(ErrorCode::BadArguments(format!(
+            "{n} result formats are given for {num_columns} columns"
+        ))),
+    }
+}
+
+fn field_descriptions(schema: &DataSchema, formats: &[i16]) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .zip(formats)
+        .map(|(field, format)| {
+            let (type_oid, type_len) = postgres_type(field.data_type());
+            FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_len,
+                format: *format,
+            }
+        })
+        .collect()
+}
+
+pub struct InteractiveWorker<R, W>
+where
+    R: AsyncRead + Send + Unpin,
+    W: AsyncWrite + Send + Unpin,
+{
+    session: Arc<Session>,
+    client_addr: Option<SocketAddr>,
+    /// the connection is not encrypted while the handler supports TLS,
+    /// passwords are refused instead of being sent in plaintext
+    insecure: bool,
+    reader: R,
+    writer: MessageWriter<W>,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    /// after an error in the extended query protocol, messages are skipped until `Sync`
+    skip_until_sync: bool,
+}
+
+impl<R, W> InteractiveWorker<R, W>
+where
+    R: AsyncRead + Send + Unpin,
+    W: AsyncWrite + Send + Unpin,
+{
+    pub fn create(
+        session: Arc<Session>,
+        client_addr: Option<SocketAddr>,
+        insecure: bool,
+        reader: R,
+        writer: W,
+    ) -> Self {
+        InteractiveWorker {
+            session,
+            client_addr,
+            insecure,
+            reader,
+            writer: MessageWriter::create(writer),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            skip_until_sync: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(mut self, params: HashMap<String, String>) -> Result<()> {
+        if !self.startup(params).await? {
+            return Ok(());
+        }
+
+        loop {
+            let message = match FrontendMessage::read(&mut self.reader).await? {
+                Some(message) => message,
+                None => return Ok(()),
+            };
+
+            if self.session.is_aborting() {
+                let error = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.write_error(&error);
+                self.writer.flush().await?;
+                return Err(error);
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Query(query) => {
+                    let instant = Instant::now();
+                    self.skip_until_sync = false;
+                    self.on_query(&query).await?;
+                    self.writer.ready_for_query(self.transaction_status());
+                    self.writer.flush().await?;
+                    histogram!(
+                        super::postgres_metrics::METRIC_POSTGRES_PROCESSOR_REQUEST_DURATION,
+                        instant.elapsed()
+                    );
+                }
+                FrontendMessage::Sync => {
+                    self.skip_until_sync = false;
+                    self.portals.remove("");
+                    self.writer.ready_for_query(self.transaction_status());
+                    self.writer.flush().await?;
+                }
+                FrontendMessage::Flush => self.writer.flush().await?,
+                _ if self.skip_until_sync => {}
+                message => {
+                    if let Err(error) = self.on_extended_message(message).await {
+                        self.write_error(&error);
+                        self.skip_until_sync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Authenticate the user with the parameters of the startup message,
+    /// returns false if the connection should be closed.
+    #[async_backtrace::framed]
+    async fn startup(&mut self, params: HashMap<String, String>) -> Result<bool> {
+        let user = params.get("user").cloned().unwrap_or_default();
+        if let Err(error) = self.authenticate(&user).await {
+            error!(
+                "PostgreSQL handler authenticate failed, user_name: {}, client_address: {:?}, failure_cause: {}",
+                user, self.client_addr, error
+            );
+            let error = ErrorCode::AuthenticateFailure(format!(
+                "password authentication failed for user \"{user}\""
+            ));
+            self.write_error(&error);
+            self.writer.flush().await?;
+            return Ok(false);
+        }
+
+        // libpq uses the user name as the database by default, which may not exist
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            let query = format!("USE `{}`", database.replace('`', "``"));
+            if let Err(error) = self.execute_to_end(&query).await {
+                if database != &user {
+                    self.write_error(&error);
+                    self.writer.flush().await?;
+                    return Ok(false);
+                }
+            }
+        }
+
+        let timezone = self.session.get_settings().get_timezone()?;
+        let version = format!("{}-{}", POSTGRES_VERSION, *DATABEND_COMMIT_VERSION);
+        for (name, value) in [
+            ("server_version", version.as_str()),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, YMD"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            self.writer.parameter_status(name, value);
+        }
+        self.writer.ready_for_query(self.transaction_status());
+        self.writer.flush().await?;
+        Ok(true)
+    }
+
+    /// Users with passwords are asked for the cleartext password, which is checked by `AuthMgr`,
+    /// handlers supporting TLS only ask for it on encrypted connections.
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, user: &str) -> Result<()> {
+        let client_ip = self
+            .client_addr
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_else(|| "%".to_string());
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&self.session.get_current_tenant(), user, &client_ip)
+            .await?;
+
+        let password = match user_info.auth_info {
+            AuthInfo::None => None,
+            _ if self.insecure => {
+                return Err(ErrorCode::AuthenticateFailure(
+                    "password authentication requires an SSL connection",
+                ));
+            }
+            _ => {
+                self.writer.authentication_cleartext_password();
+                self.writer.flush().await?;
+                match FrontendMessage::read(&mut self.reader).await? {
+                    Some(FrontendMessage::Password(password)) => Some(password.into_bytes()),
+                    _ => {
+                        return Err(ErrorCode::AuthenticateFailure("expected password response"));
+                    }
+                }
+            }
+        };
+
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password,
+            hostname: Some(client_ip),
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await?;
+        self.writer.authentication_ok();
+        Ok(())
+    }
+
+    /// The status of `ReadyForQuery`: idle, in a transaction block or in a failed one.
+    fn transaction_status(&self) -> u8 {
+        match self.session.txn_mgr().lock().state() {
+            TxnState::AutoCommit => b'I',
+            TxnState::Active => b'T',
+            TxnState::Fail => b'E',
+        }
+    }
+
+    /// Statements failing before being executed also abort the transaction block.
+    fn write_error(&mut self, error: &ErrorCode) {
+        self.session.txn_mgr().lock().set_fail();
+        if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
+            error!("OnQuery Error: {:?}", error);
+        }
+        self.writer
+            .error_response(sqlstate(error), &error.message());
+    }
+
+    /// Run the statements of a simple query, until one of them fails.
+    #[async_backtrace::framed]
+    async fn on_query(&mut self, query: &str) -> Result<()> {
+        let statements = split_statements(query);
+        if statements.is_empty() {
+            self.writer.empty_query_response();
+            return Ok(());
+        }
+
+        for statement in statements {
+            let result = match self.start_query(statement).await {
+                Ok(mut running) => {
+                    running.formats = vec![TEXT_FORMAT; running.schema.num_fields()];
+                    if running.has_result_set {
+                        self.writer.row_description(&field_descriptions(
+                            &running.schema,
+                            &running.formats,
+                        ));
+                    }
+                    self.write_rows(&mut running, 0).await.map(|_| running)
+                }
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(running) => self.writer.command_complete(&running.command_tag()),
+                Err(error) => {
+                    self.write_error(&error.display_with_sql(statement));
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_extended_message(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                mut param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{name}\" already exists"
+                    )));
+                }
+                let count = parameter_count(&query).max(param_types.len());
+                param_types.resize(count, 0);
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                self.writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.statement(&statement)?;
+                if params.len() != statement.param_types.len() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "bind message supplies {} parameters, but prepared statement requires {}",
+                        params.len(),
+                        statement.param_types.len()
+                    )));
+                }
+                let param_formats = column_formats(&param_formats, params.len())?;
+                let literals = params
+                    .iter()
+                    .zip(&statement.param_types)
+                    .zip(&param_formats)
+                    .map(|((value, type_oid), format)| {
+                        parameter_literal(value.as_deref(), *type_oid, *format)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let query = bind_parameters(&statement.query, &literals)?;
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    running: None,
+                });
+                self.writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.statement(&name)?;
+                let param_types = statement
+                    .param_types
+                    .iter()
+                    .map(|t| if *t == 0 { TEXT_OID } else { *t })
+                    .collect::<Vec<_>>();
+                // the result columns don't depend on the values of parameters
+                let nulls = vec!["NULL".to_string(); param_types.len()];
+                let query = bind_parameters(&statement.query, &nulls)?;
+                self.writer.parameter_description(&param_types);
+                self.describe(&query, &[]).await?;
+            }
+            FrontendMessage::Describe { name, .. } => {
+                let portal = self.portals.get(&name).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("portal \"{name}\" does not exist"))
+                })?;
+                let (query, formats) = (portal.query.clone(), portal.result_formats.clone());
+                self.describe(&query, &formats).await?;
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let mut entry = self.portals.remove(&portal).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("portal \"{portal}\" does not exist"))
+                })?;
+                let result = self.execute_portal(&mut entry, max_rows).await;
+                self.portals.insert(portal, entry);
+                result?;
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                self.writer.close_complete();
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadArguments("unexpected password message"));
+            }
+            FrontendMessage::Unsupported(tag) => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "postgres message type '{}' is not supported",
+                    tag as char
+                )));
+            }
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!("handled in the main loop"),
+        }
+        Ok(())
+    }
+
+    fn statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{name}\" does not exist"))
+        })
+    }
+
+    /// Describe the result columns of a query by planning it, without executing it.
+    #[async_backtrace::framed]
+    async fn describe(&mut self, query: &str, formats: &[i16]) -> Result<()> {
+        if ignored_command(query).is_some() {
+            self.writer.no_data();
+            return Ok(());
+        }
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner
+            .plan_sql(query)
+            .await
+            .map_err(|e| e.display_with_sql(query))?;
+        if !plan.has_result_set() {
+            self.writer.no_data();
+            return Ok(());
+        }
+        let schema = plan.schema();
+        let formats = column_formats(formats, schema.num_fields())?;
+        self.writer
+            .row_description(&field_descriptions(&schema, &formats));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_portal(&mut self, portal: &mut Portal, max_rows: i32) -> Result<()> {
+        let mut running = match portal.running.take() {
+            Some(running) => running,
+            None => {
+                let mut running = self
+                    .start_query(&portal.query)
+                    .await
+                    .map_err(|e| e.display_with_sql(&portal.query))?;
+                running.formats =
+                    column_formats(&portal.result_formats, running.schema.num_fields())?;
+                running
+            }
+        };
+        let max_rows = if max_rows > 0 { max_rows as usize } else { 0 };
+        if self.write_rows(&mut running, max_rows).await? {
+            self.writer.command_complete(&running.command_tag());
+        } else {
+            self.writer.portal_suspended();
+            portal.running = Some(running);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn start_query(&mut self, query: &str) -> Result<RunningQuery> {
+        if ignored_command(query).is_some() {
+            info!("Ignored postgres command: {}", query);
+            return Ok(RunningQuery::ignored(query));
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        let has_result_set = plan.has_result_set();
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+        let blocks = interpreter.execute(context.clone()).await?;
+        let format = context.get_format_settings()?;
+        Ok(RunningQuery {
+            query: query.to_string(),
+            schema: interpreter.schema(),
+            has_result_set,
+            blocks,
+            context: Some(context),
+            encoder: Some(ValueEncoder::create(format.timezone)),
+            formats: vec![],
+            columns: vec![],
+            num_rows: 0,
+            next_row: 0,
+            rows_sent: 0,
+        })
+    }
+
+    /// Run a statement without sending anything to the client.
+    #[async_backtrace::framed]
+    async fn execute_to_end(&mut self, query: &str) -> Result<()> {
+        let mut running = self.start_query(query).await?;
+        while let Some(block) = running.blocks.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    /// Send the rows as `DataRow`s, at most `max_rows` of them if it's not 0.
+    /// Returns true if all the rows are sent.
+    #[async_backtrace::framed]
+    async fn write_rows(&mut self, running: &mut RunningQuery, max_rows: usize) -> Result<bool> {
+        let mut rows_written = 0;
+        loop {
+            if running.next_row >= running.num_rows {
+                match running.blocks.next().await {
+                    None => return Ok(true),
+                    Some(block) => {
+                        // for statements without result sets, the stream is still pulled
+                        // because errors may occur in the stream
+                        let block = block?;
+                        if running.has_result_set {
+                            running.num_rows = block.num_rows();
+                            running.next_row = 0;
+                            running.columns = block
+                                .convert_to_full()
+                                .columns()
+                                .iter()
+                                .map(|column| column.value.clone().into_column().unwrap())
+                                .collect();
+                        }
+                        continue;
+                    }
+                }
+            }
+            if max_rows > 0 && rows_written >= max_rows {
+                return Ok(false);
+            }
+
+            let encoder = running.encoder.as_ref().unwrap();
+            let row_index = running.next_row;
+            self.writer.begin_data_row(running.columns.len());
+            for (column, format) in running.columns.iter().zip(&running.formats) {
+                if let ScalarRef::Null = unsafe { column.index_unchecked(row_index) } {
+                    self.writer.data_row_null();
+                } else if *format == BINARY_FORMAT {
+                    self.writer
+                        .data_row_value(|buf| encoder.write_binary(column, row_index, buf));
+                } else {
+                    self.writer
+                        .data_row_value(|buf| encoder.write_text(column, row_index, buf));
+                }
+            }
+            self.writer.end_data_row();
+            running.next_row += 1;
+            running.rows_sent += 1;
+            rows_written += 1;
+            self.writer.flush_if_full().await?;
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio::io;
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::io::BufReader;
+use common_base::base::tokio::net::TcpStream;
+use common_base::runtime::Runtime;
+use common_base::runtime::Thread;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use tokio_rustls::TlsAcceptor;
+use tracing::error;
+
+use crate::servers::postgres::postgres_codec::StartupMessage;
+use crate::servers::postgres::postgres_interactive_worker::InteractiveWorker;
+use crate::sessions::Session;
+
+/// The answer of the encryption requests sent before the startup message.
+enum Negotiation {
+    Tls,
+    Cancel,
+    Startup(HashMap<String, String>),
+}
+
+pub struct PostgresConnection;
+
+impl PostgresConnection {
+    pub fn run_on_stream(
+        session: Arc<Session>,
+        stream: TcpStream,
+        tls: Option<TlsAcceptor>,
+    ) -> Result<()> {
+        let blocking_stream = Self::convert_stream(stream)?;
+        PostgresConnection::attach_session(&session, &blocking_stream)?;
+
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("postgres-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                if let Err(error) = Self::run_worker(session, non_blocking_stream, tls).await {
+                    error!(
+                        "Unexpected error occurred in postgres connection: {:?}",
+                        error
+                    );
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    /// Upgrade the connection to TLS if the client asks for it and the handler supports it,
+    /// passwords are not accepted on plain connections to such handlers.
+    #[async_backtrace::framed]
+    async fn run_worker(
+        session: Arc<Session>,
+        mut stream: TcpStream,
+        tls: Option<TlsAcceptor>,
+    ) -> Result<()> {
+        let client_addr = stream.peer_addr().ok();
+        let (params, insecure) = match (Self::negotiate(&mut stream, tls.is_some()).await?, tls) {
+            (Negotiation::Startup(params), tls) => (params, tls.is_some()),
+            (Negotiation::Tls, Some(acceptor)) => {
+                let mut stream = acceptor.accept(stream).await?;
+                return match Self::negotiate(&mut stream, false).await? {
+                    Negotiation::Startup(params) => {
+                        let (r, w) = io::split(stream);
+                        Self::run_interactive(session, client_addr, false, params, r, w).await
+                    }
+                    _ => Ok(()),
+                };
+            }
+            _ => return Ok(()),
+        };
+
+        let (r, w) = stream.into_split();
+        Self::run_interactive(session, client_addr, insecure, params, r, w).await
+    }
+
+    async fn run_interactive<R, W>(
+        session: Arc<Session>,
+        client_addr: Option<SocketAddr>,
+        insecure: bool,
+        params: HashMap<String, String>,
+        reader: R,
+        writer: W,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        InteractiveWorker::create(
+            session,
+            client_addr,
+            insecure,
+            BufReader::new(reader),
+            writer,
+        )
+        .run(params)
+        .await
+    }
+
+    /// Answer the `SSLRequest` and `GSSENCRequest` messages until the startup message,
+    /// clients continue with a plain connection if the encryption is refused.
+    #[async_backtrace::framed]
+    async fn negotiate<S>(stream: &mut S, tls: bool) -> Result<Negotiation>
+    where S: AsyncRead + AsyncWrite + Unpin {
+        loop {
+            match StartupMessage::read(stream).await? {
+                StartupMessage::SslRequest if tls => {
+                    stream.write_all(b"S").await?;
+                    stream.flush().await?;
+                    return Ok(Negotiation::Tls);
+                }
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    stream.write_all(b"N").await?;
+                    stream.flush().await?;
+                }
+                // cancel requests are sent on new connections, which are closed without answers
+                StartupMessage::CancelRequest => return Ok(Negotiation::Cancel),
+                StartupMessage::Startup(params) => return Ok(Negotiation::Startup(params)),
+            }
+        }
+    }
+
+    fn attach_session(session: &Arc<Session>, blocking_stream: &std::net::TcpStream) -> Result<()> {
+        let host = blocking_stream.peer_addr().ok();
+        let blocking_stream_ref = blocking_stream.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
+        let stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        stream.set_nonblocking(false).map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+
+        Ok(stream)
+    }
+}
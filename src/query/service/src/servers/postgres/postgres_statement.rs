@@ -0,0 +1,191 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Settings of postgres sessions which are set by drivers, but have no effect in databend.
+const IGNORED_SETTINGS: &[&str] = &[
+    "application_name",
+    "client_encoding",
+    "client_min_messages",
+    "datestyle",
+    "extra_float_digits",
+    "intervalstyle",
+    "search_path",
+    "statement_timeout",
+];
+
+enum Mark {
+    /// `$n` outside of literals, identifiers and comments
+    Placeholder {
+        start: usize,
+        end: usize,
+        index: usize,
+    },
+    Semicolon(usize),
+}
+
+/// Find the placeholders and semicolons in a query,
+/// skipping string literals, quoted identifiers and comments.
+fn scan(query: &str) -> Vec<Mark> {
+    let bytes = query.as_bytes();
+    let mut marks = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    if quote == b'\'' && bytes[i] == b'\\' {
+                        i += 2;
+                    } else if bytes[i] == quote && bytes.get(i + 1) == Some(&quote) {
+                        i += 2;
+                    } else if bytes[i] == quote {
+                        break;
+                    } else {
+                        i += 1;
+                    }
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'$' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if let Ok(index) = query[start + 1..i].parse::<usize>() {
+                    marks.push(Mark::Placeholder {
+                        start,
+                        end: i,
+                        index,
+                    });
+                }
+            }
+            b';' => {
+                marks.push(Mark::Semicolon(i));
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    marks
+}
+
+/// The number of parameters of a query, which is the largest `$n` in it.
+pub fn parameter_count(query: &str) -> usize {
+    scan(query)
+        .iter()
+        .filter_map(|mark| match mark {
+            Mark::Placeholder { index, .. } => Some(*index),
+            Mark::Semicolon(_) => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Replace the placeholders `$1`, `$2`... with the literals of the parameters.
+pub fn bind_parameters(query: &str, literals: &[String]) -> Result<String> {
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for mark in scan(query) {
+        if let Mark::Placeholder { start, end, index } = mark {
+            let literal = index
+                .checked_sub(1)
+                .and_then(|i| literals.get(i))
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("there is no parameter ${index}"))
+                })?;
+            bound.push_str(&query[last..start]);
+            bound.push_str(literal);
+            last = end;
+        }
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
+
+/// Split the statements of a simple query, which may contain several of them.
+pub fn split_statements(query: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut last = 0;
+    for mark in scan(query) {
+        if let Mark::Semicolon(pos) = mark {
+            statements.push(&query[last..pos]);
+            last = pos + 1;
+        }
+    }
+    statements.push(&query[last..]);
+    statements
+        .into_iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Commands sent by postgres drivers which are not supported by databend,
+/// they are answered with their command tags without being executed.
+///
+/// Transactions are accepted as no-ops, each statement is committed on its own.
+pub fn ignored_command(query: &str) -> Option<&'static str> {
+    let lower = query.to_lowercase();
+    let mut words = lower
+        .split(|c: char| c.is_whitespace() || c == '=' || c == ';')
+        .filter(|w| !w.is_empty());
+    match words.next()? {
+        "begin" | "start" => Some("BEGIN"),
+        "commit" | "end" => Some("COMMIT"),
+        "rollback" | "abort" => Some("ROLLBACK"),
+        "set" => {
+            let mut name = words.next()?;
+            if name == "session" || name == "local" {
+                name = words.next()?;
+            }
+            IGNORED_SETTINGS.contains(&name).then_some("SET")
+        }
+        _ => None,
+    }
+}
+
+/// The tag of `CommandComplete` for a statement.
+pub fn command_tag(query: &str, has_result_set: bool, rows: usize, affected_rows: usize) -> String {
+    let words = query
+        .split_whitespace()
+        .take(2)
+        .map(|w| w.trim_end_matches(';').to_uppercase())
+        .collect::<Vec<_>>();
+    let keyword = words.first().cloned().unwrap_or_default();
+    match keyword.as_str() {
+        _ if has_result_set => format!("SELECT {rows}"),
+        "INSERT" => format!("INSERT 0 {affected_rows}"),
+        "UPDATE" | "DELETE" | "REPLACE" | "COPY" | "MERGE" => {
+            format!("{keyword} {affected_rows}")
+        }
+        "CREATE" | "DROP" | "ALTER" => words.join(" "),
+        _ => keyword,
+    }
+}
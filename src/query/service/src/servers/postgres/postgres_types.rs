@@ -0,0 +1,393 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping databend types to postgres types, and the text and binary formats of values,
+//! see https://www.postgresql.org/docs/current/protocol-overview.html#PROTOCOL-FORMAT-CODES
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Offset;
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::months_days_micros;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::utils::date_helper::DateConverter;
+use common_expression::Column;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+pub const BOOL_OID: u32 = 16;
+pub const NAME_OID: u32 = 19;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const BYTEA_OID: u32 = 17;
+pub const TEXT_OID: u32 = 25;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const BPCHAR_OID: u32 = 1042;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const INTERVAL_OID: u32 = 1186;
+pub const NUMERIC_OID: u32 = 1700;
+
+pub const TEXT_FORMAT: i16 = 0;
+pub const BINARY_FORMAT: i16 = 1;
+
+/// days from 1970-01-01 to 2000-01-01, the epoch of postgres dates and timestamps
+const POSTGRES_EPOCH_DAYS: i32 = 10957;
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f";
+
+/// The oid and the length of the postgres type a databend type is sent as,
+/// types without a postgres counterpart are sent as text.
+pub fn postgres_type(data_type: &DataType) -> (u32, i16) {
+    match data_type.remove_nullable() {
+        DataType::Boolean => (BOOL_OID, 1),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => (INT2_OID, 2),
+            NumberDataType::UInt16 | NumberDataType::Int32 => (INT4_OID, 4),
+            NumberDataType::UInt32 | NumberDataType::Int64 => (INT8_OID, 8),
+            NumberDataType::UInt64 => (NUMERIC_OID, -1),
+            NumberDataType::Float32 => (FLOAT4_OID, 4),
+            NumberDataType::Float64 => (FLOAT8_OID, 8),
+        },
+        DataType::Decimal(_) => (NUMERIC_OID, -1),
+        DataType::Date => (DATE_OID, 4),
+        // timestamps are sent in the timezone of the session, without the offset
+        DataType::Timestamp => (TIMESTAMP_OID, 8),
+        DataType::Interval => (INTERVAL_OID, 16),
+        DataType::Binary => (BYTEA_OID, -1),
+        _ => (TEXT_OID, -1),
+    }
+}
+
+/// Encode the values of full columns.
+pub struct ValueEncoder {
+    text: FieldEncoderValues,
+    timezone: Tz,
+}
+
+impl ValueEncoder {
+    pub fn create(timezone: Tz) -> Self {
+        ValueEncoder {
+            text: FieldEncoderValues::create_for_postgres_handler(timezone),
+            timezone,
+        }
+    }
+
+    /// Write a value which is not null, in the text format.
+    pub fn write_text(&self, column: &Column, row_index: usize, out: &mut Vec<u8>) {
+        match unsafe { column.index_unchecked(row_index) } {
+            // the hex format of `bytea`
+            ScalarRef::Binary(v) => {
+                out.extend_from_slice(b"\\x");
+                out.extend_from_slice(hex::encode(v).as_bytes());
+            }
+            _ => self.text.write_field(column, row_index, out, true),
+        }
+    }
+
+    /// Write a value which is not null, in the binary format of its postgres type.
+    pub fn write_binary(&self, column: &Column, row_index: usize, out: &mut Vec<u8>) {
+        match unsafe { column.index_unchecked(row_index) } {
+            ScalarRef::Boolean(v) => out.push(v as u8),
+            ScalarRef::Number(number) => match number {
+                NumberScalar::Int8(v) => out.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::UInt8(v) => out.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::Int16(v) => out.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt16(v) => out.extend_from_slice(&(v as i32).to_be_bytes()),
+                NumberScalar::Int32(v) => out.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt32(v) => out.extend_from_slice(&(v as i64).to_be_bytes()),
+                NumberScalar::Int64(v) => out.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt64(v) => write_numeric(&v.to_string(), out),
+                NumberScalar::Float32(v) => out.extend_from_slice(&v.0.to_be_bytes()),
+                NumberScalar::Float64(v) => out.extend_from_slice(&v.0.to_be_bytes()),
+            },
+            ScalarRef::Decimal(_) => {
+                let mut text = vec![];
+                self.write_text(column, row_index, &mut text);
+                write_numeric(&String::from_utf8_lossy(&text), out);
+            }
+            ScalarRef::Date(v) => out.extend_from_slice(&(v - POSTGRES_EPOCH_DAYS).to_be_bytes()),
+            ScalarRef::Timestamp(v) => {
+                let offset = v.to_timestamp(self.timezone).offset().fix();
+                let local = v + offset.local_minus_utc() as i64 * 1_000_000;
+                let micros = local - POSTGRES_EPOCH_DAYS as i64 * MICROS_PER_DAY;
+                out.extend_from_slice(&micros.to_be_bytes());
+            }
+            ScalarRef::Interval(v) => {
+                out.extend_from_slice(&v.microseconds().to_be_bytes());
+                out.extend_from_slice(&v.days().to_be_bytes());
+                out.extend_from_slice(&v.months().to_be_bytes());
+            }
+            ScalarRef::Binary(v) => out.extend_from_slice(v),
+            // the binary format of text is the same as the text format
+            _ => self.write_text(column, row_index, out),
+        }
+    }
+}
+
+/// Write a decimal number in the binary format of `numeric`: digits in base 10000,
+/// the weight of the first digit, the sign and the count of decimal digits after the point.
+fn write_numeric(text: &str, out: &mut Vec<u8>) {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    let int_part = int_part.trim_start_matches('0');
+
+    let int_padded = format!("{}{int_part}", "0".repeat((4 - int_part.len() % 4) % 4));
+    let frac_padded = format!("{frac_part}{}", "0".repeat((4 - frac_part.len() % 4) % 4));
+    let to_digits = |s: &str| {
+        s.as_bytes()
+            .chunks(4)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0i16, |acc, b| acc * 10 + (b.wrapping_sub(b'0') as i16))
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut digits = to_digits(&int_padded);
+    let mut weight = digits.len() as i16 - 1;
+    digits.extend(to_digits(&frac_padded));
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    out.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    out.extend_from_slice(&weight.to_be_bytes());
+    let sign: u16 = if negative && !digits.is_empty() {
+        0x4000
+    } else {
+        0
+    };
+    out.extend_from_slice(&sign.to_be_bytes());
+    out.extend_from_slice(&(frac_part.len() as i16).to_be_bytes());
+    for digit in digits {
+        out.extend_from_slice(&digit.to_be_bytes());
+    }
+}
+
+/// Read a decimal number in the binary format of `numeric` as its text.
+fn read_numeric(data: &[u8]) -> Result<String> {
+    let read_i16 = |pos: usize| -> Result<i16> {
+        data.get(pos..pos + 2)
+            .map(|b| i16::from_be_bytes([b[0], b[1]]))
+            .ok_or_else(|| ErrorCode::BadBytes("invalid binary numeric parameter"))
+    };
+    let ndigits = read_i16(0)?.max(0) as usize;
+    let weight = read_i16(2)? as i32;
+    let sign = read_i16(4)? as u16;
+    let dscale = read_i16(6)?.max(0) as usize;
+    if sign == 0xC000 {
+        return Ok("'NaN'".to_string());
+    }
+    let digits = (0..ndigits)
+        .map(|i| read_i16(8 + i * 2))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut int_part = String::new();
+    for i in 0..=weight.max(-1) {
+        let digit = digits.get(i as usize).copied().unwrap_or(0);
+        if int_part.is_empty() {
+            int_part = digit.to_string();
+        } else {
+            int_part.push_str(&format!("{digit:04}"));
+        }
+    }
+    if int_part.is_empty() {
+        int_part.push('0');
+    }
+    let mut frac_part = String::new();
+    let mut pos = weight + 1;
+    while frac_part.len() < dscale {
+        let digit = match pos {
+            p if p < 0 => 0,
+            p => digits.get(p as usize).copied().unwrap_or(0),
+        };
+        frac_part.push_str(&format!("{digit:04}"));
+        pos += 1;
+    }
+    frac_part.truncate(dscale);
+
+    let sign = if sign == 0x4000 { "-" } else { "" };
+    if frac_part.is_empty() {
+        Ok(format!("{sign}{int_part}"))
+    } else {
+        Ok(format!("{sign}{int_part}.{frac_part}"))
+    }
+}
+
+/// Quote a string as a SQL string literal.
+pub fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// Negative numbers are put in parentheses, so that `1-$1` doesn't become a comment.
+fn number_literal(s: String) -> String {
+    if s.starts_with('-') {
+        format!("({s})")
+    } else {
+        s
+    }
+}
+
+/// Bytes are passed as their hex digits, which are decoded by `from_hex`.
+fn binary_literal(hex: &str) -> String {
+    format!("FROM_HEX({})", quote_string(hex))
+}
+
+fn is_number_literal(s: &str) -> bool {
+    s.bytes().any(|b| b.is_ascii_digit())
+        && s.bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'+' | b'-' | b'.' | b'e' | b'E'))
+        && s.parse::<f64>().is_ok()
+}
+
+fn float_literal(v: f64, text: String) -> String {
+    if v.is_finite() {
+        number_literal(text)
+    } else {
+        quote_string(&text)
+    }
+}
+
+fn fixed_bytes<const N: usize>(data: &[u8], type_oid: u32) -> Result<[u8; N]> {
+    data.try_into().map_err(|_| {
+        ErrorCode::BadBytes(format!(
+            "invalid length {} of binary parameter with type oid {type_oid}",
+            data.len()
+        ))
+    })
+}
+
+/// Render a bound parameter as a SQL literal, which replaces its placeholder in the query.
+pub fn parameter_literal(value: Option<&[u8]>, type_oid: u32, format: i16) -> Result<String> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok("NULL".to_string()),
+    };
+
+    if format == BINARY_FORMAT {
+        let literal = match type_oid {
+            BOOL_OID => match fixed_bytes::<1>(value, type_oid)?[0] {
+                0 => "FALSE".to_string(),
+                _ => "TRUE".to_string(),
+            },
+            INT2_OID => {
+                number_literal(i16::from_be_bytes(fixed_bytes(value, type_oid)?).to_string())
+            }
+            INT4_OID => {
+                number_literal(i32::from_be_bytes(fixed_bytes(value, type_oid)?).to_string())
+            }
+            INT8_OID => {
+                number_literal(i64::from_be_bytes(fixed_bytes(value, type_oid)?).to_string())
+            }
+            FLOAT4_OID => {
+                let v = f32::from_be_bytes(fixed_bytes(value, type_oid)?);
+                float_literal(v as f64, v.to_string())
+            }
+            FLOAT8_OID => {
+                let v = f64::from_be_bytes(fixed_bytes(value, type_oid)?);
+                float_literal(v, v.to_string())
+            }
+            NUMERIC_OID => {
+                let text = read_numeric(value)?;
+                if text.starts_with('\'') {
+                    text
+                } else {
+                    number_literal(text)
+                }
+            }
+            DATE_OID => {
+                let days = i32::from_be_bytes(fixed_bytes(value, type_oid)?);
+                let date = NaiveDate::from_ymd_opt(2000, 1, 1)
+                    .and_then(|epoch| epoch.checked_add_signed(Duration::days(days as i64)))
+                    .ok_or_else(|| ErrorCode::BadBytes(format!("date out of range: {days}")))?;
+                quote_string(&date.to_string())
+            }
+            TIMESTAMP_OID | TIMESTAMPTZ_OID => {
+                let micros = i64::from_be_bytes(fixed_bytes(value, type_oid)?);
+                let ts = NaiveDateTime::from_timestamp_opt(POSTGRES_EPOCH_DAYS as i64 * 86400, 0)
+                    .and_then(|epoch| epoch.checked_add_signed(Duration::microseconds(micros)))
+                    .ok_or_else(|| {
+                        ErrorCode::BadBytes(format!("timestamp out of range: {micros}"))
+                    })?;
+                quote_string(&ts.format(TIMESTAMP_FORMAT).to_string())
+            }
+            INTERVAL_OID => {
+                let data = fixed_bytes::<16>(value, type_oid)?;
+                let interval = months_days_micros::new(
+                    i32::from_be_bytes(data[12..].try_into().unwrap()),
+                    i32::from_be_bytes(data[8..12].try_into().unwrap()),
+                    i64::from_be_bytes(data[..8].try_into().unwrap()),
+                );
+                format!(
+                    "CAST({} AS INTERVAL)",
+                    quote_string(&interval_to_string(interval).to_string())
+                )
+            }
+            BYTEA_OID => binary_literal(&hex::encode(value)),
+            TEXT_OID | VARCHAR_OID | BPCHAR_OID | NAME_OID | UNKNOWN_OID | 0 => {
+                quote_string(&String::from_utf8_lossy(value))
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "binary format of parameters with type oid {type_oid} is not supported"
+                )));
+            }
+        };
+        return Ok(literal);
+    }
+
+    let text = String::from_utf8_lossy(value);
+    let literal = match type_oid {
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID
+            if is_number_literal(&text) =>
+        {
+            number_literal(text.to_string())
+        }
+        BYTEA_OID => match text.strip_prefix("\\x") {
+            Some(hex) => binary_literal(hex),
+            None => format!("TO_BINARY({})", quote_string(&text)),
+        },
+        BOOL_OID => match text.to_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => "TRUE".to_string(),
+            "f" | "false" | "n" | "no" | "off" | "0" => "FALSE".to_string(),
+            _ => quote_string(&text),
+        },
+        _ => quote_string(&text),
+    };
+    Ok(literal)
+}
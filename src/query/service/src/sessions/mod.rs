@@ -22,6 +22,7 @@ mod session_mgr;
 mod session_mgr_status;
 mod session_status;
 mod session_type;
+mod workload_queue;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -35,3 +36,6 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use workload_queue::QueuedInfo;
+pub use workload_queue::WorkloadPermit;
+pub use workload_queue::WorkloadQueueManager;
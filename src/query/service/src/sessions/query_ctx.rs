@@ -22,6 +22,7 @@ use std::str::FromStr;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use chrono_tz::Tz;
@@ -36,6 +37,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -45,10 +47,12 @@ use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::InputError;
@@ -61,7 +65,11 @@ use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
+use common_storages_stream::stream_table::STREAM_ENGINE;
+use common_users::workload_group_cache_mgr::find_role_workload_group;
+use common_users::workload_group_cache_mgr::find_user_workload_group;
 use common_users::UserApiProvider;
+use common_users::WorkloadGroupCacheManager;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
 use parking_lot::RwLock;
@@ -74,9 +82,11 @@ use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueuedInfo;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadQueueManager;
 use crate::storages::Table;
 
 const MYSQL_VERSION: &str = "8.0.26";
@@ -115,6 +125,22 @@ impl QueryContext {
         })
     }
 
+    // Cap the parallelism of the query by the cpu quota of the user.
+    fn apply_cpu_quota(&self) {
+        let max_cpu = match self.shared.get_current_user() {
+            Ok(user) => user.quota.max_cpu,
+            Err(_) => return,
+        };
+        if max_cpu == 0 {
+            return;
+        }
+        if let Ok(max_threads) = self.query_settings.get_max_threads() {
+            if max_threads > max_cpu {
+                let _ = self.query_settings.set_max_threads(max_cpu);
+            }
+        }
+    }
+
     // Build fuse/system normal table by table info.
     fn build_table_by_table_info(
         &self,
@@ -219,6 +245,78 @@ impl QueryContext {
     pub fn get_created_time(&self) -> SystemTime {
         self.shared.created_time
     }
+
+    pub fn set_recursive_cte_working_table(&self, cte_id: usize, blocks: Vec<DataBlock>) {
+        self.shared.set_recursive_cte_working_table(cte_id, blocks)
+    }
+
+    pub fn get_recursive_cte_working_table(&self, cte_id: usize) -> Vec<DataBlock> {
+        self.shared.get_recursive_cte_working_table(cte_id)
+    }
+
+    pub fn remove_recursive_cte_working_table(&self, cte_id: usize) {
+        self.shared.remove_recursive_cte_working_table(cte_id)
+    }
+
+    /// Wait in the queue of the workload group of the current user until the query can run.
+    ///
+    /// Queries of users and roles not mapped to any workload group run immediately,
+    /// and the queries executed within a running query, like the ones of procedures,
+    /// share its slot. Each call is paired with a `release_workload_slot`.
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_slot(&self) -> Result<()> {
+        if !self.shared.enter_workload() {
+            return Ok(());
+        }
+
+        let session = self.get_current_session();
+        if !session.get_type().is_user_session() {
+            return Ok(());
+        }
+
+        let user = match self.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return Ok(()),
+        };
+        let groups = WorkloadGroupCacheManager::instance()
+            .get_workload_groups(&self.get_tenant())
+            .await?;
+        if groups.is_empty() {
+            return Ok(());
+        }
+
+        // A group the user is mapped to wins over the groups of its roles.
+        let mut group = find_user_workload_group(&groups, &user.identity()).cloned();
+        if group.is_none() && groups.iter().any(|group| !group.roles.is_empty()) {
+            let roles: Vec<String> = session
+                .get_all_available_roles()
+                .await?
+                .into_iter()
+                .map(|role| role.name)
+                .collect();
+            group = find_role_workload_group(&groups, &roles).cloned();
+        }
+
+        if let Some(group) = group {
+            self.shared.set_queued_info(QueuedInfo {
+                group: group.name.clone(),
+                enqueued_at: Instant::now(),
+                admitted_at: None,
+            });
+
+            let permit = WorkloadQueueManager::instance()
+                .acquire(&group, || self.shared.check_aborting())
+                .await?;
+            let max_server_memory = GlobalConfig::instance().query.max_server_memory_usage;
+            self.shared
+                .set_workload_permit(permit, group.query_memory_limit(max_server_memory));
+        }
+        Ok(())
+    }
+
+    pub fn release_workload_slot(&self) {
+        self.shared.release_workload_permit();
+    }
 }
 
 #[async_trait::async_trait]
@@ -436,6 +534,7 @@ impl TableContext for QueryContext {
             unsafe {
                 self.query_settings.unchecked_apply_changes(session_change);
             }
+            self.apply_cpu_quota();
         }
         self.query_settings.clone()
     }
@@ -540,6 +639,18 @@ impl TableContext for QueryContext {
         self.shared.consume_precommit_blocks()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
+    fn get_consumed_streams(&self) -> Vec<Arc<dyn Table>> {
+        self.shared
+            .get_tables_refs()
+            .into_iter()
+            .filter(|table| table.engine() == STREAM_ENGINE)
+            .collect()
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
@@ -555,6 +666,17 @@ impl TableContext for QueryContext {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn validate_privilege(
+        &self,
+        object: &GrantObject,
+        privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()> {
+        self.get_current_session()
+            .validate_privilege(object, privileges)
+            .await
+    }
+
     /// Fetch a Table by db and table name.
     ///
     /// It guaranteed to return a consistent result for multiple calls, in a same query.
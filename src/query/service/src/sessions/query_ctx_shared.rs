@@ -15,9 +15,11 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
@@ -43,7 +45,9 @@ use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::QueuedInfo;
 use crate::sessions::Session;
+use crate::sessions::WorkloadPermit;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -80,6 +84,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
+    /// The rows produced by the last iteration of each recursive CTE.
+    pub(in crate::sessions) recursive_cte_working_tables:
+        Arc<RwLock<HashMap<usize, Vec<DataBlock>>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
     // DashMap<file_path, HashMap<ErrorCode::code, (ErrorCode, Number of occurrences)>>
@@ -93,6 +100,13 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    /// The queue status of the query in its workload group.
+    pub(in crate::sessions) queued_info: Arc<RwLock<Option<QueuedInfo>>>,
+    /// The running slot of the workload group, held until the query finishes.
+    pub(in crate::sessions) workload_permit: Arc<Mutex<Option<WorkloadPermit>>>,
+    pub(in crate::sessions) workload_memory_limit: Arc<RwLock<Option<u64>>>,
+    /// The number of running executions of the query, nested ones share the running slot.
+    pub(in crate::sessions) workload_depth: Arc<AtomicUsize>,
 }
 
 impl QueryContextShared {
@@ -119,6 +133,7 @@ impl QueryContextShared {
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             precommit_blocks: Arc::new(RwLock::new(vec![])),
+            recursive_cte_working_tables: Arc::new(RwLock::new(HashMap::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             on_error_map: Arc::new(RwLock::new(None)),
@@ -126,6 +141,10 @@ impl QueryContextShared {
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            queued_info: Arc::new(RwLock::new(None)),
+            workload_permit: Arc::new(Mutex::new(None)),
+            workload_memory_limit: Arc::new(RwLock::new(None)),
+            workload_depth: Arc::new(AtomicUsize::new(0)),
         }))
     }
 
@@ -273,7 +292,16 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The table mutated in the active transaction is read from the transaction buffer.
+        let buffered_table_info = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = buffered_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -295,6 +323,11 @@ impl QueryContextShared {
                     2,
                     Some("query-ctx".to_string()),
                 )?);
+                // The memory of the query is tracked by the runtime, limit it by the user quota
+                // and the workload group.
+                if let Some(limit) = self.query_memory_limit() {
+                    runtime.get_tracker().set_limit(limit as i64);
+                }
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
@@ -372,6 +405,21 @@ impl QueryContextShared {
         swapped_precommit_blocks
     }
 
+    pub fn set_recursive_cte_working_table(&self, cte_id: usize, blocks: Vec<DataBlock>) {
+        let mut working_tables = self.recursive_cte_working_tables.write();
+        working_tables.insert(cte_id, blocks);
+    }
+
+    pub fn get_recursive_cte_working_table(&self, cte_id: usize) -> Vec<DataBlock> {
+        let working_tables = self.recursive_cte_working_tables.read();
+        working_tables.get(&cte_id).cloned().unwrap_or_default()
+    }
+
+    pub fn remove_recursive_cte_working_table(&self, cte_id: usize) {
+        let mut working_tables = self.recursive_cte_working_tables.write();
+        working_tables.remove(&cte_id);
+    }
+
     pub fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.stage_attachment.read().clone()
     }
@@ -389,6 +437,60 @@ impl QueryContextShared {
         let status = self.status.read();
         status.clone()
     }
+
+    pub fn get_queued_info(&self) -> Option<QueuedInfo> {
+        self.queued_info.read().clone()
+    }
+
+    pub fn set_queued_info(&self, info: QueuedInfo) {
+        *self.queued_info.write() = Some(info);
+    }
+
+    /// Keep the running slot of the workload group until the query finishes.
+    pub fn set_workload_permit(&self, permit: WorkloadPermit, memory_limit: Option<u64>) {
+        if let Some(info) = self.queued_info.write().as_mut() {
+            info.admitted_at = Some(Instant::now());
+        }
+
+        *self.workload_memory_limit.write() = memory_limit;
+        if let Some(runtime) = self.get_runtime() {
+            if let Some(limit) = self.query_memory_limit() {
+                runtime.get_tracker().set_limit(limit as i64);
+            }
+        }
+
+        *self.workload_permit.lock() = Some(permit);
+    }
+
+    /// Enter an execution of the query, returns true if it's the outermost one.
+    pub fn enter_workload(&self) -> bool {
+        self.workload_depth.fetch_add(1, Ordering::SeqCst) == 0
+    }
+
+    /// Leave an execution of the query, the running slot is released by the outermost one.
+    pub fn release_workload_permit(&self) {
+        let depth = self
+            .workload_depth
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |depth| {
+                depth.checked_sub(1)
+            });
+        if depth == Ok(1) {
+            self.workload_permit.lock().take();
+        }
+    }
+
+    fn query_memory_limit(&self) -> Option<u64> {
+        let user_limit = match self.get_current_user() {
+            Ok(user) if user.quota.max_memory_in_bytes > 0 => Some(user.quota.max_memory_in_bytes),
+            _ => None,
+        };
+        let group_limit = *self.workload_memory_limit.read();
+
+        match (user_limit, group_limit) {
+            (Some(user_limit), Some(group_limit)) => Some(user_limit.min(group_limit)),
+            (user_limit, group_limit) => user_limit.or(group_limit),
+        }
+    }
 }
 
 impl Drop for QueryContextShared {
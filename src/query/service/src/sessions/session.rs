@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -46,6 +48,7 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    txn_mgr: TxnManagerRef,
 }
 
 impl Session {
@@ -63,6 +66,7 @@ impl Session {
             session_ctx,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -84,6 +88,10 @@ impl Session {
         *lock = typ;
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     pub fn is_aborting(self: &Arc<Self>) -> bool {
         self.session_ctx.get_abort()
     }
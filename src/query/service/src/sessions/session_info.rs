@@ -31,6 +31,7 @@ impl Session {
 
     fn to_process_info(self: &Arc<Self>, session_ctx: &SessionContext) -> ProcessInfo {
         let mut memory_usage = 0;
+        let mut queued_info = None;
 
         let shared_query_context = &session_ctx.get_query_context_shared();
         if let Some(shared) = shared_query_context {
@@ -38,6 +39,7 @@ impl Session {
                 let mem_stat = runtime.get_tracker();
                 memory_usage = mem_stat.get_memory_usage();
             }
+            queued_info = shared.get_queued_info();
         }
 
         ProcessInfo {
@@ -57,6 +59,8 @@ impl Session {
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
+            workload_group: queued_info.as_ref().map(|info| info.group.clone()),
+            queued_time: queued_info.as_ref().map(|info| info.queued_time()),
         }
     }
 
@@ -64,7 +68,10 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => String::from("Aborting"),
             None => String::from("Idle"),
-            Some(_) => String::from("Query"),
+            Some(shared) => match shared.get_queued_info() {
+                Some(info) if info.is_queued() => String::from("Queued"),
+                _ => String::from("Query"),
+            },
         }
     }
 
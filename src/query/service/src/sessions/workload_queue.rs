@@ -0,0 +1,244 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::oneshot;
+use common_base::base::GlobalInstance;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use parking_lot::Mutex;
+
+// How often a queued query checks whether it has been killed.
+const ABORT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Admission control of the queries of workload groups on this node.
+///
+/// A query is admitted when both the node (`max_running_queries`) and its group
+/// (`max_concurrency`) have a free slot. Otherwise it waits in the queue, where the
+/// queries of the groups with higher priority are admitted first, FIFO within a priority.
+pub struct WorkloadQueueManager {
+    inner: Arc<QueueInner>,
+}
+
+struct QueueInner {
+    max_running_queries: u64,
+    state: Mutex<QueueState>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    next_seq: u64,
+    running: u64,
+    running_by_group: HashMap<String, u64>,
+    waiting: Vec<WaitingQuery>,
+}
+
+struct WaitingQuery {
+    seq: u64,
+    group: String,
+    max_concurrency: u64,
+    priority: u64,
+    sender: oneshot::Sender<WorkloadPermit>,
+}
+
+/// A running slot of a workload group, released when dropped.
+pub struct WorkloadPermit {
+    inner: Arc<QueueInner>,
+    group: String,
+}
+
+/// The queue status of a query, shown in `system.processes`.
+#[derive(Clone, Debug)]
+pub struct QueuedInfo {
+    pub group: String,
+    pub enqueued_at: Instant,
+    pub admitted_at: Option<Instant>,
+}
+
+impl QueuedInfo {
+    pub fn is_queued(&self) -> bool {
+        self.admitted_at.is_none()
+    }
+
+    pub fn queued_time(&self) -> Duration {
+        let end = self.admitted_at.unwrap_or_else(Instant::now);
+        end.saturating_duration_since(self.enqueued_at)
+    }
+}
+
+impl WorkloadQueueManager {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Self::create(conf.query.max_running_queries));
+        Ok(())
+    }
+
+    pub fn create(max_running_queries: u64) -> Arc<WorkloadQueueManager> {
+        Arc::new(WorkloadQueueManager {
+            inner: Arc::new(QueueInner {
+                max_running_queries,
+                state: Mutex::new(QueueState::default()),
+            }),
+        })
+    }
+
+    pub fn instance() -> Arc<WorkloadQueueManager> {
+        GlobalInstance::get()
+    }
+
+    /// Wait until the query can run in the group.
+    ///
+    /// Fails if the query waits longer than the queue timeout of the group,
+    /// or if `check_aborting` fails while it waits.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        &self,
+        group: &WorkloadGroup,
+        check_aborting: impl Fn() -> Result<()>,
+    ) -> Result<WorkloadPermit> {
+        let (sender, mut receiver) = oneshot::channel();
+        let seq = {
+            let mut state = self.inner.state.lock();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiting.push(WaitingQuery {
+                seq,
+                group: group.name.clone(),
+                max_concurrency: group.max_concurrency,
+                priority: group.priority,
+                sender,
+            });
+            seq
+        };
+        QueueInner::dispatch(&self.inner);
+
+        let deadline = group
+            .queue_timeout()
+            .map(|timeout| Instant::now() + timeout);
+        loop {
+            let wait = match deadline {
+                Some(deadline) => deadline
+                    .saturating_duration_since(Instant::now())
+                    .min(ABORT_CHECK_INTERVAL),
+                None => ABORT_CHECK_INTERVAL,
+            };
+
+            match tokio::time::timeout(wait, &mut receiver).await {
+                Ok(Ok(permit)) => return Ok(permit),
+                Ok(Err(_)) => {
+                    return Err(ErrorCode::Internal(
+                        "Workload queue dropped a waiting query",
+                    ));
+                }
+                Err(_) => {
+                    if let Err(cause) = check_aborting() {
+                        self.cancel(seq);
+                        return Err(cause);
+                    }
+                    if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+                        self.cancel(seq);
+                        return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                            "Query waited more than {} seconds in the queue of workload group {}",
+                            group.queue_timeout_secs, group.name
+                        )));
+                    }
+                }
+            }
+        }
+    }
+
+    // Remove a query from the queue. If it was admitted meanwhile, the permit
+    // is released when the receiver is dropped.
+    fn cancel(&self, seq: u64) {
+        let mut state = self.inner.state.lock();
+        state.waiting.retain(|query| query.seq != seq);
+    }
+}
+
+impl QueueInner {
+    fn dispatch(inner: &Arc<QueueInner>) {
+        let admitted = {
+            let mut state = inner.state.lock();
+            state
+                .waiting
+                .sort_by(|a, b| b.priority.cmp(&a.priority).then(a.seq.cmp(&b.seq)));
+
+            let mut admitted = vec![];
+            let mut waiting = Vec::with_capacity(state.waiting.len());
+            for query in std::mem::take(&mut state.waiting) {
+                let node_full =
+                    inner.max_running_queries != 0 && state.running >= inner.max_running_queries;
+                let group_running = state
+                    .running_by_group
+                    .get(&query.group)
+                    .copied()
+                    .unwrap_or(0);
+                let group_full =
+                    query.max_concurrency != 0 && group_running >= query.max_concurrency;
+
+                if node_full || group_full {
+                    waiting.push(query);
+                    continue;
+                }
+
+                state.running += 1;
+                *state
+                    .running_by_group
+                    .entry(query.group.clone())
+                    .or_insert(0) += 1;
+                admitted.push(query);
+            }
+            state.waiting = waiting;
+            admitted
+        };
+
+        // Send outside the lock: a permit that can't be delivered is dropped here,
+        // which releases the slot and dispatches again.
+        for query in admitted {
+            let permit = WorkloadPermit {
+                inner: inner.clone(),
+                group: query.group,
+            };
+            let _ = query.sender.send(permit);
+        }
+    }
+}
+
+impl WorkloadPermit {
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+}
+
+impl Drop for WorkloadPermit {
+    fn drop(&mut self) {
+        {
+            let mut state = self.inner.state.lock();
+            state.running -= 1;
+            if let Some(running) = state.running_by_group.get_mut(&self.group) {
+                *running -= 1;
+                if *running == 0 {
+                    state.running_by_group.remove(&self.group);
+                }
+            }
+        }
+        QueueInner::dispatch(&self.inner);
+    }
+}
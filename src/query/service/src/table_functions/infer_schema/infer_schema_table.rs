@@ -34,13 +34,16 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::StageFileFormatType;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::input_formats::InputContext;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::parse_stage_location;
+use common_sql::binder::validate_stage_privilege;
 use common_storage::init_stage_operator;
 use common_storage::read_parquet_schema_async;
 use common_storage::StageFilesInfo;
@@ -178,6 +181,7 @@ impl AsyncSource for InferSchemaSource {
 
         let (stage_info, path) =
             parse_stage_location(&self.ctx, &self.args_parsed.location).await?;
+        validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Read).await?;
         let files_info = StageFilesInfo {
             path,
             ..self.args_parsed.files_info.clone()
@@ -194,9 +198,16 @@ impl AsyncSource for InferSchemaSource {
                 let arrow_schema = read_parquet_schema_async(&operator, &first_file.path).await?;
                 TableSchema::from(&arrow_schema)
             }
+            StageFileFormatType::Avro | StageFileFormatType::Orc => {
+                let input_format = InputContext::get_input_format(&file_format_params)?;
+                let schema = input_format
+                    .infer_schema(&first_file.path, &operator)
+                    .await?;
+                schema.as_ref().clone()
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro and Orc",
                 ));
             }
         };
@@ -34,6 +34,7 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -43,6 +44,7 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::parse_stage_location;
+use common_sql::binder::validate_stage_privilege;
 use common_storage::StageFilesInfo;
 use common_storages_stage::StageTable;
 
@@ -183,6 +185,7 @@ impl AsyncSource for ListStagesSource {
 
         let (stage_info, path) =
             parse_stage_location(&self.ctx, &self.args_parsed.location).await?;
+        validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Read).await?;
         let op = StageTable::get_op(&stage_info)?;
 
         let files_info = StageFilesInfo {
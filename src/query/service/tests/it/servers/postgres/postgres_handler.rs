@@ -0,0 +1,257 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::AuthType;
+use common_meta_app::principal::UserInfo;
+use common_users::UserApiProvider;
+use databend_query::servers::PostgresHandler;
+use databend_query::servers::Server;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+use tokio_postgres::config::SslMode;
+use tokio_postgres::types::Type;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::rustls::ServerName;
+use tokio_rustls::TlsConnector;
+
+use crate::tests::tls_constants::TEST_CA_CERT;
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let client = create_connection(listening.port()).await?;
+
+    let messages = client
+        .simple_query("SELECT 1, 'a', NULL; SELECT true")
+        .await
+        .map_err(to_error_code)?;
+
+    let rows = messages
+        .iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some(row),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].get(0), Some("1"));
+    assert_eq!(rows[0].get(1), Some("a"));
+    assert_eq!(rows[0].get(2), None);
+    assert_eq!(rows[1].get(0), Some("t"));
+
+    let error = client
+        .simple_query("SELECT * FROM not_exists_table")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code().map(|c| c.code()), Some("42P01"));
+
+    // The session is still usable after an error.
+    client
+        .simple_query("SELECT 1")
+        .await
+        .map_err(to_error_code)?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let client = create_connection(listening.port()).await?;
+
+    let statement = client
+        .prepare_typed("SELECT CAST($1 AS BIGINT) + 1, $2", &[
+            Type::INT4,
+            Type::TEXT,
+        ])
+        .await
+        .map_err(to_error_code)?;
+    assert_eq!(statement.params(), &[Type::INT4, Type::TEXT]);
+    assert_eq!(statement.columns()[0].type_(), &Type::INT8);
+
+    // tokio-postgres sends the parameters and receives the results in binary format.
+    for i in [-1i32, 41] {
+        let rows = client
+            .query(&statement, &[&i, &"it's"])
+            .await
+            .map_err(to_error_code)?;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<_, i64>(0), i as i64 + 1);
+        assert_eq!(rows[0].get::<_, String>(1), "it's");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_rejected_session() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(ConfigBuilder::create().max_active_sessions(1).build()).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    {
+        // Accepted connection
+        let client = create_connection(listening.port()).await?;
+
+        // Rejected connection
+        match create_connection(listening.port()).await {
+            Ok(_) => panic!("Expected rejected connection"),
+            Err(error) => {
+                assert_eq!(
+                    error.message(),
+                    "Current active sessions (1) has exceeded the max_active_sessions limit (1)"
+                );
+            }
+        };
+
+        drop(client);
+    }
+
+    // Wait for the connection to be destroyed
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    // Accepted connection
+    create_connection(listening.port()).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_tls_connection() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .postgres_handler_tls_server_key(TEST_SERVER_KEY)
+        .postgres_handler_tls_server_cert(TEST_SERVER_CERT)
+        .build();
+    let tenant = config.query.tenant_id.clone();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let auth_info = AuthInfo::new(AuthType::Sha256Password, &Some("pg_pwd".to_string()))?;
+    UserApiProvider::instance()
+        .add_user(&tenant, UserInfo::new("pg_user", "%", auth_info), false)
+        .await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // Passwords are not sent on plain connections to handlers supporting TLS.
+    let config = format!(
+        "host=127.0.0.1 port={} user=pg_user password=pg_pwd dbname=default sslmode=disable",
+        listening.port()
+    );
+    let error = tokio_postgres::connect(&config, NoTls).await.unwrap_err();
+    assert_eq!(
+        to_error_code(error).message(),
+        "password authentication failed for user \"pg_user\""
+    );
+
+    let stream = create_tls_stream(listening.port()).await?;
+    let (client, connection) = tokio_postgres::Config::new()
+        .user("pg_user")
+        .password("pg_pwd")
+        .dbname("default")
+        .ssl_mode(SslMode::Disable)
+        .connect_raw(stream, NoTls)
+        .await
+        .map_err(to_error_code)?;
+    tokio::spawn(connection);
+
+    let messages = client
+        .simple_query("SELECT 1")
+        .await
+        .map_err(to_error_code)?;
+    assert!(matches!(&messages[0], SimpleQueryMessage::Row(row) if row.get(0) == Some("1")));
+
+    Ok(())
+}
+
+/// Send `SSLRequest` and upgrade the connection like libpq does with `sslmode=require`.
+async fn create_tls_stream(port: u16) -> Result<tokio_rustls::client::TlsStream<TcpStream>> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+    stream.write_all(&[0, 0, 0, 8, 4, 210, 22, 47]).await?;
+    let mut answer = [0u8; 1];
+    stream.read_exact(&mut answer).await?;
+    assert_eq!(answer[0], b'S');
+
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut BufReader::new(File::open(TEST_CA_CERT)?))? {
+        roots
+            .add(&Certificate(cert))
+            .map_err(|e| ErrorCode::TLSConfigurationFailure(e.to_string()))?;
+    }
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    let server_name = ServerName::try_from("localhost")
+        .map_err(|e| ErrorCode::TLSConfigurationFailure(e.to_string()))?;
+    let stream = TlsConnector::from(Arc::new(config))
+        .connect(server_name, stream)
+        .await?;
+    Ok(stream)
+}
+
+async fn create_connection(port: u16) -> Result<Client> {
+    let config = format!("host=127.0.0.1 port={port} user=root dbname=default");
+    let (client, connection) = tokio_postgres::connect(&config, NoTls)
+        .await
+        .map_err(to_error_code)?;
+    tokio::spawn(connection);
+    Ok(client)
+}
+
+fn to_error_code(error: tokio_postgres::Error) -> ErrorCode {
+    match error.as_db_error() {
+        Some(db_error) => ErrorCode::UnknownException(db_error.message().to_string()),
+        None => ErrorCode::UnknownException(error.to_string()),
+    }
+}
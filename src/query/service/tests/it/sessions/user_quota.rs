@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::runtime::MemStat;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
+use common_meta_app::storage::StorageFsConfig;
+use common_meta_app::storage::StorageParams;
+use databend_query::interpreters::StorageUsageCache;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGuard;
+use tempfile::TempDir;
+
+async fn create_query_context_with_quota(
+    tmp_dir: &TempDir,
+    quota: UserQuota,
+) -> Result<(TestGuard, Arc<QueryContext>)> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.storage.params = StorageParams::Fs(StorageFsConfig {
+        root: tmp_dir.path().to_str().unwrap().to_string(),
+    });
+
+    let mut user = UserInfo::new("quota_user", "%", AuthInfo::None);
+    user.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    user.quota = quota;
+
+    databend_query::test_kits::create_query_context_with_config(conf, Some(user)).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_user_storage_quota() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let (_guard, ctx) = create_query_context_with_quota(&tmp_dir, UserQuota {
+        max_storage_in_bytes: 1,
+        ..UserQuota::no_limit()
+    })
+    .await?;
+
+    execute_command(ctx.clone(), "create table default.t(a int)").await?;
+    // The user owns no data yet.
+    execute_command(ctx.clone(), "insert into default.t values(1)").await?;
+
+    // The cached usage is refreshed after it expires.
+    let user = ctx.get_current_user()?;
+    StorageUsageCache::instance().invalidate_cache(&ctx.get_tenant(), &user.identity());
+    let res = execute_command(ctx.clone(), "insert into default.t values(2)").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::USER_QUOTA_EXCEEDED);
+
+    // The data of the tables of another user with the same name is not counted.
+    let other = UserInfo::new("quota_user", "127.0.0.1", AuthInfo::None).identity();
+    let used = StorageUsageCache::instance()
+        .get_storage_usage(ctx.as_ref(), &ctx.get_tenant(), &other)
+        .await?;
+    assert_eq!(used, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_user_memory_quota() -> Result<()> {
+    let max_memory = 512 * 1024 * 1024;
+    let tmp_dir = TempDir::new().unwrap();
+    let (_guard, ctx) = create_query_context_with_quota(&tmp_dir, UserQuota {
+        max_memory_in_bytes: max_memory,
+        ..UserQuota::no_limit()
+    })
+    .await?;
+
+    // The memory of the query is tracked by the runtime of the query.
+    let mem_stat = ctx
+        .try_spawn(async { MemStat::current() })?
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(MemStat::record_memory::<true>(&Some(mem_stat.clone()), 1024).is_ok());
+    assert!(MemStat::record_memory::<true>(&Some(mem_stat), max_memory as i64).is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_user_cpu_quota() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let (_guard, ctx) = create_query_context_with_quota(&tmp_dir, UserQuota {
+        max_cpu: 2,
+        ..UserQuota::no_limit()
+    })
+    .await?;
+
+    execute_command(ctx.clone(), "set max_threads = 16").await?;
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    assert_eq!(ctx.get_settings().get_max_threads()?, 2);
+
+    execute_command(ctx.clone(), "set max_threads = 1").await?;
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    assert_eq!(ctx.get_settings().get_max_threads()?, 1);
+
+    Ok(())
+}
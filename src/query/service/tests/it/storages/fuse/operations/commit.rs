@@ -30,6 +30,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
@@ -37,9 +38,11 @@ use common_expression::DataBlock;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
 use common_meta_app::schema::CreateDatabaseReply;
@@ -593,10 +596,26 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    fn get_consumed_streams(&self) -> Vec<Arc<dyn Table>> {
+        self.ctx.get_consumed_streams()
+    }
+
     async fn get_file_format(&self, _name: &str) -> Result<FileFormatParams> {
         todo!()
     }
 
+    async fn validate_privilege(
+        &self,
+        _object: &GrantObject,
+        _privileges: Vec<UserPrivilegeType>,
+    ) -> Result<()> {
+        todo!()
+    }
+
     async fn get_table(
         &self,
         _catalog: &str,
@@ -124,6 +124,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("max_recursive_cte_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations of the recursive term of a recursive CTE.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
@@ -148,6 +154,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_vector_index", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables vector indexes to answer approximate nearest neighbor searches.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
@@ -267,7 +279,19 @@ impl DefaultSettings {
                 }),
                 ("spilling_bytes_threshold_per_proc", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
-                    desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
+                    desc: "Sets the maximum amount of memory in bytes that an aggregator or a sort can use before spilling data to storage during query execution.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("join_spilling_bytes_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that a hash join can use before spilling partitions to storage. Setting it to 0 disables the grace hash join.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("join_spilling_partition_bits", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "Sets the number of bits used to partition both sides of a grace hash join, which has 2^bits partitions.",
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
@@ -195,6 +195,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_recursive_cte_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_recursive_cte_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -227,6 +231,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
 
+    pub fn get_enable_vector_index(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_vector_index")? != 0)
+    }
+
     pub fn set_enable_dphyp(&self, val: bool) -> Result<()> {
         self.try_set_u64("enable_dphyp", u64::from(val))
     }
@@ -318,6 +326,18 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_join_spilling_bytes_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_bytes_threshold")? as usize)
+    }
+
+    pub fn set_join_spilling_bytes_threshold(&self, value: usize) -> Result<()> {
+        self.try_set_u64("join_spilling_bytes_threshold", value as u64)
+    }
+
+    pub fn get_join_spilling_partition_bits(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_partition_bits")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
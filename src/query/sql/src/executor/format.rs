@@ -42,6 +42,8 @@ use crate::executor::ExchangeSink;
 use crate::executor::ExchangeSource;
 use crate::executor::FragmentKind;
 use crate::executor::IEJoin;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Window;
 use crate::planner::MetadataRef;
@@ -148,6 +150,12 @@ fn to_format_tree(
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
         PhysicalPlan::IEJoin(plan) => ie_join_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveCte(plan) => {
+            recursive_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => {
+            recursive_cte_scan_to_format_tree(plan, prof_span_set)
+        }
     }
 }
 
@@ -848,6 +856,60 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("distinct: {}", plan.distinct))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    prof_span_set: &ProfSpanSetRef,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    if let Some(prof_span) = prof_span_set.lock().unwrap().get(&plan.plan_id) {
+        let process_time = prof_span.process_time / 1000 / 1000; // milliseconds
+        children.push(FormatTreeNode::new(format!(
+            "total process time: {process_time}ms"
+        )));
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -639,6 +639,49 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_id: IndexType,
+    pub distinct: bool,
+    /// The anchor term, evaluated once.
+    pub anchor: Box<PhysicalPlan>,
+    /// The recursive term, evaluated until it produces no new rows.
+    pub recursive: Box<PhysicalPlan>,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_id: IndexType,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -699,6 +742,8 @@ pub enum PhysicalPlan {
     Exchange(Exchange),
     UnionAll(UnionAll),
     RuntimeFilterSource(RuntimeFilterSource),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -739,6 +784,8 @@ impl PhysicalPlan {
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
             PhysicalPlan::IEJoin(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
         }
     }
 
@@ -764,6 +811,8 @@ impl PhysicalPlan {
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
             PhysicalPlan::IEJoin(_) => "IEJoin".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         }
     }
 
@@ -800,6 +849,11 @@ impl PhysicalPlan {
             PhysicalPlan::IEJoin(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
         }
     }
 
@@ -823,6 +877,8 @@ impl PhysicalPlan {
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::IEJoin(_)
+            | PhysicalPlan::RecursiveCte(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
             | PhysicalPlan::AggregatePartial(_) => None,
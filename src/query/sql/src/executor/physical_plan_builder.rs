@@ -23,6 +23,7 @@ use common_catalog::plan::AggIndexInfo;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VectorSearchInfo;
 use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -55,7 +56,10 @@ use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::Limit;
 use super::NthValueFunctionDesc;
+use super::Project;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
@@ -988,6 +992,55 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::RecursiveCte(op) => {
+                let columns = op
+                    .columns
+                    .iter()
+                    .map(|index| (*index, self.metadata.read().column(*index).data_type()))
+                    .collect::<Vec<_>>();
+                let anchor = self.build(s_expr.child(0)?).await?;
+                let anchor = self.build_recursive_cte_term(
+                    anchor,
+                    &op.anchor_columns,
+                    &columns,
+                    stat_info.clone(),
+                )?;
+                let recursive = self.build(s_expr.child(1)?).await?;
+                let recursive = self.build_recursive_cte_term(
+                    recursive,
+                    &op.recursive_columns,
+                    &columns,
+                    stat_info.clone(),
+                )?;
+                let schema = anchor.output_schema()?;
+                Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+                    plan_id: self.next_plan_id(),
+                    cte_id: op.cte_id,
+                    distinct: op.distinct,
+                    anchor: Box::new(anchor),
+                    recursive: Box::new(recursive),
+                    schema,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(op) => {
+                let fields = op
+                    .columns
+                    .iter()
+                    .map(|index| {
+                        let data_type = self.metadata.read().column(*index).data_type();
+                        DataField::new(&index.to_string(), data_type)
+                    })
+                    .collect::<Vec<_>>();
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_id: op.cte_id,
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -1226,6 +1279,60 @@ impl PhysicalPlanBuilder {
         }))
     }
 
+    /// Casts the output columns of a term of recursive CTE to the types of the CTE's
+    /// columns, and lays them out in the order of the CTE's columns, so that the rows
+    /// produced by different terms and iterations can be mixed up.
+    fn build_recursive_cte_term(
+        &mut self,
+        input: PhysicalPlan,
+        input_columns: &[IndexType],
+        columns: &[(IndexType, DataType)],
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let input_schema = input.output_schema()?;
+        let items = input_columns
+            .iter()
+            .zip(columns.iter())
+            .map(|(input_index, (index, data_type))| {
+                let field = input_schema.field_with_name(&input_index.to_string())?;
+                let column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        table_index: None,
+                        column_name: field.name().clone(),
+                        index: *input_index,
+                        data_type: Box::new(field.data_type().clone()),
+                        visibility: Visibility::Visible,
+                    },
+                });
+                Ok(ScalarItem {
+                    scalar: wrap_cast(&column, data_type),
+                    index: *index,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let eval_scalar = self.build_eval_scalar(
+            input,
+            &crate::plans::EvalScalar { items },
+            stat_info.clone(),
+        )?;
+
+        let eval_scalar_schema = eval_scalar.output_schema()?;
+        let projections = columns
+            .iter()
+            .map(|(index, _)| eval_scalar_schema.index_of(&index.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PhysicalPlan::Project(Project {
+            plan_id: self.next_plan_id(),
+            input: Box::new(eval_scalar),
+            projections,
+            columns: columns.iter().map(|(index, _)| *index).collect(),
+            stat_info: Some(stat_info),
+        }))
+    }
+
     fn build_virtual_columns(&self, columns: &ColumnSet) -> Option<Vec<VirtualColumnInfo>> {
         let mut virtual_column_infos = Vec::new();
         for index in columns.iter() {
@@ -1477,6 +1584,21 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        let vector_search =
+            scan.vector_search
+                .as_ref()
+                .and_then(|search| match metadata.column(search.column) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => {
+                        Some(VectorSearchInfo {
+                            column_name: column_name.clone(),
+                            metric: search.metric,
+                            query: search.query.clone(),
+                            limit: search.limit,
+                        })
+                    }
+                    _ => None,
+                });
+
         Ok(PushDownInfo {
             projection: Some(projection),
             output_columns,
@@ -1488,6 +1610,7 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index,
+            vector_search,
         })
     }
 
@@ -34,6 +34,8 @@ use crate::executor::IEJoin;
 use crate::executor::Limit;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
@@ -77,6 +79,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::IEJoin(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(plan) => write!(f, "{}", plan)?,
         }
 
         for node in self.node.children() {
@@ -340,6 +344,18 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCte")
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan")
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
@@ -32,6 +32,8 @@ use super::RowFetch;
 use super::Sort;
 use super::TableScan;
 use crate::executor::IEJoin;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -59,6 +61,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
             PhysicalPlan::IEJoin(plan) => self.replace_ie_join(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
         }
     }
 
@@ -266,6 +270,24 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: plan.plan_id,
+            cte_id: plan.cte_id,
+            distinct: plan.distinct,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -378,6 +400,11 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
             }
             post_visit(plan);
         }
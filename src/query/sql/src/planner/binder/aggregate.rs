@@ -43,6 +43,7 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -129,6 +130,23 @@ impl<'a> AggregateRewriter<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
 
             // TODO(leiysky): should we recursively process subquery here?
             ScalarExpr::SubqueryExpr(_) => Ok(scalar.clone()),
@@ -50,6 +50,7 @@ pub enum ExprContext {
     WhereClause,
     GroupClaue,
     HavingClause,
+    QualifyClause,
     OrderByClause,
     LimitClause,
 
@@ -188,6 +189,17 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// If the CTE is defined with `WITH RECURSIVE`, it can refer to itself.
+    pub recursive: bool,
+    /// Set while binding the recursive term of the CTE, references to the CTE
+    /// are bound as scans of the working table.
+    pub working_table: Option<RecursiveCteWorkingTable>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecursiveCteWorkingTable {
+    pub cte_id: IndexType,
+    pub columns: Vec<(String, DataType)>,
 }
 
 impl BindContext {
@@ -258,6 +258,16 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
@@ -345,6 +355,14 @@ impl<'a> Binder {
                 }
                 self.bind_update(bind_context, stmt).await?
             },
+            Statement::MergeInto(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!("In MERGE INTO resolve optimize hints {:?} failed, err: {:?}", hints, e);
+                    }
+                }
+                self.bind_merge_into(bind_context, stmt).await?
+            },
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -430,6 +448,10 @@ impl<'a> Binder {
                 args: stmt.args.clone(),
             })),
 
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
+
             Statement::Presign(stmt) => self.bind_presign(bind_context, stmt).await?,
 
             Statement::SetVariable {
@@ -504,6 +526,27 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
+
+            // Workload groups
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => self.bind_rewrite_to_query(bind_context, "SELECT name, max_concurrency, memory_share, priority, queue_timeout, users, roles FROM system.workload_groups ORDER BY name", RewriteKind::ShowWorkloadGroups).await?,
         };
         Ok(plan)
     }
@@ -44,8 +44,11 @@ use common_expression::DataSchemaRef;
 use common_expression::Scalar;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::principal::StageType;
+use common_meta_app::principal::UserPrivilegeType;
 use common_storage::StageFilesInfo;
 use common_users::UserApiProvider;
 use parking_lot::RwLock;
@@ -84,6 +87,7 @@ impl<'a> Binder {
 
                 let (mut stage_info, path) =
                     parse_stage_location_v2(&self.ctx, &location.name, &location.path).await?;
+                validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Read).await?;
                 self.apply_stage_options(stmt, &mut stage_info).await?;
                 let files_info = StageFilesInfo {
                     path,
@@ -397,6 +401,7 @@ impl<'a> Binder {
         attachment: StageAttachment,
     ) -> Result<(StageInfo, StageFilesInfo)> {
         let (mut stage_info, path) = parse_stage_location(&self.ctx, &attachment.location).await?;
+        validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Read).await?;
 
         if let Some(ref options) = attachment.file_format_options {
             stage_info.file_format_params = FileFormatOptionsAst {
@@ -501,6 +506,7 @@ impl<'a> Binder {
 
         let (mut stage_info, path) =
             parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
+        validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Write).await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
@@ -582,6 +588,7 @@ impl<'a> Binder {
 
         let (mut stage_info, path) =
             parse_stage_location_v2(&self.ctx, dst_stage, dst_path).await?;
+        validate_stage_privilege(&self.ctx, &stage_info, UserPrivilegeType::Write).await?;
         self.apply_stage_options(stmt, &mut stage_info).await?;
 
         Ok(Plan::Copy(Box::new(CopyPlan::IntoStage {
@@ -848,6 +855,30 @@ pub async fn parse_stage_location(
     Ok((stage, path.to_string()))
 }
 
+/// Check the current user is granted the privilege on a named stage.
+///
+/// The user stage `@~` belongs to the current user, and a named stage to the user who
+/// created it, the owner of a stage needs no grant.
+#[async_backtrace::framed]
+pub async fn validate_stage_privilege(
+    ctx: &Arc<dyn TableContext>,
+    stage_info: &StageInfo,
+    privilege: UserPrivilegeType,
+) -> Result<()> {
+    if stage_info.stage_type == StageType::User {
+        return Ok(());
+    }
+    if let Some(creator) = &stage_info.creator {
+        if creator == &ctx.get_current_user()?.identity() {
+            return Ok(());
+        }
+    }
+    ctx.validate_privilege(&GrantObject::Stage(stage_info.stage_name.clone()), vec![
+        privilege,
+    ])
+    .await
+}
+
 /// parse_stage_location_v2 work similar to parse_stage_location.
 ///
 /// Difference is input location has already been parsed by parser.
@@ -883,7 +914,10 @@ pub async fn parse_file_location(
 ) -> Result<(StageInfo, String)> {
     match location.clone() {
         FileLocation::Stage(location) => {
-            parse_stage_location_v2(ctx, &location.name, &location.path).await
+            let (stage_info, path) =
+                parse_stage_location_v2(ctx, &location.name, &location.path).await?;
+            validate_stage_privilege(ctx, &stage_info, UserPrivilegeType::Read).await?;
+            Ok((stage_info, path))
         }
         FileLocation::Uri(uri) => {
             let mut location = UriLocation::from_uri(uri, "".to_string(), connection)?;
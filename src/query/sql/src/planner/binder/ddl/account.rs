@@ -17,14 +17,18 @@ use common_ast::ast::AccountMgrSource;
 use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
+use common_ast::ast::Identifier;
 use common_ast::ast::RevokeStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_users::UserApiProvider;
 
+use crate::normalize_identifier;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::GrantPrivilegePlan;
@@ -57,7 +61,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -68,9 +72,27 @@ impl Binder {
                 for x in privileges {
                     priv_types.set_privilege(*x);
                 }
+                Self::check_privileges_on_grant_object(&grant_object, &priv_types)?;
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let on = self.convert_to_column_grant_objects(level, columns)?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(*x);
+                }
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -100,7 +122,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -111,9 +133,27 @@ impl Binder {
                 for x in privileges {
                     priv_types.set_privilege(*x);
                 }
+                Self::check_privileges_on_grant_object(&grant_object, &priv_types)?;
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let on = self.convert_to_column_grant_objects(level, columns)?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(*x);
+                }
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on,
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -141,6 +181,51 @@ impl Binder {
                     .unwrap_or_else(|| self.ctx.get_current_database());
                 GrantObject::Database(catalog_name, database_name)
             }
+            AccountMgrLevel::Stage(stage_name) => GrantObject::Stage(stage_name.clone()),
+            AccountMgrLevel::UDF(udf_name) => GrantObject::UDF(udf_name.clone()),
+        }
+    }
+
+    /// Stages only accept READ and WRITE, and UDFs only accept USAGE.
+    fn check_privileges_on_grant_object(
+        grant_object: &GrantObject,
+        priv_types: &UserPrivilegeSet,
+    ) -> Result<()> {
+        if !matches!(grant_object, GrantObject::Stage(_) | GrantObject::UDF(_)) {
+            return Ok(());
+        }
+
+        let available = grant_object.available_privileges();
+        if let Some(privilege) = priv_types.iter().find(|p| !available.has_privilege(*p)) {
+            return Err(ErrorCode::IllegalGrant(format!(
+                "Illegal GRANT/REVOKE command; privilege {} can not be granted on {}",
+                privilege, grant_object
+            )));
+        }
+        Ok(())
+    }
+
+    /// Column privileges are granted on each of the columns of a table.
+    fn convert_to_column_grant_objects(
+        &self,
+        source: &AccountMgrLevel,
+        columns: &[Identifier],
+    ) -> Result<Vec<GrantObject>> {
+        match self.convert_to_grant_object(source) {
+            GrantObject::Table(catalog_name, database_name, table_name) => Ok(columns
+                .iter()
+                .map(|column| {
+                    GrantObject::Column(
+                        catalog_name.clone(),
+                        database_name.clone(),
+                        table_name.clone(),
+                        normalize_identifier(column, &self.name_resolution_ctx).name,
+                    )
+                })
+                .collect()),
+            _ => Err(ErrorCode::IllegalGrant(
+                "Illegal GRANT/REVOKE command; column privileges can only be granted on a table",
+            )),
         }
     }
 
@@ -156,13 +241,16 @@ impl Binder {
             user_options,
         } = stmt;
         let mut user_option = UserOption::default();
+        let mut quota = UserQuota::no_limit();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut quota);
         }
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
             user_option,
+            quota,
             if_not_exists: *if_not_exists,
         };
         Ok(Plan::CreateUser(Box::new(plan)))
@@ -202,18 +290,26 @@ impl Binder {
         };
 
         let mut user_option = user_info.option.clone();
+        let mut quota = user_info.quota.clone();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut quota);
         }
         let new_user_option = if user_option == user_info.option {
             None
         } else {
             Some(user_option)
         };
+        let new_quota = if quota == user_info.quota {
+            None
+        } else {
+            Some(quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            quota: new_quota,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
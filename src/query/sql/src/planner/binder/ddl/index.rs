@@ -108,7 +108,7 @@ impl Binder {
         }
 
         if let SetExpr::Select(stmt) = &query.body {
-            if stmt.having.is_some() || stmt.window_list.is_some() {
+            if stmt.having.is_some() || stmt.window_list.is_some() || stmt.qualify.is_some() {
                 return err;
             }
             match &stmt.group_by {
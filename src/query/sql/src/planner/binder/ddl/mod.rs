@@ -19,8 +19,11 @@ mod data_mask;
 mod database;
 mod index;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod view;
 mod virtual_column;
+mod workload_group;
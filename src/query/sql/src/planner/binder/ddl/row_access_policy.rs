@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::binder::Binder;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::resolve_type_name;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::ScalarExpr;
+use crate::TypeChecker;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            policy,
+        } = stmt;
+
+        // Type check the body against the declared arguments, so that a broken policy
+        // is rejected here instead of failing every query on the tables it is attached to.
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for (i, arg) in policy.args.iter().enumerate() {
+            let data_type = DataType::from(&resolve_type_name(&arg.arg_type)?);
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: None,
+                table_index: None,
+                column_name: arg.arg_name.clone(),
+                index: i,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+            };
+            let scalar = ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column });
+            aliases.push((arg.arg_name.clone(), scalar));
+        }
+        let mut bind_context = BindContext::new();
+        let mut type_checker = TypeChecker::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+        );
+        let (_, data_type) = *type_checker.resolve(&policy.body).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy '{}' must return BOOLEAN, but got {}",
+                name, data_type
+            ))
+            .set_span(policy.body.span()));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateRowAccessPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            policy: policy.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let plan = DescRowAccessPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+}
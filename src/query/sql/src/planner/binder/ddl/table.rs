@@ -66,6 +66,7 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_OWNER;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
@@ -81,6 +82,7 @@ use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
@@ -89,6 +91,7 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
@@ -437,6 +440,11 @@ impl Binder {
                 .await?;
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
+            // The data of the table counts against the storage quota of its creator.
+            options.insert(
+                OPT_KEY_OWNER.to_owned(),
+                self.ctx.get_current_user()?.identity().to_string(),
+            );
 
             let config = GlobalConfig::instance();
             let is_blocking_fs = matches!(
@@ -709,6 +717,29 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -12,17 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
+use common_ast::ast::CreateTableStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Engine;
+use common_ast::ast::Identifier;
+use common_ast::ast::Query;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::SetExpr;
+use common_ast::ast::TableReference;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 
 use crate::binder::Binder;
+use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RelOperator;
+use crate::IndexType;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -117,4 +134,186 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        check_materialized_view_query(query)?;
+
+        // The result of the view query is kept in a fuse table,
+        // which is created the same as `CREATE TABLE ... AS SELECT ...`.
+        let create_table_stmt = CreateTableStmt {
+            if_not_exists: *if_not_exists,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: Some(query.clone()),
+            transient: false,
+        };
+        let mut create_table_plan = match self.bind_create_table(&create_table_stmt).await? {
+            Plan::CreateTable(plan) => *plan,
+            _ => unreachable!(),
+        };
+
+        let (source_catalog, source_database, source_table) = match create_table_plan
+            .as_select
+            .as_deref()
+        {
+            Some(Plan::Query {
+                s_expr, metadata, ..
+            }) => {
+                let table_index = find_materialized_view_source(s_expr)?;
+                let metadata = metadata.read();
+                let table_entry = metadata.table(table_index);
+                if table_entry
+                    .table()
+                    .options()
+                    .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Materialized view can not be created on materialized view {}.{}",
+                        table_entry.database(),
+                        table_entry.name(),
+                    )));
+                }
+                if table_entry.table().engine() != "FUSE" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Materialized view can only be created on FUSE tables, but {}.{} is of engine {}",
+                        table_entry.database(),
+                        table_entry.name(),
+                        table_entry.table().engine()
+                    )));
+                }
+                (
+                    table_entry.catalog().to_string(),
+                    table_entry.database().to_string(),
+                    table_entry.name().to_string(),
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        // The view is refreshed out of the session that creates it,
+        // so the source table in the query must be fully qualified.
+        let mut query = query.clone();
+        if let SetExpr::Select(select) = &mut query.body {
+            if let Some(TableReference::Table {
+                catalog, database, ..
+            }) = select.from.first_mut()
+            {
+                *catalog = Some(Identifier {
+                    name: source_catalog.clone(),
+                    quote: Some('"'),
+                    span: None,
+                });
+                *database = Some(Identifier {
+                    name: source_database.clone(),
+                    quote: Some('"'),
+                    span: None,
+                });
+            }
+        }
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            format!("{}", query),
+        );
+
+        let plan = CreateMaterializedViewPlan {
+            create_table_plan,
+            source_catalog,
+            source_database,
+            source_table,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+}
+
+/// A materialized view is refreshed incrementally with the rows appended to its
+/// source table, so the query must be a plain `SELECT ... FROM <table> [WHERE ...]`.
+fn check_materialized_view_query(query: &Query) -> Result<()> {
+    let unsupported = || {
+        Err(ErrorCode::SemanticError(
+            "Materialized view only supports the query of `SELECT ... FROM <table> [WHERE ...]`",
+        ))
+    };
+    if query.with.is_some()
+        || !query.order_by.is_empty()
+        || !query.limit.is_empty()
+        || query.offset.is_some()
+    {
+        return unsupported();
+    }
+    let select = match &query.body {
+        SetExpr::Select(select) => select,
+        _ => return unsupported(),
+    };
+    if select.distinct
+        || select.group_by.is_some()
+        || select.having.is_some()
+        || select.window_list.is_some()
+        || select.qualify.is_some()
+    {
+        return unsupported();
+    }
+    match select.from.as_slice() {
+        [
+            TableReference::Table {
+                travel_point: None,
+                pivot: None,
+                unpivot: None,
+                ..
+            },
+        ] => Ok(()),
+        _ => unsupported(),
+    }
+}
+
+/// Returns the index of the table that the materialized view reads from,
+/// the aggregations and subqueries hidden in the query are rejected here.
+fn find_materialized_view_source(s_expr: &SExpr) -> Result<IndexType> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Ok(scan.table_index),
+        RelOperator::EvalScalar(_) | RelOperator::Filter(_) => {
+            find_materialized_view_source(s_expr.child(0)?)
+        }
+        _ => Err(ErrorCode::SemanticError(
+            "Materialized view does not support aggregations, window functions or subqueries",
+        )),
+    }
 }
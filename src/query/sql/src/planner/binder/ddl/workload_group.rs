@@ -0,0 +1,93 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+
+use crate::binder::Binder;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+
+        check_workload_group_options(options)?;
+        let mut group = WorkloadGroup::new(name);
+        for option in options {
+            option.apply(&mut group);
+        }
+
+        let plan = CreateWorkloadGroupPlan {
+            if_not_exists: *if_not_exists,
+            group,
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt { name, options } = stmt;
+
+        check_workload_group_options(options)?;
+        let plan = AlterWorkloadGroupPlan {
+            name: name.to_string(),
+            options: options.clone(),
+        };
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+}
+
+fn check_workload_group_options(options: &[WorkloadGroupOption]) -> Result<()> {
+    for option in options {
+        if let WorkloadGroupOption::MemoryShare(share) = option {
+            if *share > 100 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "MEMORY_SHARE must be a percentage between 0 and 100, but got {}",
+                    share
+                )));
+            }
+        }
+    }
+    Ok(())
+}
@@ -83,6 +83,18 @@ impl<'a> Binder {
             (None, None)
         };
 
+        // A subquery selection is rejected by the interpreter, keep it as is.
+        let selection = if input_expr.is_none() {
+            let table = self
+                .ctx
+                .get_table(&catalog_name, &database_name, &table_name)
+                .await?;
+            self.bind_dml_row_access_policy(&context, table.get_table_info(), selection)
+                .await?
+        } else {
+            selection
+        };
+
         let plan = DeletePlan {
             catalog_name,
             database_name,
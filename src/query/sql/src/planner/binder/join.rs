@@ -589,6 +589,16 @@ impl<'a> JoinConditionResolver<'a> {
                 ))
                 .set_span(*span));
             };
+            {
+                let mut metadata = self.metadata.write();
+                for index in left_scalar
+                    .used_columns()
+                    .union(&right_scalar.used_columns())
+                {
+                    metadata.add_referenced_column(*index);
+                }
+            }
+
             let idx = !matches!(join_op, JoinOperator::RightOuter) as usize;
             if let Some(col_binding) = self
                 .join_context
@@ -0,0 +1,484 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::ast::MergeOption;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableField;
+
+use crate::binder::split_conjunctions;
+use crate::binder::split_equivalent_predicate;
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::normalize_identifier;
+use crate::optimizer::optimize;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::ColumnBinding;
+
+/// A bound `WHEN MATCHED` clause, `update_list` is None for `DELETE`.
+struct MatchedEvaluator {
+    condition: Option<ScalarExpr>,
+    update_list: Option<HashMap<FieldIndex, ScalarExpr>>,
+}
+
+/// A bound `WHEN NOT MATCHED` clause, with a value for each field of the target table.
+struct UnmatchedEvaluator {
+    condition: Option<ScalarExpr>,
+    values: Vec<ScalarExpr>,
+}
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table: table_ident,
+            target_alias,
+            source,
+            join_expr,
+            merge_options,
+            ..
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table_ident);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let table_id = table.get_id();
+        let schema = table.schema();
+
+        // Bind `source LEFT OUTER JOIN target ON join_expr`, every pair of matched rows
+        // and every unmatched source row produces one row. The matched target rows are
+        // replaced by their keys, so a target row matched by several source rows is
+        // rejected when the rows are merged into the table.
+        let target_reference = TableReference::Table {
+            span: None,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table_ident.clone(),
+            alias: target_alias.as_ref().map(|name| TableAlias {
+                name: name.clone(),
+                columns: vec![],
+            }),
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+        };
+        let (source_expr, source_context) = self.bind_table_reference(bind_context, source).await?;
+        let (target_expr, target_context) = self
+            .bind_table_reference(bind_context, &target_reference)
+            .await?;
+        let target_table_index = target_context
+            .columns
+            .first()
+            .and_then(|column| column.table_index)
+            .ok_or_else(|| ErrorCode::Internal("target table of MERGE INTO has no column"))?;
+        let join = Join {
+            op: JoinOperator::LeftOuter,
+            condition: JoinCondition::On(Box::new(join_expr.clone())),
+            left: Box::new(source.clone()),
+            right: Box::new(target_reference),
+        };
+        let (join_s_expr, mut context) = self
+            .bind_join(
+                bind_context,
+                source_context,
+                target_context,
+                source_expr,
+                target_expr,
+                &join,
+            )
+            .await?;
+
+        // The (nullable) column bindings of the target table fields.
+        let target_columns = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                context
+                    .columns
+                    .iter()
+                    .find(|column| {
+                        column.table_index == Some(target_table_index)
+                            && column.column_name == field.name().as_str()
+                    })
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "column {} of the MERGE INTO target table is not bound",
+                            field.name()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<ColumnBinding>>>()?;
+        let target_column_set: ColumnSet = target_columns.iter().map(|c| c.index).collect();
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        // The ON condition must identify the matched target rows by their keys, so that
+        // they can be replaced in the same way as REPLACE INTO does.
+        let (on_condition, _) = scalar_binder.bind(join_expr).await?;
+        let mut on_conflict_fields: Vec<TableField> = vec![];
+        let mut key_columns: Vec<ColumnBinding> = vec![];
+        for predicate in split_conjunctions(&on_condition) {
+            if predicate.used_columns().is_disjoint(&target_column_set) {
+                continue;
+            }
+            let key = split_equivalent_predicate(&predicate).and_then(|(left, right)| {
+                match (&left, &right) {
+                    (ScalarExpr::BoundColumnRef(column), other)
+                    | (other, ScalarExpr::BoundColumnRef(column))
+                        if target_column_set.contains(&column.column.index)
+                            && other.used_columns().is_disjoint(&target_column_set) =>
+                    {
+                        Some(column.column.clone())
+                    }
+                    _ => None,
+                }
+            });
+            match key {
+                Some(column) => {
+                    let field_index = target_columns
+                        .iter()
+                        .position(|c| c.index == column.index)
+                        .unwrap();
+                    if !key_columns.iter().any(|c| c.index == column.index) {
+                        on_conflict_fields.push(schema.field(field_index).clone());
+                        key_columns.push(column);
+                    }
+                }
+                None => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "MERGE INTO only supports conditions of the form `<target column> = <source expression>` on the target table, but got `{}`",
+                        join_expr
+                    )));
+                }
+            }
+        }
+        if key_columns.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "MERGE INTO requires at least one condition of the form `<target column> = <source expression>`",
+            ));
+        }
+
+        let mut matched_evaluators = vec![];
+        let mut unmatched_evaluators = vec![];
+        for merge_option in merge_options {
+            match merge_option {
+                MergeOption::Match(clause) => {
+                    let condition = match &clause.selection {
+                        Some(expr) => Some(bind_merge_expr(&mut scalar_binder, expr).await?),
+                        None => None,
+                    };
+                    let update_list = match &clause.operation {
+                        MatchOperation::Update { update_list } => {
+                            let mut update_columns = HashMap::with_capacity(update_list.len());
+                            for update_expr in update_list {
+                                let col_name = normalize_identifier(
+                                    &update_expr.name,
+                                    &self.name_resolution_ctx,
+                                )
+                                .name;
+                                let index = schema.index_of(&col_name)?;
+                                if update_columns.contains_key(&index) {
+                                    return Err(ErrorCode::BadArguments(format!(
+                                        "Multiple assignments in the single statement to column `{}`",
+                                        col_name
+                                    )));
+                                }
+                                let scalar =
+                                    bind_merge_expr(&mut scalar_binder, &update_expr.expr).await?;
+                                update_columns.insert(index, scalar);
+                            }
+                            Some(update_columns)
+                        }
+                        MatchOperation::Delete => None,
+                    };
+                    matched_evaluators.push(MatchedEvaluator {
+                        condition,
+                        update_list,
+                    });
+                }
+                MergeOption::Unmatch(clause) => {
+                    let condition = match &clause.selection {
+                        Some(expr) => {
+                            let scalar = bind_merge_expr(&mut scalar_binder, expr).await?;
+                            check_unmatched_expr(&scalar, &target_column_set)?;
+                            Some(scalar)
+                        }
+                        None => None,
+                    };
+                    let field_indexes = match &clause.columns {
+                        Some(columns) => columns
+                            .iter()
+                            .map(|ident| {
+                                let col_name =
+                                    normalize_identifier(ident, &self.name_resolution_ctx).name;
+                                schema.index_of(&col_name)
+                            })
+                            .collect::<Result<Vec<_>>>()?,
+                        None => (0..schema.num_fields()).collect(),
+                    };
+                    if field_indexes.len() != clause.values.len() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "INSERT of WHEN NOT MATCHED has {} columns but {} values",
+                            field_indexes.len(),
+                            clause.values.len()
+                        )));
+                    }
+                    let mut values: Vec<Option<ScalarExpr>> = vec![None; schema.num_fields()];
+                    for (field_index, expr) in field_indexes.into_iter().zip(clause.values.iter()) {
+                        if values[field_index].is_some() {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Multiple assignments in the single statement to column `{}`",
+                                schema.field(field_index).name()
+                            )));
+                        }
+                        let scalar = bind_merge_expr(&mut scalar_binder, expr).await?;
+                        check_unmatched_expr(&scalar, &target_column_set)?;
+                        values[field_index] = Some(scalar);
+                    }
+                    let mut bound_values = Vec::with_capacity(values.len());
+                    for (field_index, value) in values.into_iter().enumerate() {
+                        let scalar = match value {
+                            Some(scalar) => scalar,
+                            None => {
+                                bind_default_value(&mut scalar_binder, schema.field(field_index))
+                                    .await?
+                            }
+                        };
+                        bound_values.push(scalar);
+                    }
+                    unmatched_evaluators.push(UnmatchedEvaluator {
+                        condition,
+                        values: bound_values,
+                    });
+                }
+            }
+        }
+
+        let column_ref = |column: &ColumnBinding| {
+            ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            })
+        };
+        let function = |func_name: &str, arguments: Vec<ScalarExpr>| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments,
+            })
+        };
+        let constant = |value: Scalar| ScalarExpr::ConstantExpr(ConstantExpr { span: None, value });
+
+        // A key of the ON condition is never NULL for a matched target row.
+        let matched = function("is_not_null", vec![column_ref(&key_columns[0])]);
+        let not_matched = function("not", vec![matched.clone()]);
+        let matched_conditions = matched_evaluators
+            .iter()
+            .map(|evaluator| match &evaluator.condition {
+                Some(condition) => function("and", vec![matched.clone(), condition.clone()]),
+                None => matched.clone(),
+            })
+            .collect::<Vec<_>>();
+        let unmatched_conditions = unmatched_evaluators
+            .iter()
+            .map(|evaluator| match &evaluator.condition {
+                Some(condition) => function("and", vec![not_matched.clone(), condition.clone()]),
+                None => not_matched.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        // The first clause whose condition holds is applied to a row. A matched row
+        // without such clause is written back unchanged.
+        let mut items = Vec::with_capacity(schema.num_fields() + key_columns.len() + 1);
+        for (field_index, field) in schema.fields().iter().enumerate() {
+            let data_type = DataType::from(field.data_type());
+            let nullable_type = data_type.wrap_nullable();
+            let target_value = wrap_cast(&column_ref(&target_columns[field_index]), &nullable_type);
+            let mut arguments = vec![];
+            for (evaluator, condition) in matched_evaluators.iter().zip(&matched_conditions) {
+                let value = evaluator
+                    .update_list
+                    .as_ref()
+                    .and_then(|update_list| update_list.get(&field_index))
+                    .map(|scalar| wrap_cast(scalar, &nullable_type))
+                    .unwrap_or_else(|| target_value.clone());
+                arguments.push(condition.clone());
+                arguments.push(value);
+            }
+            for (evaluator, condition) in unmatched_evaluators.iter().zip(&unmatched_conditions) {
+                arguments.push(condition.clone());
+                arguments.push(wrap_cast(&evaluator.values[field_index], &nullable_type));
+            }
+            arguments.push(matched.clone());
+            arguments.push(target_value);
+            // The value of the rows not to be written, it only needs to be castable.
+            arguments.push(wrap_cast(
+                &constant(Scalar::default_value(&data_type)),
+                &nullable_type,
+            ));
+            let scalar = wrap_cast(&function("if", arguments), &data_type);
+            items.push((field.name().clone(), scalar));
+        }
+        for key_column in &key_columns {
+            items.push((
+                format!("_merge_key_{}", key_column.column_name),
+                column_ref(key_column),
+            ));
+        }
+        let mut arguments = vec![];
+        for (evaluator, condition) in matched_evaluators.iter().zip(&matched_conditions) {
+            arguments.push(condition.clone());
+            arguments.push(constant(Scalar::Boolean(evaluator.update_list.is_some())));
+        }
+        for condition in &unmatched_conditions {
+            arguments.push(condition.clone());
+            arguments.push(constant(Scalar::Boolean(true)));
+        }
+        arguments.push(matched);
+        items.push(("_merge_append".to_string(), function("if", arguments)));
+
+        let mut output_context = BindContext::new();
+        let mut scalar_items = Vec::with_capacity(items.len());
+        for (name, scalar) in items {
+            let column = self.create_column_binding(None, None, None, name, scalar.data_type()?);
+            scalar_items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            output_context.columns.push(column);
+        }
+        let s_expr = SExpr::create_unary(
+            Arc::new(
+                EvalScalar {
+                    items: scalar_items,
+                }
+                .into(),
+            ),
+            Arc::new(join_s_expr),
+        );
+
+        let input = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata: self.metadata.clone(),
+            bind_context: Box::new(output_context),
+            rewrite_kind: None,
+            formatted_ast: None,
+            ignore_result: false,
+        };
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let input = optimize(self.ctx.clone(), opt_ctx, input)?;
+
+        Ok(Plan::MergeInto(Box::new(MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id,
+            schema,
+            on_conflict_fields,
+            input: Box::new(input),
+        })))
+    }
+}
+
+async fn bind_merge_expr(
+    scalar_binder: &mut ScalarBinder<'_>,
+    expr: &common_ast::ast::Expr,
+) -> Result<ScalarExpr> {
+    let (scalar, _) = scalar_binder.bind(expr).await?;
+    if matches!(scalar, ScalarExpr::SubqueryExpr(_)) {
+        return Err(ErrorCode::Unimplemented(
+            "MERGE INTO does not support subquery in clauses temporarily",
+        ));
+    }
+    Ok(scalar)
+}
+
+fn check_unmatched_expr(scalar: &ScalarExpr, target_columns: &ColumnSet) -> Result<()> {
+    if !scalar.used_columns().is_disjoint(target_columns) {
+        return Err(ErrorCode::SemanticError(
+            "columns of the target table can not be referenced in WHEN NOT MATCHED clause",
+        ));
+    }
+    Ok(())
+}
+
+async fn bind_default_value(
+    scalar_binder: &mut ScalarBinder<'_>,
+    field: &TableField,
+) -> Result<ScalarExpr> {
+    let data_type = DataType::from(field.data_type());
+    if let Some(default_expr) = field.default_expr() {
+        let tokens = tokenize_sql(default_expr)?;
+        let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let (scalar, _) = scalar_binder.bind(&ast).await?;
+        return Ok(wrap_cast(&scalar, &data_type));
+    }
+    let value = if data_type.is_nullable() {
+        Scalar::Null
+    } else {
+        Scalar::default_value(&data_type)
+    };
+    Ok(wrap_cast(
+        &ScalarExpr::ConstantExpr(ConstantExpr { span: None, value }),
+        &data_type,
+    ))
+}
@@ -29,10 +29,13 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod recursive_cte;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -51,6 +54,7 @@ pub use bind_context::*;
 pub use binder::Binder;
 pub use builders::*;
 pub use copy::parse_stage_location;
+pub use copy::validate_stage_privilege;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
 pub use scalar::ScalarBinder;
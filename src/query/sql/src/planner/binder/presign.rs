@@ -16,8 +16,10 @@ use common_ast::ast::PresignAction as AstPresignAction;
 use common_ast::ast::PresignLocation;
 use common_ast::ast::PresignStmt;
 use common_exception::Result;
+use common_meta_app::principal::UserPrivilegeType;
 
 use super::copy::parse_stage_location_v2;
+use super::copy::validate_stage_privilege;
 use crate::binder::Binder;
 use crate::plans::Plan;
 use crate::plans::PresignAction;
@@ -36,14 +38,18 @@ impl Binder {
                 let (stage_info, path) =
                     parse_stage_location_v2(&self.ctx, &stage_location.name, &stage_location.path)
                         .await?;
+                let (action, privilege) = match stmt.action {
+                    AstPresignAction::Download => {
+                        (PresignAction::Download, UserPrivilegeType::Read)
+                    }
+                    AstPresignAction::Upload => (PresignAction::Upload, UserPrivilegeType::Write),
+                };
+                validate_stage_privilege(&self.ctx, &stage_info, privilege).await?;
 
                 Ok(Plan::Presign(Box::new(PresignPlan {
                     stage: Box::new(stage_info),
                     path,
-                    action: match stmt.action {
-                        AstPresignAction::Download => PresignAction::Download,
-                        AstPresignAction::Upload => PresignAction::Upload,
-                    },
+                    action,
                     expire: stmt.expire,
                     content_type: stmt.content_type.clone(),
                 })))
@@ -209,6 +209,7 @@ impl Binder {
                         Some(Indirection::Star(span)) => *span,
                         _ => None,
                     };
+                    let start = output.items.len();
                     match names.len() {
                         1 | 2 => self.resolve_qualified_name_without_database_name(
                             span,
@@ -228,6 +229,14 @@ impl Binder {
                         )?,
                         _ => return Err(ErrorCode::SemanticError("Unsupported indirection type")),
                     };
+
+                    // Columns expanded from the wildcard are referenced by the query.
+                    let mut metadata = self.metadata.write();
+                    for item in &output.items[start..] {
+                        for index in item.scalar.used_columns() {
+                            metadata.add_referenced_column(index);
+                        }
+                    }
                 }
                 SelectTarget::AliasedExpr { expr, alias } => {
                     let mut scalar_binder = ScalarBinder::new(
@@ -0,0 +1,300 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check::common_super_type;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::table::cte_columns_alias;
+use crate::binder::CteInfo;
+use crate::binder::RecursiveCteWorkingTable;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::BindContext;
+use crate::Binder;
+use crate::NameResolutionContext;
+
+/// The recursive term is bound again with the widened column types until the
+/// types of the working table are stable, e.g. `SELECT 1 UNION ALL SELECT n + 1 FROM t`
+/// widens `n` from UInt8 to UInt64.
+const MAX_RECURSIVE_CTE_BIND_PASSES: usize = 8;
+
+impl Binder {
+    /// Check if the CTE refers to itself.
+    pub(super) fn is_recursive_cte(&self, table_name: &str, query: &Query) -> bool {
+        let mut finder = CteReferenceFinder::new(table_name, &self.name_resolution_ctx);
+        finder.visit_query(query);
+        finder.found
+    }
+
+    /// Bind `<anchor term> UNION [ALL] <recursive term>`, the references to the CTE
+    /// in the recursive term read the rows produced by the previous iteration.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => {
+                set_operation
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Recursive CTE `{table_name}` must be of the form `<anchor term> UNION [ALL] <recursive term>`"
+                ))
+                .set_span(span));
+            }
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive CTE `{table_name}`"
+            ))
+            .set_span(span));
+        }
+        let mut finder = CteReferenceFinder::new(table_name, &self.name_resolution_ctx);
+        finder.visit_set_expr(&set_operation.left);
+        if finder.found {
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive reference to `{table_name}` is not allowed in the anchor term"
+            ))
+            .set_span(span));
+        }
+
+        let mut anchor_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let (anchor_expr, anchor_context) = self
+            .bind_set_expr(&mut anchor_context, &set_operation.left, &[], 0)
+            .await?;
+
+        let cols_alias = cte_columns_alias(&cte_info.columns_alias, alias);
+        if cols_alias.len() > anchor_context.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                anchor_context.columns.len(),
+                cols_alias.len()
+            ))
+            .set_span(span));
+        }
+        let column_names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                cols_alias
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let mut data_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+
+        let cte_id = self.metadata.write().add_recursive_cte();
+        let mut passes = 0;
+        let (recursive_expr, recursive_context) = loop {
+            passes += 1;
+            let working_table = RecursiveCteWorkingTable {
+                cte_id,
+                columns: column_names
+                    .iter()
+                    .cloned()
+                    .zip(data_types.iter().cloned())
+                    .collect(),
+            };
+            let mut recursive_context = BindContext::with_parent(Box::new(bind_context.clone()));
+            recursive_context
+                .ctes_map
+                .insert(table_name.to_string(), CteInfo {
+                    working_table: Some(working_table),
+                    ..cte_info.clone()
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(&mut recursive_context, &set_operation.right, &[], 0)
+                .await?;
+            if recursive_context.columns.len() != data_types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                )
+                .set_span(span));
+            }
+
+            let common_types = data_types
+                .iter()
+                .zip(recursive_context.columns.iter())
+                .map(|(data_type, column)| {
+                    common_super_type(
+                        data_type.clone(),
+                        *column.data_type.clone(),
+                        &BUILTIN_FUNCTIONS.default_cast_rules,
+                    )
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "Recursive CTE's types cannot be matched, anchor term type: {:?}, recursive term type: {:?}",
+                            data_type, column.data_type
+                        ))
+                        .set_span(span)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if common_types == data_types {
+                break (recursive_expr, recursive_context);
+            }
+            if passes >= MAX_RECURSIVE_CTE_BIND_PASSES {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Cannot determine the column types of recursive CTE `{table_name}`"
+                ))
+                .set_span(span));
+            }
+            data_types = common_types;
+        };
+
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let mut output_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for (column_name, data_type) in column_names.into_iter().zip(data_types.into_iter()) {
+            let column = self.create_column_binding(
+                None,
+                Some(alias_table_name.clone()),
+                None,
+                column_name,
+                data_type,
+            );
+            output_context.add_column_binding(column);
+        }
+
+        let recursive_cte = RecursiveCte {
+            cte_id,
+            distinct: !set_operation.all,
+            columns: output_context.columns.iter().map(|c| c.index).collect(),
+            anchor_columns: anchor_context.columns.iter().map(|c| c.index).collect(),
+            recursive_columns: recursive_context.columns.iter().map(|c| c.index).collect(),
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(recursive_cte.into()),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+        Ok((s_expr, output_context))
+    }
+
+    /// Bind a reference to the CTE in its recursive term.
+    pub(super) fn bind_recursive_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        working_table: &RecursiveCteWorkingTable,
+    ) -> Result<(SExpr, BindContext)> {
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let cols_alias = match alias {
+            Some(alias) => alias
+                .columns
+                .iter()
+                .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                .collect(),
+            None => vec![],
+        };
+        if cols_alias.len() > working_table.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                working_table.columns.len(),
+                cols_alias.len()
+            )));
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for (index, (column_name, data_type)) in working_table.columns.iter().enumerate() {
+            let column = self.create_column_binding(
+                None,
+                Some(alias_table_name.clone()),
+                None,
+                cols_alias
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| column_name.clone()),
+                data_type.clone(),
+            );
+            new_bind_context.add_column_binding(column);
+        }
+
+        let scan = RecursiveCteScan {
+            cte_id: working_table.cte_id,
+            columns: new_bind_context.columns.iter().map(|c| c.index).collect(),
+        };
+        Ok((SExpr::create_leaf(Arc::new(scan.into())), new_bind_context))
+    }
+}
+
+/// Find the references to a CTE in a query.
+struct CteReferenceFinder<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'a> CteReferenceFinder<'a> {
+    fn new(cte_name: &'a str, name_resolution_ctx: &'a NameResolutionContext) -> Self {
+        Self {
+            cte_name,
+            name_resolution_ctx,
+            found: false,
+        }
+    }
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceFinder<'a> {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.found = true;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+}
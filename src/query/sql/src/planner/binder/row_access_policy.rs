@@ -0,0 +1,161 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_api::RowAccessPolicyApi;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableRowAccessPolicy;
+use common_users::UserApiProvider;
+
+use crate::binder::Binder;
+use crate::binder::Visibility;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::BindContext;
+use crate::ScalarExpr;
+use crate::TypeChecker;
+
+impl Binder {
+    /// Wrap the scan of a table in a filter built from the row access policy attached to it.
+    ///
+    /// The filter sits right above the scan, so the optimizer pushes it down into the
+    /// scan and the storage can still prune with it.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        binding: &TableRowAccessPolicy,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let predicate = self
+            .row_access_policy_predicate(bind_context, binding)
+            .await?;
+        let filter = Filter {
+            predicates: vec![predicate],
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Restrict the selection of a DELETE or UPDATE to the rows the row access policy
+    /// attached to the table lets the current user see.
+    ///
+    /// DML doesn't read the table through the bound scan, so the filter added by
+    /// `bind_row_access_policy` has no effect there and the policy is ANDed into the
+    /// selection instead.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_dml_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        table_info: &TableInfo,
+        selection: Option<ScalarExpr>,
+    ) -> Result<Option<ScalarExpr>> {
+        let binding = match &table_info.meta.row_access_policy {
+            Some(binding) => binding,
+            None => return Ok(selection),
+        };
+        let predicate = self
+            .row_access_policy_predicate(bind_context, binding)
+            .await?;
+        Ok(Some(match selection {
+            Some(selection) => ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![selection, predicate],
+            }),
+            None => predicate,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn row_access_policy_predicate(
+        &mut self,
+        bind_context: &BindContext,
+        binding: &TableRowAccessPolicy,
+    ) -> Result<ScalarExpr> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.ctx.get_tenant(),
+                    name: binding.policy.clone(),
+                },
+            })
+            .await?
+            .policy;
+
+        if policy.args.len() != binding.columns.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "Row access policy '{}' expects {} arguments, but it is attached with {} columns",
+                binding.policy,
+                policy.args.len(),
+                binding.columns.len()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column_name) in policy.args.iter().zip(binding.columns.iter()) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|c| c.visibility == Visibility::Visible && &c.column_name == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "column {} referenced by row access policy '{}' not exists",
+                        column_name, binding.policy
+                    ))
+                })?;
+            let scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            aliases.push((arg_name.clone(), scalar));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast_expr = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        // The body can only see the policy arguments, not the other columns of the table.
+        let mut policy_context = BindContext::new();
+        let mut type_checker = TypeChecker::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+        );
+        let (predicate, data_type) = *type_checker.resolve(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy '{}' must return BOOLEAN, but got {}",
+                binding.policy, data_type
+            )));
+        }
+        Ok(predicate)
+    }
+}
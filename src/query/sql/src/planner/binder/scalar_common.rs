@@ -38,7 +38,6 @@ impl<'a, F> Finder<'a, F>
 where F: Fn(&ScalarExpr) -> bool
 {
     /// Create a new finder with the `test_fn`
-    #[allow(dead_code)]
     fn new(find_fn: &'a F) -> Self {
         Self {
             find_fn,
@@ -63,6 +62,12 @@ where F: Fn(&ScalarExpr) -> bool
     }
 }
 
+pub fn find_window_functions(scalar: &ScalarExpr) -> Result<Vec<ScalarExpr>> {
+    let finder = Finder::new(&|scalar| matches!(scalar, ScalarExpr::WindowFunction(_)));
+    let finder = scalar.accept(finder)?;
+    Ok(finder.scalars)
+}
+
 pub fn split_conjunctions(scalar: &ScalarExpr) -> Vec<ScalarExpr> {
     match scalar {
         ScalarExpr::FunctionCall(func) if func.func_name == "and" => vec![
@@ -157,6 +162,7 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
         }
         ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contain_subquery),
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
+        ScalarExpr::LambdaFunction(lambda) => lambda.args.iter().any(contain_subquery),
         _ => false,
     }
 }
@@ -204,6 +210,10 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
+        ScalarExpr::LambdaFunction(scalar) => scalar
+            .args
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
     }
 }
 
@@ -184,6 +184,15 @@ impl Binder {
             None
         };
 
+        let qualify = if let Some(qualify) = &stmt.qualify {
+            Some(
+                self.analyze_window_qualify(&mut from_context, &aliases, qualify)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
         let order_items = self
             .analyze_order_items(
                 &mut from_context,
@@ -227,6 +236,13 @@ impl Binder {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
+        // QUALIFY filters the rows by the results of window functions.
+        if let Some((qualify, span)) = qualify {
+            s_expr = self
+                .bind_qualify(&mut from_context, qualify, span, s_expr)
+                .await?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -308,6 +324,8 @@ impl Binder {
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -594,6 +612,7 @@ impl Binder {
         // SELECT ... FROM t WHERE ... ORDER BY ... LIMIT ...
         if stmt.group_by.is_some()
             || stmt.having.is_some()
+            || stmt.qualify.is_some()
             || stmt.distinct
             || !bind_context.ctes_map.is_empty()
             || !bind_context.aggregate_info.group_items.is_empty()
@@ -731,6 +750,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
@@ -35,6 +35,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Sort;
@@ -377,6 +378,24 @@ impl Binder {
                         target_type: target_type.clone(),
                     }))
                 }
+                ScalarExpr::LambdaFunction(lambda) => {
+                    let args = lambda
+                        .args
+                        .iter()
+                        .map(|arg| {
+                            self.rewrite_scalar_with_replacement(bind_context, arg, replacement_fn)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                        span: lambda.span,
+                        func_name: lambda.func_name.clone(),
+                        display_name: lambda.display_name.clone(),
+                        args,
+                        lambda_expr: lambda.lambda_expr.clone(),
+                        lambda_display: lambda.lambda_display.clone(),
+                        return_type: lambda.return_type.clone(),
+                    }))
+                }
                 _ => Ok(original_scalar.clone()),
             },
         }
@@ -86,6 +86,8 @@ impl BindContext {
                     | DataType::Timestamp
                     | DataType::Date
                     | DataType::Bitmap
+                    | DataType::Binary
+                    | DataType::Geometry
                     | DataType::Variant => wrap_cast(&scalar, field_data_type),
                     DataType::String => {
                         // parse string to JSON value
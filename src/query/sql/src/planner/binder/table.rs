@@ -33,6 +33,7 @@ use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
@@ -53,6 +54,8 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::InputContext;
+use common_storage::init_stage_operator;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -60,6 +63,8 @@ use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
+use common_storages_stage::StageTable;
+use common_storages_stream::stream_table::StreamTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
@@ -109,9 +114,8 @@ impl Binder {
         }
         let catalog = CATALOG_DEFAULT;
         let database = "system";
-        let tenant = self.ctx.get_tenant();
         let table_meta: Arc<dyn Table> = self
-            .resolve_data_source(tenant.as_str(), catalog, database, "one", &None)
+            .resolve_data_source(catalog, database, "one", &None)
             .await?;
         let table_index = self.metadata.write().add_table(
             CATALOG_DEFAULT.to_owned(),
@@ -190,7 +194,6 @@ impl Binder {
                 // Resolve table with catalog
                 let table_meta = match self
                     .resolve_data_source(
-                        tenant.as_str(),
                         catalog.as_str(),
                         database.as_str(),
                         table_name.as_str(),
@@ -306,9 +309,66 @@ impl Binder {
                             .set_span(*span))
                         }
                     }
+                    "STREAM" => {
+                        let stream = StreamTable::try_from_table(table_meta.as_ref())?;
+                        let base_table = self
+                            .ctx
+                            .get_table(&catalog, stream.base_database(), stream.base_table())
+                            .await?;
+                        if base_table.get_id() != stream.base_table_id() {
+                            return Err(ErrorCode::IllegalStream(format!(
+                                "Base table {}.{} of stream {} has been dropped or recreated",
+                                stream.base_database(),
+                                stream.base_table(),
+                                table_meta.name()
+                            ))
+                            .set_span(*span));
+                        }
+                        let columns = base_table
+                            .schema()
+                            .fields()
+                            .iter()
+                            .map(|field| field.name().clone())
+                            .collect::<Vec<_>>();
+                        let tokens = tokenize_sql(&stream.changes_query(&columns))?;
+                        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+                        let mut new_bind_context =
+                            BindContext::with_parent(Box::new(bind_context.clone()));
+                        if let Statement::Query(query) = &stmt {
+                            self.metadata.write().add_table(
+                                catalog,
+                                database.clone(),
+                                table_meta,
+                                table_alias_name,
+                                false,
+                            );
+                            let (s_expr, mut new_bind_context) =
+                                self.bind_query(&mut new_bind_context, query).await?;
+                            if let Some(alias) = alias {
+                                new_bind_context
+                                    .apply_table_alias(alias, &self.name_resolution_ctx)?;
+                            } else {
+                                for column in new_bind_context.columns.iter_mut() {
+                                    column.database_name = None;
+                                    column.table_name = Some(
+                                        normalize_identifier(table, &self.name_resolution_ctx).name,
+                                    );
+                                }
+                            }
+                            Ok((s_expr, new_bind_context))
+                        } else {
+                            Err(ErrorCode::Internal(format!(
+                                "Invalid STREAM object: {}",
+                                table_meta.name()
+                            ))
+                            .set_span(*span))
+                        }
+                    }
                     _ => {
                         let table_name = table_meta.name();
                         let full_table_name = format!("{catalog}.{database}.{table_name}");
+                        let row_access_policy =
+                            table_meta.get_table_info().meta.row_access_policy.clone();
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
@@ -324,9 +384,14 @@ impl Binder {
                                 .add_agg_indexes(full_table_name, agg_indexes);
                         }
 
-                        let (s_expr, mut bind_context) = self
+                        let (mut s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        if let Some(binding) = &row_access_policy {
+                            s_expr = self
+                                .bind_row_access_policy(&bind_context, binding, s_expr)
+                                .await?;
+                        }
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -435,6 +500,7 @@ impl Binder {
                                 params: vec![],
                                 args: params.clone(),
                                 window: None,
+                                lambda: None,
                             }),
                             alias: None,
                         }],
@@ -443,6 +509,7 @@ impl Binder {
                         group_by: None,
                         having: None,
                         window_list: None,
+                        qualify: None,
                     };
                     self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
                         .await
@@ -523,39 +590,56 @@ impl Binder {
         alias: &Option<TableAlias>,
         files_to_copy: Option<Vec<StageFileInfo>>,
     ) -> Result<(SExpr, BindContext)> {
-        if matches!(stage_info.file_format_params, FileFormatParams::Parquet(..)) {
-            let read_options = ParquetReadOptions::default();
-
-            let table =
+        let table = match &stage_info.file_format_params {
+            FileFormatParams::Parquet(..) => {
+                let read_options = ParquetReadOptions::default();
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
-                    .await?;
-
-            let table_alias_name = if let Some(table_alias) = alias {
-                Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
-            } else {
-                None
-            };
-
-            let table_index = self.metadata.write().add_table(
-                CATALOG_DEFAULT.to_string(),
-                "system".to_string(),
-                table.clone(),
-                table_alias_name,
-                false,
-            );
-
-            let (s_expr, mut bind_context) = self
-                .bind_base_table(bind_context, "system", table_index)
-                .await?;
-            if let Some(alias) = alias {
-                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+                    .await?
             }
-            Ok((s_expr, bind_context))
+            FileFormatParams::Avro(..) | FileFormatParams::Orc(..) => {
+                // the schema is taken from the first file
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) if !files.is_empty() => files[0].path.clone(),
+                    _ => files_info.first_file(&operator).await?.path,
+                };
+                let input_format = InputContext::get_input_format(&stage_info.file_format_params)?;
+                let schema = input_format.infer_schema(&first_file, &operator).await?;
+                StageTable::try_create(StageTableInfo {
+                    schema,
+                    files_info,
+                    stage_info,
+                    files_to_copy,
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "stage table function only support parquet, avro and orc format for now",
+                ));
+            }
+        };
+
+        let table_alias_name = if let Some(table_alias) = alias {
+            Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
         } else {
-            Err(ErrorCode::Unimplemented(
-                "stage table function only support parquet format for now",
-            ))
+            None
+        };
+
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table.clone(),
+            table_alias_name,
+            false,
+        );
+
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
         }
+        Ok((s_expr, bind_context))
     }
 
     #[async_backtrace::framed]
@@ -641,6 +725,15 @@ impl Binder {
         alias: &Option<TableAlias>,
         cte_info: &CteInfo,
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(working_table) = &cte_info.working_table {
+            return self.bind_recursive_cte_scan(bind_context, table_name, alias, working_table);
+        }
+        if cte_info.recursive && self.is_recursive_cte(table_name, &cte_info.query) {
+            return self
+                .bind_recursive_cte(span, bind_context, table_name, alias, cte_info)
+                .await;
+        }
+
         let mut new_bind_context = BindContext {
             parent: Some(Box::new(bind_context.clone())),
             bound_internal_columns: BTreeMap::new(),
@@ -658,16 +751,7 @@ impl Binder {
         let (s_expr, mut new_bind_context) = self
             .bind_query(&mut new_bind_context, &cte_info.query)
             .await?;
-        let mut cols_alias = cte_info.columns_alias.clone();
-        if let Some(alias) = alias {
-            for (idx, col_alias) in alias.columns.iter().enumerate() {
-                if idx < cte_info.columns_alias.len() {
-                    cols_alias[idx] = col_alias.name.clone();
-                } else {
-                    cols_alias.push(col_alias.name.clone());
-                }
-            }
-        }
+        let cols_alias = cte_columns_alias(&cte_info.columns_alias, alias);
         let alias_table_name = alias
             .as_ref()
             .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
@@ -782,15 +866,17 @@ impl Binder {
     #[async_backtrace::framed]
     async fn resolve_data_source(
         &self,
-        tenant: &str,
         catalog_name: &str,
         database_name: &str,
         table_name: &str,
         travel_point: &Option<NavigationPoint>,
     ) -> Result<Arc<dyn Table>> {
-        // Resolve table with catalog
-        let catalog = self.catalogs.get_catalog(catalog_name)?;
-        let mut table_meta = catalog.get_table(tenant, database_name, table_name).await?;
+        // Resolve table with the query context, so that the same table
+        // metadata is used all through the query.
+        let mut table_meta = self
+            .ctx
+            .get_table(catalog_name, database_name, table_name)
+            .await?;
 
         if let Some(tp) = travel_point {
             table_meta = table_meta.navigate_to(tp).await?;
@@ -871,3 +957,22 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
     let args = table_args.expect_all_positioned("RESULT_SCAN", Some(1))?;
     string_value(&args[0])
 }
+
+/// Column names of a CTE, the names in the table alias take precedence over
+/// the names in the CTE definition.
+pub(super) fn cte_columns_alias(
+    columns_alias: &[String],
+    alias: &Option<TableAlias>,
+) -> Vec<String> {
+    let mut cols_alias = columns_alias.to_vec();
+    if let Some(alias) = alias {
+        for (idx, col_alias) in alias.columns.iter().enumerate() {
+            if idx < columns_alias.len() {
+                cols_alias[idx] = col_alias.name.clone();
+            } else {
+                cols_alias.push(col_alias.name.clone());
+            }
+        }
+    }
+    cols_alias
+}
@@ -111,6 +111,9 @@ impl Binder {
         } else {
             None
         };
+        let push_downs = self
+            .bind_dml_row_access_policy(&context, table.get_table_info(), push_downs)
+            .await?;
 
         let plan = UpdatePlan {
             catalog: catalog_name,
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
 use common_ast::ast::WindowDefinition;
 use common_ast::ast::WindowSpec;
 use common_exception::ErrorCode;
@@ -22,12 +23,20 @@ use common_exception::Result;
 use common_exception::Span;
 
 use super::select::SelectList;
+use crate::binder::aggregate::AggregateRewriter;
+use crate::binder::find_window_functions;
+use crate::binder::split_conjunctions;
+use crate::binder::ExprContext;
+use crate::binder::ScalarBinder;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::GroupingChecker;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -42,6 +51,7 @@ use crate::ColumnBinding;
 use crate::IndexType;
 use crate::MetadataRef;
 use crate::Visibility;
+use crate::WindowChecker;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -253,6 +263,23 @@ impl<'a> WindowRewriter<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
 
             // TODO(leiysky): should we recursively process subquery here?
             ScalarExpr::SubqueryExpr(_) => Ok(scalar.clone()),
@@ -513,4 +540,116 @@ impl Binder {
 
         Ok(())
     }
+
+    /// Analyze the QUALIFY clause, this will rewrite the aggregate functions and the window
+    /// functions which are not in the select list.
+    #[async_backtrace::framed]
+    pub(super) async fn analyze_window_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        aliases: &[(String, ScalarExpr)],
+        qualify: &Expr,
+    ) -> Result<(ScalarExpr, Span)> {
+        let last_expr_context = bind_context.expr_context.clone();
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            aliases,
+        );
+        let (scalar, _) = scalar_binder.bind(qualify).await?;
+
+        // QUALIFY is meaningless without window functions, either in the select list or in itself.
+        if bind_context.windows.window_functions.is_empty()
+            && find_window_functions(&scalar)?.is_empty()
+        {
+            return Err(ErrorCode::SemanticError(
+                "QUALIFY clause requires at least one window function in the SELECT list or the QUALIFY clause",
+            )
+            .set_span(qualify.span()));
+        }
+
+        let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
+        let scalar = rewriter.visit(&scalar)?;
+        let scalar = self.rewrite_qualify_window_functions(bind_context, &scalar)?;
+        bind_context.set_expr_context(last_expr_context);
+        Ok((scalar, qualify.span()))
+    }
+
+    /// The window functions already analyzed in the select list (e.g. referred by alias)
+    /// are kept as is, so that they are evaluated only once.
+    fn rewrite_qualify_window_functions(
+        &self,
+        bind_context: &mut BindContext,
+        scalar: &ScalarExpr,
+    ) -> Result<ScalarExpr> {
+        match scalar {
+            ScalarExpr::WindowFunction(window)
+                if bind_context
+                    .windows
+                    .window_functions_map
+                    .contains_key(&window.display_name) =>
+            {
+                Ok(scalar.clone())
+            }
+            ScalarExpr::FunctionCall(func) => {
+                let arguments = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.rewrite_qualify_window_functions(bind_context, arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(FunctionCall {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    params: func.params.clone(),
+                    arguments,
+                }
+                .into())
+            }
+            ScalarExpr::CastExpr(cast) => Ok(CastExpr {
+                span: cast.span,
+                is_try: cast.is_try,
+                argument: Box::new(
+                    self.rewrite_qualify_window_functions(bind_context, &cast.argument)?,
+                ),
+                target_type: cast.target_type.clone(),
+            }
+            .into()),
+            _ => {
+                let mut rewriter = WindowRewriter::new(bind_context, self.metadata.clone());
+                rewriter.visit(scalar)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(super) async fn bind_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        qualify: ScalarExpr,
+        span: Span,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+
+        let scalar = if bind_context.in_grouping {
+            let grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(&qualify, span)?
+        } else {
+            let window_checker = WindowChecker::new(bind_context);
+            window_checker.resolve(&qualify)?
+        };
+
+        let filter = Filter {
+            predicates: split_conjunctions(&scalar),
+            is_having: false,
+        };
+
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
 }
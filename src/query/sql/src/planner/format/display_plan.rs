@@ -34,6 +34,11 @@ impl Plan {
 
             Plan::Call(plan) => Ok(format!("{:?}", plan)),
 
+            // transaction
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
+
             // catalog
             Plan::ShowCreateCatalog(show_create_catalog) => {
                 Ok(format!("{:?}", show_create_catalog))
@@ -62,6 +67,8 @@ impl Plan {
                 Ok(format!("{:?}", modify_table_column))
             }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -79,6 +86,16 @@ impl Plan {
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(format!("{:?}", create_materialized_view))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(format!("{:?}", refresh_materialized_view))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
@@ -103,6 +120,7 @@ impl Plan {
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
@@ -125,6 +143,11 @@ impl Plan {
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+
+            // Workload group
+            Plan::CreateWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AlterWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::DropWorkloadGroup(p) => Ok(format!("{:?}", p)),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
@@ -156,6 +179,11 @@ impl Plan {
             Plan::CreateDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
+
+            // row access policy
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
         }
     }
 }
@@ -76,6 +76,8 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -119,6 +121,19 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
             )
         }
         ScalarExpr::SubqueryExpr(_) => "SUBQUERY".to_string(),
+        ScalarExpr::LambdaFunction(lambda) => {
+            format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display,
+            )
+        }
     }
 }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -58,6 +59,10 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Number of recursive CTEs, used to generate their ids.
+    recursive_ctes: usize,
+    /// Columns referenced by the query, used to check column-level privileges.
+    referenced_columns: ColumnSet,
 }
 
 impl Metadata {
@@ -122,6 +127,30 @@ impl Metadata {
         &self.lazy_columns
     }
 
+    pub fn add_referenced_column(&mut self, index: IndexType) {
+        self.referenced_columns.insert(index);
+    }
+
+    /// Names of the columns of the table referenced by the query.
+    /// An inner field of a tuple column is reported as the tuple column itself.
+    pub fn referenced_column_names(&self, index: IndexType) -> BTreeSet<String> {
+        self.referenced_columns
+            .iter()
+            .filter_map(|column_index| match self.column(*column_index) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index,
+                    column_name,
+                    path_indices,
+                    ..
+                }) if *table_index == index => match path_indices {
+                    Some(_) => column_name.split(':').next().map(|name| name.to_string()),
+                    None => Some(column_name.clone()),
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
@@ -183,6 +212,12 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_recursive_cte(&mut self) -> IndexType {
+        let cte_id = self.recursive_ctes;
+        self.recursive_ctes += 1;
+        cte_id
+    }
+
     pub fn add_internal_column(
         &mut self,
         table_index: IndexType,
@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
@@ -771,6 +772,22 @@ impl SubqueryRewriter {
                     target_type: cast_expr.target_type.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.flatten_scalar(arg, correlated_columns))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }))
+            }
             _ => Err(ErrorCode::Internal(
                 "Invalid scalar for flattening subquery",
             )),
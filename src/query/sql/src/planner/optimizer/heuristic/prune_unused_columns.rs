@@ -254,7 +254,23 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::RecursiveCte(p) => {
+                // The anchor term and the recursive term must produce all the columns,
+                // which are fed back to the recursive term by position.
+                Ok(SExpr::create_binary(
+                    Arc::new(RelOperator::RecursiveCte(p.clone())),
+                    Arc::new(self.keep_required_columns(
+                        expr.child(0)?,
+                        p.anchor_columns.iter().cloned().collect(),
+                    )?),
+                    Arc::new(self.keep_required_columns(
+                        expr.child(1)?,
+                        p.recursive_columns.iter().cloned().collect(),
+                    )?),
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
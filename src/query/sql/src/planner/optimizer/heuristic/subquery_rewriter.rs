@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -143,18 +144,22 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
-                Arc::new(s_expr.plan().clone()),
-                Arc::new(self.rewrite(s_expr.child(0)?)?),
-                Arc::new(self.rewrite(s_expr.child(1)?)?),
-            )),
+            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+                Ok(SExpr::create_binary(
+                    Arc::new(s_expr.plan().clone()),
+                    Arc::new(self.rewrite(s_expr.child(0)?)?),
+                    Arc::new(self.rewrite(s_expr.child(1)?)?),
+                ))
+            }
 
             RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
                 Arc::new(s_expr.plan().clone()),
                 Arc::new(self.rewrite(s_expr.child(0)?)?),
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::Scan(_)
+            | RelOperator::RecursiveCteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -205,6 +210,28 @@ impl SubqueryRewriter {
                     s_expr,
                 ))
             }
+            ScalarExpr::LambdaFunction(lambda) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
             ScalarExpr::SubqueryExpr(subquery) => {
                 // Rewrite subquery recursively
                 let mut subquery = subquery.clone();
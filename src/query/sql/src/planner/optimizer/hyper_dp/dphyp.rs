@@ -232,7 +232,9 @@ impl DPhpy {
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
             | RelOperator::DummyTableScan(_)
-            | RelOperator::RuntimeFilterSource(_) => Ok((s_expr, false)),
+            | RelOperator::RuntimeFilterSource(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_) => Ok((s_expr, false)),
         }
     }
 
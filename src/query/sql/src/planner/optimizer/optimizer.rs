@@ -28,6 +28,7 @@ use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
@@ -147,6 +148,7 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    let contains_recursive_cte = contains_recursive_cte(&s_expr);
 
     let heuristic =
         HeuristicOptimizer::new(ctx.get_function_context()?, bind_context, metadata.clone());
@@ -162,9 +164,10 @@ pub fn optimize_query(
         result = cascades.optimize(result)?;
     }
     // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
+    // with reading data from local tales(e.g. system tables) or with recursive CTE.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_recursive_cte;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
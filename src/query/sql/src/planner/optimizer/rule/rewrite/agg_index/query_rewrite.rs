@@ -30,6 +30,7 @@ use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::RelOperator;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
@@ -201,6 +202,12 @@ fn rewrite_scalar_index(columns: &HashMap<String, IndexType>, scalar: &mut Scala
         ScalarExpr::CastExpr(cast) => {
             rewrite_scalar_index(columns, &mut cast.argument);
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            lambda
+                .args
+                .iter_mut()
+                .for_each(|arg| rewrite_scalar_index(columns, arg));
+        }
         _ => { /*  do nothing */ }
     }
 }
@@ -528,6 +535,17 @@ impl RewriteInfomartion<'_> {
                 self.format_scalar(&cast.argument),
                 cast.target_type
             ),
+            ScalarExpr::LambdaFunction(lambda) => format!(
+                "{}({}, {})",
+                &lambda.func_name,
+                lambda
+                    .args
+                    .iter()
+                    .map(|arg| { self.format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                &lambda.lambda_display
+            ),
             ScalarExpr::AggregateFunction(agg) => {
                 format!(
                     "{}<{}>({})",
@@ -834,6 +852,25 @@ fn rewrite_query_item(
                 .into(),
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            let mut new_args = Vec::with_capacity(lambda.args.len());
+            for arg in lambda.args.iter() {
+                let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
+                new_args.push(new_arg);
+            }
+            Some(
+                LambdaFunc {
+                    span: None,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into(),
+            )
+        }
         ScalarExpr::AggregateFunction(_) => None, /* Aggregate function must appear in index selection. */
         _ => unreachable!(), // Window function and subquery will not appear in index.
     }
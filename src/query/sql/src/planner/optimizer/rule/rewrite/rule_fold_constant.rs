@@ -172,6 +172,11 @@ impl RuleFoldConstant {
                 }
             }
             ScalarExpr::CastExpr(cast) => self.fold_constant(&mut cast.argument)?,
+            ScalarExpr::LambdaFunction(lambda) => {
+                for arg in lambda.args.iter_mut() {
+                    self.fold_constant(arg)?
+                }
+            }
             _ => (),
         }
 
@@ -28,6 +28,7 @@ use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
@@ -251,6 +252,19 @@ fn replace_column_binding(
             argument: Box::new(replace_column_binding(index_pairs, *(expr.argument))?),
             target_type: expr.target_type,
         })),
+        ScalarExpr::LambdaFunction(expr) => Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+            span: expr.span,
+            func_name: expr.func_name,
+            display_name: expr.display_name,
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<_>>>()?,
+            lambda_expr: expr.lambda_expr,
+            lambda_display: expr.lambda_display,
+            return_type: expr.return_type,
+        })),
         ScalarExpr::SubqueryExpr(_) => Err(ErrorCode::Unimplemented(
             "replace_column_binding: don't support subquery",
         )),
@@ -16,16 +16,34 @@ use std::cmp;
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_vector::VectorMetric;
 
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::plans::VectorSearch;
+use crate::TypeCheck;
 
 /// Input:  Sort
 ///           \
@@ -35,6 +53,22 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           LogicalGet(padding order_by and limit)
+///
+/// Input:  Sort
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet(padding vector_search)
+///
+/// The second pattern only applies to `ORDER BY <distance function>(<column>, <constant vector>) LIMIT <n>`,
+/// which can be answered by the vector indexes of the table.
 
 pub struct RulePushDownSortScan {
     id: RuleID,
@@ -45,20 +79,44 @@ impl RulePushDownSortScan {
     pub fn new() -> Self {
         Self {
             id: RuleID::PushDownSortScan,
-            patterns: vec![SExpr::create_unary(
-                Arc::new(
-                    PatternPlan {
-                        plan_type: RelOp::Sort,
-                    }
-                    .into(),
+            patterns: vec![
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Sort,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
                 ),
-                Arc::new(SExpr::create_leaf(Arc::new(
-                    PatternPlan {
-                        plan_type: RelOp::Scan,
-                    }
-                    .into(),
-                ))),
-            )],
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Sort,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::EvalScalar,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_leaf(Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ))),
+                    )),
+                ),
+            ],
         }
     }
 }
@@ -71,6 +129,9 @@ impl Rule for RulePushDownSortScan {
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let sort: Sort = s_expr.plan().clone().try_into()?;
         let child = s_expr.child(0)?;
+        if let RelOperator::EvalScalar(eval_scalar) = child.plan() {
+            return self.apply_vector_search(s_expr, &sort, eval_scalar, state);
+        }
         let mut get: Scan = child.plan().clone().try_into()?;
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
@@ -90,3 +151,94 @@ impl Rule for RulePushDownSortScan {
         &self.patterns
     }
 }
+
+impl RulePushDownSortScan {
+    fn apply_vector_search(
+        &self,
+        s_expr: &SExpr,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        state: &mut TransformResult,
+    ) -> Result<()> {
+        let child = s_expr.child(0)?;
+        let mut get: Scan = child.child(0)?.plan().clone().try_into()?;
+        if get.vector_search.is_some() {
+            return Ok(());
+        }
+        let vector_search = match Self::vector_search(sort, eval_scalar, &get) {
+            Some(vector_search) => vector_search,
+            None => return Ok(()),
+        };
+        get.vector_search = Some(vector_search);
+
+        let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+        let child = child.replace_children(vec![Arc::new(get)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(child)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    // Matches `ORDER BY <distance function>(<column>, <constant vector>) LIMIT <n>`,
+    // where the order puts the nearest vectors first.
+    fn vector_search(sort: &Sort, eval_scalar: &EvalScalar, get: &Scan) -> Option<VectorSearch> {
+        let limit = sort.limit?;
+        let order = sort.items.first()?;
+        let item = eval_scalar.items.iter().find(|i| i.index == order.index)?;
+        let func = match &item.scalar {
+            ScalarExpr::FunctionCall(func) if func.arguments.len() == 2 => func,
+            _ => return None,
+        };
+        let metric = VectorMetric::from_function_name(&func.func_name)?;
+        if metric.nearest_first_asc() != order.asc {
+            return None;
+        }
+
+        let (column, query) = match &func.arguments[..] {
+            [
+                ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }),
+                ScalarExpr::ConstantExpr(ConstantExpr { value, .. }),
+            ]
+            | [
+                ScalarExpr::ConstantExpr(ConstantExpr { value, .. }),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }),
+            ] => (column, value),
+            _ => return None,
+        };
+        if column.table_index != Some(get.table_index) {
+            return None;
+        }
+
+        Some(VectorSearch {
+            column: column.index,
+            metric,
+            query: Self::vector_constant(query)?,
+            limit,
+        })
+    }
+
+    // Casts a constant array, e.g. `[1, 2.5, 3]`, to the `Array(Float32)` the distance functions take.
+    fn vector_constant(value: &Scalar) -> Option<Vec<F32>> {
+        let cast = ScalarExpr::CastExpr(CastExpr {
+            span: None,
+            is_try: false,
+            argument: Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+                span: None,
+                value: value.clone(),
+            })),
+            target_type: Box::new(DataType::Array(Box::new(DataType::Number(
+                NumberDataType::Float32,
+            )))),
+        });
+        let expr = cast.type_check().ok()?;
+        let (expr, _) =
+            ConstantFolder::fold(&expr, &FunctionContext::default(), &BUILTIN_FUNCTIONS);
+        match expr {
+            Expr::Constant {
+                scalar: Scalar::Array(array),
+                ..
+            } => Some(Float32Type::try_downcast_column(&array)?.to_vec()),
+            _ => None,
+        }
+    }
+}
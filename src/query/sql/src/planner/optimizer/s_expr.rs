@@ -251,6 +251,8 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::RuntimeFilterSource(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
@@ -306,5 +308,6 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
+        ScalarExpr::LambdaFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
     }
 }
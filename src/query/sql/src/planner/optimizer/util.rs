@@ -31,6 +31,15 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         }
 }
 
+/// Check if a query contains recursive CTE, whose iterations can only be executed locally.
+pub fn contains_recursive_cte(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::RecursiveCte(_))
+        || s_expr
+            .children()
+            .iter()
+            .any(|child| contains_recursive_cte(child))
+}
+
 /// Check the expr contains ProjectSet op.
 pub fn contains_project_set(s_expr: &SExpr) -> bool {
     if let Some(child) = s_expr.children().iter().next() {
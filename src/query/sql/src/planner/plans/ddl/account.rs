@@ -23,12 +23,14 @@ use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateUserPlan {
     pub user: UserIdentity,
     pub auth_info: AuthInfo,
     pub user_option: UserOption,
+    pub quota: UserQuota,
     pub if_not_exists: bool,
 }
 
@@ -38,6 +40,7 @@ pub struct AlterUserPlan {
     // None means no change to make
     pub auth_info: Option<AuthInfo>,
     pub user_option: Option<UserOption>,
+    pub quota: Option<UserQuota>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -105,12 +108,12 @@ impl ShowRolesPlan {
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RevokePrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
@@ -18,10 +18,12 @@ mod database;
 mod file_format;
 mod index;
 mod stage;
+mod stream;
 mod table;
 mod udf;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use account::*;
 pub use catalog::*;
@@ -29,7 +31,9 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
@@ -216,6 +216,37 @@ impl ModifyTableColumnPlan {
     }
 }
 
+// Table add row access policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop row access policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateTablePlan {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::plans::CreateTablePlan;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +43,21 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    /// Creates the fuse table holding the result of the view query,
+    /// the same as `CREATE TABLE ... AS SELECT ...`.
+    pub create_table_plan: CreateTablePlan,
+    /// The table that the view query reads from.
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_meta_types::MetaId;
+
+use crate::plans::Plan;
+
+/// MERGE INTO is planned as a query over `source LEFT OUTER JOIN target`, which
+/// produces one row per matched target row and per unmatched source row:
+///
+/// - the values of the target table columns after the merge clauses are applied,
+/// - the values of the `on_conflict_fields` of the matched target row, NULL if the
+///   source row is not matched,
+/// - a boolean column telling if the row should be written to the table.
+///
+/// The existing rows with the matched keys are removed, and the rows to write are
+/// appended, in a single commit.
+#[derive(Clone)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    pub schema: TableSchemaRef,
+    pub on_conflict_fields: Vec<TableField>,
+    pub input: Box<Plan>,
+}
+
+impl PartialEq for MergeInto {
+    fn eq(&self, other: &Self) -> bool {
+        self.catalog == other.catalog
+            && self.database == other.database
+            && self.table == other.table
+            && self.schema == other.schema
+            && self.on_conflict_fields == other.on_conflict_fields
+    }
+}
+
+impl MergeInto {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl std::fmt::Debug for MergeInto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeInto")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .field("schema", &self.schema)
+            .field("on conflict", &self.on_conflict_fields)
+            .finish()
+    }
+}
@@ -26,14 +26,18 @@ pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod runtime_filter_source;
 mod scalar_expr;
 mod scan;
@@ -59,6 +63,7 @@ pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use merge_into::MergeInto;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
@@ -67,8 +72,11 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
 pub use scalar_expr::*;
@@ -36,6 +36,8 @@ use crate::optimizer::StatInfo;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -76,6 +78,8 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    RecursiveCte,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -97,6 +101,8 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     Pattern(PatternPlan),
 }
@@ -118,6 +124,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -137,6 +145,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -156,6 +166,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -175,6 +187,8 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -228,6 +242,12 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -492,3 +512,43 @@ impl TryFrom<RelOperator> for ProjectSet {
         }
     }
 }
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
@@ -46,43 +46,56 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnsPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnsPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GenerateVirtualColumnsPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTablePlan;
@@ -141,6 +154,11 @@ pub enum Plan {
     // Call
     Call(Box<CallPlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Catalogs
     ShowCreateCatalog(Box<ShowCreateCatalogPlan>),
     CreateCatalog(Box<CreateCatalogPlan>),
@@ -164,6 +182,8 @@ pub enum Plan {
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
@@ -179,11 +199,18 @@ pub enum Plan {
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeInto>),
 
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
 
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
@@ -205,6 +232,11 @@ pub enum Plan {
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
 
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Role
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
@@ -252,6 +284,11 @@ pub enum Plan {
     CreateDatamaskPolicy(Box<CreateDatamaskPolicyPlan>),
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
+
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -276,6 +313,7 @@ pub enum RewriteKind {
     DescribeStage,
     ListStage,
     ShowRoles,
+    ShowWorkloadGroups,
 }
 
 impl Display for Plan {
@@ -302,6 +340,8 @@ impl Display for Plan {
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
@@ -314,6 +354,10 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
+            Plan::CreateStream(_) => write!(f, "CreateStream"),
+            Plan::DropStream(_) => write!(f, "DropStream"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
@@ -340,11 +384,18 @@ impl Display for Plan {
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::CreateWorkloadGroup(_) => write!(f, "CreateWorkloadGroup"),
+            Plan::AlterWorkloadGroup(_) => write!(f, "AlterWorkloadGroup"),
+            Plan::DropWorkloadGroup(_) => write!(f, "DropWorkloadGroup"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Call(_) => write!(f, "Call"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
@@ -374,6 +425,15 @@ impl Display for Plan {
             Plan::DescDatamaskPolicy(..) => {
                 write!(f, "Desc Data Mask Policy")
             }
+            Plan::CreateRowAccessPolicy(..) => {
+                write!(f, "Create Row Access Policy")
+            }
+            Plan::DropRowAccessPolicy(..) => {
+                write!(f, "Drop Row Access Policy")
+            }
+            Plan::DescRowAccessPolicy(..) => {
+                write!(f, "Desc Row Access Policy")
+            }
         }
     }
 }
@@ -417,6 +477,9 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             other => {
                 debug_assert!(!other.has_result_set());
                 Arc::new(DataSchema::empty())
@@ -447,6 +510,7 @@ impl Plan {
                 | Plan::Presign(_)
                 | Plan::VacuumTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
         )
     }
 }
@@ -0,0 +1,122 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Recursive common table expression, e.g.
+/// `WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 10)`.
+///
+/// The left child is the anchor term and the right child is the recursive term,
+/// which reads the rows produced by the previous iteration with `RecursiveCteScan`.
+/// The recursive term is evaluated repeatedly until it produces no new rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    /// Identifies the working table shared with the `RecursiveCteScan`s of the recursive term.
+    pub cte_id: IndexType,
+    /// Whether the anchor term and the recursive term are combined with `UNION` instead of `UNION ALL`.
+    pub distinct: bool,
+    /// Output columns of the recursive CTE.
+    pub columns: Vec<IndexType>,
+    /// Output columns of the anchor term, in the order of `columns`.
+    pub anchor_columns: Vec<IndexType>,
+    /// Output columns of the recursive term, in the order of `columns`.
+    pub recursive_columns: Vec<IndexType>,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.extend(self.anchor_columns.iter());
+        used_columns.extend(self.recursive_columns.iter());
+        Ok(used_columns)
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive output columns
+        let output_columns = self.columns.iter().cloned().collect();
+
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns.clone());
+        used_columns.extend(right_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        // The iterations are driven by a single processor on the local node.
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // The number of iterations is unknown, so only the anchor term and
+        // one iteration of the recursive term are taken into account.
+        let left_stat_info = rel_expr.derive_cardinality_child(0)?;
+        let right_stat_info = rel_expr.derive_cardinality_child(1)?;
+        Ok(Arc::new(StatInfo {
+            cardinality: left_stat_info.cardinality + right_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
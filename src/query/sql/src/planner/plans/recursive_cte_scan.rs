@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Reads the rows produced by the previous iteration of a `RecursiveCte`,
+/// i.e. the self-reference in the recursive term.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCteScan {
+    /// The `cte_id` of the `RecursiveCte` this scan belongs to.
+    pub cte_id: IndexType,
+    /// Output columns, in the order of the columns of the recursive CTE.
+    pub columns: Vec<IndexType>,
+}
+
+impl RecursiveCteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.columns.iter().cloned().collect())
+    }
+}
+
+impl Operator for RecursiveCteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        Ok(Arc::new(RelationalProperty {
+            output_columns: self.used_columns()?,
+            outer_columns: ColumnSet::new(),
+            used_columns: self.used_columns()?,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, _rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // The size of the working table is unknown until execution.
+        Ok(Arc::new(StatInfo {
+            cardinality: 1.0,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
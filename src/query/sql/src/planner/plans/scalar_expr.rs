@@ -21,6 +21,7 @@ use common_exception::Range;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use educe::Educe;
 use itertools::Itertools;
@@ -42,6 +43,7 @@ pub enum ScalarExpr {
     FunctionCall(FunctionCall),
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
+    LambdaFunction(LambdaFunc),
 }
 
 impl ScalarExpr {
@@ -79,6 +81,13 @@ impl ScalarExpr {
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.args {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
         }
     }
 
@@ -108,6 +117,13 @@ impl ScalarExpr {
                 Ok(result)
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_tables(metadata),
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = vec![];
+                for scalar in &scalar.args {
+                    result.append(&mut scalar.used_tables(metadata.clone())?);
+                }
+                Ok(result)
+            }
             ScalarExpr::WindowFunction(_) | ScalarExpr::SubqueryExpr(_) => {
                 Err(ErrorCode::Unimplemented(
                     "SubqueryExpr/WindowFunction doesn't support used_tables method".to_string(),
@@ -132,6 +148,7 @@ impl ScalarExpr {
             }),
             ScalarExpr::CastExpr(expr) => expr.span.or(expr.argument.span()),
             ScalarExpr::SubqueryExpr(expr) => expr.span,
+            ScalarExpr::LambdaFunction(expr) => expr.span,
             _ => None,
         }
     }
@@ -248,6 +265,23 @@ impl TryFrom<ScalarExpr> for CastExpr {
     }
 }
 
+impl From<LambdaFunc> for ScalarExpr {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to LambdaFunc"))
+        }
+    }
+}
+
 impl From<SubqueryExpr> for ScalarExpr {
     fn from(v: SubqueryExpr) -> Self {
         Self::SubqueryExpr(v)
@@ -414,6 +448,22 @@ pub struct CastExpr {
     pub target_type: Box<DataType>,
 }
 
+/// A higher-order function call like `array_transform(arr, x -> x + 1)`.
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct LambdaFunc {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    pub display_name: String,
+    pub args: Vec<ScalarExpr>,
+    /// The type-checked lambda body, whose column refs are the lambda parameters by position.
+    #[educe(Hash(ignore))]
+    pub lambda_expr: Box<RemoteExpr>,
+    pub lambda_display: String,
+    pub return_type: Box<DataType>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum SubqueryType {
     Any,
@@ -20,6 +20,8 @@ use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::F32;
+use common_vector::VectorMetric;
 use itertools::Itertools;
 
 use crate::optimizer::histogram_from_ndv;
@@ -60,6 +62,16 @@ pub struct AggIndexInfo {
     pub predicates: Vec<ScalarExpr>,
 }
 
+/// Approximate nearest neighbor search, pushed down from
+/// `ORDER BY <distance function>(<column>, <constant vector>) LIMIT <n>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorSearch {
+    pub column: IndexType,
+    pub metric: VectorMetric,
+    pub query: Vec<F32>,
+    pub limit: usize,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -77,6 +89,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub vector_search: Option<VectorSearch>,
 
     pub statistics: Statistics,
 }
@@ -103,6 +116,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            vector_search: self.vector_search.clone(),
         }
     }
 
@@ -33,6 +33,7 @@ impl VisitorMut for DistinctToGroupBy {
             group_by,
             having,
             window_list,
+            qualify,
             ..
         } = stmt;
 
@@ -66,6 +67,7 @@ impl VisitorMut for DistinctToGroupBy {
                             group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
                             window_list: None,
+                            qualify: None,
                         })),
                         order_by: vec![],
                         limit: vec![],
@@ -89,6 +91,7 @@ impl VisitorMut for DistinctToGroupBy {
                                 args: vec![],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             }),
                             alias: alias.clone(),
                         }],
@@ -101,6 +104,7 @@ impl VisitorMut for DistinctToGroupBy {
                         group_by: None,
                         having: having.clone(),
                         window_list: window_list.clone(),
+                        qualify: qualify.clone(),
                     };
 
                     *stmt = new_stmt;
@@ -21,6 +21,7 @@ use crate::binder::Visibility;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::BindContext;
 
@@ -108,6 +109,23 @@ impl<'a> GroupingChecker<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::SubqueryExpr(_) => {
                 // TODO(leiysky): check subquery in the future
                 Ok(scalar.clone())
@@ -122,6 +122,27 @@ fn resolve_column_type<C: LoweringContext>(
                 args,
             })
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| resolve_column_type(arg, context))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(RawExpr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+                return_type: return_type.clone(),
+            })
+        }
         RawExpr::Constant { .. } => Ok(raw_expr.clone()),
     }
 }
@@ -236,6 +257,14 @@ impl ScalarExpr {
                 data_type: subquery.data_type(),
                 display_name: "DUMMY".to_string(),
             },
+            ScalarExpr::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: func.span,
+                name: func.func_name.clone(),
+                args: func.args.iter().map(ScalarExpr::as_raw_expr).collect(),
+                lambda_expr: func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+                return_type: (*func.return_type).clone(),
+            },
         }
     }
 
@@ -21,6 +21,7 @@ use common_ast::ast::BinaryOperator;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
@@ -58,7 +59,10 @@ use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
 use common_users::UserApiProvider;
 use simsearch::SimSearch;
 
@@ -77,6 +81,7 @@ use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
@@ -190,6 +195,7 @@ impl<'a> TypeChecker<'a> {
                 )?;
                 let (scalar, data_type) = match result {
                     NameResolutionResult::Column(column) => {
+                        self.metadata.write().add_referenced_column(column.index);
                         let data_type = *column.data_type.clone();
                         (
                             BoundColumnRef {
@@ -321,6 +327,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -530,6 +537,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -580,9 +588,16 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                if let Some(lambda) = lambda {
+                    let display_name = format!("{:#}", expr);
+                    return self
+                        .resolve_lambda_function(*span, func_name, display_name, args, lambda)
+                        .await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
@@ -809,12 +824,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1667,6 +1677,28 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve `INTERVAL <expr> <unit>` to an `Interval` value, e.g. `INTERVAL 3 DAY` is `to_days(3)`.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        match interval_kind {
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => Err(ErrorCode::SemanticError(format!(
+                "Unsupported interval unit: {interval_kind}"
+            ))
+            .set_span(span)),
+            _ => {
+                let func_name = format!("to_{}s", interval_kind.to_string().to_lowercase());
+                self.resolve_function(span, &func_name, vec![], &[expr])
+                    .await
+            }
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_add(
@@ -1955,6 +1987,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -1991,6 +2024,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -2384,6 +2418,13 @@ impl<'a> TypeChecker<'a> {
             return Ok(None);
         };
 
+        self.ctx
+            .validate_privilege(&GrantObject::UDF(udf.name.clone()), vec![
+                UserPrivilegeType::Usage,
+            ])
+            .await
+            .map_err(|e| e.set_span(span))?;
+
         let parameters = udf.parameters;
         if parameters.len() != arguments.len() {
             return Err(ErrorCode::SyntaxException(format!(
@@ -2417,6 +2458,325 @@ impl<'a> TypeChecker<'a> {
         Ok(Some(self.resolve(&udf_expr).await?))
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        display_name: String,
+        args: &[Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} does not support lambda expression"
+            ))
+            .set_span(span));
+        }
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for lambda function, {func_name} expects 1 argument, but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        let params = lambda
+            .params
+            .iter()
+            .map(|param| normalize_identifier(param, self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+        let expected_params = match func_name {
+            "array_reduce" | "array_sort" | "map_filter" => 2,
+            _ => 1,
+        };
+        if params.len() != expected_params {
+            return Err(ErrorCode::SemanticError(format!(
+                "incorrect number of parameters in lambda function, {func_name} expects {expected_params} parameter(s), but got {}",
+                params.len()
+            ))
+            .set_span(span));
+        }
+
+        let box (arg, arg_type) = self.resolve(&args[0]).await?;
+        if func_name == "map_filter" {
+            return self
+                .resolve_map_lambda_function(
+                    span,
+                    func_name,
+                    display_name,
+                    arg,
+                    arg_type,
+                    params,
+                    lambda,
+                )
+                .await;
+        }
+        let mut arg = arg;
+        let mut inner_ty = match arg_type.remove_nullable() {
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            // The lambda expression is never evaluated on an empty array.
+            DataType::EmptyArray => {
+                if func_name == "array_reduce" {
+                    return Ok(Box::new((
+                        ConstantExpr {
+                            span,
+                            value: Scalar::Null,
+                        }
+                        .into(),
+                        DataType::Null,
+                    )));
+                }
+                return Ok(Box::new((arg, arg_type)));
+            }
+            DataType::Array(box inner_ty) => inner_ty,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {func_name} expects an array argument, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let (lambda_scalar, return_type) = match func_name {
+            "array_transform" => {
+                let (lambda_scalar, lambda_type) = self
+                    .resolve_lambda_body(
+                        &params,
+                        &vec![inner_ty.clone(); params.len()],
+                        &lambda.expr,
+                    )
+                    .await?;
+                let return_type = DataType::Array(Box::new(lambda_type));
+                (lambda_scalar, return_type)
+            }
+            "array_filter" => {
+                let (lambda_scalar, lambda_type) = self
+                    .resolve_lambda_body(
+                        &params,
+                        &vec![inner_ty.clone(); params.len()],
+                        &lambda.expr,
+                    )
+                    .await?;
+                let lambda_scalar = match lambda_type.remove_nullable() {
+                    DataType::Boolean => lambda_scalar,
+                    DataType::Null => wrap_cast(&lambda_scalar, &DataType::Boolean.wrap_nullable()),
+                    _ => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "the lambda expression of array_filter must return a boolean, but got {lambda_type}"
+                        ))
+                        .set_span(span));
+                    }
+                };
+                (lambda_scalar, DataType::Array(Box::new(inner_ty)))
+            }
+            "array_reduce" => {
+                let (mut lambda_scalar, mut lambda_type) = self
+                    .resolve_lambda_body(
+                        &params,
+                        &vec![inner_ty.clone(); params.len()],
+                        &lambda.expr,
+                    )
+                    .await?;
+                if lambda_type != inner_ty {
+                    // Promote the elements to the result type of the lambda expression,
+                    // e.g. `(acc, x) -> acc + x` on `Array(UInt8)` accumulates with `UInt16`.
+                    let acc_type = if lambda_type == DataType::Null {
+                        inner_ty.wrap_nullable()
+                    } else {
+                        lambda_type.clone()
+                    };
+                    let array_type = DataType::Array(Box::new(acc_type.clone()));
+                    let array_type = if arg_type.is_nullable() {
+                        array_type.wrap_nullable()
+                    } else {
+                        array_type
+                    };
+                    arg = wrap_cast(&arg, &array_type);
+                    inner_ty = acc_type;
+                    (lambda_scalar, lambda_type) = self
+                        .resolve_lambda_body(
+                            &params,
+                            &vec![inner_ty.clone(); params.len()],
+                            &lambda.expr,
+                        )
+                        .await?;
+                    if lambda_type != inner_ty {
+                        lambda_scalar = wrap_cast(&lambda_scalar, &inner_ty);
+                    }
+                }
+                (lambda_scalar, inner_ty.wrap_nullable())
+            }
+            "array_sort" => {
+                let (lambda_scalar, lambda_type) = self
+                    .resolve_lambda_body(
+                        &params,
+                        &vec![inner_ty.clone(); params.len()],
+                        &lambda.expr,
+                    )
+                    .await?;
+                if !lambda_type.remove_nullable().is_numeric() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "the lambda expression of array_sort must return a number, but got {lambda_type}"
+                    ))
+                    .set_span(span));
+                }
+                let lambda_scalar = if lambda_type != DataType::Number(NumberDataType::Int64) {
+                    wrap_cast(&lambda_scalar, &DataType::Number(NumberDataType::Int64))
+                } else {
+                    lambda_scalar
+                };
+                (lambda_scalar, DataType::Array(Box::new(inner_ty)))
+            }
+            _ => unreachable!(),
+        };
+        let return_type = if arg_type.is_nullable() && !return_type.is_nullable() {
+            return_type.wrap_nullable()
+        } else {
+            return_type
+        };
+
+        let lambda_expr = lambda_scalar.type_check()?.as_remote_expr();
+        let lambda_func = LambdaFunc {
+            span,
+            func_name: func_name.to_string(),
+            display_name,
+            args: vec![arg],
+            lambda_expr: Box::new(lambda_expr),
+            lambda_display: lambda.to_string(),
+            return_type: Box::new(return_type.clone()),
+        };
+        Ok(Box::new((lambda_func.into(), return_type)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    async fn resolve_map_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        display_name: String,
+        arg: ScalarExpr,
+        arg_type: DataType,
+        params: Vec<String>,
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let kv_types = match arg_type.remove_nullable() {
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            // The lambda expression is never evaluated on an empty map.
+            DataType::EmptyMap => return Ok(Box::new((arg, arg_type))),
+            DataType::Map(box DataType::Tuple(kv_types)) => kv_types,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {func_name} expects a map argument, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let (lambda_scalar, lambda_type) = self
+            .resolve_lambda_body(&params, &kv_types, &lambda.expr)
+            .await?;
+        let lambda_scalar = match lambda_type.remove_nullable() {
+            DataType::Boolean => lambda_scalar,
+            DataType::Null => wrap_cast(&lambda_scalar, &DataType::Boolean.wrap_nullable()),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the lambda expression of {func_name} must return a boolean, but got {lambda_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        let lambda_expr = lambda_scalar.type_check()?.as_remote_expr();
+        let lambda_func = LambdaFunc {
+            span,
+            func_name: func_name.to_string(),
+            display_name,
+            args: vec![arg],
+            lambda_expr: Box::new(lambda_expr),
+            lambda_display: lambda.to_string(),
+            return_type: Box::new(arg_type.clone()),
+        };
+        Ok(Box::new((lambda_func.into(), arg_type)))
+    }
+
+    /// Resolve the body of a lambda expression in a new `BindContext`, the lambda
+    /// parameters are bound to the column indexes `0..params.len()`.
+    #[async_backtrace::framed]
+    async fn resolve_lambda_body(
+        &mut self,
+        params: &[String],
+        param_types: &[DataType],
+        expr: &Expr,
+    ) -> Result<(ScalarExpr, DataType)> {
+        let mut bind_context = BindContext::new();
+        for (index, (param, param_type)) in params.iter().zip(param_types.iter()).enumerate() {
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                table_index: None,
+                column_name: param.clone(),
+                index,
+                data_type: Box::new(param_type.clone()),
+                visibility: Visibility::Visible,
+            });
+        }
+        let mut type_checker = TypeChecker::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let box (scalar, data_type) = type_checker.resolve(expr).await?;
+        if !Self::check_lambda_body(&scalar, params.len()) {
+            return Err(ErrorCode::SemanticError(
+                "lambda expression can only contain lambda parameters, constants and scalar functions"
+                    .to_string(),
+            )
+            .set_span(expr.span()));
+        }
+        Ok((scalar, data_type))
+    }
+
+    fn check_lambda_body(scalar: &ScalarExpr, num_params: usize) -> bool {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => {
+                column_ref.column.table_index.is_none() && column_ref.column.index < num_params
+            }
+            ScalarExpr::ConstantExpr(_) => true,
+            ScalarExpr::FunctionCall(func) => func
+                .arguments
+                .iter()
+                .all(|arg| Self::check_lambda_body(arg, num_params)),
+            ScalarExpr::CastExpr(cast) => Self::check_lambda_body(&cast.argument, num_params),
+            ScalarExpr::LambdaFunction(func) => func
+                .args
+                .iter()
+                .all(|arg| Self::check_lambda_body(arg, num_params)),
+            _ => false,
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_map_access(
@@ -2604,6 +2964,7 @@ impl<'a> TypeChecker<'a> {
             Ok(result) => {
                 let (scalar, data_type) = match result {
                     NameResolutionResult::Column(column) => {
+                        self.metadata.write().add_referenced_column(column.index);
                         let data_type = *column.data_type.clone();
                         (BoundColumnRef { span, column }.into(), data_type)
                     }
@@ -2897,6 +3258,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2907,6 +3269,7 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: lambda.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -3032,8 +3395,11 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             })?)
         }
         TypeName::String => TableDataType::String,
+        TypeName::Binary => TableDataType::Binary,
+        TypeName::Geometry => TableDataType::Geometry,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type)?;
@@ -18,6 +18,7 @@ use common_exception::Result;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::ScalarExpr;
@@ -56,6 +57,23 @@ impl<'a> WindowChecker<'a> {
                 target_type: cast.target_type.clone(),
             }
             .into()),
+            ScalarExpr::LambdaFunction(lambda) => {
+                let args = lambda
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda.span,
+                    func_name: lambda.func_name.clone(),
+                    display_name: lambda.display_name.clone(),
+                    args,
+                    lambda_expr: lambda.lambda_expr.clone(),
+                    lambda_display: lambda.lambda_display.clone(),
+                    return_type: lambda.return_type.clone(),
+                }
+                .into())
+            }
             ScalarExpr::SubqueryExpr(_) => {
                 // TODO(leiysky): check subquery in the future
                 Ok(scalar.clone())
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
@@ -36,17 +38,26 @@ pub fn blocks_to_parquet(
     compression: TableCompression,
 ) -> Result<(u64, ThriftFileMetaData)> {
     let arrow_schema = schema.as_ref().to_arrow();
+    let batches = blocks
+        .into_iter()
+        .map(Chunk::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    chunks_to_parquet(arrow_schema, batches, write_buffer, compression)
+}
 
+/// Serialize arrow chunks to parquet format.
+pub fn chunks_to_parquet(
+    arrow_schema: ArrowSchema,
+    batches: Vec<Chunk<Box<dyn Array>>>,
+    write_buffer: &mut Vec<u8>,
+    compression: TableCompression,
+) -> Result<(u64, ThriftFileMetaData)> {
     let row_group_write_options = WriteOptions {
         write_statistics: false,
         version: Version::V2,
         compression: compression.into(),
         data_pagesize_limit: None,
     };
-    let batches = blocks
-        .into_iter()
-        .map(Chunk::try_from)
-        .collect::<Result<Vec<_>>>()?;
 
     let encoding_map = |data_type: &ArrowDataType| match data_type {
         ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
@@ -17,3 +17,4 @@
 mod block;
 
 pub use block::blocks_to_parquet;
+pub use block::chunks_to_parquet;
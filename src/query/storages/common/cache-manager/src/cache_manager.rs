@@ -35,11 +35,13 @@ use crate::caches::CompactSegmentInfoCache;
 use crate::caches::FileMetaDataCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
+use crate::caches::VectorIndexCache;
 use crate::ColumnArrayMeter;
 use crate::CompactSegmentInfoMeter;
 use crate::PrunePartitionsCache;
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
+static DEFAULT_VECTOR_INDEX_CACHE_ITEMS: u64 = 3000;
 
 /// Where all the caches reside
 pub struct CacheManager {
@@ -48,6 +50,7 @@ pub struct CacheManager {
     segment_info_cache: Option<CompactSegmentInfoCache>,
     bloom_index_filter_cache: Option<BloomIndexFilterCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
+    vector_index_cache: Option<VectorIndexCache>,
     prune_partitions_cache: Option<PrunePartitionsCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
@@ -88,6 +91,7 @@ impl CacheManager {
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
                 bloom_index_meta_cache: None,
+                vector_index_cache: None,
                 prune_partitions_cache: None,
                 file_meta_data_cache: None,
                 table_statistic_cache: None,
@@ -110,6 +114,8 @@ impl CacheManager {
                 config.table_bloom_index_meta_count,
                 "bloom_index_file_meta_data",
             );
+            let vector_index_cache =
+                Self::new_item_cache(DEFAULT_VECTOR_INDEX_CACHE_ITEMS, "vector_index");
             let prune_partitions_cache =
                 Self::new_item_cache(config.table_prune_partitions_count, "prune_partitions");
 
@@ -120,6 +126,7 @@ impl CacheManager {
                 segment_info_cache,
                 bloom_index_filter_cache,
                 bloom_index_meta_cache,
+                vector_index_cache,
                 prune_partitions_cache,
                 file_meta_data_cache,
                 table_statistic_cache,
@@ -155,6 +162,10 @@ impl CacheManager {
         self.bloom_index_meta_cache.clone()
     }
 
+    pub fn get_vector_index_cache(&self) -> Option<VectorIndexCache> {
+        self.vector_index_cache.clone()
+    }
+
     pub fn get_prune_partitions_cache(&self) -> Option<PrunePartitionsCache> {
         self.prune_partitions_cache.clone()
     }
@@ -0,0 +1,155 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::ArrayType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_vector::IvfIndex;
+
+/// Vector index of a block.
+///
+/// It keeps an [`IvfIndex`] of each indexed column, which tells how near the
+/// vectors of the block are to a query vector without reading the block.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VectorIndex {
+    pub indexes: BTreeMap<ColumnId, IvfIndex>,
+}
+
+impl VectorIndex {
+    pub const VERSION: u64 = 1;
+
+    /// Vector indexes can be built on `Array(Float32)` columns, which hold the embeddings.
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(
+            data_type.remove_nullable(),
+            TableDataType::Array(box TableDataType::Number(NumberDataType::Float32))
+        )
+    }
+
+    /// Builds the index of `columns` from a block of `source_schema`.
+    ///
+    /// The columns come from the table option, which is not updated when the table
+    /// is altered, so columns that are dropped, renamed or no longer hold vectors are skipped.
+    /// Returns `None` if none of the columns holds a vector.
+    pub fn try_create(
+        source_schema: &TableSchema,
+        columns: &[String],
+        block: &DataBlock,
+    ) -> Result<Option<Self>> {
+        let mut indexes = BTreeMap::new();
+        for name in columns {
+            let offset = match source_schema.index_of(name) {
+                Ok(offset) => offset,
+                Err(_) => continue,
+            };
+            let field = source_schema.field(offset);
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+
+            let entry = block.get_by_offset(offset);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            let (column, validity) = match column {
+                Column::Nullable(box nullable) => (nullable.column, Some(nullable.validity)),
+                column => (column, None),
+            };
+            let array = ArrayType::<Float32Type>::try_downcast_column(&column).unwrap();
+            let values = array.values.iter().map(|v| v.0).collect::<Vec<_>>();
+            let vectors = array
+                .offsets
+                .windows(2)
+                .enumerate()
+                .filter(|(row, range)| {
+                    range[0] < range[1] && validity.as_ref().map_or(true, |v| v.get_bit(*row))
+                })
+                .map(|(row, range)| (row as u32, &values[range[0] as usize..range[1] as usize]))
+                .collect::<Vec<_>>();
+
+            if let Some(index) = IvfIndex::build(&vectors) {
+                indexes.insert(field.column_id(), index);
+            }
+        }
+
+        if indexes.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(Self { indexes }))
+        }
+    }
+
+    /// Serializes the index in little endian:
+    ///
+    /// `version: u64, num_columns: u32, (column_id: u32, len: u32, index: [u8; len])*`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&Self::VERSION.to_le_bytes());
+        buf.extend_from_slice(&(self.indexes.len() as u32).to_le_bytes());
+        for (column_id, index) in &self.indexes {
+            let bytes = index.to_bytes();
+            buf.extend_from_slice(&column_id.to_le_bytes());
+            buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            buf.extend_from_slice(&bytes);
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let read_u32 = |bytes: &[u8], off: usize| -> Result<u32> {
+            bytes
+                .get(off..off + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+                .ok_or_else(|| ErrorCode::BadBytes("unexpected end of vector index"))
+        };
+
+        let version = bytes
+            .get(0..8)
+            .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+            .ok_or_else(|| ErrorCode::BadBytes("unexpected end of vector index"))?;
+        if version != Self::VERSION {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported vector index version {}, expected {}",
+                version,
+                Self::VERSION
+            )));
+        }
+
+        let num_columns = read_u32(bytes, 8)?;
+        let mut off = 12;
+        let mut indexes = BTreeMap::new();
+        for _ in 0..num_columns {
+            let column_id = read_u32(bytes, off)?;
+            let len = read_u32(bytes, off + 4)? as usize;
+            off += 8;
+            let index_bytes = bytes
+                .get(off..off + len)
+                .ok_or_else(|| ErrorCode::BadBytes("unexpected end of vector index"))?;
+            indexes.insert(column_id, IvfIndex::from_bytes(index_bytes)?);
+            off += len;
+        }
+
+        Ok(Self { indexes })
+    }
+}
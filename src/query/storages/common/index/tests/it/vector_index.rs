@@ -0,0 +1,89 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::Result;
+use common_expression::types::array::ArrayColumn;
+use common_expression::types::number::Float32Type;
+use common_expression::types::number::UInt8Type;
+use common_expression::types::AnyType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use storages_common_index::VectorIndex;
+
+#[test]
+fn test_vector_index() -> Result<()> {
+    let vector_type =
+        TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+    let schema = TableSchema::new(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("v", vector_type.clone()),
+        TableField::new("nv", vector_type.wrap_nullable()),
+    ]);
+
+    // Rows: [0, 0], [1, 1], [], [3, 3]
+    let vectors = || ArrayColumn::<AnyType> {
+        values: Float32Type::from_data(vec![0f32, 0.0, 1.0, 1.0, 3.0, 3.0]),
+        offsets: Buffer::<u64>::from(vec![0, 2, 4, 4, 6]),
+    };
+    let block = DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![0u8, 1, 2, 3]),
+        Column::Array(Box::new(vectors())),
+        Column::Array(Box::new(vectors()))
+            .wrap_nullable(Some(vec![true, false, true, true].into())),
+    ]);
+
+    let columns = vec!["v".to_string(), "nv".to_string()];
+    let index = VectorIndex::try_create(&schema, &columns, &block)?.unwrap();
+    assert_eq!(index.indexes.len(), 2);
+
+    // The empty vector is not indexed, nor is the NULL.
+    let v = &index.indexes[&schema.field_with_name("v")?.column_id()];
+    assert_eq!(v.dimension(), 2);
+    assert_eq!(
+        v.search(common_vector::VectorMetric::L2, &[0.0, 0.0], 10)?,
+        vec![0, 1, 3]
+    );
+    let nv = &index.indexes[&schema.field_with_name("nv")?.column_id()];
+    assert_eq!(
+        nv.search(common_vector::VectorMetric::L2, &[0.0, 0.0], 10)?,
+        vec![0, 3]
+    );
+
+    let bytes = index.to_bytes();
+    assert_eq!(VectorIndex::from_bytes(&bytes)?, index);
+    assert!(VectorIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    let mut other_version = bytes.clone();
+    other_version[0] += 1;
+    assert!(VectorIndex::from_bytes(&other_version).is_err());
+
+    // Columns of the option that are not vectors, or not in the schema
+    // after they are dropped or renamed, are skipped.
+    let columns = vec!["id".to_string(), "dropped".to_string()];
+    assert_eq!(VectorIndex::try_create(&schema, &columns, &block)?, None);
+    let columns = vec!["dropped".to_string(), "v".to_string()];
+    let index = VectorIndex::try_create(&schema, &columns, &block)?.unwrap();
+    assert_eq!(index.indexes.len(), 1);
+    assert!(VectorIndex::supported_type(&vector_type.wrap_nullable()));
+    assert!(!VectorIndex::supported_type(&TableDataType::Array(
+        Box::new(TableDataType::Number(NumberDataType::Float64))
+    )));
+
+    Ok(())
+}
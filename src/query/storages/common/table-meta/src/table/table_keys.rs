@@ -22,6 +22,14 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
+/// The query of a materialized view, which is kept in the options of the fuse
+/// table holding the result of the query.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+/// The ids of the materialized views built on a table, separated by comma.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
+/// The identity (`'name'@'host'`) of the user who created the table, the data of the table
+/// counts against the storage quota of this user.
+pub const OPT_KEY_OWNER: &str = "owner";
 
 /// Legacy table snapshot location key
 ///
@@ -40,6 +48,9 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_OWNER);
     r
 });
 
@@ -48,6 +59,9 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_OWNER);
     r
 });
 
@@ -22,6 +22,7 @@ use common_meta_app::schema::TableInfo;
 use common_storages_memory::MemoryTable;
 use common_storages_null::NullTable;
 use common_storages_random::RandomTable;
+use common_storages_stream::stream_table::StreamTable;
 use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
 
@@ -96,6 +97,12 @@ impl StorageFactory {
             descriptor: Arc::new(ViewTable::description),
         });
 
+        // Register STREAM table engine
+        creators.insert("STREAM".to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
         // Register RANDOM table engine
         creators.insert("RANDOM".to_string(), Storage {
             creator: Arc::new(RandomTable::try_create),
@@ -86,6 +86,7 @@ use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 
 #[derive(Clone)]
@@ -189,9 +190,27 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            vector_index_columns: self.vector_index_columns(),
         }
     }
 
+    /// Columns listed in table option `vector_index_columns`.
+    pub fn vector_index_columns(&self) -> Vec<String> {
+        self.table_info
+            .options()
+            .get(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS)
+            .map(|v| Self::parse_vector_index_columns(v))
+            .unwrap_or_default()
+    }
+
+    pub fn parse_vector_index_columns(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect()
+    }
+
     /// Get max page size.
     /// For native storage format.
     pub fn get_max_page_size(&self) -> Option<usize> {
@@ -526,6 +545,17 @@ impl Table for FuseTable {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        on_conflict_fields: Vec<TableField>,
+    ) -> Result<()> {
+        self.build_merge_into_pipeline(ctx, on_conflict_fields, pipeline)
+            .await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_commit_insertion", skip(self, ctx, operations), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn commit_insertion(
@@ -28,6 +28,7 @@ use crate::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::constants::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -143,6 +144,10 @@ impl TableMetaLocationGenerator {
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
+
+    pub fn gen_vector_index_location(location: &str) -> String {
+        location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VECTOR_INDEX_PREFIX)
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -31,6 +31,7 @@ use storages_common_cache::Loader;
 use storages_common_cache_manager::CacheManager;
 use storages_common_cache_manager::CompactSegmentInfoMeter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfoVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -45,6 +46,7 @@ pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
 pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
+pub type VectorIndexReader = InMemoryItemCacheReader<VectorIndex, LoaderWrapper<Operator>>;
 pub type CompactSegmentInfoReader = InMemoryItemCacheReader<
     CompactSegmentInfo,
     LoaderWrapper<(Operator, TableSchemaRef)>,
@@ -82,6 +84,13 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn vector_index_reader(dal: Operator) -> VectorIndexReader {
+        VectorIndexReader::new(
+            CacheManager::instance().get_vector_index_cache(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -138,6 +147,15 @@ impl Loader<BloomIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<VectorIndex> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<VectorIndex> {
+        let data = self.0.read(&params.location).await?;
+        VectorIndex::from_bytes(&data)
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Result<Reader> {
     let reader = if let Some(len) = len_hint {
         op.range_reader(path, 0..len).await?
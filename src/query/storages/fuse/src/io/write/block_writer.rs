@@ -34,6 +34,7 @@ use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::operations::BloomIndexState;
+use crate::operations::VectorIndexState;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
 
@@ -94,6 +95,7 @@ pub struct BlockSerialization {
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub vector_index_state: Option<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -122,6 +124,12 @@ impl BlockBuilder {
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
+        let vector_index_state = VectorIndexState::try_create(
+            &self.source_schema,
+            &data_block,
+            &self.write_settings.vector_index_columns,
+            &block_location.0,
+        )?;
 
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
@@ -157,6 +165,7 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            vector_index_state,
         };
         Ok(serialized)
     }
@@ -26,6 +26,9 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // columns to build vector index on, see `FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS`
+    pub vector_index_columns: Vec<String>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +38,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            vector_index_columns: vec![],
         }
     }
 }
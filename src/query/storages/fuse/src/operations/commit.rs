@@ -20,6 +20,7 @@ use std::time::Instant;
 use backoff::backoff::Backoff;
 use backoff::ExponentialBackoffBuilder;
 use common_base::base::ProgressValues;
+use common_catalog::catalog::Catalog;
 use common_catalog::table::Table;
 use common_catalog::table::TableExt;
 use common_catalog::table_context::TableContext;
@@ -29,10 +30,13 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
+use common_storages_stream::stream_table::StreamTable;
+use common_storages_stream::stream_table::OPT_KEY_SNAPSHOT_ID;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
@@ -127,7 +131,8 @@ impl FuseTable {
             {
                 Ok(_) => {
                     break {
-                        if transient {
+                        // the new snapshot is not committed yet in an explicit transaction
+                        if transient && !ctx.txn_mgr().lock().is_active() {
                             // Removes historical data, if table is transient
                             warn!(
                                 "transient table detected, purging historical data. ({})",
@@ -413,8 +418,28 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
-        // 3. let's roll
-        let reply = catalog.update_table_meta(table_info, req).await;
+        // 3.1 advance the offsets of the streams consumed by this query
+        let stream_reqs = Self::build_update_stream_meta_reqs(ctx).await?;
+
+        // 4. let's roll
+        let txn_mgr = ctx.txn_mgr();
+        let is_txn_active = txn_mgr.lock().is_active();
+        if is_txn_active {
+            // in an explicit transaction, the new table meta is committed together
+            // with the other tables mutated in the transaction by `COMMIT`
+            let mut txn_mgr = txn_mgr.lock();
+            txn_mgr.update_table_meta(table_info, req);
+            for (stream_info, stream_req) in stream_reqs {
+                txn_mgr.update_table_meta(&stream_info, stream_req);
+            }
+            TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+            return Ok(());
+        }
+        let reply = if stream_reqs.is_empty() {
+            catalog.update_table_meta(table_info, req).await.map(|_| ())
+        } else {
+            Self::update_table_and_streams_meta(catalog.as_ref(), req, stream_reqs).await
+        };
         match reply {
             Ok(_) => {
                 // upsert snapshot statistics cache
@@ -452,6 +477,91 @@ impl FuseTable {
         }
     }
 
+    /// Build the requests which move the offsets of the streams consumed by the query
+    /// to the snapshots of their base tables that the query has read.
+    #[async_backtrace::framed]
+    async fn build_update_stream_meta_reqs(
+        ctx: &dyn TableContext,
+    ) -> Result<Vec<(TableInfo, UpdateTableMetaReq)>> {
+        let mut reqs = vec![];
+        for table in ctx.get_consumed_streams() {
+            let stream = StreamTable::try_from_table(table.as_ref())?;
+            let stream_info = stream.get_table_info();
+            // the base table is cached in the query context, it is the version
+            // that the changes have been read from.
+            let base_table = ctx
+                .get_table(
+                    stream_info.catalog(),
+                    stream.base_database(),
+                    stream.base_table(),
+                )
+                .await?;
+            let base_table = FuseTable::try_from_table(base_table.as_ref())?;
+            let snapshot_id = base_table
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.simple().to_string());
+            if snapshot_id.as_deref() == stream.offset_snapshot_id() {
+                continue;
+            }
+
+            let mut new_table_meta = stream_info.meta.clone();
+            match snapshot_id {
+                Some(snapshot_id) => {
+                    new_table_meta
+                        .options
+                        .insert(OPT_KEY_SNAPSHOT_ID.to_string(), snapshot_id);
+                }
+                None => {
+                    new_table_meta.options.remove(OPT_KEY_SNAPSHOT_ID);
+                }
+            }
+            let req = UpdateTableMetaReq {
+                table_id: stream_info.ident.table_id,
+                seq: MatchSeq::Exact(stream_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            };
+            reqs.push((stream_info.clone(), req));
+        }
+        Ok(reqs)
+    }
+
+    /// Commit the table meta and the offsets of the consumed streams atomically.
+    #[async_backtrace::framed]
+    async fn update_table_and_streams_meta(
+        catalog: &dyn Catalog,
+        req: UpdateTableMetaReq,
+        stream_reqs: Vec<(TableInfo, UpdateTableMetaReq)>,
+    ) -> Result<()> {
+        let mut update_table_metas = vec![req];
+        update_table_metas.extend(stream_reqs.iter().map(|(_, req)| req.clone()));
+        let reply = catalog
+            .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+            .await;
+        match reply {
+            Ok(_) => Ok(()),
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                // the stream has been consumed by others concurrently, retrying
+                // would read the same changes again.
+                for (stream_info, _) in &stream_reqs {
+                    let (ident, _) = catalog
+                        .get_table_meta_by_id(stream_info.ident.table_id)
+                        .await?;
+                    if ident.seq != stream_info.ident.seq {
+                        return Err(ErrorCode::StreamVersionMismatched(format!(
+                            "stream {} has been changed by others while being consumed",
+                            stream_info.desc
+                        )));
+                    }
+                }
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn merge_append_operations(
         append_log_entries: &[AppendOperationLogEntry],
     ) -> Result<(Vec<String>, Statistics)> {
@@ -761,9 +871,10 @@ mod utils {
 
     #[inline]
     pub fn no_side_effects_in_meta_store(e: &ErrorCode) -> bool {
-        // currently, the only error that we know,  which indicates there are no side effects
-        // is TABLE_VERSION_MISMATCHED
+        // currently, the only errors that we know,  which indicate there are no side effects
+        // are TABLE_VERSION_MISMATCHED and STREAM_VERSION_MISMATCHED
         e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+            || e.code() == ErrorCode::STREAM_VERSION_MISMATCHED
     }
 
     // check if there are any fuse table legacy options
@@ -25,6 +25,7 @@ use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
@@ -102,6 +103,29 @@ impl BloomIndexState {
     }
 }
 
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        source_schema: &TableSchema,
+        block: &DataBlock,
+        columns: &[String],
+        block_location: &str,
+    ) -> Result<Option<Self>> {
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        let maybe_vector_index = VectorIndex::try_create(source_schema, columns, block)?;
+        Ok(maybe_vector_index.map(|vector_index| Self {
+            data: vector_index.to_bytes(),
+            location: TableMetaLocationGenerator::gen_vector_index_location(block_location),
+        }))
+    }
+}
+
 enum State {
     None,
     NeedSerialize(DataBlock),
@@ -111,6 +135,7 @@ enum State {
         meta_data: HashMap<ColumnId, ColumnMeta>,
         block_statistics: BlockStatistics,
         bloom_index_state: Option<BloomIndexState>,
+        vector_index_state: Option<VectorIndexState>,
     },
     GenerateSegment,
     SerializedSegment {
@@ -232,6 +257,12 @@ impl Processor for FuseTableSink {
                 let column_distinct_count = bloom_index_state
                     .as_ref()
                     .map(|i| i.column_distinct_count.clone());
+                let vector_index_state = VectorIndexState::try_create(
+                    &self.source_schema,
+                    &block,
+                    &self.write_settings.vector_index_columns,
+                    &block_location.0,
+                )?;
                 let block_statistics = BlockStatistics::from(
                     &block,
                     block_location.0,
@@ -255,6 +286,7 @@ impl Processor for FuseTableSink {
                     block_statistics,
                     meta_data,
                     bloom_index_state,
+                    vector_index_state,
                 };
             }
             State::GenerateSegment => {
@@ -304,6 +336,7 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
                 bloom_index_state,
+                vector_index_state,
             } => {
                 let start = Instant::now();
 
@@ -342,6 +375,16 @@ impl Processor for FuseTableSink {
                     }
                 }
 
+                // write vector index
+                if let Some(vector_index_state) = vector_index_state {
+                    io::write_data(
+                        vector_index_state.data,
+                        &self.data_accessor,
+                        &vector_index_state.location,
+                    )
+                    .await?;
+                }
+
                 let (bloom_index_location, bloom_index_size) =
                     if let Some(bloom_index_state) = bloom_index_state {
                         (
@@ -425,6 +425,15 @@ impl FuseTable {
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
+            // vector indexes are not tracked in block meta, derive them from the blocks.
+            if !self.vector_index_columns().is_empty() {
+                let vector_indexes_to_be_purged = blocks_to_be_purged
+                    .iter()
+                    .map(|location| TableMetaLocationGenerator::gen_vector_index_location(location))
+                    .collect();
+                self.try_purge_location_files(ctx.clone(), vector_indexes_to_be_purged)
+                    .await?;
+            }
             self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
                 .await?;
         }
@@ -381,6 +381,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.vector_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
 
         // generate log
         let mutation = ReplacementLogEntry {
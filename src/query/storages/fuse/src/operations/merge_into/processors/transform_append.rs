@@ -232,10 +232,20 @@ impl AsyncAccumulatingTransform for AppendTransform {
             }
         }
 
+        // 3. persistent vector index
+        if let Some(vector_index_state) = serialized_block_state.vector_index_state {
+            io::write_data(
+                vector_index_state.data,
+                &self.data_accessor,
+                &vector_index_state.location,
+            )
+            .await?;
+        }
+
         self.accumulator
             .add_with_block_meta(serialized_block_state.block_meta);
 
-        // 3. output operation log if any
+        // 4. output operation log if any
         let append_log = self.try_output_mutation().await?;
         self.output_mutation_block(append_log)
     }
@@ -39,6 +39,7 @@ pub mod util;
 pub use compact::CompactOptions;
 pub use fuse_sink::BloomIndexState;
 pub use fuse_sink::FuseTableSink;
+pub use fuse_sink::VectorIndexState;
 pub use mutation::BlockCompactMutator;
 pub use mutation::CompactPartInfo;
 pub use mutation::FillInternalColumnProcessor;
@@ -185,6 +185,9 @@ impl Processor for CompactSource {
                 if let Some(index_state) = serialized.bloom_index_state {
                     write_data(index_state.data, &self.dal, &index_state.location.0).await?;
                 }
+                if let Some(index_state) = serialized.vector_index_state {
+                    write_data(index_state.data, &self.dal, &index_state.location).await?;
+                }
 
                 // Perf
                 {
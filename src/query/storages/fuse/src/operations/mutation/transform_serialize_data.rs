@@ -191,6 +191,14 @@ impl Processor for SerializeDataTransform {
                     )
                     .await?;
                 }
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.dal,
+                        &vector_index_state.location,
+                    )
+                    .await?;
+                }
                 let block_meta = Arc::new(serialized.block_meta);
                 self.state = State::Output(Replacement::Replaced(block_meta));
             }
@@ -167,7 +167,7 @@ impl FuseTable {
             }
         }
 
-        let pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
+        let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
             FusePruner::create(&ctx, dal.clone(), table_info.schema(), &push_downs)?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -181,6 +181,7 @@ impl FuseTable {
                 cluster_keys,
             )?
         };
+        pruner.vector_index_columns = self.vector_index_columns();
 
         let block_metas = pruner.pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -20,12 +20,17 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::SortColumnDescription;
 use common_expression::TableField;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_transforms::processors::transforms::create_dummy_item;
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
+use common_pipeline_transforms::processors::transforms::TransformSortPartial;
+use common_sql::evaluator::CompoundBlockOperator;
 use rand::prelude::SliceRandom;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
@@ -41,8 +46,10 @@ use crate::operations::merge_into::MergeIntoOperationAggregator;
 use crate::operations::merge_into::OnConflictField;
 use crate::operations::merge_into::TableMutationAggregator;
 use crate::operations::mutation::SegmentIndex;
+use crate::operations::replace_into::processor_merge_into::MergeIntoProcessor;
 use crate::operations::replace_into::processor_replace_into::ReplaceIntoProcessor;
 use crate::pipelines::Pipeline;
+use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -106,24 +113,7 @@ impl FuseTable {
         on_conflict_field_identifiers: Vec<TableField>,
         pipeline: &'a mut Pipeline,
     ) -> Result<()> {
-        let schema = self.table_info.schema();
-
-        let mut on_conflicts = Vec::with_capacity(on_conflict_field_identifiers.len());
-        for f in on_conflict_field_identifiers {
-            let field_name = f.name();
-            let (field_index, _) = match schema.column_with_name(field_name) {
-                Some(idx) => idx,
-                None => {
-                    return Err(ErrorCode::Internal(
-                        "not expected, on conflict field not found (after binding)",
-                    ));
-                }
-            };
-            on_conflicts.push(OnConflictField {
-                table_field: f.clone(),
-                field_index,
-            })
-        }
+        let on_conflicts = self.on_conflict_fields(on_conflict_field_identifiers)?;
 
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, self.get_block_thresholds())?;
@@ -156,13 +146,129 @@ impl FuseTable {
             ReplaceIntoProcessor::create(on_conflicts.clone(), empty_table);
         pipeline.add_pipe(replace_into_processor.into_pipe());
 
-        // 3. connect to broadcast processor and append transform
+        // 3. connect to broadcast processor, append transform and the mutation pipes
+        self.chain_upsert_pipes(
+            ctx,
+            on_conflicts,
+            cluster_stats_gen,
+            base_snapshot,
+            pipeline,
+        )
+        .await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline<'a>(
+        &'a self,
+        ctx: Arc<dyn TableContext>,
+        on_conflict_field_identifiers: Vec<TableField>,
+        pipeline: &'a mut Pipeline,
+    ) -> Result<()> {
+        let on_conflicts = self.on_conflict_fields(on_conflict_field_identifiers)?;
+        let num_table_columns = self.table_info.schema().fields().len();
 
+        // 1. resize input to 1, the deletion of matched keys is broadcast to all the segments
+        pipeline.resize(1)?;
+
+        // 2. connect with MergeIntoProcessor, which splits the input into
+        // (0) -> output_port_append_data, the rows to append
+        // (1) -> output_port_merge_into_action, the deletion of the rows with matched keys
         let base_snapshot = self
             .read_table_snapshot()
             .await?
             .unwrap_or_else(|| Arc::new(self.new_empty_snapshot()));
+        let empty_table = base_snapshot.segments.is_empty();
+        let merge_into_processor =
+            MergeIntoProcessor::create(num_table_columns, on_conflicts.len(), empty_table);
+        pipeline.add_pipe(merge_into_processor.into_pipe());
+
+        // 3. evaluate the cluster keys of the rows to append
+        let cluster_stats_gen =
+            self.get_cluster_stats_gen(ctx.clone(), 0, self.get_block_thresholds())?;
+        let operators = cluster_stats_gen.operators.clone();
+        if !operators.is_empty() {
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor = CompoundBlockOperator::create(
+                input.clone(),
+                output.clone(),
+                num_table_columns,
+                cluster_stats_gen.func_ctx.clone(),
+                operators,
+            );
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                PipeItem::create(ProcessorPtr::create(processor), vec![input], vec![output]),
+                create_dummy_item(),
+            ]));
+        }
+        let cluster_keys = &cluster_stats_gen.cluster_key_index;
+        if !cluster_keys.is_empty() {
+            let sort_descs: Vec<SortColumnDescription> = cluster_keys
+                .iter()
+                .map(|index| SortColumnDescription {
+                    offset: *index,
+                    asc: true,
+                    nulls_first: false,
+                    is_nullable: false, // This information is not needed here.
+                })
+                .collect();
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor =
+                TransformSortPartial::try_create(input.clone(), output.clone(), None, sort_descs)?;
+            pipeline.add_pipe(Pipe::create(2, 2, vec![
+                PipeItem::create(ProcessorPtr::create(processor), vec![input], vec![output]),
+                create_dummy_item(),
+            ]));
+        }
+
+        // 4. connect to broadcast processor, append transform and the mutation pipes
+        self.chain_upsert_pipes(
+            ctx,
+            on_conflicts,
+            cluster_stats_gen,
+            base_snapshot,
+            pipeline,
+        )
+        .await
+    }
 
+    fn on_conflict_fields(
+        &self,
+        on_conflict_field_identifiers: Vec<TableField>,
+    ) -> Result<Vec<OnConflictField>> {
+        let schema = self.table_info.schema();
+
+        let mut on_conflicts = Vec::with_capacity(on_conflict_field_identifiers.len());
+        for f in on_conflict_field_identifiers {
+            let field_name = f.name();
+            let (field_index, _) = match schema.column_with_name(field_name) {
+                Some(idx) => idx,
+                None => {
+                    return Err(ErrorCode::Internal(
+                        "not expected, on conflict field not found (after binding)",
+                    ));
+                }
+            };
+            on_conflicts.push(OnConflictField {
+                table_field: f.clone(),
+                field_index,
+            })
+        }
+        Ok(on_conflicts)
+    }
+
+    // Connects the pipes after the processor which splits the input into the data to append
+    // and the MergeIntoOperations.
+    #[async_backtrace::framed]
+    async fn chain_upsert_pipes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        on_conflicts: Vec<OnConflictField>,
+        cluster_stats_gen: ClusterStatsGenerator,
+        base_snapshot: Arc<TableSnapshot>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
         let max_threads = ctx.get_settings().get_max_threads()?;
         let segment_partition_num =
             std::cmp::min(base_snapshot.segments.len(), max_threads as usize);
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 pub mod mutator_replace_into;
+pub mod processor_merge_into;
 pub mod processor_replace_into;
@@ -46,7 +46,7 @@ impl ReplaceIntoMutator {
     }
 }
 
-enum ColumnHash {
+pub(crate) enum ColumnHash {
     NoConflict(HashSet<UniqueKeyDigest>),
     Conflict,
 }
@@ -85,7 +85,7 @@ impl ReplaceIntoMutator {
         }
     }
 
-    fn build_column_hash(
+    pub(crate) fn build_column_hash(
         columns: &[&Column],
         saw: &mut HashSet<UniqueKeyDigest>,
         num_rows: usize,
@@ -121,7 +121,10 @@ impl ReplaceIntoMutator {
         ))
     }
 
-    fn columns_min_max(columns: &[&Column], num_rows: usize) -> Result<Vec<(Scalar, Scalar)>> {
+    pub(crate) fn columns_min_max(
+        columns: &[&Column],
+        num_rows: usize,
+    ) -> Result<Vec<(Scalar, Scalar)>> {
         let mut res = Vec::with_capacity(columns.len());
         for column in columns {
             let min = Self::eval((*column).clone(), num_rows, "min")?;
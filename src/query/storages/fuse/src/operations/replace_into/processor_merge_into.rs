@@ -0,0 +1,241 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Value;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::DeletionByColumn;
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::MergeIntoOperation;
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::UniqueKeyDigest;
+use crate::operations::replace_into::mutator_replace_into::ColumnHash;
+use crate::operations::replace_into::mutator_replace_into::ReplaceIntoMutator;
+
+// Splits the rows produced by MERGE INTO, which are laid out as
+// (<table columns>, <keys of the matched target row>, <should append>), into
+// - the rows to append
+// - the deletion of the existing rows with the matched keys
+pub struct MergeIntoProcessor {
+    num_table_columns: usize,
+    num_keys: usize,
+
+    input_port: Arc<InputPort>,
+    output_port_merge_into_action: Arc<OutputPort>,
+    output_port_append_data: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data_merge_into_action: Option<DataBlock>,
+    output_data_append: Option<DataBlock>,
+
+    target_table_empty: bool,
+    // the keys of the target rows matched so far, a target row can only be matched once
+    key_saw: HashSet<UniqueKeyDigest>,
+}
+
+impl MergeIntoProcessor {
+    pub fn create(num_table_columns: usize, num_keys: usize, target_table_empty: bool) -> Self {
+        let input_port = InputPort::create();
+        let output_port_merge_into_action = OutputPort::create();
+        let output_port_append_data = OutputPort::create();
+
+        Self {
+            num_table_columns,
+            num_keys,
+            input_port,
+            output_port_merge_into_action,
+            output_port_append_data,
+            input_data: None,
+            output_data_merge_into_action: None,
+            output_data_append: None,
+            target_table_empty,
+            key_saw: Default::default(),
+        }
+    }
+
+    pub fn into_pipe(self) -> Pipe {
+        let pipe_item = self.into_pipe_item();
+        Pipe::create(1, 2, vec![pipe_item])
+    }
+
+    pub fn into_pipe_item(self) -> PipeItem {
+        let input = self.input_port.clone();
+        let output_port_merge_into_action = self.output_port_merge_into_action.clone();
+        let output_port_append_data = self.output_port_append_data.clone();
+        let processor_ptr = ProcessorPtr::create(Box::new(self));
+        PipeItem::create(processor_ptr, vec![input], vec![
+            output_port_append_data,
+            output_port_merge_into_action,
+        ])
+    }
+
+    fn deletion_of_matched_keys(&mut self, data_block: &DataBlock) -> Result<MergeIntoOperation> {
+        let num_rows = data_block.num_rows();
+        let key_columns = data_block.columns()
+            [self.num_table_columns..self.num_table_columns + self.num_keys]
+            .iter()
+            .map(|entry| {
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+
+        // the keys are NULL for the source rows which match nothing
+        let matched = match &key_columns[0] {
+            Column::Nullable(column) => column.validity.clone(),
+            Column::Null { len } => Bitmap::new_zeroed(*len),
+            _ => MutableBitmap::from_len_set(num_rows).into(),
+        };
+        let num_matched = matched.len() - matched.unset_bits();
+        if num_matched == 0 {
+            return Ok(MergeIntoOperation::None);
+        }
+
+        let key_columns = key_columns
+            .iter()
+            .map(|column| column.filter(&matched).remove_nullable())
+            .collect::<Vec<_>>();
+        let key_columns = key_columns.iter().collect::<Vec<_>>();
+        let key_hashes = match ReplaceIntoMutator::build_column_hash(
+            &key_columns,
+            &mut self.key_saw,
+            num_matched,
+        )? {
+            ColumnHash::NoConflict(key_hashes) => key_hashes,
+            ColumnHash::Conflict => {
+                return Err(ErrorCode::StorageOther(
+                    "duplicated data detected in merge source, a target row is matched by more than one source row",
+                ));
+            }
+        };
+        let columns_min_max = ReplaceIntoMutator::columns_min_max(&key_columns, num_matched)?;
+        Ok(MergeIntoOperation::Delete(DeletionByColumn {
+            columns_min_max,
+            key_hashes,
+        }))
+    }
+
+    fn rows_to_append(&self, data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let entry = &data_block.columns()[self.num_table_columns + self.num_keys];
+        let predicate = match entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows)
+        {
+            Column::Nullable(column) => {
+                let values = BooleanType::try_downcast_column(&column.column).unwrap();
+                &values & (&column.validity)
+            }
+            column => BooleanType::try_downcast_column(&column).unwrap(),
+        };
+        let predicate = Value::<BooleanType>::Column(predicate);
+        let columns = data_block.columns()[..self.num_table_columns].to_vec();
+        DataBlock::new(columns, num_rows).filter_boolean_value(&predicate)
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for MergeIntoProcessor {
+    fn name(&self) -> String {
+        "MergeIntoTransform".to_owned()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        let finished = self.input_port.is_finished()
+            && self.output_data_append.is_none()
+            && self.output_data_merge_into_action.is_none();
+
+        if finished {
+            self.output_port_merge_into_action.finish();
+            self.output_port_append_data.finish();
+            return Ok(Event::Finished);
+        }
+
+        let mut pushed_something = false;
+        if self.output_port_append_data.can_push() {
+            if let Some(data) = self.output_data_append.take() {
+                self.output_port_append_data.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if self.output_port_merge_into_action.can_push() {
+            if let Some(data) = self.output_data_merge_into_action.take() {
+                self.output_port_merge_into_action.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if pushed_something {
+            Ok(Event::NeedConsume)
+        } else {
+            if self.input_data.is_some() {
+                return Ok(Event::Sync);
+            }
+
+            if self.input_port.has_data() {
+                if self.output_data_append.is_none() && self.output_data_merge_into_action.is_none()
+                {
+                    // no pending data (being sent to down streams)
+                    self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                    Ok(Event::Sync)
+                } else {
+                    // data pending
+                    Ok(Event::NeedConsume)
+                }
+            } else {
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            if !self.target_table_empty {
+                let merge_into_action = self.deletion_of_matched_keys(&data_block)?;
+                if merge_into_action != MergeIntoOperation::None {
+                    self.output_data_merge_into_action =
+                        Some(DataBlock::empty_with_meta(Box::new(merge_into_action)));
+                }
+            }
+            let data_block = self.rows_to_append(data_block)?;
+            if !data_block.is_empty() {
+                self.output_data_append = Some(data_block);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -69,18 +69,27 @@ impl FuseTable {
             let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog())?;
 
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            };
+
+            // in an explicit transaction, the new table meta is committed by `COMMIT`,
+            // and the copied files and historical data are kept.
+            let txn_mgr = ctx.txn_mgr();
+            let is_txn_active = txn_mgr.lock().is_active();
+            if is_txn_active {
+                txn_mgr.lock().update_table_meta(&self.table_info, req);
+                return Ok(());
+            }
+
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                })
-                .await?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             // best effort to remove the table's copied files.
             catalog
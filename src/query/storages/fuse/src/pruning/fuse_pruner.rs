@@ -48,6 +48,7 @@ use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -69,6 +70,8 @@ pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
+    // columns with vector indexes, see `FuseTable::vector_index_columns`
+    pub vector_index_columns: Vec<String>,
 }
 
 impl FusePruner {
@@ -199,11 +202,12 @@ impl FusePruner {
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
+            vector_index_columns: vec![],
         })
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &self,
@@ -265,7 +269,10 @@ impl FusePruner {
                 }
 
                 // TopN pruner.
-                self.topn_pruning(metas)
+                let metas = self.topn_pruning(metas)?;
+
+                // Vector pruner.
+                self.vector_pruning(metas).await
             }
         }
     }
@@ -292,6 +299,38 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector pruner:
+    // if there is a vector search and no filters, use vector pruner
+    #[async_backtrace::framed]
+    async fn vector_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let search = self
+            .push_down
+            .as_ref()
+            .filter(|p| p.filter.is_none() && p.prewhere.is_none())
+            .and_then(|p| p.vector_search.clone());
+        if let Some(search) = search {
+            if self
+                .pruning_ctx
+                .ctx
+                .get_settings()
+                .get_enable_vector_index()?
+            {
+                if let Some(vector_pruner) = VectorPruner::create(
+                    self.pruning_ctx.clone(),
+                    &self.table_schema,
+                    &self.vector_index_columns,
+                    search,
+                ) {
+                    return vector_pruner.pruning(metas).await;
+                }
+            }
+        }
+        Ok(metas)
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -18,6 +18,7 @@ mod fuse_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
+mod vector_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
@@ -27,3 +28,4 @@ pub use fuse_pruner::PruningContext;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
+pub use vector_pruner::VectorPruner;
@@ -0,0 +1,149 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_catalog::plan::VectorSearchInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableSchemaRef;
+use futures_util::future;
+use storages_common_cache::LoadParams;
+use storages_common_index::VectorIndex;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::PruningContext;
+
+/// The nearest lists are taken until they hold `limit * CANDIDATES_FACTOR` vectors,
+/// as the lists only approximate the nearest vectors.
+const CANDIDATES_FACTOR: usize = 4;
+
+/// Vector pruner:
+/// keeps the blocks that may hold the nearest vectors of a vector search.
+///
+/// The lists of the vector indexes of all blocks are ranked by the distance of their
+/// centroids to the query, and only the blocks owning the nearest lists are kept.
+/// Blocks without a vector index of the column are always kept.
+pub struct VectorPruner {
+    pruning_ctx: Arc<PruningContext>,
+    column_id: ColumnId,
+    search: VectorSearchInfo,
+}
+
+impl VectorPruner {
+    /// Returns `None` if the column of the search is not listed in the
+    /// `vector_index_columns` option of the table.
+    pub fn create(
+        pruning_ctx: Arc<PruningContext>,
+        schema: &TableSchemaRef,
+        vector_index_columns: &[String],
+        search: VectorSearchInfo,
+    ) -> Option<Self> {
+        if !vector_index_columns.contains(&search.column_name) {
+            return None;
+        }
+        let field = schema.field_with_name(&search.column_name).ok()?;
+        if !VectorIndex::supported_type(field.data_type()) {
+            return None;
+        }
+        Some(VectorPruner {
+            pruning_ctx,
+            column_id: field.column_id(),
+            search,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let pruning_tasks = metas.iter().enumerate().map(|(idx, (_, block_meta))| {
+            let reader = MetaReaders::vector_index_reader(self.pruning_ctx.dal.clone());
+            let params = LoadParams {
+                location: TableMetaLocationGenerator::gen_vector_index_location(
+                    &block_meta.location.0,
+                ),
+                len_hint: None,
+                ver: VectorIndex::VERSION,
+                put_cache: true,
+            };
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                // blocks written before the index was enabled have no vector index.
+                let index = match reader.read(&params).await {
+                    Ok(index) => Ok(Some(index)),
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(None),
+                    Err(e) => Err(e),
+                };
+                (idx, index)
+            }
+        });
+
+        let join_handlers = self
+            .pruning_ctx
+            .pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(
+                self.pruning_ctx.pruning_semaphore.clone(),
+                pruning_tasks,
+            )
+            .await?;
+
+        let joint = future::try_join_all(join_handlers)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("vector pruning failure, {}", e)))?;
+
+        let query = self.search.query.iter().map(|v| v.0).collect::<Vec<_>>();
+        let mut keep = vec![false; metas.len()];
+        // (distance of the centroid, block, number of vectors) of every list.
+        let mut lists = vec![];
+        for (idx, index) in joint {
+            let index = index?;
+            let ivf_index = index
+                .as_ref()
+                .and_then(|index| index.indexes.get(&self.column_id))
+                .filter(|ivf_index| ivf_index.dimension() == query.len());
+            match ivf_index {
+                Some(ivf_index) => {
+                    for (distance, size) in ivf_index.probe(self.search.metric, &query)? {
+                        lists.push((distance, idx, size));
+                    }
+                }
+                None => keep[idx] = true,
+            }
+        }
+        lists.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let candidates = self.search.limit.saturating_mul(CANDIDATES_FACTOR);
+        let mut taken = 0;
+        for (_, idx, size) in lists {
+            if taken >= candidates {
+                break;
+            }
+            keep[idx] = true;
+            taken += size;
+        }
+
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect())
+    }
+}
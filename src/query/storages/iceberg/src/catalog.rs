@@ -82,6 +82,7 @@ pub const ICEBERG_CATALOG: &str = "iceberg";
 /// - Instances of `Database` are created from reading subdirectories of
 ///    Iceberg table
 /// - Table metadata are saved in external Iceberg storage
+/// - Databases and tables created in the catalog are directories in the storage
 #[derive(Clone)]
 pub struct IcebergCatalog {
     /// name of this iceberg table
@@ -145,13 +146,9 @@ impl IcebergCatalog {
         }
         Ok(dbs)
     }
-}
 
-#[async_trait]
-impl Catalog for IcebergCatalog {
-    #[tracing::instrument(level = "debug", skip(self))]
     #[async_backtrace::framed]
-    async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+    async fn iceberg_database(&self, db_name: &str) -> Result<IcebergDatabase> {
         if self.flatten {
             // is flatten catalog, must return `default` catalog
             if db_name != "default" {
@@ -159,10 +156,10 @@ impl Catalog for IcebergCatalog {
                     "Database {db_name} does not exist"
                 )));
             }
-            let tbl: Arc<dyn Database> = Arc::new(
-                IcebergDatabase::create_database_omitted_default(&self.name, self.operator.clone()),
-            );
-            return Ok(tbl);
+            return Ok(IcebergDatabase::create_database_omitted_default(
+                &self.name,
+                self.operator.clone(),
+            ));
         }
 
         let rel_path = format!("{db_name}/");
@@ -181,9 +178,18 @@ impl Catalog for IcebergCatalog {
             .map_root(|root| format!("{root}{rel_path}"));
         let db_root = DataOperator::try_create(&db_sp).await?;
 
-        Ok(Arc::new(IcebergDatabase::create_database_from_read(
+        Ok(IcebergDatabase::create_database_from_read(
             &self.name, db_name, db_root,
-        )))
+        ))
+    }
+}
+
+#[async_trait]
+impl Catalog for IcebergCatalog {
+    #[tracing::instrument(level = "debug", skip(self))]
+    #[async_backtrace::framed]
+    async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        Ok(Arc::new(self.iceberg_database(db_name).await?))
     }
 
     #[async_backtrace::framed]
@@ -192,23 +198,70 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
-        unimplemented!()
+    async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        let db_name = &req.name_ident.db_name;
+        if self.flatten {
+            return Err(ErrorCode::Unimplemented(format!(
+                "cannot create database {db_name} in flatten iceberg catalog {}",
+                self.name
+            )));
+        }
+        let rel_path = format!("{db_name}/");
+        let operator = self.operator.operator();
+        if operator.is_exist(&rel_path).await? {
+            if req.if_not_exists {
+                return Ok(CreateDatabaseReply { db_id: 0 });
+            }
+            return Err(ErrorCode::DatabaseAlreadyExists(format!(
+                "Database {db_name} already exists"
+            )));
+        }
+        operator.create_dir(&rel_path).await?;
+        Ok(CreateDatabaseReply { db_id: 0 })
     }
 
+    /// Only empty databases can be dropped, as the tables in them may be shared with other engines.
     #[async_backtrace::framed]
-    async fn drop_database(&self, _req: DropDatabaseReq) -> Result<DropDatabaseReply> {
-        unimplemented!()
+    async fn drop_database(&self, req: DropDatabaseReq) -> Result<DropDatabaseReply> {
+        let db_name = &req.name_ident.db_name;
+        let db = match self.get_database(&req.name_ident.tenant, db_name).await {
+            Ok(db) => db,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE && req.if_exists => {
+                return Ok(DropDatabaseReply { spec_vec: None });
+            }
+            Err(e) => return Err(e),
+        };
+        if self.flatten {
+            return Err(ErrorCode::Unimplemented(format!(
+                "cannot drop database {db_name} in flatten iceberg catalog {}",
+                self.name
+            )));
+        }
+        if !db.list_tables().await?.is_empty() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "cannot drop database {db_name} in iceberg catalog {}, it's not empty",
+                self.name
+            )));
+        }
+        self.operator
+            .operator()
+            .remove_all(&format!("{db_name}/"))
+            .await?;
+        Ok(DropDatabaseReply { spec_vec: None })
     }
 
     #[async_backtrace::framed]
     async fn undrop_database(&self, _req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "undrop database is not supported by iceberg catalog",
+        ))
     }
 
     #[async_backtrace::framed]
     async fn rename_database(&self, _req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "rename database is not supported by iceberg catalog",
+        ))
     }
 
     fn get_table_by_info(&self, _table_info: &TableInfo) -> Result<Arc<dyn Table>> {
@@ -251,23 +304,54 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db = self
+            .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
+            .await?;
+        db.create_table(req).await
     }
 
+    /// The table is looked up in all the databases, by the id derived from its name.
     #[async_backtrace::framed]
-    async fn drop_table_by_id(&self, _req: DropTableByIdReq) -> Result<DropTableReply> {
-        unimplemented!()
+    async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
+        for db in self.list_databases("").await? {
+            match db.drop_table_by_id(req.clone()).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                res => return res,
+            }
+        }
+        if req.if_exists {
+            return Ok(DropTableReply { spec_vec: None });
+        }
+        Err(ErrorCode::UnknownTable(format!(
+            "Unknown table id {} in iceberg catalog {}",
+            req.tb_id, self.name
+        )))
     }
 
     #[async_backtrace::framed]
     async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
-        unimplemented!()
-    }
-
-    #[async_backtrace::framed]
-    async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "undrop table is not supported by iceberg catalog",
+        ))
+    }
+
+    /// Files of iceberg tables are recorded with absolute paths, the table is renamed by moving
+    /// the metadata only, see [`IcebergDatabase::move_table`].
+    #[async_backtrace::framed]
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let db = self.iceberg_database(req.db_name()).await?;
+        let new_db = self.iceberg_database(&req.new_db_name).await?;
+        match db
+            .move_table(req.table_name(), &new_db, &req.new_table_name)
+            .await
+        {
+            Ok(table_id) => Ok(RenameTableReply { table_id }),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE && req.if_exists => {
+                Ok(RenameTableReply { table_id: 0 })
+            }
+            Err(e) => Err(e),
+        }
     }
 
     #[async_backtrace::framed]
@@ -313,16 +397,30 @@ impl Catalog for IcebergCatalog {
         _db_name: &str,
         _req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply> {
-        unimplemented!()
+        // copied files are not recorded by iceberg tables
+        Ok(GetTableCopiedFileReply {
+            file_info: Default::default(),
+        })
     }
 
+    /// Iceberg tables are truncated by committing an empty snapshot, the table is looked up
+    /// in all the databases by the id derived from its name.
     #[async_backtrace::framed]
     async fn truncate_table(
         &self,
         _table_info: &TableInfo,
-        _req: TruncateTableReq,
+        req: TruncateTableReq,
     ) -> Result<TruncateTableReply> {
-        unimplemented!()
+        for db in self.list_databases("").await? {
+            match db.truncate_table(req.clone()).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                res => return res,
+            }
+        }
+        Err(ErrorCode::UnknownTable(format!(
+            "Unknown table id {} in iceberg catalog {}",
+            req.table_id, self.name
+        )))
     }
 
     #[async_backtrace::framed]
@@ -427,6 +525,6 @@ impl Catalog for IcebergCatalog {
 
     // Get table engines
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![]
     }
 }
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 //! this module is used for converting iceberg data types, schemas and other metadata
-//! to databend
+//! to databend, and databend schemas of new tables to iceberg
 
 use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::types::decimal::DecimalSize;
 use common_expression::types::DecimalDataType;
 use common_expression::types::NumberDataType;
@@ -30,6 +32,8 @@ use iceberg_rs::model::schema::SchemaV2;
 use iceberg_rs::model::schema::StructField;
 use iceberg_rs::model::table::TableMetadata;
 use itertools::Itertools;
+use serde_json::json;
+use serde_json::Value as JsonValue;
 
 /// generate TableMeta from Iceberg table meta
 pub(crate) fn meta_iceberg_to_databend(
@@ -84,7 +88,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
         iceberg_rs::model::schema::AllType::Primitive(p) => match p {
             iceberg_rs::model::schema::PrimitiveType::Boolean => TableDataType::Boolean,
             iceberg_rs::model::schema::PrimitiveType::Int => {
-                TableDataType::Number(NumberDataType::UInt32)
+                TableDataType::Number(NumberDataType::Int32)
             }
             iceberg_rs::model::schema::PrimitiveType::Long => {
                 TableDataType::Number(NumberDataType::Int64)
@@ -117,7 +121,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
             iceberg_rs::model::schema::PrimitiveType::String => TableDataType::String,
             iceberg_rs::model::schema::PrimitiveType::Uuid => TableDataType::String,
             iceberg_rs::model::schema::PrimitiveType::Fixed(_) => TableDataType::String,
-            iceberg_rs::model::schema::PrimitiveType::Binary => TableDataType::String,
+            iceberg_rs::model::schema::PrimitiveType::Binary => TableDataType::Binary,
         },
         iceberg_rs::model::schema::AllType::Struct(s) => {
             let (names, fields): (Vec<String>, Vec<TableDataType>) = s
@@ -157,6 +161,59 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
     }
 }
 
+/// generate the iceberg schema of a new table, field ids are assigned in order from 1
+pub(crate) fn schema_databend_to_iceberg(schema: &TableSchema) -> Result<JsonValue> {
+    let fields = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let data_type = field.data_type();
+            Ok(json!({
+                "id": i + 1,
+                "name": field.name(),
+                "required": !data_type.is_nullable(),
+                "type": primitive_databend_to_iceberg(&data_type.remove_nullable())?,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({"type": "struct", "schema-id": 0, "fields": fields}))
+}
+
+/// Narrow integers are widened, since iceberg only has 32 and 64 bits integers.
+fn primitive_databend_to_iceberg(data_type: &TableDataType) -> Result<String> {
+    let iceberg_type = match data_type {
+        TableDataType::Boolean => "boolean",
+        TableDataType::Number(
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16,
+        ) => "int",
+        TableDataType::Number(NumberDataType::Int64 | NumberDataType::UInt32) => "long",
+        TableDataType::Number(NumberDataType::Float32) => "float",
+        TableDataType::Number(NumberDataType::Float64) => "double",
+        TableDataType::Decimal(decimal) if decimal.precision() <= 38 => {
+            return Ok(format!(
+                "decimal({},{})",
+                decimal.precision(),
+                decimal.scale()
+            ));
+        }
+        TableDataType::Date => "date",
+        TableDataType::Timestamp => "timestamp",
+        TableDataType::String => "string",
+        TableDataType::Binary => "binary",
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "data type {data_type} is not supported by iceberg tables"
+            )));
+        }
+    };
+    Ok(iceberg_type.to_string())
+}
+
 #[cfg(test)]
 mod convert_test {
     use common_meta_app::storage::StorageFsConfig;
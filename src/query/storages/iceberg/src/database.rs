@@ -21,15 +21,26 @@ use common_catalog::database::Database;
 use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::TruncateTableReply;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::meta_writer::MetadataBuilder;
 use crate::table::IcebergTable;
+use crate::table::LOCATION_PTR;
+use crate::table::META_PTR;
+use crate::table::RENAMED_MARK;
 
 #[derive(Clone, Debug)]
 pub struct IcebergDatabase {
@@ -86,36 +97,190 @@ impl IcebergDatabase {
             info,
         }
     }
-}
 
-#[async_trait]
-impl Database for IcebergDatabase {
-    fn name(&self) -> &str {
-        &self.info.name_ident.db_name
+    /// Names of the tables, which are the sub directories of the database,
+    /// except the directories keeping the files of renamed tables.
+    #[async_backtrace::framed]
+    async fn list_table_names(&self) -> Result<Vec<String>> {
+        let mut names = vec![];
+        let op = self.db_root.operator();
+        let mut lister = op.list("/").await?;
+        while let Some(page) = lister.next_page().await? {
+            for entry in page {
+                let meta = op.metadata(&entry, Metakey::Mode).await?;
+                if meta.mode() != EntryMode::DIR
+                    || op
+                        .is_exist(&format!("{}{RENAMED_MARK}", entry.path()))
+                        .await?
+                {
+                    continue;
+                }
+                names.push(entry.name().trim_end_matches('/').to_string());
+            }
+        }
+        Ok(names)
     }
 
-    fn get_db_info(&self) -> &DatabaseInfo {
-        &self.info
+    /// the table is found by the id derived from its name
+    #[async_backtrace::framed]
+    async fn table_name_by_id(&self, table_id: u64) -> Result<String> {
+        let db_name = self.name();
+        for table_name in self.list_table_names().await? {
+            if IcebergTable::table_id(db_name, &table_name) == table_id {
+                return Ok(table_name);
+            }
+        }
+        Err(ErrorCode::UnknownTable(format!(
+            "Unknown table id {table_id} in database '{db_name}'"
+        )))
     }
 
+    /// The directory of the metadata versions of a table, and the root of the table files,
+    /// which is another directory if the table is renamed.
     #[async_backtrace::framed]
-    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+    async fn table_roots(&self, table_name: &str) -> Result<(DataOperator, DataOperator)> {
         let path = format!("{table_name}/");
         let op = self.db_root.operator();
         // check existence first
-        if !op.stat(&path).await?.mode().is_dir() {
+        if !op.is_exist(&path).await?
+            || !op.stat(&path).await?.mode().is_dir()
+            || op.is_exist(&format!("{path}{RENAMED_MARK}")).await?
+        {
             return Err(ErrorCode::UnknownTable(format!(
                 "table {table_name} does not exist or is not a valid table"
             )));
         }
 
         let table_sp = self.db_root.params().map_root(|r| format!("{r}{path}"));
-        let tbl_root = DataOperator::try_create(&table_sp).await?;
+        let meta_root = DataOperator::try_create(&table_sp).await?;
+        let location_ptr = format!("{path}{LOCATION_PTR}");
+        let tbl_root = if op.is_exist(&location_ptr).await? {
+            let root = String::from_utf8(op.read(&location_ptr).await?).map_err(|_| {
+                ErrorCode::ReadTableDataError(format!(
+                    "invalid {LOCATION_PTR} of iceberg table {table_name}"
+                ))
+            })?;
+            DataOperator::try_create(&table_sp.map_root(|_| root.trim().to_string())).await?
+        } else {
+            meta_root.clone()
+        };
+        Ok((meta_root, tbl_root))
+    }
+
+    /// Rename a table to `new_table_name` in the database `to`.
+    ///
+    /// Files of iceberg tables are recorded with absolute paths, so they are kept where the table
+    /// was created: the current metadata is written to the directory of the new name, along with
+    /// the storage root of the table files. The metadata versions are removed from the directory
+    /// of the old name, which is marked as renamed, and a table of the old name can't be created
+    /// until the renamed table is dropped or renamed back.
+    #[async_backtrace::framed]
+    pub(crate) async fn move_table(
+        &self,
+        table_name: &str,
+        to: &IcebergDatabase,
+        new_table_name: &str,
+    ) -> Result<u64> {
+        let (meta_root, tbl_root) = self.table_roots(table_name).await?;
+        let new_path = format!("{new_table_name}/");
+        let to_op = to.db_root.operator();
+        // renamed back to the directory of the table files
+        let back = meta_root.params() != tbl_root.params()
+            && to.db_root.params().map_root(|r| format!("{r}{new_path}")) == tbl_root.params();
+        if !back && to_op.is_exist(&new_path).await? {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Table '{}'.'{new_table_name}' already exists",
+                to.name()
+            )));
+        }
+
+        let meta_op = meta_root.operator();
+        let meta_path = IcebergTable::version_detect(&meta_op).await?;
+        let metadata = meta_op.read(&meta_path).await?;
+        to_op
+            .write(&format!("{new_path}metadata/v1.metadata.json"), metadata)
+            .await?;
+        to_op.write(&format!("{new_path}{META_PTR}"), "1").await?;
+        if back {
+            to_op.delete(&format!("{new_path}{RENAMED_MARK}")).await?;
+        } else {
+            let root = match tbl_root.params() {
+                StorageParams::S3(cfg) => cfg.root,
+                StorageParams::Gcs(cfg) => cfg.root,
+                StorageParams::Oss(cfg) => cfg.root,
+                StorageParams::Fs(cfg) => cfg.root,
+                params => {
+                    return Err(ErrorCode::StorageUnsupported(format!(
+                        "renaming iceberg tables on {params} is not supported"
+                    )));
+                }
+            };
+            to_op
+                .write(&format!("{new_path}{LOCATION_PTR}"), root)
+                .await?;
+        }
 
+        let path = format!("{table_name}/");
+        let op = self.db_root.operator();
+        if op.is_exist(&format!("{path}{LOCATION_PTR}")).await? {
+            // renamed before, the directory only has metadata versions
+            op.remove_all(&path).await?;
+        } else {
+            op.write(&format!("{path}{RENAMED_MARK}"), new_table_name.to_string())
+                .await?;
+            let mut lister = meta_op.list("metadata/").await?;
+            while let Some(page) = lister.next_page().await? {
+                for entry in page {
+                    if entry.name().ends_with(".metadata.json") {
+                        meta_op.delete(entry.path()).await?;
+                    }
+                }
+            }
+            meta_op.delete(META_PTR).await?;
+        }
+        Ok(IcebergTable::table_id(to.name(), new_table_name))
+    }
+}
+
+/// The absolute location of a table, data files and metadata files are recorded
+/// with absolute paths under it.
+fn table_location(params: &StorageParams) -> Result<String> {
+    let location = match params {
+        StorageParams::S3(cfg) => format!("s3://{}/{}", cfg.bucket, cfg.root.trim_matches('/')),
+        StorageParams::Gcs(cfg) => {
+            format!("gs://{}/{}", cfg.bucket, cfg.root.trim_matches('/'))
+        }
+        StorageParams::Oss(cfg) => {
+            format!("oss://{}/{}", cfg.bucket, cfg.root.trim_matches('/'))
+        }
+        StorageParams::Fs(cfg) => format!("file:///{}", cfg.root.trim_matches('/')),
+        _ => {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "creating iceberg tables on {params} is not supported"
+            )));
+        }
+    };
+    Ok(location)
+}
+
+#[async_trait]
+impl Database for IcebergDatabase {
+    fn name(&self) -> &str {
+        &self.info.name_ident.db_name
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        let (meta_root, tbl_root) = self.table_roots(table_name).await?;
         let tbl = IcebergTable::try_create_table_from_read(
             &self.ctl_name,
             &self.info.name_ident.db_name,
             table_name,
+            meta_root,
             tbl_root,
         )
         .await?;
@@ -125,19 +290,74 @@ impl Database for IcebergDatabase {
     #[async_backtrace::framed]
     async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
         let mut tables = vec![];
+        for tbl_name in self.list_table_names().await? {
+            let table = self.get_table(&tbl_name).await?;
+            tables.push(table);
+        }
+        Ok(tables)
+    }
+
+    /// Create a table directory with the first version of metadata,
+    /// the table is versioned with the version hint.
+    #[async_backtrace::framed]
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db_name = self.name();
+        let table_name = &req.name_ident.table_name;
+        let table_id = IcebergTable::table_id(db_name, table_name);
+        let path = format!("{table_name}/");
         let op = self.db_root.operator();
-        let mut lister = op.list("/").await?;
-        while let Some(page) = lister.next_page().await? {
-            for entry in page {
-                let meta = op.metadata(&entry, Metakey::Mode).await?;
-                if meta.mode() != EntryMode::DIR {
-                    continue;
-                }
-                let tbl_name = entry.name().trim_end_matches('/');
-                let table = self.get_table(tbl_name).await?;
-                tables.push(table);
+        if op.is_exist(&path).await? {
+            if req.if_not_exists {
+                return Ok(CreateTableReply {
+                    table_id,
+                    new_table: false,
+                });
             }
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Table '{db_name}'.'{table_name}' already exists"
+            )));
         }
-        Ok(tables)
+
+        let table_sp = self.db_root.params().map_root(|r| format!("{r}{path}"));
+        let location = table_location(&table_sp)?;
+        let metadata = MetadataBuilder::new_table(&location, &req.table_meta.schema)?.build()?;
+        op.write(&format!("{path}metadata/v1.metadata.json"), metadata)
+            .await?;
+        op.write(&format!("{path}{META_PTR}"), "1").await?;
+
+        Ok(CreateTableReply {
+            table_id,
+            new_table: true,
+        })
+    }
+
+    /// Remove all the files of the table, the table is found by the id derived from its name.
+    #[async_backtrace::framed]
+    async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
+        let table_name = self.table_name_by_id(req.tb_id).await?;
+        let (meta_root, tbl_root) = self.table_roots(&table_name).await?;
+        if tbl_root.params() != meta_root.params() {
+            // the files of a renamed table
+            tbl_root.operator().remove_all("/").await?;
+        }
+        self.db_root
+            .operator()
+            .remove_all(&format!("{table_name}/"))
+            .await?;
+        Ok(DropTableReply { spec_vec: None })
+    }
+
+    /// Truncate the table by committing an empty snapshot, see [`IcebergTable::truncate_data`].
+    #[async_backtrace::framed]
+    async fn truncate_table(&self, req: TruncateTableReq) -> Result<TruncateTableReply> {
+        let table_name = self.table_name_by_id(req.table_id).await?;
+        let table = self.get_table(&table_name).await?;
+        table
+            .as_any()
+            .downcast_ref::<IcebergTable>()
+            .expect("must be an iceberg table")
+            .truncate_data()
+            .await?;
+        Ok(TruncateTableReply {})
     }
 }
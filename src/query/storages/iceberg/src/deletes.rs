@@ -0,0 +1,249 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Equality delete files delete the rows of older data files with the same values
+//! in the equality columns, null values are equal to each other.
+//!
+//! They are applied when partitions are read: the equality columns of the data files
+//! they apply to are read, and the positions of the matching rows are deleted like
+//! the positions in position delete files. Scans of such tables read the affected data
+//! files once more, until the deletes are rewritten by a compaction of other engines.
+
+use std::collections::HashSet;
+use std::io::Cursor;
+
+use common_arrow::arrow::io::parquet::read as pread;
+use common_exception::Result;
+use common_expression::Column;
+use common_expression::DataType;
+use common_expression::Scalar;
+
+use crate::manifest::AvroValue;
+use crate::manifest::DataFile;
+
+/// the rows deleted by an equality delete file
+pub(crate) struct EqualityDeletes {
+    spec_id: i32,
+    sequence_number: i64,
+    partition: Vec<(String, AvroValue)>,
+    /// names and types of the equality columns
+    columns: Vec<(String, DataType)>,
+    /// values of the equality columns of the deleted rows
+    keys: HashSet<Vec<Scalar>>,
+}
+
+impl EqualityDeletes {
+    /// read the equality columns of a delete file
+    pub fn try_create(
+        spec_id: i32,
+        file: &DataFile,
+        columns: Vec<(String, DataType)>,
+        data: &[u8],
+    ) -> Result<Self> {
+        let mut keys = HashSet::new();
+        for_each_row(data, &columns, |row| {
+            keys.insert(row);
+        })?;
+        Ok(Self {
+            spec_id,
+            sequence_number: file.sequence_number,
+            partition: file.partition.clone(),
+            columns,
+            keys,
+        })
+    }
+
+    /// The deletes apply to the data files of smaller sequence numbers, in the same partition
+    /// unless the deletes are unpartitioned.
+    pub fn applies_to(&self, spec_id: i32, file: &DataFile) -> bool {
+        self.sequence_number > file.sequence_number
+            && (self.partition.is_empty()
+                || (self.spec_id == spec_id && self.partition == file.partition))
+    }
+}
+
+/// positions of the rows of a data file deleted by the equality deletes applying to it
+pub(crate) fn deleted_rows(data: &[u8], deletes: &[&EqualityDeletes]) -> Result<Vec<u64>> {
+    // the equality columns of all the deletes are read at once
+    let mut columns: Vec<(String, DataType)> = vec![];
+    for delete in deletes {
+        for column in &delete.columns {
+            if !columns.iter().any(|(name, _)| name == &column.0) {
+                columns.push(column.clone());
+            }
+        }
+    }
+    let indices = deletes
+        .iter()
+        .map(|delete| {
+            delete
+                .columns
+                .iter()
+                .map(|(name, _)| columns.iter().position(|(n, _)| n == name).unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut positions = vec![];
+    let mut pos = 0;
+    for_each_row(data, &columns, |row| {
+        let deleted = deletes.iter().zip(&indices).any(|(delete, indices)| {
+            let key = indices.iter().map(|i| row[*i].clone()).collect::<Vec<_>>();
+            delete.keys.contains(&key)
+        });
+        if deleted {
+            positions.push(pos);
+        }
+        pos += 1;
+    })?;
+    Ok(positions)
+}
+
+/// Call `f` with the values of the columns of each row in a parquet file, columns are
+/// found by names. The columns missing in the file, added after it's written, are null.
+fn for_each_row(
+    data: &[u8],
+    columns: &[(String, DataType)],
+    mut f: impl FnMut(Vec<Scalar>),
+) -> Result<()> {
+    let mut reader = Cursor::new(data);
+    let metadata = pread::read_metadata(&mut reader)?;
+    let schema = pread::infer_schema(&metadata)?
+        .filter(|_, field| columns.iter().any(|(name, _)| name == &field.name));
+    if schema.fields.is_empty() {
+        for _ in 0..metadata.num_rows {
+            f(vec![Scalar::Null; columns.len()]);
+        }
+        return Ok(());
+    }
+    let indices = columns
+        .iter()
+        .map(|(name, _)| schema.fields.iter().position(|f| &f.name == name))
+        .collect::<Vec<_>>();
+
+    let chunks = pread::FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+    for chunk in chunks {
+        let chunk = chunk?;
+        let values = indices
+            .iter()
+            .zip(columns)
+            .map(|(index, (_, data_type))| {
+                index.map(|i| Column::from_arrow(chunk.arrays()[i].as_ref(), data_type))
+            })
+            .collect::<Vec<_>>();
+        for row in 0..chunk.len() {
+            f(values
+                .iter()
+                .map(|column| match column {
+                    Some(column) => column.index(row).unwrap().to_owned(),
+                    None => Scalar::Null,
+                })
+                .collect());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common_expression::types::NumberDataType;
+    use common_expression::DataType;
+
+    use super::deleted_rows;
+    use super::EqualityDeletes;
+    use crate::manifest::read_manifest;
+    use crate::manifest::read_manifest_list;
+    use crate::manifest::DataContent;
+
+    const TABLE_DIR: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../../../tests/data/iceberg/iceberg_ctl/iceberg_db/iceberg_eq_tbl/"
+    );
+
+    fn read_file(path: &str) -> Vec<u8> {
+        let name = path.split("/iceberg_eq_tbl/").nth(1).unwrap_or(path);
+        std::fs::read(format!("{TABLE_DIR}{name}")).unwrap()
+    }
+
+    fn column_type(field_id: i32) -> (String, DataType) {
+        match field_id {
+            1 => (
+                "id".to_string(),
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32))),
+            ),
+            _ => (
+                "data".to_string(),
+                DataType::Nullable(Box::new(DataType::String)),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_equality_deletes() {
+        let list =
+            read_manifest_list(&read_file("metadata/snap-1004-1-manifest-list.avro")).unwrap();
+        let mut files = vec![];
+        for manifest in &list {
+            files.extend(
+                read_manifest(
+                    &read_file(&manifest.manifest_path),
+                    manifest.sequence_number,
+                )
+                .unwrap(),
+            );
+        }
+        let (data_files, delete_files): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .partition(|f| f.content == DataContent::Data);
+        assert_eq!(
+            data_files
+                .iter()
+                .map(|f| f.sequence_number)
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+
+        let deletes = delete_files
+            .iter()
+            .map(|file| {
+                assert_eq!(file.content, DataContent::EqualityDeletes);
+                let columns = file
+                    .equality_ids
+                    .iter()
+                    .map(|id| column_type(*id))
+                    .collect();
+                EqualityDeletes::try_create(0, file, columns, &read_file(&file.file_path)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'), (8, NULL)
+        // 2 and 5 by id, 'd' and NULL by data
+        let applied = deletes
+            .iter()
+            .filter(|d| d.applies_to(0, &data_files[0]))
+            .collect::<Vec<_>>();
+        assert_eq!(applied.len(), 2);
+        let data = read_file(&data_files[0].file_path);
+        assert_eq!(deleted_rows(&data, &applied).unwrap(), vec![1, 3, 4, 5]);
+
+        // (2, 'b2'), (7, 'g'), the re-inserted 2 is not deleted by the older deletes
+        let applied = deletes
+            .iter()
+            .filter(|d| d.applies_to(0, &data_files[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(applied.len(), 1);
+        let data = read_file(&data_files[1].file_path);
+        assert!(deleted_rows(&data, &applied).unwrap().is_empty());
+    }
+}
@@ -86,6 +86,24 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## Writing
+//!
+//! Databases and unpartitioned tables can be created and dropped in non-flatten catalogs,
+//! and data can be inserted into tables of the catalog:
+//! ```sql
+//! CREATE DATABASE icb_ctl.db2;
+//! CREATE TABLE icb_ctl.db2.tbl (id INT, data STRING);
+//! INSERT INTO icb_ctl.db2.tbl VALUES (1, 'a');
+//! ```
+//!
+//! Each insertion writes parquet data files and commits a new snapshot,
+//! retrying when other writers committed to the table concurrently.
+//! Truncating a table commits a snapshot without data files.
+//!
+//! Renaming a table moves its metadata to the directory of the new name,
+//! the data files and manifests stay in the directory the table is created in,
+//! since they are recorded with absolute paths.
 
 /// the Iceberg Catalog implementation
 mod catalog;
@@ -93,12 +111,18 @@ mod catalog;
 mod converters;
 /// database implementation
 mod database;
-/// reading manifestlist and manifest files
-#[allow(unused)]
+/// applying equality delete files
+mod deletes;
+/// reading and writing manifestlist and manifest files
 mod manifest;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// table metadata writer
+mod meta_writer;
+/// sink writing data files of inserts
+mod sink;
+/// statistics for pruning data files
+mod statistics;
 /// table implementation
 mod table;
 
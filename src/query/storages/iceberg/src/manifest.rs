@@ -12,56 +12,944 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Manifest lists and manifests are avro files, the fields are looked up by name,
+//! so that both v1 and v2 layouts can be read.
+//!
+//! Manifests of appended data files are written with the layout of the table format version,
+//! the avro schemas carry the field ids required by iceberg.
+
 use std::collections::HashMap;
+use std::io::Cursor;
 
+use common_arrow::arrow::io::avro::avro_schema::read::block_iterator;
+use common_arrow::arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use common_arrow::arrow::io::avro::avro_schema::read::read_metadata;
+use common_arrow::arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value as JsonValue;
 
 /// item in manifest list file
 /// read manifest file by this struct
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManifestPtr {
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestFile {
     pub manifest_path: String,
-    pub manifest_length: usize,
-    pub partition_spec_id: usize,
-    pub added_snapshot_id: usize,
-    pub existing_data_files_count: usize,
-    pub deleted_data_files_count: usize,
-    pub partitions: Vec<ManiPart>,
-    pub added_rows_count: usize,
-    pub existing_rows_count: usize,
-    pub deleted_rows_count: usize,
-}
-
-/// item of manifest spec in `ManifestPtr`
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManiPart {
-    pub contains_null: bool,
-    pub lower_bound: String,
-    pub upper_bound: String,
-}
-
-/// manifest file
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct Manifest {
-    pub status: isize,
-    pub snapshot_id: i64,
-    pub data_file: DataFile,
-}
-
-/// data file
-#[derive(Clone, Debug, Deserialize)]
+    pub partition_spec_id: i32,
+    /// inherited by the entries without sequence number, 0 for v1 tables
+    pub sequence_number: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum DataContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// data file or delete file of a live manifest entry
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct DataFile {
+    pub content: DataContent,
+    /// data sequence number, the files added by a commit are inherited
+    /// from the manifest list until it's committed
+    pub sequence_number: i64,
     pub file_path: String,
     pub file_format: String,
-    pub partition: HashMap<String, u64>,
-    pub record_count: usize,
-    pub file_size_in_bytes: usize,
-    pub block_size_in_bytes: usize,
-    pub column_sizes: HashMap<u64, u64>,
-    pub value_counts: HashMap<u64, u64>,
-    pub null_value_counts: HashMap<u64, u64>,
-    pub lower_bounds: HashMap<u64, String>,
-    pub upper_bounds: HashMap<u64, String>,
-    pub key_metadata: Option<String>,
-    pub split_offsets: Vec<u64>,
+    /// partition values, by the names of the partition fields
+    pub partition: Vec<(String, AvroValue)>,
+    pub record_count: u64,
+    pub file_size_in_bytes: u64,
+    /// column statistics, by field id
+    pub value_counts: HashMap<i32, u64>,
+    pub null_value_counts: HashMap<i32, u64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    /// field ids of the columns compared by equality delete files
+    pub equality_ids: Vec<i32>,
+}
+
+/// a datum decoded from avro
+///
+/// Unlike the avro input format, binary values are kept as is,
+/// since the bounds in manifests are binary serialized values.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum AvroValue {
+    Null,
+    Boolean(bool),
+    /// both int and long
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    /// both bytes and fixed
+    Bytes(Vec<u8>),
+    String(String),
+    Record(Vec<(String, AvroValue)>),
+    Array(Vec<AvroValue>),
+    Map(Vec<(String, AvroValue)>),
+}
+
+impl AvroValue {
+    fn field(&self, name: &str) -> Option<&AvroValue> {
+        match self {
+            AvroValue::Record(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_long(&self) -> Option<i64> {
+        match self {
+            AvroValue::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            AvroValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// read the manifest list of a snapshot
+pub(crate) fn read_manifest_list(data: &[u8]) -> Result<Vec<ManifestFile>> {
+    read_avro_file(data)?
+        .iter()
+        .map(|record| {
+            Ok(ManifestFile {
+                manifest_path: required_string(record, "manifest_path")?,
+                partition_spec_id: optional_long(record, "partition_spec_id")?.unwrap_or(0) as i32,
+                sequence_number: optional_long(record, "sequence_number")?.unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// read the entries of a manifest list as they are, to be carried over to a new snapshot
+pub(crate) fn read_manifest_list_entries(data: &[u8]) -> Result<Vec<AvroValue>> {
+    read_avro_file(data)
+}
+
+/// Read the live data files and delete files in a manifest, deleted entries are skipped.
+///
+/// The entries without sequence number inherit the sequence number of the manifest.
+pub(crate) fn read_manifest(data: &[u8], sequence_number: i64) -> Result<Vec<DataFile>> {
+    let mut files = vec![];
+    for entry in read_avro_file(data)? {
+        // 0: EXISTING, 1: ADDED, 2: DELETED
+        if required_long(&entry, "status")? == 2 {
+            continue;
+        }
+        let file = entry
+            .field("data_file")
+            .ok_or_else(|| missing_field("data_file"))?;
+        let content = match optional_long(file, "content")? {
+            None | Some(0) => DataContent::Data,
+            Some(1) => DataContent::PositionDeletes,
+            Some(2) => DataContent::EqualityDeletes,
+            Some(v) => {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid content {v} of iceberg data file"
+                )));
+            }
+        };
+        let partition = match file.field("partition") {
+            Some(AvroValue::Record(fields)) => fields.clone(),
+            _ => vec![],
+        };
+        let equality_ids = match file.field("equality_ids") {
+            None | Some(AvroValue::Null) => vec![],
+            Some(AvroValue::Array(ids)) => ids
+                .iter()
+                .map(|id| id.as_long().map(|id| id as i32))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid_field("equality_ids"))?,
+            Some(_) => return Err(invalid_field("equality_ids")),
+        };
+        files.push(DataFile {
+            content,
+            sequence_number: optional_long(&entry, "sequence_number")?.unwrap_or(sequence_number),
+            file_path: required_string(file, "file_path")?,
+            file_format: required_string(file, "file_format")?,
+            partition,
+            record_count: required_long(file, "record_count")? as u64,
+            file_size_in_bytes: required_long(file, "file_size_in_bytes")? as u64,
+            value_counts: id_map(file, "value_counts", |v| v.as_long().map(|v| v as u64))?,
+            null_value_counts: id_map(file, "null_value_counts", |v| {
+                v.as_long().map(|v| v as u64)
+            })?,
+            lower_bounds: id_map(file, "lower_bounds", bytes_value)?,
+            upper_bounds: id_map(file, "upper_bounds", bytes_value)?,
+            equality_ids,
+        });
+    }
+    Ok(files)
+}
+
+/// Write a manifest of the data files added by a snapshot.
+///
+/// Only unpartitioned specs are supported, the sequence numbers of the entries
+/// are inherited from the manifest list.
+pub(crate) fn write_manifest(
+    format_version: i32,
+    snapshot_id: i64,
+    schema: &JsonValue,
+    spec_id: i32,
+    files: &[DataFile],
+) -> Result<Vec<u8>> {
+    let entries = files
+        .iter()
+        .map(|file| {
+            let mut data_file = vec![];
+            if format_version >= 2 {
+                data_file.push(("content".to_string(), AvroValue::Long(0)));
+            }
+            data_file.extend([
+                (
+                    "file_path".to_string(),
+                    AvroValue::String(file.file_path.clone()),
+                ),
+                (
+                    "file_format".to_string(),
+                    AvroValue::String(file.file_format.clone()),
+                ),
+                ("partition".to_string(), AvroValue::Record(vec![])),
+                (
+                    "record_count".to_string(),
+                    AvroValue::Long(file.record_count as i64),
+                ),
+                (
+                    "file_size_in_bytes".to_string(),
+                    AvroValue::Long(file.file_size_in_bytes as i64),
+                ),
+                (
+                    "value_counts".to_string(),
+                    id_map_value(&file.value_counts, |v| AvroValue::Long(*v as i64)),
+                ),
+                (
+                    "null_value_counts".to_string(),
+                    id_map_value(&file.null_value_counts, |v| AvroValue::Long(*v as i64)),
+                ),
+                (
+                    "lower_bounds".to_string(),
+                    id_map_value(&file.lower_bounds, |v| AvroValue::Bytes(v.clone())),
+                ),
+                (
+                    "upper_bounds".to_string(),
+                    id_map_value(&file.upper_bounds, |v| AvroValue::Bytes(v.clone())),
+                ),
+            ]);
+            if format_version == 1 {
+                // required by v1, but no longer used by readers
+                data_file.push((
+                    "block_size_in_bytes".to_string(),
+                    AvroValue::Long(DEFAULT_BLOCK_SIZE),
+                ));
+            }
+            AvroValue::Record(vec![
+                // ADDED
+                ("status".to_string(), AvroValue::Long(1)),
+                ("snapshot_id".to_string(), AvroValue::Long(snapshot_id)),
+                ("data_file".to_string(), AvroValue::Record(data_file)),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let mut metadata = vec![
+        ("schema", schema.to_string()),
+        ("partition-spec", "[]".to_string()),
+        ("partition-spec-id", spec_id.to_string()),
+        ("format-version", format_version.to_string()),
+    ];
+    if format_version >= 2 {
+        metadata.push(("content", "data".to_string()));
+    }
+    write_avro_file(&manifest_entry_schema(format_version), &metadata, &entries)
+}
+
+/// The entry of a manifest added by a snapshot, in the manifest list.
+pub(crate) fn new_manifest_entry(
+    format_version: i32,
+    manifest_path: &str,
+    manifest_length: u64,
+    spec_id: i32,
+    snapshot_id: i64,
+    sequence_number: i64,
+    files: &[DataFile],
+) -> AvroValue {
+    let added_files = files.len() as i64;
+    let added_rows = files.iter().map(|f| f.record_count).sum::<u64>() as i64;
+    let mut fields = vec![
+        (
+            "manifest_path".to_string(),
+            AvroValue::String(manifest_path.to_string()),
+        ),
+        (
+            "manifest_length".to_string(),
+            AvroValue::Long(manifest_length as i64),
+        ),
+        (
+            "partition_spec_id".to_string(),
+            AvroValue::Long(spec_id as i64),
+        ),
+        (
+            "added_snapshot_id".to_string(),
+            AvroValue::Long(snapshot_id),
+        ),
+        ("added_rows_count".to_string(), AvroValue::Long(added_rows)),
+        ("existing_rows_count".to_string(), AvroValue::Long(0)),
+        ("deleted_rows_count".to_string(), AvroValue::Long(0)),
+        ("partitions".to_string(), AvroValue::Array(vec![])),
+    ];
+    if format_version >= 2 {
+        fields.extend([
+            ("content".to_string(), AvroValue::Long(0)),
+            (
+                "sequence_number".to_string(),
+                AvroValue::Long(sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                AvroValue::Long(sequence_number),
+            ),
+            (
+                "added_files_count".to_string(),
+                AvroValue::Long(added_files),
+            ),
+            ("existing_files_count".to_string(), AvroValue::Long(0)),
+            ("deleted_files_count".to_string(), AvroValue::Long(0)),
+        ]);
+    } else {
+        fields.extend([
+            (
+                "added_data_files_count".to_string(),
+                AvroValue::Long(added_files),
+            ),
+            ("existing_data_files_count".to_string(), AvroValue::Long(0)),
+            ("deleted_data_files_count".to_string(), AvroValue::Long(0)),
+        ]);
+    }
+    AvroValue::Record(fields)
+}
+
+/// Write the manifest list of a snapshot, the entries are written by the names of
+/// their fields, so that entries read from an older manifest list can be carried over.
+pub(crate) fn write_manifest_list(
+    format_version: i32,
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+    entries: &[AvroValue],
+) -> Result<Vec<u8>> {
+    let mut metadata = vec![
+        ("snapshot-id", snapshot_id.to_string()),
+        ("format-version", format_version.to_string()),
+    ];
+    if let Some(parent) = parent_snapshot_id {
+        metadata.push(("parent-snapshot-id", parent.to_string()));
+    }
+    if format_version >= 2 {
+        metadata.push(("sequence-number", sequence_number.to_string()));
+    }
+    write_avro_file(&manifest_file_schema(format_version), &metadata, entries)
+}
+
+/// the value of `block_size_in_bytes` written by the java implementation
+const DEFAULT_BLOCK_SIZE: i64 = 64 * 1024 * 1024;
+
+fn id_map_value<T>(map: &HashMap<i32, T>, f: impl Fn(&T) -> AvroValue) -> AvroValue {
+    let mut items = map
+        .iter()
+        .map(|(k, v)| {
+            AvroValue::Record(vec![
+                ("key".to_string(), AvroValue::Long(*k as i64)),
+                ("value".to_string(), f(v)),
+            ])
+        })
+        .collect::<Vec<_>>();
+    items.sort_by_key(|item| item.field("key").and_then(AvroValue::as_long));
+    AvroValue::Array(items)
+}
+
+fn optional(schema: JsonValue) -> JsonValue {
+    json!(["null", schema])
+}
+
+fn field(name: &str, field_id: i32, schema: JsonValue) -> JsonValue {
+    if schema.is_array() {
+        json!({"name": name, "type": schema, "default": null, "field-id": field_id})
+    } else {
+        json!({"name": name, "type": schema, "field-id": field_id})
+    }
+}
+
+/// Maps with int keys are written as arrays of key-value records.
+fn id_map_schema(key_id: i32, value_id: i32, value: &str) -> JsonValue {
+    optional(json!({
+        "type": "array",
+        "logicalType": "map",
+        "items": {
+            "type": "record",
+            "name": format!("k{key_id}_v{value_id}"),
+            "fields": [field("key", key_id, json!("int")), field("value", value_id, json!(value))],
+        },
+    }))
+}
+
+fn manifest_entry_schema(format_version: i32) -> JsonValue {
+    let mut file_fields = vec![];
+    if format_version >= 2 {
+        file_fields.push(field("content", 134, json!("int")));
+    }
+    file_fields.extend([
+        field("file_path", 100, json!("string")),
+        field("file_format", 101, json!("string")),
+        field(
+            "partition",
+            102,
+            json!({"type": "record", "name": "r102", "fields": []}),
+        ),
+        field("record_count", 103, json!("long")),
+        field("file_size_in_bytes", 104, json!("long")),
+    ]);
+    if format_version == 1 {
+        file_fields.push(field("block_size_in_bytes", 105, json!("long")));
+    }
+    file_fields.extend([
+        field("column_sizes", 108, id_map_schema(117, 118, "long")),
+        field("value_counts", 109, id_map_schema(119, 120, "long")),
+        field("null_value_counts", 110, id_map_schema(121, 122, "long")),
+        field("nan_value_counts", 137, id_map_schema(138, 139, "long")),
+        field("lower_bounds", 125, id_map_schema(126, 127, "bytes")),
+        field("upper_bounds", 128, id_map_schema(129, 130, "bytes")),
+        field("key_metadata", 131, optional(json!("bytes"))),
+        field(
+            "split_offsets",
+            132,
+            optional(json!({"type": "array", "items": "long", "element-id": 133})),
+        ),
+    ]);
+    if format_version >= 2 {
+        file_fields.push(field(
+            "equality_ids",
+            135,
+            optional(json!({"type": "array", "items": "int", "element-id": 136})),
+        ));
+    }
+    file_fields.push(field("sort_order_id", 140, optional(json!("int"))));
+
+    let mut fields = vec![
+        field("status", 0, json!("int")),
+        field("snapshot_id", 1, optional(json!("long"))),
+    ];
+    if format_version >= 2 {
+        fields.push(field("sequence_number", 3, optional(json!("long"))));
+        fields.push(field("file_sequence_number", 4, optional(json!("long"))));
+    }
+    fields.push(field(
+        "data_file",
+        2,
+        json!({"type": "record", "name": "r2", "fields": file_fields}),
+    ));
+    json!({"type": "record", "name": "manifest_entry", "fields": fields})
+}
+
+fn manifest_file_schema(format_version: i32) -> JsonValue {
+    let partitions = optional(json!({
+        "type": "array",
+        "items": {
+            "type": "record",
+            "name": "r508",
+            "fields": [
+                field("contains_null", 509, json!("boolean")),
+                field("contains_nan", 518, optional(json!("boolean"))),
+                field("lower_bound", 510, optional(json!("bytes"))),
+                field("upper_bound", 511, optional(json!("bytes"))),
+            ],
+        },
+        "element-id": 508,
+    }));
+    let mut fields = vec![
+        field("manifest_path", 500, json!("string")),
+        field("manifest_length", 501, json!("long")),
+        field("partition_spec_id", 502, json!("int")),
+    ];
+    if format_version >= 2 {
+        fields.extend([
+            field("content", 517, json!("int")),
+            field("sequence_number", 515, json!("long")),
+            field("min_sequence_number", 516, json!("long")),
+            field("added_snapshot_id", 503, json!("long")),
+            field("added_files_count", 504, json!("int")),
+            field("existing_files_count", 505, json!("int")),
+            field("deleted_files_count", 506, json!("int")),
+            field("added_rows_count", 512, json!("long")),
+            field("existing_rows_count", 513, json!("long")),
+            field("deleted_rows_count", 514, json!("long")),
+            field("partitions", 507, partitions),
+            field("key_metadata", 519, optional(json!("bytes"))),
+        ]);
+    } else {
+        fields.extend([
+            field("added_snapshot_id", 503, optional(json!("long"))),
+            field("added_data_files_count", 504, optional(json!("int"))),
+            field("existing_data_files_count", 505, optional(json!("int"))),
+            field("deleted_data_files_count", 506, optional(json!("int"))),
+            field("partitions", 507, partitions),
+            field("added_rows_count", 512, optional(json!("long"))),
+            field("existing_rows_count", 513, optional(json!("long"))),
+            field("deleted_rows_count", 514, optional(json!("long"))),
+        ]);
+    }
+    json!({"type": "record", "name": "manifest_file", "fields": fields})
+}
+
+fn missing_field(name: &str) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("missing field {name} in iceberg manifest"))
+}
+
+fn invalid_field(name: &str) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("invalid field {name} in iceberg manifest"))
+}
+
+fn required_long(record: &AvroValue, name: &str) -> Result<i64> {
+    optional_long(record, name)?.ok_or_else(|| missing_field(name))
+}
+
+fn optional_long(record: &AvroValue, name: &str) -> Result<Option<i64>> {
+    match record.field(name) {
+        None | Some(AvroValue::Null) => Ok(None),
+        Some(v) => v.as_long().map(Some).ok_or_else(|| invalid_field(name)),
+    }
+}
+
+fn required_string(record: &AvroValue, name: &str) -> Result<String> {
+    record
+        .field(name)
+        .ok_or_else(|| missing_field(name))?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid_field(name))
+}
+
+fn bytes_value(value: &AvroValue) -> Option<Vec<u8>> {
+    match value {
+        AvroValue::Bytes(v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+/// Maps with int keys are written as arrays of key-value records by iceberg.
+fn id_map<T>(
+    record: &AvroValue,
+    name: &str,
+    f: impl Fn(&AvroValue) -> Option<T>,
+) -> Result<HashMap<i32, T>> {
+    let mut map = HashMap::new();
+    match record.field(name) {
+        None | Some(AvroValue::Null) => {}
+        Some(AvroValue::Array(items)) => {
+            for item in items {
+                let key = required_long(item, "key")? as i32;
+                let value = item
+                    .field("value")
+                    .and_then(&f)
+                    .ok_or_else(|| invalid_field(name))?;
+                map.insert(key, value);
+            }
+        }
+        Some(AvroValue::Map(items)) => {
+            for (key, value) in items {
+                let key = key.parse::<i32>().map_err(|_| invalid_field(name))?;
+                map.insert(key, f(value).ok_or_else(|| invalid_field(name))?);
+            }
+        }
+        Some(_) => return Err(invalid_field(name)),
+    }
+    Ok(map)
+}
+
+/// read all the records in an avro object container file
+fn read_avro_file(data: &[u8]) -> Result<Vec<AvroValue>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid avro file header: {e}")))?;
+    let schema = AvroSchema::Record(metadata.record);
+
+    let mut records = vec![];
+    let mut blocks = block_iterator(reader, metadata.compression, metadata.marker);
+    while let Some(block) = blocks
+        .next()
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid avro data block: {e}")))?
+    {
+        let mut buf = block.data.as_slice();
+        for _ in 0..block.number_of_rows {
+            records.push(read_value(&mut buf, &schema)?);
+        }
+    }
+    Ok(records)
+}
+
+fn eof_error() -> ErrorCode {
+    ErrorCode::ReadTableDataError("unexpected end of avro data")
+}
+
+fn read_long(buf: &mut &[u8]) -> Result<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let (byte, rest) = buf.split_first().ok_or_else(eof_error)?;
+        *buf = rest;
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(ErrorCode::ReadTableDataError("invalid avro varint"));
+        }
+    }
+    // zigzag
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+fn read_len(buf: &mut &[u8]) -> Result<usize> {
+    let len = read_long(buf)?;
+    if len < 0 {
+        return Err(ErrorCode::ReadTableDataError(format!(
+            "invalid avro length {len}"
+        )));
+    }
+    Ok(len as usize)
+}
+
+fn read_slice<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(eof_error());
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(data)
+}
+
+fn read_string(buf: &mut &[u8]) -> Result<String> {
+    let len = read_len(buf)?;
+    let data = read_slice(buf, len)?;
+    String::from_utf8(data.to_vec())
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid utf8 in avro string: {e}")))
+}
+
+/// Items of arrays and maps are encoded as a series of blocks, each block
+/// starts with its count, a negative count is followed by the block size in bytes.
+fn read_block_count(buf: &mut &[u8]) -> Result<usize> {
+    let count = read_long(buf)?;
+    if count < 0 {
+        read_long(buf)?;
+        Ok(count.unsigned_abs() as usize)
+    } else {
+        Ok(count as usize)
+    }
+}
+
+fn read_value(buf: &mut &[u8], schema: &AvroSchema) -> Result<AvroValue> {
+    let value = match schema {
+        AvroSchema::Null => AvroValue::Null,
+        AvroSchema::Boolean => AvroValue::Boolean(read_slice(buf, 1)?[0] != 0),
+        AvroSchema::Int(_) | AvroSchema::Long(_) => AvroValue::Long(read_long(buf)?),
+        AvroSchema::Float => {
+            let data = read_slice(buf, 4)?;
+            AvroValue::Float(f32::from_le_bytes(data.try_into().unwrap()))
+        }
+        AvroSchema::Double => {
+            let data = read_slice(buf, 8)?;
+            AvroValue::Double(f64::from_le_bytes(data.try_into().unwrap()))
+        }
+        AvroSchema::Bytes(_) => {
+            let len = read_len(buf)?;
+            AvroValue::Bytes(read_slice(buf, len)?.to_vec())
+        }
+        AvroSchema::Fixed(fixed) => AvroValue::Bytes(read_slice(buf, fixed.size)?.to_vec()),
+        AvroSchema::String(_) => AvroValue::String(read_string(buf)?),
+        AvroSchema::Record(record) => {
+            let mut fields = Vec::with_capacity(record.fields.len());
+            for f in &record.fields {
+                fields.push((f.name.clone(), read_value(buf, &f.schema)?));
+            }
+            AvroValue::Record(fields)
+        }
+        AvroSchema::Enum(e) => {
+            let index = read_len(buf)?;
+            let symbol = e.symbols.get(index).ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "invalid index {index} of avro enum {}",
+                    e.name
+                ))
+            })?;
+            AvroValue::String(symbol.clone())
+        }
+        AvroSchema::Array(item) => {
+            let mut items = vec![];
+            loop {
+                let count = read_block_count(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    items.push(read_value(buf, item)?);
+                }
+            }
+            AvroValue::Array(items)
+        }
+        AvroSchema::Map(value) => {
+            let mut items = vec![];
+            loop {
+                let count = read_block_count(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    let key = read_string(buf)?;
+                    items.push((key, read_value(buf, value)?));
+                }
+            }
+            AvroValue::Map(items)
+        }
+        AvroSchema::Union(variants) => {
+            let index = read_len(buf)?;
+            let variant = variants.get(index).ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!("invalid avro union index {index}"))
+            })?;
+            read_value(buf, variant)?
+        }
+    };
+    Ok(value)
+}
+
+/// Write the records in an uncompressed avro object container file,
+/// along with the key-value metadata in the header.
+fn write_avro_file(
+    schema: &JsonValue,
+    metadata: &[(&str, String)],
+    records: &[AvroValue],
+) -> Result<Vec<u8>> {
+    let schema_json = schema.to_string();
+    let avro_schema: AvroSchema = serde_json::from_str(&schema_json)?;
+
+    let mut buf = b"Obj\x01".to_vec();
+    write_long(&mut buf, metadata.len() as i64 + 2);
+    write_bytes(&mut buf, b"avro.schema");
+    write_bytes(&mut buf, schema_json.as_bytes());
+    write_bytes(&mut buf, b"avro.codec");
+    write_bytes(&mut buf, b"null");
+    for (key, value) in metadata {
+        write_bytes(&mut buf, key.as_bytes());
+        write_bytes(&mut buf, value.as_bytes());
+    }
+    write_long(&mut buf, 0);
+    let marker = *uuid::Uuid::new_v4().as_bytes();
+    buf.extend_from_slice(&marker);
+
+    if !records.is_empty() {
+        let mut block = vec![];
+        for record in records {
+            write_value(&mut block, record, &avro_schema)?;
+        }
+        write_long(&mut buf, records.len() as i64);
+        write_long(&mut buf, block.len() as i64);
+        buf.extend_from_slice(&block);
+        buf.extend_from_slice(&marker);
+    }
+    Ok(buf)
+}
+
+fn write_long(buf: &mut Vec<u8>, value: i64) {
+    // zigzag
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_bytes(buf: &mut Vec<u8>, data: &[u8]) {
+    write_long(buf, data.len() as i64);
+    buf.extend_from_slice(data);
+}
+
+/// Fields missing in a record are written as nulls.
+fn write_value(buf: &mut Vec<u8>, value: &AvroValue, schema: &AvroSchema) -> Result<()> {
+    match (schema, value) {
+        (AvroSchema::Null, AvroValue::Null) => {}
+        (AvroSchema::Boolean, AvroValue::Boolean(v)) => buf.push(*v as u8),
+        (AvroSchema::Int(_) | AvroSchema::Long(_), AvroValue::Long(v)) => write_long(buf, *v),
+        (AvroSchema::Float, AvroValue::Float(v)) => buf.extend_from_slice(&v.to_le_bytes()),
+        (AvroSchema::Double, AvroValue::Double(v)) => buf.extend_from_slice(&v.to_le_bytes()),
+        (AvroSchema::Bytes(_), AvroValue::Bytes(v)) => write_bytes(buf, v),
+        (AvroSchema::Fixed(fixed), AvroValue::Bytes(v)) if fixed.size == v.len() => {
+            buf.extend_from_slice(v)
+        }
+        (AvroSchema::String(_), AvroValue::String(v)) => write_bytes(buf, v.as_bytes()),
+        (AvroSchema::Enum(e), AvroValue::String(v)) => {
+            match e.symbols.iter().position(|s| s == v) {
+                Some(index) => write_long(buf, index as i64),
+                None => return Err(invalid_value(value, schema)),
+            }
+        }
+        (AvroSchema::Record(record), AvroValue::Record(_)) => {
+            for f in &record.fields {
+                let v = value.field(&f.name).unwrap_or(&AvroValue::Null);
+                write_value(buf, v, &f.schema)?;
+            }
+        }
+        (AvroSchema::Array(item), AvroValue::Array(items)) => {
+            if !items.is_empty() {
+                write_long(buf, items.len() as i64);
+                for v in items {
+                    write_value(buf, v, item)?;
+                }
+            }
+            write_long(buf, 0);
+        }
+        (AvroSchema::Map(item), AvroValue::Map(items)) => {
+            if !items.is_empty() {
+                write_long(buf, items.len() as i64);
+                for (k, v) in items {
+                    write_bytes(buf, k.as_bytes());
+                    write_value(buf, v, item)?;
+                }
+            }
+            write_long(buf, 0);
+        }
+        (AvroSchema::Union(variants), _) => {
+            let is_null = matches!(value, AvroValue::Null);
+            let index = variants
+                .iter()
+                .position(|s| matches!(s, AvroSchema::Null) == is_null)
+                .ok_or_else(|| invalid_value(value, schema))?;
+            write_long(buf, index as i64);
+            write_value(buf, value, &variants[index])?;
+        }
+        _ => return Err(invalid_value(value, schema)),
+    }
+    Ok(())
+}
+
+fn invalid_value(value: &AvroValue, schema: &AvroSchema) -> ErrorCode {
+    ErrorCode::Internal(format!("cannot write {value:?} as avro type {schema:?}"))
+}
+
+#[cfg(test)]
+mod manifest_test {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::new_manifest_entry;
+    use super::read_manifest;
+    use super::read_manifest_list;
+    use super::read_manifest_list_entries;
+    use super::write_manifest;
+    use super::write_manifest_list;
+    use super::DataContent;
+    use super::DataFile;
+
+    const TABLE_DIR: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../../../tests/data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/metadata/"
+    );
+
+    fn read_file(name: &str) -> Vec<u8> {
+        std::fs::read(format!("{TABLE_DIR}{name}")).unwrap()
+    }
+
+    #[test]
+    fn test_read_manifests() {
+        let list = read_manifest_list(&read_file(
+            "snap-6788296308394418127-1-ba3e3b20-02d8-47b7-8aba-5432b9aa72b9.avro",
+        ))
+        .unwrap();
+        assert_eq!(list.len(), 2);
+
+        let mut files = vec![];
+        for manifest in &list {
+            let name = manifest.manifest_path.rsplit('/').next().unwrap();
+            files.extend(read_manifest(&read_file(name), manifest.sequence_number).unwrap());
+        }
+        // the overwritten data files are marked as deleted in the manifests
+        assert_eq!(files.iter().map(|f| f.record_count).sum::<u64>(), 6);
+        for file in &files {
+            assert_eq!(file.content, DataContent::Data);
+            assert_eq!(file.file_format.to_lowercase(), "parquet");
+            assert!(file.file_path.contains("/iceberg_tbl/data/"));
+            // bounds of `id`, an int column
+            assert_eq!(file.lower_bounds[&1].len(), 4);
+        }
+    }
+
+    fn data_file(path: &str) -> DataFile {
+        DataFile {
+            content: DataContent::Data,
+            sequence_number: 0,
+            file_path: path.to_string(),
+            file_format: "PARQUET".to_string(),
+            partition: vec![],
+            record_count: 3,
+            file_size_in_bytes: 1024,
+            value_counts: HashMap::from([(1, 3), (2, 3)]),
+            null_value_counts: HashMap::from([(1, 0), (2, 1)]),
+            lower_bounds: HashMap::from([(1, 5i32.to_le_bytes().to_vec())]),
+            upper_bounds: HashMap::from([(1, 9i32.to_le_bytes().to_vec())]),
+            equality_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn test_write_manifest() {
+        let schema = json!({
+            "type": "struct",
+            "schema-id": 0,
+            "fields": [
+                {"id": 1, "name": "id", "required": true, "type": "int"},
+                {"id": 2, "name": "data", "required": false, "type": "string"},
+            ],
+        });
+        let files = vec![
+            data_file("s3://bkt/tbl/data/0.parquet"),
+            data_file("s3://bkt/tbl/data/1.parquet"),
+        ];
+        for format_version in [1, 2] {
+            let data = write_manifest(format_version, 42, &schema, 0, &files).unwrap();
+            assert_eq!(read_manifest(&data, 0).unwrap(), files);
+        }
+    }
+
+    #[test]
+    fn test_write_manifest_list() {
+        let mut entries = read_manifest_list_entries(&read_file(
+            "snap-6788296308394418127-1-ba3e3b20-02d8-47b7-8aba-5432b9aa72b9.avro",
+        ))
+        .unwrap();
+        entries.push(new_manifest_entry(
+            1,
+            "s3://bkt/tbl/metadata/m0.avro",
+            4096,
+            0,
+            42,
+            0,
+            &[data_file("s3://bkt/tbl/data/0.parquet")],
+        ));
+        let data = write_manifest_list(1, 42, Some(6788296308394418127), 0, &entries).unwrap();
+
+        let list = read_manifest_list(&data).unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            list[0].manifest_path,
+            entries[0].field("manifest_path").unwrap().as_str().unwrap()
+        );
+        assert_eq!(list[2].manifest_path, "s3://bkt/tbl/metadata/m0.avro");
+    }
 }
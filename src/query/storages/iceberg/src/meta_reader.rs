@@ -13,3 +13,101 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+
+/// the parts of table metadata needed for scanning a table
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ScanMetadata {
+    /// the base location of the table, files are recorded with absolute paths under it
+    pub location: String,
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    pub current_schema_id: Option<i32>,
+    /// v1 tables may only have this single schema
+    pub schema: Option<Schema>,
+    #[serde(default)]
+    pub schemas: Vec<Schema>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Snapshot {
+    pub snapshot_id: i64,
+    pub manifest_list: Option<String>,
+    /// v1 tables may list manifests in snapshots without manifest list files
+    pub manifests: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Schema {
+    pub schema_id: Option<i32>,
+    pub fields: Vec<SchemaField>,
+}
+
+/// only the top level fields are needed, nested types are not parsed
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct SchemaField {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionField {
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+impl ScanMetadata {
+    pub fn try_create(meta_json: &[u8]) -> Result<Self> {
+        serde_json::de::from_slice(meta_json)
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid metadata: {e:?}")))
+    }
+
+    /// the current snapshot, `None` if the table has no data yet
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        // `-1` is used by v1 tables for no current snapshot
+        let id = self.current_snapshot_id?;
+        self.snapshots.iter().find(|s| s.snapshot_id == id)
+    }
+
+    pub fn current_schema(&self) -> Option<&Schema> {
+        self.schemas
+            .iter()
+            .find(|s| s.schema_id.is_some() && s.schema_id == self.current_schema_id)
+            .or(self.schema.as_ref())
+            .or_else(|| self.schemas.last())
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&PartitionSpec> {
+        self.partition_specs.iter().find(|s| s.spec_id == spec_id)
+    }
+
+    /// turn an absolute path recorded in metadata into a path relative to the table root
+    pub fn relative_path(&self, path: &str) -> Result<String> {
+        let location = self.location.trim_end_matches('/');
+        path.strip_prefix(location)
+            .map(|p| p.trim_start_matches('/').to_string())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "file {path} is not under the table location {location}"
+                ))
+            })
+    }
+}
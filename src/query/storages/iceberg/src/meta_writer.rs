@@ -0,0 +1,388 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! this module builds new versions of table metadata for commits,
+//! the fields not understood by databend are kept as they are.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use serde_json::json;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+use crate::converters::schema_databend_to_iceberg;
+
+/// table property of the name mapping used for data files without field ids
+const NAME_MAPPING_PROPERTY: &str = "schema.name-mapping.default";
+
+/// a snapshot to add to the table
+pub(crate) struct NewSnapshot {
+    pub snapshot_id: i64,
+    pub sequence_number: i64,
+    /// absolute path of the manifest list
+    pub manifest_list: String,
+    /// `append`, `overwrite` or `delete`
+    pub operation: &'static str,
+    pub added_data_files: u64,
+    pub added_records: u64,
+    pub added_files_size: u64,
+}
+
+/// the table metadata being built, as json
+pub(crate) struct MetadataBuilder {
+    meta: JsonMap<String, JsonValue>,
+}
+
+impl MetadataBuilder {
+    pub fn try_create(meta_json: &[u8]) -> Result<Self> {
+        match serde_json::from_slice(meta_json) {
+            Ok(JsonValue::Object(meta)) => Ok(Self { meta }),
+            Ok(_) => Err(ErrorCode::ReadTableDataError(
+                "invalid metadata: not a json object",
+            )),
+            Err(e) => Err(ErrorCode::ReadTableDataError(format!(
+                "invalid metadata: {e:?}"
+            ))),
+        }
+    }
+
+    /// the metadata of a new table in format version 2, without any snapshot
+    pub fn new_table(location: &str, schema: &TableSchema) -> Result<Self> {
+        let iceberg_schema = schema_databend_to_iceberg(schema)?;
+        let meta = json!({
+            "format-version": 2,
+            "table-uuid": uuid::Uuid::new_v4().to_string(),
+            "location": location,
+            "last-sequence-number": 0,
+            "last-updated-ms": Utc::now().timestamp_millis(),
+            "last-column-id": schema.num_fields(),
+            "current-schema-id": 0,
+            "schemas": [iceberg_schema],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {},
+            "current-snapshot-id": -1,
+            "refs": {},
+            "snapshots": [],
+            "snapshot-log": [],
+            "metadata-log": [],
+        });
+        let mut builder = Self::try_create(meta.to_string().as_bytes())?;
+        builder.set_default_name_mapping()?;
+        Ok(builder)
+    }
+
+    fn i64_field(&self, name: &str) -> Option<i64> {
+        self.meta.get(name).and_then(JsonValue::as_i64)
+    }
+
+    pub fn format_version(&self) -> i32 {
+        self.i64_field("format-version").unwrap_or(1) as i32
+    }
+
+    pub fn current_snapshot_id(&self) -> Option<i64> {
+        // `-1` is used for no current snapshot
+        self.i64_field("current-snapshot-id").filter(|id| *id >= 0)
+    }
+
+    /// sequence numbers are only tracked by v2 tables, and are always 0 in v1 tables
+    pub fn next_sequence_number(&self) -> i64 {
+        if self.format_version() >= 2 {
+            self.i64_field("last-sequence-number").unwrap_or(0) + 1
+        } else {
+            0
+        }
+    }
+
+    /// the current schema, written into manifests
+    pub fn current_schema(&self) -> Result<JsonValue> {
+        let current = self.meta.get("current-schema-id");
+        self.meta
+            .get("schemas")
+            .and_then(JsonValue::as_array)
+            .and_then(|schemas| {
+                schemas
+                    .iter()
+                    .find(|s| current.is_some() && s.get("schema-id") == current)
+            })
+            .or_else(|| self.meta.get("schema"))
+            .cloned()
+            .ok_or_else(|| ErrorCode::ReadTableDataError("invalid metadata: missing schema"))
+    }
+
+    /// the id of the default partition spec, and whether it has partition fields
+    pub fn default_spec(&self) -> (i32, bool) {
+        let spec_id = self.i64_field("default-spec-id").unwrap_or(0);
+        let partitioned = self
+            .meta
+            .get("partition-specs")
+            .and_then(JsonValue::as_array)
+            .and_then(|specs| {
+                specs
+                    .iter()
+                    .find(|s| s.get("spec-id").and_then(JsonValue::as_i64) == Some(spec_id))
+            })
+            .or_else(|| self.meta.get("partition-spec"))
+            .and_then(|spec| spec.get("fields").or(Some(spec)))
+            .and_then(JsonValue::as_array)
+            .map(|fields| !fields.is_empty())
+            .unwrap_or(false);
+        (spec_id as i32, partitioned)
+    }
+
+    /// The data files written by databend carry no field ids, so that readers
+    /// resolve their columns by names through the default name mapping.
+    pub fn set_default_name_mapping(&mut self) -> Result<()> {
+        let schema = self.current_schema()?;
+        let properties = self.meta.entry("properties").or_insert_with(|| json!({}));
+        if properties.get(NAME_MAPPING_PROPERTY).is_some() {
+            return Ok(());
+        }
+        let mapping = schema
+            .get("fields")
+            .and_then(JsonValue::as_array)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .map(|f| json!({"field-id": f["id"], "names": [f["name"]]}))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if let JsonValue::Object(properties) = properties {
+            properties.insert(
+                NAME_MAPPING_PROPERTY.to_string(),
+                JsonValue::String(JsonValue::Array(mapping).to_string()),
+            );
+        }
+        Ok(())
+    }
+
+    /// Add a snapshot and make it the current snapshot of the main branch,
+    /// the metadata file being replaced is recorded in the metadata log.
+    pub fn add_snapshot(&mut self, snapshot: NewSnapshot, previous_metadata: &str) {
+        let now = Utc::now().timestamp_millis();
+        let parent = self.current_snapshot_id();
+        let replace = snapshot.operation != "append";
+
+        let mut summary = JsonMap::new();
+        summary.insert("operation".to_string(), json!(snapshot.operation));
+        summary.insert(
+            "added-data-files".to_string(),
+            json!(snapshot.added_data_files.to_string()),
+        );
+        summary.insert(
+            "added-records".to_string(),
+            json!(snapshot.added_records.to_string()),
+        );
+        summary.insert(
+            "added-files-size".to_string(),
+            json!(snapshot.added_files_size.to_string()),
+        );
+        let parent_summary = parent
+            .and_then(|id| self.snapshot(id))
+            .and_then(|s| s.get("summary"))
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+        let totals = [
+            ("total-data-files", snapshot.added_data_files),
+            ("total-records", snapshot.added_records),
+            ("total-files-size", snapshot.added_files_size),
+            ("total-delete-files", 0),
+            ("total-position-deletes", 0),
+            ("total-equality-deletes", 0),
+        ];
+        for (key, added) in totals {
+            let previous = parent_summary
+                .get(key)
+                .and_then(JsonValue::as_str)
+                .and_then(|v| v.parse::<u64>().ok());
+            let total = match (replace, parent, previous) {
+                (true, _, _) | (false, None, _) => added,
+                (false, Some(_), Some(previous)) => previous + added,
+                // the total is unknown
+                (false, Some(_), None) => continue,
+            };
+            summary.insert(key.to_string(), json!(total.to_string()));
+        }
+
+        let mut new_snapshot = JsonMap::new();
+        new_snapshot.insert("snapshot-id".to_string(), json!(snapshot.snapshot_id));
+        if let Some(parent) = parent {
+            new_snapshot.insert("parent-snapshot-id".to_string(), json!(parent));
+        }
+        if self.format_version() >= 2 {
+            new_snapshot.insert(
+                "sequence-number".to_string(),
+                json!(snapshot.sequence_number),
+            );
+            self.meta.insert(
+                "last-sequence-number".to_string(),
+                json!(snapshot.sequence_number),
+            );
+        }
+        new_snapshot.insert("timestamp-ms".to_string(), json!(now));
+        new_snapshot.insert("summary".to_string(), JsonValue::Object(summary));
+        new_snapshot.insert("manifest-list".to_string(), json!(snapshot.manifest_list));
+        if let Some(schema_id) = self.meta.get("current-schema-id").cloned() {
+            new_snapshot.insert("schema-id".to_string(), schema_id);
+        }
+
+        let last_updated = self.i64_field("last-updated-ms").unwrap_or(now);
+        self.push("snapshots", JsonValue::Object(new_snapshot));
+        self.push(
+            "snapshot-log",
+            json!({"timestamp-ms": now, "snapshot-id": snapshot.snapshot_id}),
+        );
+        self.push(
+            "metadata-log",
+            json!({"timestamp-ms": last_updated, "metadata-file": previous_metadata}),
+        );
+        self.meta.insert(
+            "current-snapshot-id".to_string(),
+            json!(snapshot.snapshot_id),
+        );
+        self.meta.insert("last-updated-ms".to_string(), json!(now));
+        let refs = self.meta.entry("refs").or_insert_with(|| json!({}));
+        if let JsonValue::Object(refs) = refs {
+            refs.insert(
+                "main".to_string(),
+                json!({"snapshot-id": snapshot.snapshot_id, "type": "branch"}),
+            );
+        }
+    }
+
+    fn snapshot(&self, snapshot_id: i64) -> Option<&JsonValue> {
+        self.meta
+            .get("snapshots")
+            .and_then(JsonValue::as_array)?
+            .iter()
+            .find(|s| s.get("snapshot-id").and_then(JsonValue::as_i64) == Some(snapshot_id))
+    }
+
+    fn push(&mut self, name: &str, value: JsonValue) {
+        let array = self.meta.entry(name).or_insert_with(|| json!([]));
+        if let JsonValue::Array(array) = array {
+            array.push(value);
+        }
+    }
+
+    pub fn build(self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec_pretty(&JsonValue::Object(self.meta))?)
+    }
+}
+
+/// Figure out the path of the metadata version after `current`, along with the
+/// version to write into the version hint for tables versioned by it.
+///
+/// Both `v{N}.metadata.json` and `{NNNNN}-{uuid}.metadata.json` are supported.
+pub(crate) fn next_metadata_path(current: &str) -> Result<(String, Option<u64>)> {
+    let name = current
+        .strip_prefix("metadata/")
+        .and_then(|name| name.strip_suffix(".metadata.json"))
+        .unwrap_or_default();
+    if let Some(version) = name.strip_prefix('v').and_then(|v| v.parse::<u64>().ok()) {
+        let next = version + 1;
+        return Ok((format!("metadata/v{next}.metadata.json"), Some(next)));
+    }
+    if let Some((version, _)) = name.split_once('-') {
+        if let Ok(version) = version.parse::<u64>() {
+            let path = format!(
+                "metadata/{:05}-{}.metadata.json",
+                version + 1,
+                uuid::Uuid::new_v4()
+            );
+            return Ok((path, None));
+        }
+    }
+    Err(ErrorCode::Unimplemented(format!(
+        "cannot figure out the next version of iceberg metadata file {current}"
+    )))
+}
+
+#[cfg(test)]
+mod meta_writer_test {
+    use common_expression::types::NumberDataType;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
+    use iceberg_rs::model::table::TableMetadata;
+
+    use super::next_metadata_path;
+    use super::MetadataBuilder;
+    use super::NewSnapshot;
+    use crate::meta_reader::ScanMetadata;
+
+    #[test]
+    fn test_next_metadata_path() {
+        let (path, hint) = next_metadata_path("metadata/v9.metadata.json").unwrap();
+        assert_eq!(path, "metadata/v10.metadata.json");
+        assert_eq!(hint, Some(10));
+
+        let (path, hint) =
+            next_metadata_path("metadata/00005-032145b7-6a0c-4a53-bc3d-b7b571ccab3b.metadata.json")
+                .unwrap();
+        assert!(path.starts_with("metadata/00006-"));
+        assert!(path.ends_with(".metadata.json"));
+        assert_eq!(hint, None);
+
+        assert!(next_metadata_path("metadata/latest.metadata.json").is_err());
+    }
+
+    #[test]
+    fn test_new_table_with_snapshot() {
+        let schema = TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int8)),
+            TableField::new("name", TableDataType::String.wrap_nullable()),
+        ]);
+        let mut builder = MetadataBuilder::new_table("s3://bkt/db/tbl", &schema).unwrap();
+        assert_eq!(builder.format_version(), 2);
+        assert_eq!(builder.current_snapshot_id(), None);
+        assert_eq!(builder.default_spec(), (0, false));
+
+        builder.add_snapshot(
+            NewSnapshot {
+                snapshot_id: 42,
+                sequence_number: builder.next_sequence_number(),
+                manifest_list: "s3://bkt/db/tbl/metadata/snap-42.avro".to_string(),
+                operation: "append",
+                added_data_files: 1,
+                added_records: 3,
+                added_files_size: 1024,
+            },
+            "s3://bkt/db/tbl/metadata/v1.metadata.json",
+        );
+        let meta_json = builder.build().unwrap();
+
+        // can be read by both metadata readers
+        let metadata: TableMetadata = serde_json::from_slice(&meta_json).unwrap();
+        let metadata = metadata.to_latest();
+        assert_eq!(metadata.schemas[0].struct_fields.fields.len(), 2);
+
+        let scan_meta = ScanMetadata::try_create(&meta_json).unwrap();
+        let snapshot = scan_meta.current_snapshot().unwrap();
+        assert_eq!(snapshot.snapshot_id, 42);
+        assert_eq!(
+            snapshot.manifest_list.as_deref(),
+            Some("s3://bkt/db/tbl/metadata/snap-42.avro")
+        );
+
+        let builder = MetadataBuilder::try_create(&meta_json).unwrap();
+        assert_eq!(builder.next_sequence_number(), 2);
+    }
+}
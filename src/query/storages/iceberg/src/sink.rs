@@ -0,0 +1,366 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! this module writes the data appended to a table into parquet data files,
+//! the written files are committed by [`crate::IcebergTable`] at the end of the insertion.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::write::transverse;
+use common_arrow::arrow::io::parquet::write::CompressionOptions;
+use common_arrow::arrow::io::parquet::write::Encoding;
+use common_arrow::arrow::io::parquet::write::FileWriter;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::Version;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::NumberDomain;
+use common_expression::types::string::StringDomain;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Domain;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use opendal::Operator;
+
+use crate::manifest::DataContent;
+use crate::manifest::DataFile;
+
+/// the size of data buffered in memory before writing a data file
+const MAX_BUFFER_SIZE: usize = 128 * 1024 * 1024;
+
+/// bounds of longer strings are not recorded
+const MAX_STRING_BOUND_SIZE: usize = 64;
+
+#[typetag::serde(name = "iceberg_data_file")]
+impl BlockMetaInfo for DataFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match DataFile::downcast_ref_from(info) {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+impl From<DataFile> for DataBlock {
+    fn from(value: DataFile) -> Self {
+        DataBlock::empty_with_meta(Box::new(value))
+    }
+}
+
+impl TryFrom<&DataBlock> for DataFile {
+    type Error = ErrorCode;
+    fn try_from(block: &DataBlock) -> Result<Self> {
+        block
+            .get_meta()
+            .and_then(DataFile::downcast_ref_from)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "invalid data block meta of iceberg data file, {:?}",
+                    block.get_meta()
+                ))
+            })
+    }
+}
+
+enum State {
+    None,
+    NeedSerialize,
+    NeedWrite(String, Vec<u8>, DataFile),
+    Finished,
+}
+
+pub struct IcebergTableSink {
+    state: State,
+    input: Arc<InputPort>,
+    ctx: Arc<dyn TableContext>,
+    /// operator on the table root
+    operator: Operator,
+    /// base location of the table, data files are recorded with absolute paths under it
+    location: String,
+    schema: TableSchemaRef,
+    /// iceberg field id of each column
+    field_ids: Vec<i32>,
+
+    blocks: Vec<DataBlock>,
+    buffered_size: usize,
+}
+
+impl IcebergTableSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        operator: Operator,
+        location: String,
+        schema: TableSchemaRef,
+        field_ids: Vec<i32>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(IcebergTableSink {
+            state: State::None,
+            input,
+            ctx,
+            operator,
+            location,
+            schema,
+            field_ids,
+            blocks: vec![],
+            buffered_size: 0,
+        })))
+    }
+
+    fn serialize(&self, blocks: &[DataBlock]) -> Result<(Vec<u8>, DataFile)> {
+        let block = DataBlock::concat(blocks)?.convert_to_full();
+        let num_rows = block.num_rows() as u64;
+
+        let mut value_counts = HashMap::new();
+        let mut null_value_counts = HashMap::new();
+        let mut lower_bounds = HashMap::new();
+        let mut upper_bounds = HashMap::new();
+        for (entry, field_id) in block.columns().iter().zip(self.field_ids.iter()) {
+            let column = match &entry.value {
+                Value::Column(column) => column,
+                Value::Scalar(_) => unreachable!("block has been converted to full"),
+            };
+            let null_count = match column {
+                Column::Null { len } => *len,
+                Column::Nullable(c) => c.validity.unset_bits(),
+                _ => 0,
+            };
+            value_counts.insert(*field_id, num_rows);
+            null_value_counts.insert(*field_id, null_count as u64);
+            if let Some((lower, upper)) = domain_bounds(&column.domain()) {
+                lower_bounds.insert(*field_id, lower);
+                upper_bounds.insert(*field_id, upper);
+            }
+        }
+
+        let mut data = vec![];
+        write_parquet(&self.schema.to_arrow(), block, &mut data)?;
+
+        let file_path = format!(
+            "{}/data/{}.parquet",
+            self.location.trim_end_matches('/'),
+            uuid::Uuid::new_v4()
+        );
+        let file = DataFile {
+            content: DataContent::Data,
+            // inherited from the manifest list
+            sequence_number: 0,
+            file_path,
+            file_format: "PARQUET".to_string(),
+            partition: vec![],
+            record_count: num_rows,
+            file_size_in_bytes: data.len() as u64,
+            value_counts,
+            null_value_counts,
+            lower_bounds,
+            upper_bounds,
+            equality_ids: vec![],
+        };
+        Ok((data, file))
+    }
+}
+
+#[async_trait]
+impl Processor for IcebergTableSink {
+    fn name(&self) -> String {
+        "IcebergTableSink".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match &self.state {
+            State::NeedSerialize => return Ok(Event::Sync),
+            State::NeedWrite(..) => return Ok(Event::Async),
+            State::Finished => return Ok(Event::Finished),
+            State::None => {}
+        }
+
+        if self.input.is_finished() {
+            if self.blocks.is_empty() {
+                self.state = State::Finished;
+                return Ok(Event::Finished);
+            }
+            self.state = State::NeedSerialize;
+            return Ok(Event::Sync);
+        }
+
+        if !self.input.has_data() {
+            self.input.set_need_data();
+            return Ok(Event::NeedData);
+        }
+
+        let block = self.input.pull_data().unwrap()?;
+        if block.num_rows() > 0 {
+            self.buffered_size += block.memory_size();
+            self.blocks.push(block);
+        }
+        if self.buffered_size >= MAX_BUFFER_SIZE {
+            self.state = State::NeedSerialize;
+            return Ok(Event::Sync);
+        }
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::None) {
+            State::NeedSerialize => {
+                let blocks = std::mem::take(&mut self.blocks);
+                self.buffered_size = 0;
+                let (data, file) = self.serialize(&blocks)?;
+                let path = file
+                    .file_path
+                    .strip_prefix(self.location.trim_end_matches('/'))
+                    .unwrap_or_default()
+                    .trim_start_matches('/')
+                    .to_string();
+                self.state = State::NeedWrite(path, data, file);
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("Unknown state for iceberg table sink.")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::None) {
+            State::NeedWrite(path, data, file) => {
+                self.operator.write(&path, data).await?;
+                self.ctx.push_precommit_block(DataBlock::from(file));
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("Unknown state for iceberg table sink.")),
+        }
+    }
+}
+
+/// Write a block in parquet, strings are written as utf8 as required by iceberg.
+fn write_parquet(schema: &ArrowSchema, block: DataBlock, buf: &mut Vec<u8>) -> Result<()> {
+    let chunk = Chunk::try_from(block)?;
+    let mut fields = Vec::with_capacity(schema.fields.len());
+    let mut arrays = Vec::with_capacity(schema.fields.len());
+    for (field, array) in schema.fields.iter().zip(chunk.into_arrays()) {
+        if field.data_type == ArrowDataType::LargeBinary {
+            arrays.push(cast(
+                array.as_ref(),
+                &ArrowDataType::LargeUtf8,
+                CastOptions::default(),
+            )?);
+            fields.push(ArrowField::new(
+                &field.name,
+                ArrowDataType::LargeUtf8,
+                field.is_nullable,
+            ));
+        } else {
+            arrays.push(array);
+            fields.push(field.clone());
+        }
+    }
+    let schema = ArrowSchema::from(fields);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Zstd(None),
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+        .collect::<Vec<_>>();
+    let row_groups = RowGroupIterator::try_new(
+        std::iter::once(Ok(Chunk::try_new(arrays)?)),
+        &schema,
+        options,
+        encodings,
+    )?;
+    let mut writer = FileWriter::try_new(buf, schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(())
+}
+
+/// Encode the bounds of a column with the single-value serialization of iceberg,
+/// only the types of iceberg primitive columns are handled.
+fn domain_bounds(domain: &Domain) -> Option<(Vec<u8>, Vec<u8>)> {
+    let bounds = match domain {
+        Domain::Nullable(NullableDomain {
+            value: Some(value), ..
+        }) => return domain_bounds(value),
+        Domain::Boolean(BooleanDomain {
+            has_false,
+            has_true,
+        }) => (vec![!*has_false as u8], vec![*has_true as u8]),
+        Domain::Number(NumberDomain::Int32(d)) => {
+            (d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec())
+        }
+        Domain::Number(NumberDomain::Int64(d)) => {
+            (d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec())
+        }
+        // NaN is not included in bounds
+        Domain::Number(NumberDomain::Float32(d)) if !d.min.0.is_nan() && !d.max.0.is_nan() => (
+            d.min.0.to_le_bytes().to_vec(),
+            d.max.0.to_le_bytes().to_vec(),
+        ),
+        Domain::Number(NumberDomain::Float64(d)) if !d.min.0.is_nan() && !d.max.0.is_nan() => (
+            d.min.0.to_le_bytes().to_vec(),
+            d.max.0.to_le_bytes().to_vec(),
+        ),
+        Domain::Date(d) => (d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec()),
+        Domain::Timestamp(d) => (d.min.to_le_bytes().to_vec(), d.max.to_le_bytes().to_vec()),
+        Domain::String(StringDomain {
+            min,
+            max: Some(max),
+        }) if min.len() <= MAX_STRING_BOUND_SIZE && max.len() <= MAX_STRING_BOUND_SIZE => {
+            (min.clone(), max.clone())
+        }
+        _ => return None,
+    };
+    Some(bounds)
+}
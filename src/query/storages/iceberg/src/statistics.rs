@@ -0,0 +1,250 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! this module converts the statistics recorded in manifests to databend statistics,
+//! which are used to prune data files.
+
+use std::collections::HashMap;
+
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::ColumnId;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::manifest::AvroValue;
+use crate::manifest::DataFile;
+use crate::meta_reader::PartitionSpec;
+use crate::meta_reader::Schema;
+
+/// the databend column of an iceberg field
+#[derive(Clone, Debug)]
+pub(crate) struct StatsColumn {
+    pub column_id: ColumnId,
+    /// the data type without nullable
+    pub data_type: TableDataType,
+}
+
+/// map the top level primitive iceberg fields to databend columns
+pub(crate) fn stats_columns(iceberg: &Schema, schema: &TableSchema) -> HashMap<i32, StatsColumn> {
+    iceberg
+        .fields
+        .iter()
+        .filter_map(|f| {
+            let field = schema.field_with_name(&f.name).ok()?;
+            let data_type = field.data_type().remove_nullable();
+            match data_type {
+                TableDataType::Boolean
+                | TableDataType::Number(_)
+                | TableDataType::Date
+                | TableDataType::Timestamp
+                | TableDataType::String => Some((f.id, StatsColumn {
+                    column_id: field.column_id(),
+                    data_type,
+                })),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Collect the statistics of a data file from the column bounds,
+/// and the identity partition values for the columns without bounds.
+pub(crate) fn data_file_statistics(
+    file: &DataFile,
+    spec: Option<&PartitionSpec>,
+    columns: &HashMap<i32, StatsColumn>,
+) -> StatisticsOfColumns {
+    let mut stats = StatisticsOfColumns::new();
+    for (field_id, column) in columns {
+        let (lower, upper) = match (
+            file.lower_bounds.get(field_id),
+            file.upper_bounds.get(field_id),
+        ) {
+            (Some(lower), Some(upper)) => (lower, upper),
+            _ => continue,
+        };
+        let min = bound_to_scalar(lower, &column.data_type);
+        let max = bound_to_scalar(upper, &column.data_type);
+        if let (Some(min), Some(max)) = (min, max) {
+            stats.insert(column.column_id, ColumnStatistics {
+                min,
+                max,
+                null_count: file.null_value_counts.get(field_id).copied().unwrap_or(0),
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+    }
+
+    let identity_fields = spec
+        .iter()
+        .flat_map(|s| s.fields.iter())
+        .filter(|f| f.transform == "identity");
+    for field in identity_fields {
+        let column = match columns.get(&field.source_id) {
+            Some(column) => column,
+            None => continue,
+        };
+        if stats.contains_key(&column.column_id) {
+            continue;
+        }
+        let value = file
+            .partition
+            .iter()
+            .find(|(name, _)| name == &field.name)
+            .map(|(_, v)| v);
+        let (scalar, null_count) = match value {
+            Some(AvroValue::Null) => (Some(Scalar::Null), file.record_count),
+            Some(v) => (partition_to_scalar(v, &column.data_type), 0),
+            None => (None, 0),
+        };
+        if let Some(scalar) = scalar {
+            stats.insert(column.column_id, ColumnStatistics {
+                min: scalar.clone(),
+                max: scalar,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+    }
+    stats
+}
+
+/// Decode a bound with the single-value serialization of iceberg.
+///
+/// Bounds written before a promotion from int to long or float to double
+/// keep the original width.
+fn bound_to_scalar(data: &[u8], data_type: &TableDataType) -> Option<Scalar> {
+    let scalar = match data_type {
+        TableDataType::Boolean => Scalar::Boolean(*data.first()? != 0),
+        TableDataType::Number(NumberDataType::Int32) => Scalar::Number(NumberScalar::Int32(
+            i32::from_le_bytes(data.try_into().ok()?),
+        )),
+        TableDataType::Number(NumberDataType::Int64) => {
+            Scalar::Number(NumberScalar::Int64(match data.len() {
+                4 => i32::from_le_bytes(data.try_into().ok()?) as i64,
+                _ => i64::from_le_bytes(data.try_into().ok()?),
+            }))
+        }
+        TableDataType::Number(NumberDataType::Float32) => Scalar::Number(NumberScalar::Float32(
+            F32::from(f32::from_le_bytes(data.try_into().ok()?)),
+        )),
+        TableDataType::Number(NumberDataType::Float64) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(match data.len() {
+                4 => f32::from_le_bytes(data.try_into().ok()?) as f64,
+                _ => f64::from_le_bytes(data.try_into().ok()?),
+            })))
+        }
+        TableDataType::Date => Scalar::Date(i32::from_le_bytes(data.try_into().ok()?)),
+        TableDataType::Timestamp => Scalar::Timestamp(i64::from_le_bytes(data.try_into().ok()?)),
+        TableDataType::String => Scalar::String(data.to_vec()),
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+fn partition_to_scalar(value: &AvroValue, data_type: &TableDataType) -> Option<Scalar> {
+    let scalar = match (value, data_type) {
+        (AvroValue::Boolean(v), TableDataType::Boolean) => Scalar::Boolean(*v),
+        (AvroValue::Long(v), TableDataType::Number(NumberDataType::Int32)) => {
+            Scalar::Number(NumberScalar::Int32(i32::try_from(*v).ok()?))
+        }
+        (AvroValue::Long(v), TableDataType::Number(NumberDataType::Int64)) => {
+            Scalar::Number(NumberScalar::Int64(*v))
+        }
+        (AvroValue::Float(v), TableDataType::Number(NumberDataType::Float32)) => {
+            Scalar::Number(NumberScalar::Float32(F32::from(*v)))
+        }
+        (AvroValue::Float(v), TableDataType::Number(NumberDataType::Float64)) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(*v as f64)))
+        }
+        (AvroValue::Double(v), TableDataType::Number(NumberDataType::Float64)) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(*v)))
+        }
+        (AvroValue::Long(v), TableDataType::Date) => Scalar::Date(i32::try_from(*v).ok()?),
+        (AvroValue::Long(v), TableDataType::Timestamp) => Scalar::Timestamp(*v),
+        (AvroValue::String(v), TableDataType::String) => Scalar::String(v.as_bytes().to_vec()),
+        (AvroValue::Bytes(v), TableDataType::String) => Scalar::String(v.clone()),
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod statistics_test {
+    use std::collections::HashMap;
+
+    use common_expression::types::NumberDataType;
+    use common_expression::types::NumberScalar;
+    use common_expression::Scalar;
+    use common_expression::TableDataType;
+
+    use super::data_file_statistics;
+    use super::StatsColumn;
+    use crate::manifest::AvroValue;
+    use crate::manifest::DataContent;
+    use crate::manifest::DataFile;
+    use crate::meta_reader::PartitionField;
+    use crate::meta_reader::PartitionSpec;
+
+    #[test]
+    fn test_data_file_statistics() {
+        let file = DataFile {
+            content: DataContent::Data,
+            sequence_number: 1,
+            file_path: "s3://bkt/tbl/data/0.parquet".to_string(),
+            file_format: "PARQUET".to_string(),
+            partition: vec![("region".to_string(), AvroValue::String("eu".to_string()))],
+            record_count: 3,
+            file_size_in_bytes: 1024,
+            value_counts: HashMap::from([(1, 3)]),
+            null_value_counts: HashMap::from([(1, 1)]),
+            lower_bounds: HashMap::from([(1, 5i32.to_le_bytes().to_vec())]),
+            upper_bounds: HashMap::from([(1, 9i32.to_le_bytes().to_vec())]),
+            equality_ids: vec![],
+        };
+        let spec = PartitionSpec {
+            spec_id: 0,
+            fields: vec![PartitionField {
+                source_id: 2,
+                name: "region".to_string(),
+                transform: "identity".to_string(),
+            }],
+        };
+        let columns = HashMap::from([
+            (1, StatsColumn {
+                column_id: 0,
+                data_type: TableDataType::Number(NumberDataType::Int32),
+            }),
+            (2, StatsColumn {
+                column_id: 1,
+                data_type: TableDataType::String,
+            }),
+        ]);
+
+        let stats = data_file_statistics(&file, Some(&spec), &columns);
+        assert_eq!(stats[&0].min, Scalar::Number(NumberScalar::Int32(5)));
+        assert_eq!(stats[&0].max, Scalar::Number(NumberScalar::Int32(9)));
+        assert_eq!(stats[&0].null_count, 1);
+        assert_eq!(stats[&1].min, Scalar::String(b"eu".to_vec()));
+        assert_eq!(stats[&1].max, Scalar::String(b"eu".to_vec()));
+    }
+}
@@ -15,31 +15,87 @@
 //! 2022-11-25:
 //! TODO: support synchronize with remote
 //! Note:
-//! currently, we only care about immutable tables
-//! once the table created we don't update it.
+//! a table instance is a snapshot of the table when it's read,
+//! appended data is committed as a new version of the table metadata.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::Cursor;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_arrow::arrow::array::Int64Array;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::ParquetTableInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataType;
+use common_expression::TableDataType;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::Pipeline;
 use common_storage::DataOperator;
+use common_storage::StageFileInfo;
+use common_storage::StageFileStatus;
+use common_storage::StageFilesInfo;
+use common_storages_parquet::ParquetTable;
+use futures::future::try_join_all;
 use futures::StreamExt;
 use iceberg_rs::model::table::TableMetadata;
 use opendal::Operator;
+use storages_common_pruner::RangePrunerCreator;
 
 use crate::converters::meta_iceberg_to_databend;
+use crate::deletes::deleted_rows;
+use crate::deletes::EqualityDeletes;
+use crate::manifest::new_manifest_entry;
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::read_manifest_list_entries;
+use crate::manifest::write_manifest;
+use crate::manifest::write_manifest_list;
+use crate::manifest::AvroValue;
+use crate::manifest::DataContent;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestFile;
+use crate::meta_reader::ScanMetadata;
+use crate::meta_reader::Snapshot;
+use crate::meta_writer::next_metadata_path;
+use crate::meta_writer::MetadataBuilder;
+use crate::meta_writer::NewSnapshot;
+use crate::sink::IcebergTableSink;
+use crate::statistics::data_file_statistics;
+use crate::statistics::stats_columns;
 
 /// file marking the current version of metadata file
-const META_PTR: &str = "metadata/version_hint.text";
+pub(crate) const META_PTR: &str = "metadata/version_hint.text";
+
+/// File in the directory of a renamed table, recording the storage root of the table files,
+/// which are kept where the table was created since they are recorded with absolute paths.
+pub(crate) const LOCATION_PTR: &str = "metadata/location.text";
+
+/// file left in the directory of the files of a renamed table, which is no longer a table
+pub(crate) const RENAMED_MARK: &str = "metadata/renamed.text";
+
+/// the default of `commit.retry.num-retries` in the java implementation, plus the first attempt
+const MAX_COMMIT_ATTEMPTS: usize = 5;
 
 /// accessor wrapper as a table
 #[allow(unused)]
@@ -48,10 +104,14 @@ pub struct IcebergTable {
     database: String,
     /// name of the current table
     name: String,
-    /// root of the table
+    /// directory of the metadata versions, the root of the table unless it's renamed
+    meta_root: DataOperator,
+    /// root of the table, where data files and manifests are
     tbl_root: DataOperator,
     /// table metadata
     manifests: TableMetadata,
+    /// table metadata for planning scans
+    scan_meta: ScanMetadata,
     /// table information
     info: TableInfo,
 }
@@ -63,9 +123,10 @@ impl IcebergTable {
         catalog: &str,
         database: &str,
         table_name: &str,
+        meta_root: DataOperator,
         tbl_root: DataOperator,
     ) -> Result<IcebergTable> {
-        let op = tbl_root.operator();
+        let op = meta_root.operator();
         // detect the latest manifest file
         let latest_manifest = Self::version_detect(&op).await?;
         // get table metadata from metadata file
@@ -82,12 +143,13 @@ impl IcebergTable {
                     &latest_manifest, e
                 ))
             })?;
+        let scan_meta = ScanMetadata::try_create(&meta_json)?;
 
         let sp = tbl_root.params();
 
         // construct table info
         let info = TableInfo {
-            ident: TableIdent::new(0, 0),
+            ident: TableIdent::new(Self::table_id(database, table_name), 0),
             desc: format!("IcebergTable: '{database}'.'{table_name}'"),
             name: table_name.to_string(),
             meta: meta_iceberg_to_databend(catalog, &sp, &metadata),
@@ -98,17 +160,27 @@ impl IcebergTable {
         Ok(Self {
             database: database.to_string(),
             name: table_name.to_string(),
+            meta_root,
             tbl_root,
             manifests: metadata,
+            scan_meta,
             info,
         })
     }
 
+    /// Iceberg tables have no ids, the id of a table is derived from its name,
+    /// so that the table can be found by the id when it's dropped.
+    pub(crate) fn table_id(database: &str, table_name: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (database, table_name).hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// version_detect figures out the manifest list version of the table
     /// and gives the relative path from table root directory
     /// to latest metadata json file
     #[async_backtrace::framed]
-    async fn version_detect(tbl_root: &Operator) -> Result<String> {
+    pub(crate) async fn version_detect(tbl_root: &Operator) -> Result<String> {
         // try Dremio's way
         // Dremio has an `version_hint.txt` file
         // recording the latest snapshot version number
@@ -145,6 +217,526 @@ impl IcebergTable {
             .map(|s| format!("metadata/{s}"))
             .ok_or_else(|| ErrorCode::ReadTableDataError("Cannot get the latest manifest file"))
     }
+
+    /// the data files are read as parquet files in a stage on the table root
+    fn parquet_table_info(&self, files_to_read: Option<Vec<StageFileInfo>>) -> ParquetTableInfo {
+        ParquetTableInfo {
+            read_options: ParquetReadOptions::default(),
+            stage_info: StageInfo::new_external_stage(self.tbl_root.params(), "/"),
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: None,
+            },
+            table_info: self.info.clone(),
+            arrow_schema: self.info.schema().to_arrow(),
+            files_to_read,
+        }
+    }
+
+    /// read the live data files, position delete files and equality delete files
+    /// of the current snapshot, along with the partition spec id of each file
+    #[async_backtrace::framed]
+    async fn read_files(&self) -> Result<IcebergFiles> {
+        let snapshot = match self.scan_meta.current_snapshot() {
+            Some(snapshot) => snapshot,
+            // empty table
+            None => return Ok((vec![], vec![], vec![])),
+        };
+        let op = self.tbl_root.operator();
+
+        let manifests = match (&snapshot.manifest_list, &snapshot.manifests) {
+            (Some(list), _) => {
+                let data = op.read(&self.scan_meta.relative_path(list)?).await?;
+                read_manifest_list(&data)?
+            }
+            (None, Some(paths)) => paths
+                .iter()
+                .map(|path| ManifestFile {
+                    manifest_path: path.clone(),
+                    partition_spec_id: 0,
+                    sequence_number: 0,
+                })
+                .collect(),
+            (None, None) => vec![],
+        };
+
+        let files = try_join_all(manifests.iter().map(|manifest| {
+            let op = op.clone();
+            let path = self.scan_meta.relative_path(&manifest.manifest_path);
+            async move {
+                let data = op.read(&path?).await?;
+                read_manifest(&data, manifest.sequence_number)
+            }
+        }))
+        .await?;
+
+        let mut data_files = vec![];
+        let mut position_deletes = vec![];
+        let mut equality_deletes = vec![];
+        for (manifest, files) in manifests.iter().zip(files.into_iter()) {
+            for file in files {
+                match file.content {
+                    DataContent::Data => data_files.push((manifest.partition_spec_id, file)),
+                    DataContent::PositionDeletes => position_deletes.push(file),
+                    DataContent::EqualityDeletes => {
+                        equality_deletes.push((manifest.partition_spec_id, file))
+                    }
+                }
+            }
+        }
+        Ok((data_files, position_deletes, equality_deletes))
+    }
+
+    /// Read the position delete files, and collect the deleted positions of the data files
+    /// to read, by the paths relative to the table root.
+    #[async_backtrace::framed]
+    async fn read_position_deletes(
+        &self,
+        delete_files: &[DataFile],
+        data_files: &HashSet<&str>,
+    ) -> Result<HashMap<String, Vec<u64>>> {
+        let op = self.tbl_root.operator();
+        let deletes = try_join_all(delete_files.iter().map(|file| {
+            let op = op.clone();
+            let path =
+                check_file_format(file).and_then(|_| self.scan_meta.relative_path(&file.file_path));
+            async move {
+                let data = op.read(&path?).await?;
+                read_position_delete_file(&data, data_files)
+            }
+        }))
+        .await?;
+
+        let mut deleted_positions: HashMap<String, Vec<u64>> = HashMap::new();
+        for (path, pos) in deletes.into_iter().flatten() {
+            deleted_positions
+                .entry(self.scan_meta.relative_path(&path)?)
+                .or_default()
+                .push(pos);
+        }
+        for positions in deleted_positions.values_mut() {
+            positions.sort_unstable();
+            positions.dedup();
+        }
+        Ok(deleted_positions)
+    }
+
+    /// Read the equality delete files, and collect the deleted positions of the data files
+    /// they apply to, by the paths relative to the table root.
+    ///
+    /// The equality columns of the affected data files are read here, see [`crate::deletes`].
+    #[async_backtrace::framed]
+    async fn read_equality_deletes(
+        &self,
+        delete_files: &[(i32, DataFile)],
+        data_files: &[(i32, DataFile)],
+    ) -> Result<HashMap<String, Vec<u64>>> {
+        if delete_files.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let op = self.tbl_root.operator();
+        let deletes = try_join_all(delete_files.iter().map(|(spec_id, file)| {
+            let op = op.clone();
+            let path =
+                check_file_format(file).and_then(|_| self.scan_meta.relative_path(&file.file_path));
+            let columns = self.equality_columns(file);
+            async move {
+                let data = op.read(&path?).await?;
+                EqualityDeletes::try_create(*spec_id, file, columns?, &data)
+            }
+        }))
+        .await?;
+
+        let deleted = try_join_all(data_files.iter().filter_map(|(spec_id, file)| {
+            let applied = deletes
+                .iter()
+                .filter(|delete| delete.applies_to(*spec_id, file))
+                .collect::<Vec<_>>();
+            if applied.is_empty() {
+                return None;
+            }
+            let op = op.clone();
+            let path = self.scan_meta.relative_path(&file.file_path);
+            Some(async move {
+                let path = path?;
+                let data = op.read(&path).await?;
+                let positions = deleted_rows(&data, &applied)?;
+                Ok::<_, ErrorCode>((path, positions))
+            })
+        }))
+        .await?;
+        tracing::info!(
+            "iceberg table {}: {} data files affected by {} equality delete files",
+            self.name,
+            deleted.len(),
+            delete_files.len()
+        );
+        Ok(deleted.into_iter().collect())
+    }
+
+    /// names and types of the columns compared by an equality delete file
+    fn equality_columns(&self, file: &DataFile) -> Result<Vec<(String, DataType)>> {
+        let iceberg_schema = self.scan_meta.current_schema().ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!("iceberg table {} has no schema", self.name))
+        })?;
+        let schema = self.info.schema();
+        file.equality_ids
+            .iter()
+            .map(|id| {
+                let name = iceberg_schema
+                    .fields
+                    .iter()
+                    .find(|f| f.id == *id)
+                    .map(|f| &f.name)
+                    .ok_or_else(|| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "unknown equality field {id} in iceberg delete file {}",
+                            file.file_path
+                        ))
+                    })?;
+                let field = schema.field_with_name(name)?;
+                Ok((name.clone(), DataType::from(field.data_type())))
+            })
+            .collect()
+    }
+
+    /// the iceberg field ids of the columns, only primitive columns can be written
+    fn field_ids(&self) -> Result<Vec<i32>> {
+        let iceberg_schema = self.scan_meta.current_schema().ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!("iceberg table {} has no schema", self.name))
+        })?;
+        self.info
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                if matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+                ) {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "writing nested column {} of iceberg table {} is not supported",
+                        field.name(),
+                        self.name
+                    )));
+                }
+                iceberg_schema
+                    .fields
+                    .iter()
+                    .find(|f| &f.name == field.name())
+                    .map(|f| f.id)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "column {} not found in the schema of iceberg table {}",
+                            field.name(),
+                            self.name
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// the entries of the manifest list of a snapshot, to be carried over to a new snapshot
+    #[async_backtrace::framed]
+    async fn manifest_list_entries(
+        op: &Operator,
+        scan_meta: &ScanMetadata,
+        snapshot: &Snapshot,
+    ) -> Result<Vec<AvroValue>> {
+        if let Some(list) = &snapshot.manifest_list {
+            let data = op.read(&scan_meta.relative_path(list)?).await?;
+            return read_manifest_list_entries(&data);
+        }
+        // v1 snapshots without manifest list
+        let mut entries = vec![];
+        for path in snapshot.manifests.iter().flatten() {
+            let meta = op.stat(&scan_meta.relative_path(path)?).await?;
+            entries.push(AvroValue::Record(vec![
+                ("manifest_path".to_string(), AvroValue::String(path.clone())),
+                (
+                    "manifest_length".to_string(),
+                    AvroValue::Long(meta.content_length() as i64),
+                ),
+                ("partition_spec_id".to_string(), AvroValue::Long(0)),
+                (
+                    "added_snapshot_id".to_string(),
+                    AvroValue::Long(snapshot.snapshot_id),
+                ),
+            ]));
+        }
+        Ok(entries)
+    }
+
+    /// Commit a new snapshot with the data files written by [`IcebergTableSink`],
+    /// the snapshot replaces all the data of the table unless the operation is `append`.
+    ///
+    /// The commit is optimistic: the next metadata version is written only if no other
+    /// writer has committed it since the latest version is read, otherwise the commit
+    /// is retried on top of the new latest version. Note that the check and the write
+    /// are not atomic on object storages.
+    #[async_backtrace::framed]
+    async fn commit_data_files(&self, files: Vec<DataFile>, operation: &'static str) -> Result<()> {
+        let op = self.tbl_root.operator();
+        let meta_op = self.meta_root.operator();
+        let location = self.scan_meta.location.trim_end_matches('/').to_string();
+        let commit_id = uuid::Uuid::new_v4();
+        let snapshot_id = (commit_id.as_u64_pair().0 & i64::MAX as u64) as i64;
+
+        // the manifest doesn't depend on the table version, it's written only once
+        let mut manifest: Option<(String, u64)> = None;
+        for attempt in 1..=MAX_COMMIT_ATTEMPTS {
+            let meta_path = Self::version_detect(&meta_op).await?;
+            let meta_json = meta_op.read(&meta_path).await?;
+            let scan_meta = ScanMetadata::try_create(&meta_json)?;
+            let mut builder = MetadataBuilder::try_create(&meta_json)?;
+            let format_version = builder.format_version();
+            let (spec_id, partitioned) = builder.default_spec();
+            if partitioned && !files.is_empty() {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "writing to partitioned iceberg table {} is not supported",
+                    self.name
+                )));
+            }
+
+            if manifest.is_none() && !files.is_empty() {
+                let path = format!("metadata/{commit_id}-m0.avro");
+                let data = write_manifest(
+                    format_version,
+                    snapshot_id,
+                    &builder.current_schema()?,
+                    spec_id,
+                    &files,
+                )?;
+                let length = data.len() as u64;
+                op.write(&path, data).await?;
+                manifest = Some((path, length));
+            }
+
+            let sequence_number = builder.next_sequence_number();
+            let parent = builder.current_snapshot_id();
+            let mut entries = match scan_meta.current_snapshot() {
+                Some(snapshot) if operation == "append" => {
+                    Self::manifest_list_entries(&op, &scan_meta, snapshot).await?
+                }
+                _ => vec![],
+            };
+            if let Some((path, length)) = &manifest {
+                entries.push(new_manifest_entry(
+                    format_version,
+                    &format!("{location}/{path}"),
+                    *length,
+                    spec_id,
+                    snapshot_id,
+                    sequence_number,
+                    &files,
+                ));
+            }
+            let list_path = format!("metadata/snap-{snapshot_id}-{attempt}-{commit_id}.avro");
+            let list = write_manifest_list(
+                format_version,
+                snapshot_id,
+                parent,
+                sequence_number,
+                &entries,
+            )?;
+            op.write(&list_path, list).await?;
+
+            builder.set_default_name_mapping()?;
+            builder.add_snapshot(
+                NewSnapshot {
+                    snapshot_id,
+                    sequence_number,
+                    manifest_list: format!("{location}/{list_path}"),
+                    operation,
+                    added_data_files: files.len() as u64,
+                    added_records: files.iter().map(|f| f.record_count).sum(),
+                    added_files_size: files.iter().map(|f| f.file_size_in_bytes).sum(),
+                },
+                &format!("{location}/{meta_path}"),
+            );
+
+            let (next_path, version_hint) = next_metadata_path(&meta_path)?;
+            if Self::version_detect(&meta_op).await? != meta_path
+                || meta_op.is_exist(&next_path).await?
+            {
+                tracing::info!(
+                    "iceberg table {}: {} has been committed by others, retrying",
+                    self.name,
+                    next_path
+                );
+                continue;
+            }
+            meta_op.write(&next_path, builder.build()?).await?;
+            if let Some(version) = version_hint {
+                meta_op.write(META_PTR, version.to_string()).await?;
+            }
+            tracing::info!(
+                "iceberg table {}: committed snapshot {} in {}",
+                self.name,
+                snapshot_id,
+                next_path
+            );
+            return Ok(());
+        }
+        Err(ErrorCode::TableVersionMismatched(format!(
+            "failed to commit to iceberg table {} after {} attempts",
+            self.name, MAX_COMMIT_ATTEMPTS
+        )))
+    }
+
+    /// Commit an empty snapshot, the data files are kept for the older snapshots.
+    #[async_backtrace::framed]
+    pub(crate) async fn truncate_data(&self) -> Result<()> {
+        self.commit_data_files(vec![], "delete").await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let (data_files, position_deletes, equality_deletes) = self.read_files().await?;
+
+        // prune data files by the column bounds and partition values in manifests
+        let schema = self.info.schema();
+        let filter = push_downs
+            .as_ref()
+            .and_then(|p| p.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+        let columns = self
+            .scan_meta
+            .current_schema()
+            .map(|s| stats_columns(s, &schema))
+            .unwrap_or_default();
+        let num_data_files = data_files.len();
+        let data_files = data_files
+            .into_iter()
+            .filter(|(spec_id, file)| {
+                let spec = self.scan_meta.partition_spec(*spec_id);
+                pruner.should_keep(&data_file_statistics(file, spec, &columns), None)
+            })
+            .collect::<Vec<_>>();
+        tracing::info!(
+            "iceberg table {}: {} of {} data files to read after pruning",
+            self.name,
+            data_files.len(),
+            num_data_files
+        );
+        if data_files.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
+        let paths = data_files
+            .iter()
+            .map(|(_, f)| f.file_path.as_str())
+            .collect::<HashSet<_>>();
+        let mut deleted_positions = self
+            .read_position_deletes(&position_deletes, &paths)
+            .await?;
+        let equality_deleted = self
+            .read_equality_deletes(&equality_deletes, &data_files)
+            .await?;
+        for (path, positions) in equality_deleted {
+            let merged = deleted_positions.entry(path).or_default();
+            merged.extend(positions);
+            merged.sort_unstable();
+            merged.dedup();
+        }
+
+        let files_to_read = data_files
+            .iter()
+            .map(|(_, file)| {
+                check_file_format(file)?;
+                Ok(StageFileInfo {
+                    path: self.scan_meta.relative_path(&file.file_path)?,
+                    size: file.file_size_in_bytes,
+                    md5: None,
+                    last_modified: Default::default(),
+                    etag: None,
+                    status: StageFileStatus::NeedCopy,
+                    creator: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let table = ParquetTable::from_info(&self.parquet_table_info(Some(files_to_read)))?;
+        let table = table
+            .as_any()
+            .downcast_ref::<ParquetTable>()
+            .expect("must be a parquet table");
+        table
+            .read_partitions_with_deletes(ctx, push_downs, deleted_positions)
+            .await
+    }
+}
+
+/// data files, position delete files and equality delete files, see [`IcebergTable::read_files`]
+type IcebergFiles = (Vec<(i32, DataFile)>, Vec<DataFile>, Vec<(i32, DataFile)>);
+
+fn check_file_format(file: &DataFile) -> Result<()> {
+    if file.file_format.eq_ignore_ascii_case("parquet") {
+        Ok(())
+    } else {
+        Err(ErrorCode::Unimplemented(format!(
+            "file format {} of iceberg file {} is not supported",
+            file.file_format, file.file_path
+        )))
+    }
+}
+
+/// read the `file_path` and `pos` columns of a position delete file,
+/// only the positions of `data_files` are kept.
+fn read_position_delete_file(
+    data: &[u8],
+    data_files: &HashSet<&str>,
+) -> Result<Vec<(String, u64)>> {
+    let mut reader = Cursor::new(data);
+    let metadata = pread::read_metadata(&mut reader)?;
+    let schema =
+        pread::infer_schema(&metadata)?.filter(|_, f| f.name == "file_path" || f.name == "pos");
+    if schema.fields.len() != 2 {
+        return Err(ErrorCode::ReadTableDataError(
+            "invalid position delete file: missing file_path or pos",
+        ));
+    }
+    let path_index = schema
+        .fields
+        .iter()
+        .position(|f| f.name == "file_path")
+        .unwrap();
+    let chunks = pread::FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+
+    let mut positions = vec![];
+    for chunk in chunks {
+        let chunk = chunk?;
+        let arrays = chunk.arrays();
+        let paths = arrays[path_index].as_any();
+        let pos = arrays[1 - path_index]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .ok_or_else(|| ErrorCode::ReadTableDataError("pos of position deletes must be long"))?;
+        let paths: Box<dyn Iterator<Item = Option<&str>>> =
+            if let Some(paths) = paths.downcast_ref::<Utf8Array<i32>>() {
+                Box::new(paths.iter())
+            } else if let Some(paths) = paths.downcast_ref::<Utf8Array<i64>>() {
+                Box::new(paths.iter())
+            } else {
+                return Err(ErrorCode::ReadTableDataError(
+                    "file_path of position deletes must be string",
+                ));
+            };
+        for (path, pos) in paths.zip(pos.iter()) {
+            if let (Some(path), Some(pos)) = (path, pos) {
+                if data_files.contains(path) {
+                    positions.push((path.to_string(), *pos as u64));
+                }
+            }
+        }
+    }
+    Ok(positions)
 }
 
 #[async_trait]
@@ -165,12 +757,74 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        true
+    }
+
+    /// The data files are read by the parquet table built from this source info,
+    /// see [`ParquetTable::from_info`].
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::ParquetSource(self.parquet_table_info(None))
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+        _: bool,
+    ) -> Result<()> {
+        let field_ids = self.field_ids()?;
+        let operator = self.tbl_root.operator();
+        pipeline.add_sink(|input| {
+            IcebergTableSink::try_create(
+                input,
+                ctx.clone(),
+                operator.clone(),
+                self.scan_meta.location.clone(),
+                self.info.schema(),
+                field_ids.clone(),
+            )
+        })
+    }
+
+    /// The copied files are not recorded, since iceberg tables have no place for them.
+    #[async_backtrace::framed]
+    async fn commit_insertion(
+        &self,
+        _: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let files = operations
+            .iter()
+            .map(DataFile::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        if overwrite {
+            self.commit_data_files(files, "overwrite").await
+        } else if !files.is_empty() {
+            self.commit_data_files(files, "append").await
+        } else {
+            Ok(())
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate(&self, _: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        self.truncate_data().await
     }
 }
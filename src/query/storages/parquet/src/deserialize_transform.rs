@@ -187,10 +187,19 @@ impl ParquetDeserializeTransform {
             .row_selection
             .as_ref()
             .map(|sel| intervals_to_bitmap(sel, part.num_rows));
+        // rows that are not deleted by delete files
+        let kept_rows = part
+            .deleted_rows
+            .as_ref()
+            .map(|rows| deleted_rows_to_bitmap(rows, part.num_rows));
 
         // this means it's empty projection
         if readers.is_empty() {
-            let data_block = DataBlock::new(vec![], part.num_rows);
+            let num_rows = kept_rows
+                .as_ref()
+                .map(|b| b.len() - b.unset_bits())
+                .unwrap_or(part.num_rows);
+            let data_block = DataBlock::new(vec![], num_rows);
             return Ok(Some(data_block));
         }
 
@@ -233,6 +242,11 @@ impl ParquetDeserializeTransform {
                     .map_err(|e| e.add_message("eval prewhere filter failed:"))?
                     .try_downcast::<BooleanType>()
                     .unwrap();
+                let filter = match (filter, &kept_rows) {
+                    (Value::Scalar(true), Some(kept)) => Value::Column(kept.clone()),
+                    (Value::Column(bitmap), Some(kept)) => Value::Column(&bitmap & kept),
+                    (filter, _) => filter,
+                };
 
                 // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                 if FilterHelpers::is_all_unset(&filter) {
@@ -304,7 +318,13 @@ impl ParquetDeserializeTransform {
             }
             None => {
                 let chunks = self.remain_reader.read_from_readers(readers)?;
-                self.remain_reader.deserialize(part, chunks, row_selection)
+                let block = self
+                    .remain_reader
+                    .deserialize(part, chunks, row_selection)?;
+                match &kept_rows {
+                    Some(kept) => block.filter_with_bitmap(kept),
+                    None => Ok(block),
+                }
             }
         }?;
 
@@ -392,6 +412,15 @@ impl Processor for ParquetDeserializeTransform {
     }
 }
 
+/// Convert the positions of deleted rows to a bitmap of the rows to keep.
+fn deleted_rows_to_bitmap(deleted_rows: &[usize], num_rows: usize) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_set(num_rows);
+    for row in deleted_rows {
+        bitmap.set(*row, false);
+    }
+    bitmap.into()
+}
+
 /// Convert intervals to a bitmap. The `intervals` represents the row selection across `num_rows`.
 fn intervals_to_bitmap(interval: &[Interval], num_rows: usize) -> Bitmap {
     debug_assert!(
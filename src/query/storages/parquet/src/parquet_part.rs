@@ -84,6 +84,8 @@ pub struct ParquetRowGroupPart {
     pub num_rows: usize,
     pub column_metas: HashMap<FieldIndex, ColumnMeta>,
     pub row_selection: Option<Vec<Interval>>,
+    /// Positions (relative to the row group) of the rows deleted by position delete files.
+    pub deleted_rows: Option<Vec<usize>>,
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
 }
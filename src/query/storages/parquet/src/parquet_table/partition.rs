@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::plan::PartStatistics;
@@ -121,9 +122,36 @@ impl ParquetTable {
             skip_pruning,
             top_k,
             parquet_fast_read_bytes,
+            deleted_positions: HashMap::new(),
         })
     }
 
+    /// Read partitions of `files_to_read`, skipping the rows in `deleted_positions`.
+    ///
+    /// `deleted_positions` maps a file path to the sorted positions of its deleted rows,
+    /// it is used by table formats with row-level deletes, such as Iceberg.
+    #[async_backtrace::framed]
+    pub async fn read_partitions_with_deletes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_down: Option<PushDownInfo>,
+        deleted_positions: HashMap<String, Vec<u64>>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let mut pruner = self.create_pruner(ctx, push_down, false)?;
+        pruner.deleted_positions = deleted_positions;
+
+        let file_locations = self
+            .files_to_read
+            .iter()
+            .flatten()
+            .map(|f| (f.path.clone(), f.size))
+            .collect::<Vec<_>>();
+
+        pruner
+            .read_and_prune_partitions(self.operator.clone(), &file_locations)
+            .await
+    }
+
     #[inline]
     #[async_backtrace::framed]
     pub(super) async fn do_read_partitions(
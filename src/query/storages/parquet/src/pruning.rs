@@ -71,6 +71,8 @@ pub struct PartitionPruner {
     // /// Limit of this query. If there is order by and filter, it will not be used (assign to `usize::MAX`).
     // pub limit: usize,
     pub parquet_fast_read_bytes: usize,
+    /// Sorted positions of deleted rows of each file, the positions are relative to the file.
+    pub deleted_positions: HashMap<String, Vec<u64>>,
 }
 
 impl PartitionPruner {
@@ -119,17 +121,31 @@ impl PartitionPruner {
             None
         };
 
+        let deleted_positions = self.deleted_positions.get(path);
+        let mut row_offset = 0;
         for (rg_idx, rg) in file_meta.row_groups.iter().enumerate() {
+            let rg_start = row_offset;
+            row_offset += rg.num_rows() as u64;
             if row_group_pruned[rg_idx] {
                 continue;
             }
 
+            let deleted_rows = deleted_positions
+                .map(|positions| deleted_rows_of_row_group(positions, rg_start, rg.num_rows()))
+                .filter(|rows| !rows.is_empty());
+            if matches!(&deleted_rows, Some(rows) if rows.len() == rg.num_rows()) {
+                continue;
+            }
+
             stats.read_rows += rg.num_rows();
             stats.read_bytes += rg.total_byte_size();
             stats.partitions_scanned += 1;
 
             // Currently, only blocking io is allowed to prune pages.
+            // Row selection is not applied to row groups with deleted rows,
+            // because the deleted positions are based on the unfiltered row group.
             let row_selection = if self.page_pruners.is_some()
+                && deleted_rows.is_none()
                 && is_blocking_io
                 && rg.columns().iter().all(|c| {
                     c.column_chunk().column_index_offset.is_some()
@@ -176,6 +192,7 @@ impl PartitionPruner {
                 num_rows: rg.num_rows(),
                 column_metas,
                 row_selection,
+                deleted_rows,
                 sort_min_max: None,
             })
         }
@@ -196,7 +213,10 @@ impl PartitionPruner {
         let mut large_files = vec![];
         let mut small_files = vec![];
         for (location, size) in locations {
-            if *size > self.parquet_fast_read_bytes as u64 {
+            // Files with deleted rows are always read by row groups.
+            if *size > self.parquet_fast_read_bytes as u64
+                || self.deleted_positions.contains_key(location)
+            {
                 large_files.push((location.clone(), *size));
             } else {
                 small_files.push((location.clone(), *size));
@@ -263,6 +283,18 @@ impl PartitionPruner {
     }
 }
 
+/// Collect the deleted positions in `[rg_start, rg_start + num_rows)`,
+/// and make them relative to the row group.
+fn deleted_rows_of_row_group(positions: &[u64], rg_start: u64, num_rows: usize) -> Vec<usize> {
+    let rg_end = rg_start + num_rows as u64;
+    let begin = positions.partition_point(|p| *p < rg_start);
+    let end = positions.partition_point(|p| *p < rg_end);
+    positions[begin..end]
+        .iter()
+        .map(|p| (*p - rg_start) as usize)
+        .collect()
+}
+
 /// [`RangePruner`]s for each column
 type ColumnRangePruners = Vec<(usize, Arc<dyn RangePruner + Send + Sync>)>;
 
@@ -0,0 +1,175 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+/// The database of the base table.
+pub const OPT_KEY_DATABASE_NAME: &str = "database_name";
+/// The name of the base table.
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+/// The id of the base table, a stream is invalid once its base table is recreated.
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// The id of the base table snapshot that the changes are computed from,
+/// absent if the base table had no snapshot when the offset was taken.
+pub const OPT_KEY_SNAPSHOT_ID: &str = "snapshot_id";
+
+/// The metadata column of a stream, which is either `INSERT` or `DELETE`.
+pub const STREAM_COLUMN_ACTION: &str = "change$action";
+
+/// A stream records an offset of a fuse table, reading it returns the rows
+/// changed in the base table since the offset.
+///
+/// The changed blocks are found by comparing the blocks of the offset snapshot and
+/// the current snapshot, then the rows of the changed blocks are compared: a row which
+/// is only in the added blocks is an inserted row, and a row which is only in the removed
+/// blocks is a deleted row. So the rows moved to other blocks by UPDATE, DELETE, compaction
+/// or recluster are not reported, and an updated row is reported as a deleted row of the
+/// old values and an inserted row of the new values. Identical rows are indistinguishable,
+/// only the difference of their numbers is reported.
+pub struct StreamTable {
+    table_info: TableInfo,
+    base_database: String,
+    base_table: String,
+    base_table_id: u64,
+    offset_snapshot_id: Option<String>,
+}
+
+impl StreamTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let get_option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Invalid STREAM object {}, option `{}` is missing",
+                    table_info.name, key
+                ))
+            })
+        };
+        let base_database = get_option(OPT_KEY_DATABASE_NAME)?;
+        let base_table = get_option(OPT_KEY_TABLE_NAME)?;
+        let base_table_id = get_option(OPT_KEY_TABLE_ID)?.parse::<u64>().map_err(|e| {
+            ErrorCode::Internal(format!(
+                "Invalid STREAM object {}, bad table id: {}",
+                table_info.name, e
+            ))
+        })?;
+        let offset_snapshot_id = options.get(OPT_KEY_SNAPSHOT_ID).cloned();
+
+        Ok(Box::new(StreamTable {
+            table_info,
+            base_database,
+            base_table,
+            base_table_id,
+            offset_snapshot_id,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM STORAGE (CHANGES OF FUSE TABLE)".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    pub fn base_database(&self) -> &str {
+        &self.base_database
+    }
+
+    pub fn base_table(&self) -> &str {
+        &self.base_table
+    }
+
+    pub fn base_table_id(&self) -> u64 {
+        self.base_table_id
+    }
+
+    pub fn offset_snapshot_id(&self) -> Option<&str> {
+        self.offset_snapshot_id.as_deref()
+    }
+
+    /// The query reading the changes of the base table since the offset,
+    /// `columns` are the columns of the base table.
+    pub fn changes_query(&self, columns: &[String]) -> String {
+        let table = format!(
+            "{}.{}",
+            quote_ident(&self.base_database),
+            quote_ident(&self.base_table)
+        );
+        let action = quote_ident(STREAM_COLUMN_ACTION);
+        let Some(snapshot_id) = &self.offset_snapshot_id else {
+            return format!("SELECT *, 'INSERT' AS {action} FROM {table}");
+        };
+
+        let offset = format!("{table} AT (SNAPSHOT => '{snapshot_id}')");
+        let columns = columns
+            .iter()
+            .map(|column| quote_ident(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sign = quote_ident("change$sign");
+        let row = quote_ident("change$row");
+        let net = quote_ident("change$net");
+        // The rows of the added blocks are counted as +1 and the rows of the removed
+        // blocks as -1. The identical rows of each side are numbered, so that each
+        // row of the added blocks cancels out at most one identical row of the removed
+        // blocks, the rows whose sum is not 0 are the changed rows.
+        format!(
+            "SELECT {columns}, IF({net} > 0, 'INSERT', 'DELETE') AS {action} FROM (\
+                SELECT {columns}, SUM({sign}) AS {net} FROM (\
+                    SELECT {columns}, {sign}, \
+                    ROW_NUMBER() OVER (PARTITION BY {columns}, {sign}) AS {row} FROM (\
+                        SELECT {columns}, CAST(1 AS INT8) AS {sign} FROM {table} \
+                        WHERE _block_name NOT IN (SELECT _block_name FROM {offset}) \
+                        UNION ALL \
+                        SELECT {columns}, CAST(-1 AS INT8) AS {sign} FROM {offset} \
+                        WHERE _block_name NOT IN (SELECT _block_name FROM {table})\
+                    )\
+                ) GROUP BY {columns}, {row}\
+            ) WHERE {net} != 0"
+        )
+    }
+}
+
+fn quote_ident(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+#[async_trait::async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+}
@@ -57,6 +57,7 @@ impl SyncSystemTable for CachesTable {
         let segment_info_cache = cache_manager.get_table_segment_cache();
         let bloom_index_filter_cache = cache_manager.get_bloom_index_filter_cache();
         let bloom_index_meta_cache = cache_manager.get_bloom_index_meta_cache();
+        let vector_index_cache = cache_manager.get_vector_index_cache();
         let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
         let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
         let table_data_cache = cache_manager.get_table_data_cache();
@@ -91,6 +92,12 @@ impl SyncSystemTable for CachesTable {
             size.push(bloom_index_meta_cache.size());
         }
 
+        if let Some(vector_index_cache) = vector_index_cache {
+            names.push("vector_index_cache");
+            num_items.push(vector_index_cache.len() as u64);
+            size.push(vector_index_cache.size());
+        }
+
         if let Some(prune_partitions_cache) = prune_partitions_cache {
             names.push("prune_partitions_cache");
             num_items.push(prune_partitions_cache.len() as u64);
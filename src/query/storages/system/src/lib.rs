@@ -47,6 +47,7 @@ mod tables_table;
 mod tracing_table;
 mod users_table;
 mod util;
+mod workload_groups_table;
 
 pub use build_options_table::BuildOptionsTable;
 pub use caches_table::CachesTable;
@@ -87,3 +88,4 @@ pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
+pub use workload_groups_table::WorkloadGroupsTable;
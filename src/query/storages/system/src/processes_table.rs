@@ -67,6 +67,8 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_workload_group = Vec::with_capacity(processes_info.len());
+        let mut processes_queued_time_ms = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -113,6 +115,17 @@ impl SyncSystemTable for ProcessesTable {
                     .unwrap_or("".to_owned())
                     .into_bytes(),
             );
+
+            processes_workload_group.push(
+                ProcessesTable::process_option_value(process_info.workload_group.clone())
+                    .into_bytes(),
+            );
+            processes_queued_time_ms.push(
+                process_info
+                    .queued_time
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or(0),
+            );
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -131,6 +144,8 @@ impl SyncSystemTable for ProcessesTable {
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_data(processes_workload_group),
+            UInt64Type::from_data(processes_queued_time_ms),
         ]))
     }
 }
@@ -171,6 +186,11 @@ impl ProcessesTable {
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new("workload_group", TableDataType::String),
+            TableField::new(
+                "queued_time_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -0,0 +1,127 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+
+        let names: Vec<&str> = groups.iter().map(|x| x.name.as_str()).collect();
+        let max_concurrencies: Vec<u64> = groups.iter().map(|x| x.max_concurrency).collect();
+        let memory_shares: Vec<u64> = groups.iter().map(|x| x.memory_share).collect();
+        let priorities: Vec<u64> = groups.iter().map(|x| x.priority).collect();
+        let queue_timeouts: Vec<u64> = groups.iter().map(|x| x.queue_timeout_secs).collect();
+        let users: Vec<Vec<u8>> = groups
+            .iter()
+            .map(|x| {
+                x.users
+                    .iter()
+                    .map(|u| u.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .into_bytes()
+            })
+            .collect();
+        let roles: Vec<Vec<u8>> = groups
+            .iter()
+            .map(|x| x.roles.join(",").into_bytes())
+            .collect();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(max_concurrencies),
+            UInt64Type::from_data(memory_shares),
+            UInt64Type::from_data(priorities),
+            UInt64Type::from_data(queue_timeouts),
+            StringType::from_data(users),
+            StringType::from_data(roles),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "max_concurrency",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "memory_share",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("priority", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "queue_timeout",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("users", TableDataType::String),
+            TableField::new("roles", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
@@ -38,11 +38,13 @@ mod user_mgr;
 mod user_setting;
 mod user_stage;
 mod user_udf;
+mod user_workload_group;
 
 pub mod file_format;
 pub mod idm_config;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod workload_group_cache_mgr;
 
 pub use jwt::*;
 pub use role_cache_mgr::RoleCacheManager;
@@ -50,3 +52,4 @@ pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
+pub use workload_group_cache_mgr::WorkloadGroupCacheManager;
@@ -14,13 +14,17 @@
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_management::RoleApi;
 use common_management::UserApi;
 use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::GrantEntry;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 
 use crate::role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -174,6 +178,38 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke user privileges)"))
     }
 
+    // Revoke the privileges on a dropped object from all users and roles,
+    // so that an object created later with the same name does not inherit them.
+    #[async_backtrace::framed]
+    pub async fn revoke_object_privileges(&self, tenant: &str, object: &GrantObject) -> Result<()> {
+        let granted = |entries: Vec<GrantEntry>| entries.iter().any(|e| e.matches_entry(object));
+
+        let user_client = self.get_user_api_client(tenant)?;
+        for user in self.get_users(tenant).await? {
+            if granted(user.grants.entries()) {
+                user_client
+                    .update_user_with(user.identity(), MatchSeq::GE(1), |ui: &mut UserInfo| {
+                        ui.grants.revoke_object(object)
+                    })
+                    .await
+                    .map_err(|e| e.add_message_back("(while revoke user privileges)"))?;
+            }
+        }
+
+        let role_client = self.get_role_api_client(tenant)?;
+        for role in role_client.get_roles().await? {
+            if granted(role.data.grants.entries()) {
+                role_client
+                    .update_role_with(&role.data.name, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                        ri.grants.revoke_object(object)
+                    })
+                    .await
+                    .map_err(|e| e.add_message_back("(while revoke role privileges)"))?;
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn grant_role_to_user(
         &self,
@@ -245,6 +281,21 @@ impl UserApiProvider {
         }
     }
 
+    // Update an user's quota
+    #[async_backtrace::framed]
+    pub async fn update_user_quota(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| ui.quota = quota)
+            .await
+            .map_err(|e| e.add_message_back("(while alter user quota)"))
+    }
+
     // Update an user's default role
     #[async_backtrace::framed]
     pub async fn update_user_default_role(
@@ -0,0 +1,97 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        group: WorkloadGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let group_api_client = self.get_workload_group_api_client(tenant)?;
+        let add_group = group_api_client.add_workload_group(group);
+        match add_group.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Update a workload group.
+    #[async_backtrace::framed]
+    pub async fn update_workload_group(&self, tenant: &str, group: WorkloadGroup) -> Result<u64> {
+        let group_api_client = self.get_workload_group_api_client(tenant)?;
+        let update_group = group_api_client.update_workload_group(group, MatchSeq::GE(1));
+        match update_group.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while update workload group).")),
+        }
+    }
+
+    // Get a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &str, name: &str) -> Result<WorkloadGroup> {
+        let group_api_client = self.get_workload_group_api_client(tenant)?;
+        let get_group = group_api_client.get_workload_group(name, MatchSeq::GE(0));
+        Ok(get_group.await?.data)
+    }
+
+    // Get all workload groups for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let group_api_client = self.get_workload_group_api_client(tenant)?;
+        let get_groups = group_api_client.get_workload_groups();
+
+        match get_groups.await {
+            Err(e) => Err(e.add_message_back("(while get workload groups).")),
+            Ok(groups) => Ok(groups),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let group_api_client = self.get_workload_group_api_client(tenant)?;
+        let drop_group = group_api_client.drop_workload_group(name, MatchSeq::GE(1));
+        match drop_group.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop workload group)"))
+                }
+            }
+        }
+    }
+}
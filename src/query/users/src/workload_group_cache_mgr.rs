@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use parking_lot::RwLock;
+
+use crate::UserApiProvider;
+
+struct CachedWorkloadGroups {
+    groups: Arc<Vec<WorkloadGroup>>,
+    cached_at: Instant,
+}
+
+/// Caches the workload groups of each tenant, they are looked up on every query.
+///
+/// The cache of a tenant is invalidated when its workload groups are changed on this node,
+/// and reloaded after `expire_interval` to pick up the changes made on other nodes.
+pub struct WorkloadGroupCacheManager {
+    user_manager: Arc<UserApiProvider>,
+    cache: RwLock<HashMap<String, CachedWorkloadGroups>>,
+    expire_interval: Duration,
+}
+
+impl WorkloadGroupCacheManager {
+    pub fn init() -> Result<()> {
+        // Check that the user API has been initialized.
+        let instance = UserApiProvider::instance();
+
+        GlobalInstance::set(Self::create(instance));
+        Ok(())
+    }
+
+    pub fn create(user_manager: Arc<UserApiProvider>) -> Arc<WorkloadGroupCacheManager> {
+        Arc::new(Self {
+            user_manager,
+            cache: RwLock::new(HashMap::new()),
+            expire_interval: Duration::new(15, 0),
+        })
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupCacheManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn invalidate_cache(&self, tenant: &str) {
+        let mut cached = self.cache.write();
+        cached.remove(tenant);
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Arc<Vec<WorkloadGroup>>> {
+        {
+            let cached = self.cache.read();
+            if let Some(cached_groups) = cached.get(tenant) {
+                if cached_groups.cached_at.elapsed() < self.expire_interval {
+                    return Ok(cached_groups.groups.clone());
+                }
+            }
+        }
+
+        let groups = Arc::new(self.user_manager.get_workload_groups(tenant).await?);
+        let mut cached = self.cache.write();
+        cached.insert(tenant.to_string(), CachedWorkloadGroups {
+            groups: groups.clone(),
+            cached_at: Instant::now(),
+        });
+        Ok(groups)
+    }
+}
+
+/// Find the group with the highest priority among the groups the user is mapped to.
+pub fn find_user_workload_group<'a>(
+    groups: &'a [WorkloadGroup],
+    user: &UserIdentity,
+) -> Option<&'a WorkloadGroup> {
+    groups
+        .iter()
+        .filter(|group| group.users.contains(user))
+        .max_by_key(|group| group.priority)
+}
+
+/// Find the group with the highest priority among the groups any of the roles is mapped to.
+pub fn find_role_workload_group<'a>(
+    groups: &'a [WorkloadGroup],
+    roles: &[String],
+) -> Option<&'a WorkloadGroup> {
+    groups
+        .iter()
+        .filter(|group| group.roles.iter().any(|r| roles.contains(r)))
+        .max_by_key(|group| group.priority)
+}
@@ -17,3 +17,4 @@ mod role_cache_mgr;
 mod role_mgr;
 mod user_mgr;
 mod user_udf;
+mod workload_group_cache_mgr;
@@ -0,0 +1,81 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::workload_group_cache_mgr::find_role_workload_group;
+use common_users::workload_group_cache_mgr::find_user_workload_group;
+use common_users::UserApiProvider;
+use common_users::WorkloadGroupCacheManager;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_workload_group_cache_mgr() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_manager = UserApiProvider::try_create_simple(conf).await?;
+    let cache_manager = WorkloadGroupCacheManager::create(user_manager.clone());
+
+    let groups = cache_manager.get_workload_groups("tenant1").await?;
+    assert!(groups.is_empty());
+
+    // The cached groups are kept until invalidated.
+    user_manager
+        .add_workload_group("tenant1", WorkloadGroup::new("etl"), false)
+        .await?;
+    let groups = cache_manager.get_workload_groups("tenant1").await?;
+    assert!(groups.is_empty());
+
+    cache_manager.invalidate_cache("tenant1");
+    let groups = cache_manager.get_workload_groups("tenant1").await?;
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "etl");
+
+    Ok(())
+}
+
+#[test]
+fn test_find_workload_group() -> Result<()> {
+    let groups = vec![
+        WorkloadGroup {
+            priority: 1,
+            users: vec![UserIdentity::new("u1", "%")],
+            roles: vec!["r1".to_string()],
+            ..WorkloadGroup::new("g1")
+        },
+        WorkloadGroup {
+            priority: 2,
+            users: vec![UserIdentity::new("u1", "10.0.0.1")],
+            roles: vec!["r1".to_string(), "r2".to_string()],
+            ..WorkloadGroup::new("g2")
+        },
+    ];
+
+    // Users are matched by both the name and the host.
+    let group = find_user_workload_group(&groups, &UserIdentity::new("u1", "%"));
+    assert_eq!(group.map(|g| g.name.as_str()), Some("g1"));
+    let group = find_user_workload_group(&groups, &UserIdentity::new("u1", "10.0.0.1"));
+    assert_eq!(group.map(|g| g.name.as_str()), Some("g2"));
+    let group = find_user_workload_group(&groups, &UserIdentity::new("u1", "10.0.0.2"));
+    assert!(group.is_none());
+
+    // Among several matching groups the one with the highest priority wins.
+    let group = find_role_workload_group(&groups, &["r1".to_string()]);
+    assert_eq!(group.map(|g| g.name.as_str()), Some("g2"));
+    let group = find_role_workload_group(&groups, &["r3".to_string()]);
+    assert!(group.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,452 @@
+#!/usr/bin/env python3
+"""Generate the iceberg table iceberg_ctl/iceberg_db/iceberg_eq_tbl, which has equality delete files.
+
+Spark doesn't write equality deletes (Flink does, for upserts), so the table is
+encoded by this script from the Iceberg table spec v2, the Parquet format and the
+Avro object container format with the python standard library only. Like the
+other tables, the files are recorded with absolute paths in the minio bucket.
+
+The table (id int, data string) has 4 snapshots:
+1. append data file 1: (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'), (8, NULL)
+2. equality delete file 1 on `id`: 2, 5
+3. append data file 2: (2, 'b2'), (7, 'g'), the re-inserted 2 is not deleted by
+   delete file 1, which only applies to the data files of older sequence numbers
+4. equality delete file 2 on `data`: 'd', NULL, a null deletes the rows with null
+
+The rows of the current snapshot are (1, 'a'), (2, 'b2'), (3, 'c'), (7, 'g').
+"""
+
+import json
+import os
+import struct
+
+LOCATION = "s3://testbucket/iceberg_data/iceberg_ctl/iceberg_db/iceberg_eq_tbl"
+TABLE_DIR = os.path.join(
+    os.path.dirname(os.path.realpath(__file__)), "iceberg_ctl", "iceberg_db", "iceberg_eq_tbl"
+)
+TABLE_UUID = "5f8c4a6e-2f1d-4b8e-9c3a-7d6e5f4a3b2c"
+TIMESTAMP_MS = 1690000000000
+
+SCHEMA = {
+    "type": "struct",
+    "schema-id": 0,
+    "fields": [
+        {"id": 1, "name": "id", "required": False, "type": "int"},
+        {"id": 2, "name": "data", "required": False, "type": "string"},
+    ],
+}
+FIELD_TYPES = {1: "int", 2: "string"}
+FIELD_NAMES = {1: "id", 2: "data"}
+
+
+# parquet, thrift compact protocol
+
+T_I32, T_I64, T_BINARY, T_LIST, T_STRUCT = 5, 6, 8, 9, 12
+
+
+def varint(v):
+    out = bytearray()
+    while True:
+        b = v & 0x7F
+        v >>= 7
+        if v:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            return bytes(out)
+
+
+def zigzag(v):
+    return (v << 1) ^ (v >> 63)
+
+
+def thrift_struct(fields):
+    """fields: [(field id, type, value)], in the order of field ids"""
+    out = bytearray()
+    last = 0
+    for fid, ftype, value in fields:
+        if value is None:
+            continue
+        if 0 < fid - last <= 15:
+            out.append((fid - last) << 4 | ftype)
+        else:
+            out.append(ftype)
+            out += varint(zigzag(fid))
+        last = fid
+        out += thrift_value(ftype, value)
+    out.append(0)
+    return bytes(out)
+
+
+def thrift_value(ftype, value):
+    if ftype in (T_I32, T_I64):
+        return varint(zigzag(value))
+    if ftype == T_BINARY:
+        value = value.encode() if isinstance(value, str) else value
+        return varint(len(value)) + value
+    if ftype == T_STRUCT:
+        return value
+    if ftype == T_LIST:
+        elem_type, items = value
+        header = bytes([len(items) << 4 | elem_type]) if len(items) < 15 else (
+            bytes([0xF0 | elem_type]) + varint(len(items))
+        )
+        return header + b"".join(thrift_value(elem_type, item) for item in items)
+    raise ValueError(ftype)
+
+
+# parquet physical types, encodings and repetition types
+INT32, BYTE_ARRAY = 1, 6
+PLAIN, RLE = 0, 3
+OPTIONAL = 1
+UTF8 = 0
+
+
+def definition_levels(values):
+    """RLE runs of the definition levels of an optional column, prefixed by their length."""
+    runs = bytearray()
+    i = 0
+    while i < len(values):
+        level = int(values[i] is not None)
+        count = 1
+        while i + count < len(values) and int(values[i + count] is not None) == level:
+            count += 1
+        runs += varint(count << 1) + bytes([level])
+        i += count
+    return struct.pack("<i", len(runs)) + bytes(runs)
+
+
+def plain(field_id, values):
+    out = bytearray()
+    for v in values:
+        if v is None:
+            continue
+        if FIELD_TYPES[field_id] == "int":
+            out += struct.pack("<i", v)
+        else:
+            out += struct.pack("<i", len(v.encode())) + v.encode()
+    return bytes(out)
+
+
+def write_parquet(path, field_ids, rows):
+    """Write an uncompressed parquet file with a row group of optional columns."""
+    body = bytearray(b"PAR1")
+    column_chunks = []
+    for i, field_id in enumerate(field_ids):
+        values = [row[i] for row in rows]
+        page = definition_levels(values) + plain(field_id, values)
+        header = thrift_struct(
+            [
+                (1, T_I32, 0),  # DATA_PAGE
+                (2, T_I32, len(page)),
+                (3, T_I32, len(page)),
+                (
+                    5,
+                    T_STRUCT,
+                    thrift_struct(
+                        [
+                            (1, T_I32, len(values)),
+                            (2, T_I32, PLAIN),
+                            (3, T_I32, RLE),
+                            (4, T_I32, RLE),
+                        ]
+                    ),
+                ),
+            ]
+        )
+        offset = len(body)
+        body += header + page
+        physical_type = INT32 if FIELD_TYPES[field_id] == "int" else BYTE_ARRAY
+        column_chunks.append(
+            thrift_struct(
+                [
+                    (2, T_I64, offset),
+                    (
+                        3,
+                        T_STRUCT,
+                        thrift_struct(
+                            [
+                                (1, T_I32, physical_type),
+                                (2, T_LIST, (T_I32, [PLAIN, RLE])),
+                                (3, T_LIST, (T_BINARY, [FIELD_NAMES[field_id]])),
+                                (4, T_I32, 0),  # UNCOMPRESSED
+                                (5, T_I64, len(values)),
+                                (6, T_I64, len(header) + len(page)),
+                                (7, T_I64, len(header) + len(page)),
+                                (9, T_I64, offset),
+                            ]
+                        ),
+                    ),
+                ]
+            )
+        )
+    schema = [thrift_struct([(4, T_BINARY, "table"), (5, T_I32, len(field_ids))])]
+    for field_id in field_ids:
+        is_int = FIELD_TYPES[field_id] == "int"
+        schema.append(
+            thrift_struct(
+                [
+                    (1, T_I32, INT32 if is_int else BYTE_ARRAY),
+                    (3, T_I32, OPTIONAL),
+                    (4, T_BINARY, FIELD_NAMES[field_id]),
+                    (6, T_I32, None if is_int else UTF8),
+                    (9, T_I32, field_id),
+                ]
+            )
+        )
+    row_group = thrift_struct(
+        [
+            (1, T_LIST, (T_STRUCT, column_chunks)),
+            (2, T_I64, len(body) - 4),
+            (3, T_I64, len(rows)),
+        ]
+    )
+    footer = thrift_struct(
+        [
+            (1, T_I32, 1),
+            (2, T_LIST, (T_STRUCT, schema)),
+            (3, T_I64, len(rows)),
+            (4, T_LIST, (T_STRUCT, [row_group])),
+        ]
+    )
+    body += footer + struct.pack("<i", len(footer)) + b"PAR1"
+    write(path, body)
+    return len(body)
+
+
+# avro object container files
+
+SYNC_MARKER = bytes(range(16))
+
+
+def avro_long(v):
+    return varint(zigzag(v))
+
+
+def avro_bytes(v):
+    v = v.encode() if isinstance(v, str) else v
+    return avro_long(len(v)) + v
+
+
+def avro_value(schema, value):
+    if isinstance(schema, list):
+        # unions are [null, type]
+        if value is None:
+            return avro_long(0)
+        return avro_long(1) + avro_value(schema[1], value)
+    if schema in ("int", "long"):
+        return avro_long(value)
+    if schema in ("string", "bytes"):
+        return avro_bytes(value)
+    if schema == "boolean":
+        return bytes([int(value)])
+    if schema["type"] == "record":
+        return b"".join(avro_value(f["type"], value.get(f["name"])) for f in schema["fields"])
+    if schema["type"] == "array":
+        if not value:
+            return avro_long(0)
+        items = b"".join(avro_value(schema["items"], item) for item in value)
+        return avro_long(len(value)) + items + avro_long(0)
+    raise ValueError(schema)
+
+
+def write_avro(path, schema, metadata, records):
+    metadata = dict(metadata, **{"avro.schema": json.dumps(schema), "avro.codec": "null"})
+    header = b"Obj\x01" + avro_long(len(metadata))
+    for key, value in metadata.items():
+        header += avro_bytes(key) + avro_bytes(value)
+    header += avro_long(0) + SYNC_MARKER
+    data = b"".join(avro_value(schema, r) for r in records)
+    body = header + avro_long(len(records)) + avro_long(len(data)) + data + SYNC_MARKER
+    write(path, body)
+    return len(body)
+
+
+def field(name, field_id, schema):
+    if isinstance(schema, list):
+        return {"name": name, "type": schema, "default": None, "field-id": field_id}
+    return {"name": name, "type": schema, "field-id": field_id}
+
+
+def optional(schema):
+    return ["null", schema]
+
+
+MANIFEST_ENTRY_SCHEMA = {
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        field("status", 0, "int"),
+        field("snapshot_id", 1, optional("long")),
+        field("sequence_number", 3, optional("long")),
+        field("file_sequence_number", 4, optional("long")),
+        field(
+            "data_file",
+            2,
+            {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    field("content", 134, "int"),
+                    field("file_path", 100, "string"),
+                    field("file_format", 101, "string"),
+                    field("partition", 102, {"type": "record", "name": "r102", "fields": []}),
+                    field("record_count", 103, "long"),
+                    field("file_size_in_bytes", 104, "long"),
+                    field(
+                        "equality_ids",
+                        135,
+                        optional({"type": "array", "items": "int", "element-id": 136}),
+                    ),
+                ],
+            },
+        ),
+    ],
+}
+
+MANIFEST_FILE_SCHEMA = {
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        field("manifest_path", 500, "string"),
+        field("manifest_length", 501, "long"),
+        field("partition_spec_id", 502, "int"),
+        field("content", 517, "int"),
+        field("sequence_number", 515, "long"),
+        field("min_sequence_number", 516, "long"),
+        field("added_snapshot_id", 503, "long"),
+        field("added_files_count", 504, "int"),
+        field("existing_files_count", 505, "int"),
+        field("deleted_files_count", 506, "int"),
+        field("added_rows_count", 512, "long"),
+        field("existing_rows_count", 513, "long"),
+        field("deleted_rows_count", 514, "long"),
+    ],
+}
+
+
+def write(path, data):
+    path = os.path.join(TABLE_DIR, path)
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, "wb") as f:
+        f.write(data)
+
+
+# (content, file path, equality field ids, rows), one per snapshot
+COMMITS = [
+    (0, "data/00000-0-data.parquet", [1, 2], [(1, "a"), (2, "b"), (3, "c"), (4, "d"), (5, "e"), (8, None)]),
+    (2, "data/00001-0-eq-delete.parquet", [1], [(2,), (5,)]),
+    (0, "data/00002-0-data.parquet", [1, 2], [(2, "b2"), (7, "g")]),
+    (2, "data/00003-0-eq-delete.parquet", [2], [("d",), (None,)]),
+]
+
+
+def main():
+    manifest_list_entries = []
+    snapshots = []
+    for i, (content, path, field_ids, rows) in enumerate(COMMITS):
+        sequence_number = i + 1
+        snapshot_id = 1000 + sequence_number
+        size = write_parquet(path, field_ids, rows)
+
+        entry = {
+            "status": 1,  # ADDED
+            "snapshot_id": snapshot_id,
+            # inherited from the manifest list
+            "sequence_number": None,
+            "file_sequence_number": None,
+            "data_file": {
+                "content": content,
+                "file_path": f"{LOCATION}/{path}",
+                "file_format": "PARQUET",
+                "partition": {},
+                "record_count": len(rows),
+                "file_size_in_bytes": size,
+                "equality_ids": field_ids if content == 2 else None,
+            },
+        }
+        manifest_path = f"metadata/manifest-{sequence_number}-m0.avro"
+        manifest_length = write_avro(
+            manifest_path,
+            MANIFEST_ENTRY_SCHEMA,
+            {
+                "schema": json.dumps(SCHEMA),
+                "partition-spec": "[]",
+                "partition-spec-id": "0",
+                "format-version": "2",
+                "content": "deletes" if content else "data",
+            },
+            [entry],
+        )
+        manifest_list_entries.append(
+            {
+                "manifest_path": f"{LOCATION}/{manifest_path}",
+                "manifest_length": manifest_length,
+                "partition_spec_id": 0,
+                "content": 1 if content else 0,
+                "sequence_number": sequence_number,
+                "min_sequence_number": sequence_number,
+                "added_snapshot_id": snapshot_id,
+                "added_files_count": 1,
+                "existing_files_count": 0,
+                "deleted_files_count": 0,
+                "added_rows_count": len(rows),
+                "existing_rows_count": 0,
+                "deleted_rows_count": 0,
+            }
+        )
+
+        list_path = f"metadata/snap-{snapshot_id}-1-manifest-list.avro"
+        metadata = {
+            "snapshot-id": str(snapshot_id),
+            "sequence-number": str(sequence_number),
+            "format-version": "2",
+        }
+        if i > 0:
+            metadata["parent-snapshot-id"] = str(snapshot_id - 1)
+        write_avro(list_path, MANIFEST_FILE_SCHEMA, metadata, manifest_list_entries)
+
+        snapshot = {
+            "snapshot-id": snapshot_id,
+            "sequence-number": sequence_number,
+            "timestamp-ms": TIMESTAMP_MS + sequence_number,
+            "summary": {"operation": "delete" if content else "append"},
+            "manifest-list": f"{LOCATION}/{list_path}",
+            "schema-id": 0,
+        }
+        if i > 0:
+            snapshot["parent-snapshot-id"] = snapshot_id - 1
+        snapshots.append(snapshot)
+
+    current = snapshots[-1]
+    metadata = {
+        "format-version": 2,
+        "table-uuid": TABLE_UUID,
+        "location": LOCATION,
+        "last-sequence-number": current["sequence-number"],
+        "last-updated-ms": current["timestamp-ms"],
+        "last-column-id": 2,
+        "current-schema-id": 0,
+        "schemas": [SCHEMA],
+        "default-spec-id": 0,
+        "partition-specs": [{"spec-id": 0, "fields": []}],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "properties": {},
+        "current-snapshot-id": current["snapshot-id"],
+        "refs": {"main": {"snapshot-id": current["snapshot-id"], "type": "branch"}},
+        "snapshots": snapshots,
+        "snapshot-log": [
+            {"timestamp-ms": s["timestamp-ms"], "snapshot-id": s["snapshot-id"]} for s in snapshots
+        ],
+        "metadata-log": [],
+    }
+    write(
+        f"metadata/00000-{TABLE_UUID}.metadata.json",
+        json.dumps(metadata, indent=2).encode() + b"\n",
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,273 @@
+#!/usr/bin/env python3
+"""Generate types.orc, used by the tests of loading ORC files.
+
+The file is encoded by this script from the ORC v1 specification
+(https://orc.apache.org/specification/ORCv1/) with the python standard library
+only, independently of the reader of databend, so that the reader is tested
+against files it didn't write itself. The file can be cross checked with any
+ORC implementation, e.g. `python3 -c "import pyorc; print(list(pyorc.Reader(open('types.orc', 'rb'))))"`.
+
+The file has:
+- ZLIB compression, with a small compression block size, so that the streams
+  have several compressed chunks.
+- 3 stripes of 1000 rows.
+- columns:
+    id      bigint, 1..3000, DIRECT_V2
+    name    string, one of 5 names, DICTIONARY_V2
+    payload binary, bytes which are not valid utf8, DIRECT_V2
+    score   int, null when id % 7 == 0, otherwise id % 100 - 50, DIRECT_V2
+"""
+
+import os
+import zlib
+
+ROWS = 3000
+ROWS_PER_STRIPE = 1000
+COMPRESSION_BLOCK_SIZE = 1024
+NAMES = ["alice", "bob", "carol", "dave", "eve"]
+
+# type kinds
+LONG, INT, STRING, BINARY, STRUCT = 4, 3, 7, 8, 12
+# stream kinds
+PRESENT, DATA, LENGTH, DICTIONARY_DATA = 0, 1, 2, 3
+# column encodings
+DIRECT, DIRECT_V2, DICTIONARY_V2 = 0, 2, 3
+
+
+def row(i):
+    id = i + 1
+    name = NAMES[i % len(NAMES)]
+    payload = bytes([0xFF, 0x00, id % 256, 0xC3]) + id.to_bytes(2, "big")
+    score = None if id % 7 == 0 else id % 100 - 50
+    return id, name, payload, score
+
+
+# protobuf
+
+
+def varint(v):
+    out = bytearray()
+    while True:
+        b = v & 0x7F
+        v >>= 7
+        if v:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            return bytes(out)
+
+
+def field_varint(tag, v):
+    return varint(tag << 3) + varint(v)
+
+
+def field_bytes(tag, data):
+    return varint(tag << 3 | 2) + varint(len(data)) + data
+
+
+def field_packed(tag, values):
+    return field_bytes(tag, b"".join(varint(v) for v in values))
+
+
+# run length encoding
+
+
+def zigzag(v):
+    return (v << 1) ^ (v >> 63)
+
+
+RLE_V2_WIDTHS = list(range(1, 25)) + [26, 28, 30, 32, 40, 48, 56, 64]
+# DIRECT runs are written with the aligned bit widths only, like the writer of Apache ORC.
+RLE_V2_ALIGNED_WIDTHS = [1, 2, 4, 8, 16, 24, 32, 40, 48, 56, 64]
+
+
+def rle_v2(values, signed):
+    """Integer run length encoding v2 with SHORT_REPEAT and DIRECT sub-encodings."""
+    values = [zigzag(v) if signed else v for v in values]
+    out = bytearray()
+    i = 0
+    literals = []
+
+    def flush():
+        while literals:
+            run = literals[:512]
+            del literals[:512]
+            bits = max(v.bit_length() for v in run) or 1
+            width = next(w for w in RLE_V2_ALIGNED_WIDTHS if w >= bits)
+            code = RLE_V2_WIDTHS.index(width)
+            out.append(0x40 | code << 1 | (len(run) - 1) >> 8)
+            out.append((len(run) - 1) & 0xFF)
+            acc, n = 0, 0
+            for v in run:
+                acc = acc << width | v
+                n += width
+                while n >= 8:
+                    n -= 8
+                    out.append(acc >> n & 0xFF)
+            if n:
+                out.append(acc << (8 - n) & 0xFF)
+
+    while i < len(values):
+        repeat = 1
+        while i + repeat < len(values) and values[i + repeat] == values[i] and repeat < 10:
+            repeat += 1
+        if repeat >= 3:
+            flush()
+            size = max(1, (values[i].bit_length() + 7) // 8)
+            out.append((size - 1) << 3 | (repeat - 3))
+            out += values[i].to_bytes(size, "big")
+            i += repeat
+        else:
+            literals.append(values[i])
+            i += 1
+    flush()
+    return bytes(out)
+
+
+def byte_rle(data):
+    out = bytearray()
+    i = 0
+    while i < len(data):
+        repeat = 1
+        while i + repeat < len(data) and data[i + repeat] == data[i] and repeat < 130:
+            repeat += 1
+        if repeat >= 3:
+            out += bytes([repeat - 3, data[i]])
+            i += repeat
+            continue
+        start = i
+        while i < len(data) and i - start < 128:
+            if i + 2 < len(data) and data[i] == data[i + 1] == data[i + 2]:
+                break
+            i += 1
+        out.append(256 - (i - start))
+        out += data[start:i]
+    return bytes(out)
+
+
+def boolean_rle(bits):
+    data = bytearray()
+    for i in range(0, len(bits), 8):
+        b = 0
+        for j, bit in enumerate(bits[i : i + 8]):
+            b |= bit << (7 - j)
+        data.append(b)
+    return byte_rle(bytes(data))
+
+
+# compression
+
+
+def compress(data):
+    out = bytearray()
+    for i in range(0, len(data), COMPRESSION_BLOCK_SIZE):
+        chunk = data[i : i + COMPRESSION_BLOCK_SIZE]
+        compressor = zlib.compressobj(wbits=-15)
+        compressed = compressor.compress(chunk) + compressor.flush()
+        if len(compressed) < len(chunk):
+            header = len(compressed) << 1
+            body = compressed
+        else:
+            header = len(chunk) << 1 | 1
+            body = chunk
+        out += header.to_bytes(3, "little") + body
+    return bytes(out)
+
+
+def stripe(rows):
+    ids = [r[0] for r in rows]
+    names = [r[1] for r in rows]
+    payloads = [r[2] for r in rows]
+    scores = [r[3] for r in rows]
+
+    dictionary = sorted(set(names))
+    streams = [
+        (DATA, 1, rle_v2(ids, signed=True)),
+        (DATA, 2, rle_v2([dictionary.index(n) for n in names], signed=False)),
+        (LENGTH, 2, rle_v2([len(n) for n in dictionary], signed=False)),
+        (DICTIONARY_DATA, 2, "".join(dictionary).encode()),
+        (DATA, 3, b"".join(payloads)),
+        (LENGTH, 3, rle_v2([len(p) for p in payloads], signed=False)),
+        (PRESENT, 4, boolean_rle([int(s is not None) for s in scores])),
+        (DATA, 4, rle_v2([s for s in scores if s is not None], signed=True)),
+    ]
+    encodings = [
+        (DIRECT, 0),
+        (DIRECT_V2, 0),
+        (DICTIONARY_V2, len(dictionary)),
+        (DIRECT_V2, 0),
+        (DIRECT_V2, 0),
+    ]
+
+    data = b""
+    footer = b""
+    for kind, column, stream in streams:
+        stream = compress(stream)
+        data += stream
+        footer += field_bytes(
+            1,
+            field_varint(1, kind) + field_varint(2, column) + field_varint(3, len(stream)),
+        )
+    for kind, dictionary_size in encodings:
+        encoding = field_varint(1, kind)
+        if kind == DICTIONARY_V2:
+            encoding += field_varint(2, dictionary_size)
+        footer += field_bytes(2, encoding)
+    footer += field_bytes(3, b"UTC")
+    return data, compress(footer)
+
+
+def main():
+    rows = [row(i) for i in range(ROWS)]
+
+    body = bytearray(b"ORC")
+    stripes_info = b""
+    for start in range(0, ROWS, ROWS_PER_STRIPE):
+        stripe_rows = rows[start : start + ROWS_PER_STRIPE]
+        data, footer = stripe(stripe_rows)
+        stripes_info += field_bytes(
+            3,
+            field_varint(1, len(body))
+            + field_varint(2, 0)
+            + field_varint(3, len(data))
+            + field_varint(4, len(footer))
+            + field_varint(5, len(stripe_rows)),
+        )
+        body += data + footer
+
+    types = field_bytes(
+        4,
+        field_varint(1, STRUCT)
+        + field_packed(2, [1, 2, 3, 4])
+        + b"".join(field_bytes(3, n.encode()) for n in ["id", "name", "payload", "score"]),
+    )
+    for kind in [LONG, STRING, BINARY, INT]:
+        types += field_bytes(4, field_varint(1, kind))
+
+    footer = compress(
+        field_varint(1, 3)
+        + field_varint(2, len(body))
+        + stripes_info
+        + types
+        + field_varint(6, ROWS)
+        + field_varint(8, 0)
+    )
+    metadata = b""
+    postscript = (
+        field_varint(1, len(footer))
+        + field_varint(2, 1)  # ZLIB
+        + field_varint(3, COMPRESSION_BLOCK_SIZE)
+        + field_packed(4, [0, 12])
+        + field_varint(5, len(metadata))
+        + field_varint(6, 1)
+        + field_bytes(8000, b"ORC")
+    )
+    body += metadata + footer + postscript + bytes([len(postscript)])
+
+    path = os.path.join(os.path.dirname(os.path.realpath(__file__)), "types.orc")
+    with open(path, "wb") as f:
+        f.write(body)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,282 @@
+#!/usr/bin/env python3
+"""Print the rows of an ORC file like the TSV output format, used by the tests of unloading ORC files.
+
+The file is decoded by this script from the ORC v1 specification
+(https://orc.apache.org/specification/ORCv1/) with the python standard library
+only, independently of databend, so that the writer is tested against a reader
+it doesn't share code with.
+
+Only the types written by the tests are supported: a struct of boolean, tinyint,
+smallint, int, bigint, date and string columns, NONE or ZLIB compressed.
+
+Usage: read.py <file>
+"""
+
+import datetime
+import sys
+import zlib
+
+# type kinds
+BOOLEAN, BYTE, SHORT, INT, LONG, STRING, STRUCT, DATE = 0, 1, 2, 3, 4, 7, 12, 15
+# stream kinds
+PRESENT, DATA, LENGTH, DICTIONARY_DATA = 0, 1, 2, 3
+# column encodings
+DIRECT, DICTIONARY, DIRECT_V2, DICTIONARY_V2 = 0, 1, 2, 3
+# compression kinds
+NONE, ZLIB = 0, 1
+
+
+# protobuf
+
+
+def read_varint(data, pos):
+    value, shift = 0, 0
+    while True:
+        b = data[pos]
+        pos += 1
+        value |= (b & 0x7F) << shift
+        shift += 7
+        if not b & 0x80:
+            return value, pos
+
+
+def parse_message(data):
+    """Returns the fields of a message as {tag: [value]}, values are ints or bytes."""
+    fields = {}
+    pos = 0
+    while pos < len(data):
+        key, pos = read_varint(data, pos)
+        tag, wire_type = key >> 3, key & 7
+        if wire_type == 0:
+            value, pos = read_varint(data, pos)
+        elif wire_type == 2:
+            length, pos = read_varint(data, pos)
+            value = data[pos : pos + length]
+            pos += length
+        else:
+            raise ValueError(f"unsupported wire type {wire_type}")
+        fields.setdefault(tag, []).append(value)
+    return fields
+
+
+def field(message, tag, default=0):
+    return message.get(tag, [default])[0]
+
+
+def packed(message, tag):
+    values = []
+    for data in message.get(tag, []):
+        if isinstance(data, int):
+            values.append(data)
+            continue
+        pos = 0
+        while pos < len(data):
+            value, pos = read_varint(data, pos)
+            values.append(value)
+    return values
+
+
+# compression
+
+
+def decompress(data, compression):
+    if compression == NONE:
+        return data
+    if compression != ZLIB:
+        raise ValueError(f"unsupported compression {compression}")
+    out = bytearray()
+    pos = 0
+    while pos < len(data):
+        header = int.from_bytes(data[pos : pos + 3], "little")
+        pos += 3
+        length = header >> 1
+        chunk = data[pos : pos + length]
+        pos += length
+        out += chunk if header & 1 else zlib.decompress(chunk, -15)
+    return bytes(out)
+
+
+# run length encoding
+
+
+RLE_V2_WIDTHS = list(range(1, 25)) + [26, 28, 30, 32, 40, 48, 56, 64]
+
+
+def unzigzag(v):
+    return (v >> 1) ^ -(v & 1)
+
+
+def unpack(data, pos, width, count):
+    nbytes = (width * count + 7) // 8
+    bits = int.from_bytes(data[pos : pos + nbytes], "big") >> (nbytes * 8 - width * count)
+    mask = (1 << width) - 1
+    values = [(bits >> (width * (count - 1 - i))) & mask for i in range(count)]
+    return values, pos + nbytes
+
+
+def rle_v2(data, count, signed):
+    values = []
+    pos = 0
+    while len(values) < count:
+        header = data[pos]
+        encoding = header >> 6
+        if encoding == 0:  # SHORT_REPEAT
+            width = ((header >> 3) & 7) + 1
+            repeat = (header & 7) + 3
+            value = int.from_bytes(data[pos + 1 : pos + 1 + width], "big")
+            pos += 1 + width
+            values += [unzigzag(value) if signed else value] * repeat
+        elif encoding == 1:  # DIRECT
+            width = RLE_V2_WIDTHS[(header >> 1) & 31]
+            length = ((header & 1) << 8 | data[pos + 1]) + 1
+            run, pos = unpack(data, pos + 2, width, length)
+            values += [unzigzag(v) if signed else v for v in run]
+        elif encoding == 3:  # DELTA
+            code = (header >> 1) & 31
+            width = 0 if code == 0 else RLE_V2_WIDTHS[code]
+            length = ((header & 1) << 8 | data[pos + 1]) + 1
+            base, pos = read_varint(data, pos + 2)
+            base = unzigzag(base) if signed else base
+            delta, pos = read_varint(data, pos)
+            delta = unzigzag(delta)
+            run = [base, base + delta]
+            if width == 0:
+                run += [base + delta * i for i in range(2, length)]
+            else:
+                deltas, pos = unpack(data, pos, width, length - 2)
+                for d in deltas:
+                    run.append(run[-1] + d if delta >= 0 else run[-1] - d)
+            values += run[:length]
+        else:
+            raise ValueError("unsupported PATCHED_BASE run")
+    return values[:count]
+
+
+def byte_rle(data, count):
+    values = []
+    pos = 0
+    while len(values) < count:
+        header = data[pos]
+        if header < 128:
+            values += [data[pos + 1]] * (header + 3)
+            pos += 2
+        else:
+            length = 256 - header
+            values += data[pos + 1 : pos + 1 + length]
+            pos += 1 + length
+    return values[:count]
+
+
+def booleans(data, count):
+    bits = []
+    for b in byte_rle(data, (count + 7) // 8):
+        bits += [(b >> (7 - i)) & 1 for i in range(8)]
+    return bits[:count]
+
+
+# file
+
+
+class Stripe:
+    def __init__(self, data, info, compression):
+        offset = field(info, 1)
+        index_length = field(info, 2)
+        data_length = field(info, 3)
+        footer_length = field(info, 4)
+        self.num_rows = field(info, 5)
+
+        footer_start = offset + index_length + data_length
+        footer = parse_message(
+            decompress(data[footer_start : footer_start + footer_length], compression)
+        )
+        self.streams = {}
+        pos = offset
+        for stream in footer.get(1, []):
+            stream = parse_message(stream)
+            kind, column, length = field(stream, 1), field(stream, 2), field(stream, 3)
+            self.streams[(column, kind)] = decompress(data[pos : pos + length], compression)
+            pos += length
+        self.encodings = [parse_message(e) for e in footer.get(2, [])]
+
+    def read_column(self, types, column, count):
+        present = self.streams.get((column, PRESENT))
+        valid = booleans(present, count) if present is not None else [1] * count
+        values = iter(self.read_values(types, column, sum(valid)))
+        return [next(values) if v else None for v in valid]
+
+    def read_values(self, types, column, count):
+        kind = field(types[column], 1)
+        encoding = field(self.encodings[column], 1)
+        data = self.streams.get((column, DATA), b"")
+        if kind == BOOLEAN:
+            return [bool(v) for v in booleans(data, count)]
+        if kind == BYTE:
+            return [v - 256 if v >= 128 else v for v in byte_rle(data, count)]
+        if kind in (SHORT, INT, LONG, DATE):
+            if encoding != DIRECT_V2:
+                raise ValueError(f"unsupported encoding {encoding} of column {column}")
+            return rle_v2(data, count, signed=True)
+        if kind == STRING:
+            if encoding == DIRECT_V2:
+                strings = data
+                lengths = rle_v2(self.streams[(column, LENGTH)], count, signed=False)
+                indexes = range(count)
+            elif encoding == DICTIONARY_V2:
+                size = field(self.encodings[column], 2)
+                strings = self.streams[(column, DICTIONARY_DATA)]
+                lengths = rle_v2(self.streams[(column, LENGTH)], size, signed=False)
+                indexes = rle_v2(data, count, signed=False)
+            else:
+                raise ValueError(f"unsupported encoding {encoding} of column {column}")
+            dictionary = []
+            pos = 0
+            for length in lengths:
+                dictionary.append(strings[pos : pos + length].decode())
+                pos += length
+            return [dictionary[i] for i in indexes]
+        raise ValueError(f"unsupported type {kind} of column {column}")
+
+
+def format_value(kind, value):
+    if value is None:
+        return "\\N"
+    if kind == BOOLEAN:
+        return "1" if value else "0"
+    if kind == DATE:
+        return str(datetime.date(1970, 1, 1) + datetime.timedelta(days=value))
+    return str(value)
+
+
+def main():
+    with open(sys.argv[1], "rb") as f:
+        data = f.read()
+    if data[:3] != b"ORC":
+        raise ValueError("not an ORC file")
+
+    postscript_length = data[-1]
+    postscript = parse_message(data[-1 - postscript_length : -1])
+    compression = field(postscript, 2)
+    footer_length = field(postscript, 1)
+    footer_end = len(data) - 1 - postscript_length
+    footer = parse_message(decompress(data[footer_end - footer_length : footer_end], compression))
+
+    types = [parse_message(t) for t in footer.get(4, [])]
+    root = types[0]
+    if field(root, 1) != STRUCT:
+        raise ValueError("the root type is not a struct")
+    columns = packed(root, 2)
+
+    num_rows = 0
+    for info in footer.get(3, []):
+        stripe = Stripe(data, parse_message(info), compression)
+        values = [stripe.read_column(types, c, stripe.num_rows) for c in columns]
+        kinds = [field(types[c], 1) for c in columns]
+        for row in zip(*values):
+            print("\t".join(format_value(k, v) for k, v in zip(kinds, row)))
+        num_rows += stripe.num_rows
+    if num_rows != field(footer, 6):
+        raise ValueError(f"{num_rows} rows in stripes, {field(footer, 6)} in the footer")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user and table
+echo "drop user if exists 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0014(a int, b int, c int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(1, 2, 3)" | $MYSQL_CLIENT_CONNECT
+
+## grant column privileges
+echo "GRANT SELECT(a, b) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "show grants for 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## granted columns
+echo "select 'test -- granted columns'" | $TEST_USER_CONNECT
+echo "select a, b from t20_0014" | $TEST_USER_CONNECT
+echo "select a from t20_0014 where b = 2" | $TEST_USER_CONNECT
+
+## other columns
+echo "select 'test -- other columns'" | $TEST_USER_CONNECT
+echo "select c from t20_0014" | $TEST_USER_CONNECT
+echo "select * from t20_0014" | $TEST_USER_CONNECT
+echo "select a from t20_0014 where c = 3" | $TEST_USER_CONNECT
+
+## revoke column privileges
+echo "select 'test -- revoke'" | $TEST_USER_CONNECT
+echo "REVOKE SELECT(b) ON default.t20_0014 FROM 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select a, b from t20_0014" | $TEST_USER_CONNECT
+echo "select a from t20_0014" | $TEST_USER_CONNECT
+
+## column privileges on a missing column or a database
+echo "GRANT SELECT(d) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(a) ON default.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## table privilege covers all the columns
+echo "select 'test -- table privilege'" | $TEST_USER_CONNECT
+echo "GRANT SELECT ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select * from t20_0014" | $TEST_USER_CONNECT
+
+## Drop table.
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop user
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user, table and policy
+echo "drop user if exists 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop row access policy if exists rap_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0015(region string, amount int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0015 values('EU', 10), ('US', 20)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT, UPDATE, DELETE ON default.t20_0015 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create row access policy rap_20_0015 as (r string) returns boolean -> r = 'EU' or current_user() like '%root%'" | $MYSQL_CLIENT_CONNECT
+
+## attach the policy
+echo "select 'test -- attached'" | $MYSQL_CLIENT_CONNECT
+echo "alter table t20_0015 add row access policy rap_20_0015 on (region)" | $MYSQL_CLIENT_CONNECT
+echo "select * from t20_0015 order by region" | $MYSQL_CLIENT_CONNECT
+echo "select * from t20_0015 order by region" | $TEST_USER_CONNECT
+echo "select sum(amount) from t20_0015 where amount > 0" | $TEST_USER_CONNECT
+
+## dml only touches the rows the policy lets the user see
+echo "select 'test -- dml'" | $MYSQL_CLIENT_CONNECT
+echo "update t20_0015 set amount = amount + 1" | $TEST_USER_CONNECT
+echo "select * from t20_0015 order by region" | $MYSQL_CLIENT_CONNECT
+echo "delete from t20_0015 where amount > 0" | $TEST_USER_CONNECT
+echo "select * from t20_0015 order by region" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t20_0015" | $TEST_USER_CONNECT
+
+## invalid attachments
+echo "select 'test -- invalid'" | $MYSQL_CLIENT_CONNECT
+echo "alter table t20_0015 add row access policy rap_20_0015 on (region)" | $MYSQL_CLIENT_CONNECT
+echo "alter table t20_0015 drop row access policy rap_missing" | $MYSQL_CLIENT_CONNECT
+echo "drop row access policy rap_20_0015" | $TEST_USER_CONNECT
+
+## detach the policy
+echo "select 'test -- detached'" | $MYSQL_CLIENT_CONNECT
+echo "alter table t20_0015 drop row access policy rap_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "select * from t20_0015 order by region" | $TEST_USER_CONNECT
+
+## Drop table, policy and user
+echo "drop table default.t20_0015 all" | $MYSQL_CLIENT_CONNECT
+echo "drop row access policy rap_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
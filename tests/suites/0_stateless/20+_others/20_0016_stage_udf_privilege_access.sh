@@ -0,0 +1,70 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user, table, stage and udf
+echo "drop user if exists 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop function if exists f20_0016" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0016(a int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0016 values(1), (2)" | $MYSQL_CLIENT_CONNECT
+echo "create stage s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "copy into @s20_0016 from t20_0016 file_format = (type = CSV)" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "create function f20_0016 as (a) -> a + 1" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT, INSERT ON default.t20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON system.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## without privileges on the stage and the udf
+echo "select 'test -- not granted'" | $TEST_USER_CONNECT
+echo "select count(*) from list_stage(location => '@s20_0016')" | $TEST_USER_CONNECT
+echo "copy into t20_0016 from @s20_0016 file_format = (type = CSV)" | $TEST_USER_CONNECT
+echo "presign upload @s20_0016/a.csv" | $TEST_USER_CONNECT
+echo "select f20_0016(1)" | $TEST_USER_CONNECT
+
+## grant privileges on the stage and the udf
+echo "select 'test -- granted'" | $TEST_USER_CONNECT
+echo "GRANT READ ON STAGE s20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT USAGE ON UDF f20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "show grants for 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from list_stage(location => '@s20_0016')" | $TEST_USER_CONNECT
+echo "copy into t20_0016 from @s20_0016 file_format = (type = CSV)" | $TEST_USER_CONNECT > /dev/null
+echo "select count(*) from t20_0016" | $TEST_USER_CONNECT
+echo "presign upload @s20_0016/a.csv" | $TEST_USER_CONNECT
+echo "select f20_0016(1)" | $TEST_USER_CONNECT
+
+## invalid grants
+echo "select 'test -- invalid'" | $TEST_USER_CONNECT
+echo "GRANT SELECT ON STAGE s20_0016 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT USAGE ON UDF f20_0016_missing TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## dropping the stage and the udf revokes the privileges on them
+echo "select 'test -- dropped'" | $TEST_USER_CONNECT
+echo "drop stage s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop function f20_0016" | $MYSQL_CLIENT_CONNECT
+echo "create stage s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "create function f20_0016 as (a) -> a + 1" | $MYSQL_CLIENT_CONNECT
+echo "show grants for 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from list_stage(location => '@s20_0016')" | $TEST_USER_CONNECT
+echo "select f20_0016(1)" | $TEST_USER_CONNECT
+
+## the creator of a stage needs no privileges on it
+echo "select 'test -- creator'" | $TEST_USER_CONNECT
+echo "GRANT SUPER ON *.* TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create stage s20_0016_own" | $TEST_USER_CONNECT
+echo "copy into @s20_0016_own from t20_0016 file_format = (type = CSV)" | $TEST_USER_CONNECT > /dev/null
+echo "select count(*) from list_stage(location => '@s20_0016_own')" | $TEST_USER_CONNECT
+echo "select count(*) from list_stage(location => '@s20_0016')" | $TEST_USER_CONNECT
+
+## Drop table, stage, udf and user
+echo "drop table default.t20_0016 all" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s20_0016_own" | $MYSQL_CLIENT_CONNECT
+echo "drop function f20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
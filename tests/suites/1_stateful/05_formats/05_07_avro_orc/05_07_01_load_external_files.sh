@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../../data/)
+
+# quickstop-null.avro is written by the Java implementation of Apache Avro,
+# taken from the benchmarks of the Apache Avro Rust SDK.
+echo "drop table if exists test_avro" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_avro (id BIGINT, first VARCHAR, last VARCHAR, phone VARCHAR, age INT)" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_avro from 'fs://${DATADIR}/avro/quickstop-null.avro' FILE_FORMAT = (type = AVRO)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id), sum(age), count(distinct first) from test_avro" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_avro order by id limit 3" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_avro" | $MYSQL_CLIENT_CONNECT
+
+# types.orc is written by tests/data/orc/generate.py, see the script for its layout.
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_orc (id BIGINT, name VARCHAR, payload BINARY, score INT NULL)" | $MYSQL_CLIENT_CONNECT
+echo "copy into test_orc from 'fs://${DATADIR}/orc/types.orc' FILE_FORMAT = (type = ORC)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id), count(score), sum(score), count(distinct name) from test_orc" | $MYSQL_CLIENT_CONNECT
+echo "select id, name, to_hex(payload), length(payload), score from test_orc where id in (1, 7, 1000, 1001, 3000) order by id" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_orc" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+# Should be <root>/tests/data/
+DATADIR=$(realpath $CURDIR/../../../../data/)
+
+echo "drop table if exists test_unload_orc" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_unload_orc (a BOOLEAN, b TINYINT NULL, c SMALLINT, d INT NULL, e BIGINT, f DATE, g VARCHAR NULL)" | $MYSQL_CLIENT_CONNECT
+echo "insert into test_unload_orc select number % 3 = 0, if(number % 5 = 0, NULL, number % 256 - 128), number % 7, if(number % 11 = 0, NULL, number * 100), number * number - 50000, to_date(number % 1000), if(number % 13 = 0, NULL, concat('name_', to_string(number % 17))) from numbers(100000)" | $MYSQL_CLIENT_CONNECT
+
+curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_unload_orc order by e FORMAT ORC" > /tmp/test_unload_orc.orc
+curl -s -u root: -XPOST "http://localhost:${QUERY_CLICKHOUSE_HTTP_HANDLER_PORT}" \
+	-d "select * from test_unload_orc order by e FORMAT TSV" > /tmp/test_unload_orc.tsv
+
+# the ORC file is decoded by tests/data/orc/read.py, which doesn't share code with databend
+python3 "${DATADIR}/orc/read.py" /tmp/test_unload_orc.orc > /tmp/test_unload_orc_read.tsv
+wc -l < /tmp/test_unload_orc_read.tsv
+diff /tmp/test_unload_orc_read.tsv /tmp/test_unload_orc.tsv && echo "same as TSV"
+
+rm /tmp/test_unload_orc.orc /tmp/test_unload_orc.tsv /tmp/test_unload_orc_read.tsv
+echo "drop table test_unload_orc" | $MYSQL_CLIENT_CONNECT
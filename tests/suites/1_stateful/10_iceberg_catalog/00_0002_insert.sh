@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS iceberg_ctl" | $MYSQL_CLIENT_CONNECT
+
+## Create iceberg catalog
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='s3://testbucket/iceberg_data/iceberg_ctl/'
+    AWS_KEY_ID='minioadmin'
+    AWS_SECRET_KEY='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+);
+EOF
+
+echo "CREATE DATABASE IF NOT EXISTS iceberg_ctl.iceberg_write_db;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE iceberg_ctl.iceberg_write_db.t1 (id INT NOT NULL, data STRING NULL);" | $MYSQL_CLIENT_CONNECT
+
+echo "INSERT INTO iceberg_ctl.iceberg_write_db.t1 VALUES (1, 'a'), (2, NULL);" | $MYSQL_CLIENT_CONNECT
+echo "INSERT INTO iceberg_ctl.iceberg_write_db.t1 VALUES (3, 'c');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_ctl.iceberg_write_db.t1 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+## data files written by inserts are pruned by the bounds of id
+echo "SELECT id, data FROM iceberg_ctl.iceberg_write_db.t1 WHERE id > 2;" | $MYSQL_CLIENT_CONNECT
+
+echo "INSERT OVERWRITE iceberg_ctl.iceberg_write_db.t1 VALUES (4, 'd');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_ctl.iceberg_write_db.t1 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "TRUNCATE TABLE iceberg_ctl.iceberg_write_db.t1;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_write_db.t1;" | $MYSQL_CLIENT_CONNECT
+
+## the files of a renamed table are kept in the directory it's created in
+echo "INSERT INTO iceberg_ctl.iceberg_write_db.t1 VALUES (5, 'e');" | $MYSQL_CLIENT_CONNECT
+echo "ALTER TABLE iceberg_ctl.iceberg_write_db.t1 RENAME TO t2;" | $MYSQL_CLIENT_CONNECT
+echo "SHOW TABLES IN iceberg_ctl.iceberg_write_db;" | $MYSQL_CLIENT_CONNECT
+echo "INSERT INTO iceberg_ctl.iceberg_write_db.t2 VALUES (6, 'f');" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_ctl.iceberg_write_db.t2 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "ALTER TABLE iceberg_ctl.iceberg_write_db.t2 RENAME TO t1;" | $MYSQL_CLIENT_CONNECT
+echo "SHOW TABLES IN iceberg_ctl.iceberg_write_db;" | $MYSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_ctl.iceberg_write_db.t1 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "ALTER TABLE iceberg_ctl.iceberg_write_db.t1 RENAME TO t2;" | $MYSQL_CLIENT_CONNECT
+echo "DROP TABLE iceberg_ctl.iceberg_write_db.t2;" | $MYSQL_CLIENT_CONNECT
+echo "SHOW TABLES IN iceberg_ctl.iceberg_write_db;" | $MYSQL_CLIENT_CONNECT
+echo "DROP DATABASE iceberg_ctl.iceberg_write_db;" | $MYSQL_CLIENT_CONNECT
+echo "SHOW DATABASES IN iceberg_ctl;" | $MYSQL_CLIENT_CONNECT